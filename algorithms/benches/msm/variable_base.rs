@@ -17,7 +17,10 @@
 use criterion::Criterion;
 use rand::SeedableRng;
 use rand_xorshift::XorShiftRng;
-use snarkvm_algorithms::msm::*;
+use snarkvm_algorithms::msm::{
+    variable_base::{batched::msm_batched, standard::msm_standard},
+    *,
+};
 use snarkvm_curves::{
     bls12_377::{Fr, G1Projective},
     traits::ProjectiveCurve,
@@ -38,11 +41,30 @@ fn variable_base(c: &mut Criterion) {
         .map(|_| G1Projective::rand(&mut rng).into_affine())
         .collect::<Vec<_>>();
 
-    c.bench_function("MSM Variable Base", move |b| {
+    c.bench_function("MSM Variable Base", |b| {
         b.iter(|| {
             VariableBaseMSM::multi_scalar_mul(g.as_slice(), v.as_slice());
         })
     });
+
+    c.bench_function("MSM Variable Base Standard", |b| {
+        b.iter(|| {
+            msm_standard(g.as_slice(), v.as_slice());
+        })
+    });
+
+    c.bench_function("MSM Variable Base Batched", |b| {
+        b.iter(|| {
+            msm_batched(g.as_slice(), v.as_slice());
+        })
+    });
+
+    let precomputed = PrecomputedBases::new(g.as_slice());
+    c.bench_function("MSM Variable Base Precomputed", move |b| {
+        b.iter(|| {
+            precomputed.multi_scalar_mul(v.as_slice());
+        })
+    });
 }
 
 criterion_group! {
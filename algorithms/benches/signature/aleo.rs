@@ -74,6 +74,36 @@ fn aleo_signature_verify(c: &mut Criterion) {
     });
 }
 
+fn aleo_signature_batch_verify(c: &mut Criterion) {
+    const NUM_SIGNATURES: usize = 128;
+
+    let rng = &mut thread_rng();
+    let parameters = SignatureScheme::setup("aleo_signature_batch_verify");
+    let message = [100u8; 128];
+    let batch: Vec<_> = (0..NUM_SIGNATURES)
+        .map(|_| {
+            let private_key = SignatureScheme::generate_private_key(&parameters, rng);
+            let public_key = SignatureScheme::generate_public_key(&parameters, &private_key);
+            let signature = SignatureScheme::sign(&parameters, &private_key, &message, rng).unwrap();
+            (public_key, message, signature)
+        })
+        .collect();
+
+    c.bench_function("Aleo Signature Verify 128 Sequentially", |b| {
+        b.iter(|| {
+            batch
+                .iter()
+                .all(|(public_key, message, signature)| {
+                    SignatureScheme::verify(&parameters, public_key, message, signature).unwrap()
+                })
+        })
+    });
+
+    c.bench_function("Aleo Signature Batch Verify 128", move |b| {
+        b.iter(|| parameters.batch_verify(&batch, rng).unwrap())
+    });
+}
+
 criterion_group! {
     name = aleo_signature;
     config = Criterion::default().sample_size(20);
@@ -82,5 +112,6 @@ criterion_group! {
                 aleo_signature_generate_public_key,
                 aleo_signature_sign,
                 aleo_signature_verify,
+                aleo_signature_batch_verify,
 }
 criterion_main!(aleo_signature);
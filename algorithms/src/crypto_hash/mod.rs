@@ -20,11 +20,17 @@ pub use blake2xs::*;
 pub mod grain_lfsr;
 pub use grain_lfsr::*;
 
+pub mod parameters_generator;
+pub use parameters_generator::*;
+
 pub mod sponge;
 pub use sponge::*;
 
 pub mod poseidon;
 pub use poseidon::*;
 
+pub mod rescue;
+pub use rescue::*;
+
 #[cfg(test)]
 pub mod tests;
@@ -0,0 +1,387 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::crypto_hash::{find_poseidon_ark_and_mds, PoseidonGrainLFSR, PoseidonParameters, RescueParameters};
+use snarkvm_fields::{FieldParameters, PrimeField};
+
+/// The maximum number of MDS matrices to sample from the Grain LFSR before giving up.
+const MAX_MDS_SAMPLES: u64 = 256;
+
+/// Returns Poseidon parameters for the given rate and S-box `x^alpha`, targeting `security_level` bits of security.
+///
+/// The round numbers are computed with `find_poseidon_round_numbers`, and the round constants and
+/// MDS matrix are derived from the Grain LFSR as in `find_poseidon_ark_and_mds`, skipping any
+/// MDS matrix that does not pass `is_secure_mds_matrix`. For the entries of `PoseidonDefaultParameters`,
+/// this reproduces the default parameters.
+///
+/// Returns `None` if `x^alpha` is not a permutation of the field.
+pub fn generate_poseidon_parameters<F: PrimeField>(
+    rate: usize,
+    alpha: u64,
+    security_level: u64,
+) -> Option<PoseidonParameters<F>> {
+    if rate == 0 || !is_valid_sbox_exponent::<F>(alpha) {
+        return None;
+    }
+
+    let prime_bits = F::Parameters::MODULUS_BITS as u64;
+    let (full_rounds, partial_rounds) = find_poseidon_round_numbers(prime_bits, rate, alpha, security_level)?;
+
+    for skip_matrices in 0..MAX_MDS_SAMPLES {
+        let (ark, mds) = find_poseidon_ark_and_mds::<F>(
+            prime_bits,
+            rate,
+            full_rounds as u64,
+            partial_rounds as u64,
+            skip_matrices,
+        );
+        if is_secure_mds_matrix(&mds) {
+            return Some(PoseidonParameters::new(
+                full_rounds,
+                partial_rounds,
+                alpha,
+                mds,
+                ark,
+                rate,
+                1,
+            ));
+        }
+    }
+
+    None
+}
+
+/// Returns the number of full and partial rounds of a Poseidon permutation over a prime field of
+/// `prime_bits` bits, with the given rate, a capacity of one, and S-box `x^alpha`.
+///
+/// This follows the round numbers calculation of the [reference implementation](https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/calc_round_numbers.py),
+/// covering the statistical, interpolation and Gröbner basis attacks for `security_level` bits of security.
+/// Among the round numbers that resist these attacks, it selects those minimizing the number of S-boxes,
+/// after adding the recommended security margin of two full rounds and 7.5% more partial rounds.
+pub fn find_poseidon_round_numbers(
+    prime_bits: u64,
+    rate: usize,
+    alpha: u64,
+    security_level: u64,
+) -> Option<(usize, usize)> {
+    if rate == 0 || alpha < 3 {
+        return None;
+    }
+
+    let state_len = rate + 1;
+    let t = state_len as f64;
+    let n = prime_bits as f64;
+    let m = security_level as f64;
+    let log_alpha = |x: f64| x.ln() / (alpha as f64).ln();
+
+    let is_secure = |full_rounds: usize, partial_rounds: usize| {
+        let (full_rounds, partial_rounds) = (full_rounds as f64, partial_rounds as f64);
+
+        // Statistical attacks
+        let statistical = if m <= (n - (alpha as f64 - 1.0) / 2.0).floor() * (t + 1.0) {
+            6.0
+        } else {
+            10.0
+        };
+        // Interpolation attacks
+        let interpolation = 1.0 + (log_alpha(2.0) * m.min(n)).ceil() + log_alpha(t).ceil() - partial_rounds;
+        // Gröbner basis attacks
+        let groebner_1 = log_alpha(2.0) * m.min(n) - partial_rounds;
+        let groebner_2 = t - 1.0 + log_alpha(2.0) * (m / (t + 1.0)).min(n / 2.0) - partial_rounds;
+        let groebner_3 = (t - 2.0 + m / (2.0 * (alpha as f64).log2()) - partial_rounds) / (t - 1.0);
+
+        [statistical, interpolation, groebner_1, groebner_2, groebner_3]
+            .iter()
+            .all(|bound| full_rounds >= bound.ceil())
+    };
+
+    let mut result = None;
+    let mut min_cost = usize::MAX;
+    for partial_rounds in 1..500 {
+        for full_rounds in (4..100).step_by(2) {
+            if !is_secure(full_rounds, partial_rounds) {
+                continue;
+            }
+
+            // Add the security margin.
+            let full_rounds = full_rounds + 2;
+            let partial_rounds = (partial_rounds as f64 * 1.075).ceil() as usize;
+
+            // The cost is the number of S-boxes.
+            let cost = state_len * full_rounds + partial_rounds;
+            if cost < min_cost || (cost == min_cost && result.map_or(false, |(rf, _)| full_rounds < rf)) {
+                result = Some((full_rounds, partial_rounds));
+                min_cost = cost;
+            }
+            break;
+        }
+    }
+
+    result
+}
+
+/// Returns Rescue-Prime parameters for the given rate and S-box `x^alpha`, targeting `security_level` bits of security.
+///
+/// The round constants and MDS matrix are derived from the Grain LFSR, initialized for an inverse S-box,
+/// in the same manner as for Poseidon. Note that this differs from the SHAKE-based constants of the
+/// [Rescue-Prime specification](https://eprint.iacr.org/2020/1143).
+///
+/// Returns `None` if `x^alpha` is not a permutation of the field.
+pub fn generate_rescue_parameters<F: PrimeField>(
+    rate: usize,
+    alpha: u64,
+    security_level: u64,
+) -> Option<RescueParameters<F>> {
+    if rate == 0 || !is_valid_sbox_exponent::<F>(alpha) {
+        return None;
+    }
+
+    let prime_bits = F::Parameters::MODULUS_BITS as u64;
+    let state_len = rate + 1;
+    let rounds = find_rescue_round_numbers(rate, 1, alpha, security_level)?;
+
+    let mut lfsr = PoseidonGrainLFSR::new(true, prime_bits, state_len as u64, rounds as u64, 0);
+
+    // Each round adds round constants after both the forward and the inverse S-box layers.
+    let mut ark = Vec::<Vec<F>>::with_capacity(2 * rounds);
+    for _ in 0..(2 * rounds) {
+        ark.push(lfsr.get_field_elements_rejection_sampling(state_len));
+    }
+
+    for _ in 0..MAX_MDS_SAMPLES {
+        let xs = lfsr.get_field_elements_mod_p::<F>(state_len);
+        let ys = lfsr.get_field_elements_mod_p::<F>(state_len);
+
+        let mds = xs
+            .iter()
+            .map(|x| ys.iter().map(|y| (*x + y).inverse()).collect::<Option<Vec<_>>>())
+            .collect::<Option<Vec<_>>>();
+
+        if let Some(mds) = mds {
+            if is_secure_mds_matrix(&mds) {
+                return Some(RescueParameters::new(rounds, alpha, mds, ark, rate, 1));
+            }
+        }
+    }
+
+    None
+}
+
+/// Returns the number of rounds of a Rescue-Prime permutation with the given rate, capacity,
+/// and S-box `x^alpha`, targeting `security_level` bits of security.
+///
+/// This follows the [Rescue-Prime specification](https://eprint.iacr.org/2020/1143), which bounds the
+/// cost of a Gröbner basis attack, enforces a minimum of 5 rounds, and adds a 50% security margin.
+pub fn find_rescue_round_numbers(rate: usize, capacity: usize, alpha: u64, security_level: u64) -> Option<usize> {
+    if rate == 0 || capacity == 0 || alpha < 3 {
+        return None;
+    }
+
+    let state_len = rate + capacity;
+
+    // Returns the base-2 logarithm of the binomial coefficient `n` choose `k`.
+    let log2_binomial = |n: usize, k: usize| {
+        let k = k.min(n - k);
+        (0..k)
+            .map(|i| ((n - i) as f64).log2() - ((i + 1) as f64).log2())
+            .sum::<f64>()
+    };
+
+    for rounds in 1..=25 {
+        let degree = ((alpha - 1) as usize * state_len * (rounds - 1)) / 2 + 2;
+        let variables = state_len * (rounds - 1) + rate;
+        if 2.0 * log2_binomial(variables + degree, variables) > security_level as f64 {
+            return Some((1.5 * rounds.max(5) as f64).ceil() as usize);
+        }
+    }
+
+    None
+}
+
+/// Returns `true` if the given square matrix is suitable as the linear layer of Poseidon or Rescue.
+///
+/// The matrix must be MDS, meaning every square submatrix is non-singular. In addition, for `r = 1, ..., 2t`,
+/// no non-trivial subspace on which the first state element is zero may be invariant under `M^r`,
+/// as such a subspace bypasses the S-box of the partial rounds in an invariant subspace trail
+/// (see [GRS20](https://eprint.iacr.org/2020/500)).
+pub fn is_secure_mds_matrix<F: PrimeField>(mds: &[Vec<F>]) -> bool {
+    let t = mds.len();
+    if t == 0 || t > 16 || mds.iter().any(|row| row.len() != t) {
+        return false;
+    }
+
+    // Check that every square submatrix is non-singular.
+    for rows in 1u32..(1 << t) {
+        for columns in 1u32..(1 << t) {
+            if rows.count_ones() != columns.count_ones() {
+                continue;
+            }
+
+            let submatrix = (0..t)
+                .filter(|i| rows & (1 << i) != 0)
+                .map(|i| {
+                    (0..t)
+                        .filter(|j| columns & (1 << j) != 0)
+                        .map(|j| mds[i][j])
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+
+            if determinant(submatrix).is_zero() {
+                return false;
+            }
+        }
+    }
+
+    // Check that no subspace on which the first state element is zero is invariant under `M^r`.
+    // The largest such subspace is the kernel of the matrix with rows `e_0, e_0 M^r, ..., e_0 M^{r(t-1)}`.
+    let mut power = mds.to_vec();
+    for _ in 0..(2 * t) {
+        let mut row = vec![F::zero(); t];
+        row[0] = F::one();
+
+        let mut observability_matrix = Vec::with_capacity(t);
+        for _ in 0..t {
+            let next_row = (0..t)
+                .map(|j| (0..t).fold(F::zero(), |sum, k| sum + row[k] * power[k][j]))
+                .collect();
+            observability_matrix.push(row);
+            row = next_row;
+        }
+
+        if determinant(observability_matrix).is_zero() {
+            return false;
+        }
+        power = matrix_mul(&power, mds);
+    }
+
+    true
+}
+
+/// Returns `true` if `x^alpha` is a permutation of the field, namely `alpha > 1` and `gcd(alpha, p - 1) = 1`.
+pub fn is_valid_sbox_exponent<F: PrimeField>(alpha: u64) -> bool {
+    alpha > 1 && gcd(alpha, limbs_rem(&modulus_minus_one::<F>(), alpha)) == 1
+}
+
+/// Returns the exponent of the inverse S-box, namely `alpha^{-1} mod (p - 1)`, as little-endian limbs.
+pub fn sbox_exponent_inverse<F: PrimeField>(alpha: u64) -> Option<Vec<u64>> {
+    if !is_valid_sbox_exponent::<F>(alpha) {
+        return None;
+    }
+
+    // Find `k` such that `k * (p - 1) + 1` is divisible by `alpha`, and return the quotient.
+    let modulus_minus_one = modulus_minus_one::<F>();
+    (1..alpha).find_map(|k| {
+        let mut numerator = limbs_mul_small(&modulus_minus_one, k);
+        limbs_add_small(&mut numerator, 1);
+        match limbs_rem(&numerator, alpha) == 0 {
+            true => Some(limbs_div_small(&numerator, alpha)),
+            false => None,
+        }
+    })
+}
+
+fn modulus_minus_one<F: PrimeField>() -> Vec<u64> {
+    // The modulus is odd, so subtracting one does not borrow.
+    let mut limbs = F::characteristic().to_vec();
+    limbs[0] -= 1;
+    limbs
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b),
+    }
+}
+
+fn limbs_rem(limbs: &[u64], divisor: u64) -> u64 {
+    limbs
+        .iter()
+        .rev()
+        .fold(0u128, |rem, limb| ((rem << 64) | *limb as u128) % divisor as u128) as u64
+}
+
+fn limbs_div_small(limbs: &[u64], divisor: u64) -> Vec<u64> {
+    let mut quotient = vec![0u64; limbs.len()];
+    let mut rem = 0u128;
+    for (i, limb) in limbs.iter().enumerate().rev() {
+        let current = (rem << 64) | *limb as u128;
+        quotient[i] = (current / divisor as u128) as u64;
+        rem = current % divisor as u128;
+    }
+    quotient
+}
+
+fn limbs_mul_small(limbs: &[u64], multiplier: u64) -> Vec<u64> {
+    let mut product = Vec::with_capacity(limbs.len() + 1);
+    let mut carry = 0u128;
+    for limb in limbs {
+        let current = *limb as u128 * multiplier as u128 + carry;
+        product.push(current as u64);
+        carry = current >> 64;
+    }
+    product.push(carry as u64);
+    product
+}
+
+fn limbs_add_small(limbs: &mut [u64], mut addend: u64) {
+    for limb in limbs.iter_mut() {
+        let (sum, overflow) = limb.overflowing_add(addend);
+        *limb = sum;
+        if !overflow {
+            return;
+        }
+        addend = 1;
+    }
+}
+
+fn matrix_mul<F: PrimeField>(a: &[Vec<F>], b: &[Vec<F>]) -> Vec<Vec<F>> {
+    (0..a.len())
+        .map(|i| {
+            (0..b[0].len())
+                .map(|j| (0..b.len()).fold(F::zero(), |sum, k| sum + a[i][k] * b[k][j]))
+                .collect()
+        })
+        .collect()
+}
+
+/// Returns the determinant of the given square matrix, using Gaussian elimination.
+fn determinant<F: PrimeField>(mut matrix: Vec<Vec<F>>) -> F {
+    let n = matrix.len();
+    let mut det = F::one();
+    for i in 0..n {
+        let pivot = match (i..n).find(|&row| !matrix[row][i].is_zero()) {
+            Some(pivot) => pivot,
+            None => return F::zero(),
+        };
+        if pivot != i {
+            matrix.swap(pivot, i);
+            det = -det;
+        }
+
+        det *= matrix[i][i];
+        let pivot_inverse = matrix[i][i].inverse().unwrap();
+        for row in (i + 1)..n {
+            let factor = matrix[row][i] * pivot_inverse;
+            for column in i..n {
+                let term = factor * matrix[i][column];
+                matrix[row][column] -= term;
+            }
+        }
+    }
+    det
+}
@@ -0,0 +1,343 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::crypto_hash::{sbox_exponent_inverse, CryptographicSponge, DuplexSpongeMode};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use std::{
+    io::{Read, Result as IoResult, Write},
+    sync::Arc,
+};
+
+/// Parameters for the Rescue-Prime permutation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RescueParameters<F: PrimeField> {
+    /// number of rounds, each consisting of a forward and an inverse S-box layer
+    pub rounds: usize,
+    /// Exponent used in the forward S-boxes
+    pub alpha: u64,
+    /// Exponent used in the inverse S-boxes, namely `alpha^{-1} mod (p - 1)`
+    pub alpha_inverse: Vec<u64>,
+    /// Additive Round keys. These are added after each MDS matrix application.
+    /// They are indexed by `ark[2 * round_num + step][state_element_index]`, where `step` is
+    /// zero for the forward S-box layer and one for the inverse S-box layer.
+    pub ark: Vec<Vec<F>>,
+    /// Maximally Distance Separating Matrix.
+    pub mds: Vec<Vec<F>>,
+    /// the rate (in terms of number of field elements)
+    pub rate: usize,
+    /// the capacity (in terms of number of field elements)
+    pub capacity: usize,
+}
+
+impl<F: PrimeField> RescueParameters<F> {
+    /// Initialize the parameter for Rescue Sponge.
+    pub fn new(rounds: usize, alpha: u64, mds: Vec<Vec<F>>, ark: Vec<Vec<F>>, rate: usize, capacity: usize) -> Self {
+        assert_eq!(ark.len(), 2 * rounds);
+        for item in &ark {
+            assert_eq!(item.len(), rate + capacity);
+        }
+        assert_eq!(mds.len(), rate + capacity);
+        for item in &mds {
+            assert_eq!(item.len(), rate + capacity);
+        }
+        let alpha_inverse = sbox_exponent_inverse::<F>(alpha).expect("The S-box must be a permutation");
+        Self {
+            rounds,
+            alpha,
+            alpha_inverse,
+            ark,
+            mds,
+            rate,
+            capacity,
+        }
+    }
+}
+
+impl<F: PrimeField> ToBytes for RescueParameters<F> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        (self.rounds as u32).write_le(&mut writer)?;
+        self.alpha.write_le(&mut writer)?;
+
+        (self.ark.len() as u32).write_le(&mut writer)?;
+        for fields in &self.ark {
+            (fields.len() as u32).write_le(&mut writer)?;
+            for field in fields {
+                field.write_le(&mut writer)?;
+            }
+        }
+
+        (self.mds.len() as u32).write_le(&mut writer)?;
+        for fields in &self.mds {
+            (fields.len() as u32).write_le(&mut writer)?;
+            for field in fields {
+                field.write_le(&mut writer)?;
+            }
+        }
+
+        (self.rate as u32).write_le(&mut writer)?;
+        (self.capacity as u32).write_le(&mut writer)
+    }
+}
+
+impl<F: PrimeField> FromBytes for RescueParameters<F> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let rounds: u32 = FromBytes::read_le(&mut reader)?;
+        let alpha: u64 = FromBytes::read_le(&mut reader)?;
+
+        // The lengths are untrusted, so the vectors are not preallocated.
+        let ark_length: u32 = FromBytes::read_le(&mut reader)?;
+        let mut ark = Vec::new();
+        for _ in 0..ark_length {
+            let num_fields: u32 = FromBytes::read_le(&mut reader)?;
+            let mut fields = Vec::new();
+
+            for _ in 0..num_fields {
+                let field: F = FromBytes::read_le(&mut reader)?;
+                fields.push(field);
+            }
+            ark.push(fields);
+        }
+
+        let mds_length: u32 = FromBytes::read_le(&mut reader)?;
+        let mut mds = Vec::new();
+        for _ in 0..mds_length {
+            let num_fields: u32 = FromBytes::read_le(&mut reader)?;
+            let mut fields = Vec::new();
+
+            for _ in 0..num_fields {
+                let field: F = FromBytes::read_le(&mut reader)?;
+                fields.push(field);
+            }
+            mds.push(fields);
+        }
+
+        let rate: u32 = FromBytes::read_le(&mut reader)?;
+        let capacity: u32 = FromBytes::read_le(&mut reader)?;
+
+        if sbox_exponent_inverse::<F>(alpha).is_none() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "Invalid Rescue S-box exponent",
+            ));
+        }
+
+        // Check the shapes that `Self::new` asserts, so malformed parameters return an error.
+        let width = rate as usize + capacity as usize;
+        if rate == 0
+            || ark.len() != 2 * rounds as usize
+            || mds.len() != width
+            || ark.iter().chain(mds.iter()).any(|fields| fields.len() != width)
+        {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "Invalid Rescue parameter dimensions",
+            ));
+        }
+
+        Ok(Self::new(
+            rounds as usize,
+            alpha,
+            mds,
+            ark,
+            rate as usize,
+            capacity as usize,
+        ))
+    }
+}
+
+/// A duplex sponge based using the Rescue-Prime permutation.
+///
+/// This follows the [Rescue-Prime specification](https://eprint.iacr.org/2020/1143), with the
+/// same duplex construction as `PoseidonSponge`.
+#[derive(Clone, Debug)]
+pub struct RescueSponge<F: PrimeField> {
+    // Sponge Parameters
+    pub parameters: Arc<RescueParameters<F>>,
+
+    // Sponge State
+    /// current sponge's state (current elements in the permutation block)
+    pub state: Vec<F>,
+    /// current mode (whether its absorbing or squeezing)
+    pub mode: DuplexSpongeMode,
+}
+
+impl<F: PrimeField> RescueSponge<F> {
+    fn apply_s_box(&self, state: &mut [F], exponent: &[u64]) {
+        for elem in state {
+            *elem = elem.pow(exponent);
+        }
+    }
+
+    fn apply_ark(&self, state: &mut [F], index: usize) {
+        for (i, state_elem) in state.iter_mut().enumerate() {
+            state_elem.add_assign(&self.parameters.ark[index][i]);
+        }
+    }
+
+    fn apply_mds(&self, state: &mut [F]) {
+        let mut new_state = Vec::new();
+        for i in 0..state.len() {
+            let mut cur = F::zero();
+            for (j, state_elem) in state.iter().enumerate() {
+                let term = state_elem.mul(&self.parameters.mds[i][j]);
+                cur.add_assign(&term);
+            }
+            new_state.push(cur);
+        }
+
+        state.clone_from_slice(&new_state[..state.len()])
+    }
+
+    fn permute(&mut self) {
+        let mut state = self.state.clone();
+        for i in 0..self.parameters.rounds {
+            self.apply_s_box(&mut state, &[self.parameters.alpha]);
+            self.apply_mds(&mut state);
+            self.apply_ark(&mut state, 2 * i);
+
+            self.apply_s_box(&mut state, &self.parameters.alpha_inverse);
+            self.apply_mds(&mut state);
+            self.apply_ark(&mut state, 2 * i + 1);
+        }
+        self.state = state;
+    }
+
+    // Absorbs everything in elements, this does not end in an absorbtion.
+    fn absorb_internal(&mut self, mut rate_start_index: usize, elements: &[F]) {
+        if elements.len() == 0 {
+            return;
+        }
+
+        let mut remaining_elements = elements;
+
+        loop {
+            // if we can finish in this call
+            if rate_start_index + remaining_elements.len() <= self.parameters.rate {
+                for (i, element) in remaining_elements.iter().enumerate() {
+                    self.state[self.parameters.capacity + i + rate_start_index] += element;
+                }
+                self.mode = DuplexSpongeMode::Absorbing {
+                    next_absorb_index: rate_start_index + remaining_elements.len(),
+                };
+
+                return;
+            }
+            // otherwise absorb (rate - rate_start_index) elements
+            let num_elements_absorbed = self.parameters.rate - rate_start_index;
+            for (i, element) in remaining_elements.iter().enumerate().take(num_elements_absorbed) {
+                self.state[self.parameters.capacity + i + rate_start_index] += element;
+            }
+            self.permute();
+            // the input elements got truncated by num elements absorbed
+            remaining_elements = &remaining_elements[num_elements_absorbed..];
+            rate_start_index = 0;
+        }
+    }
+
+    // Squeeze |output| many elements. This does not end in a squeeze
+    fn squeeze_internal(&mut self, mut rate_start_index: usize, output: &mut [F]) {
+        let mut output_remaining = output;
+        loop {
+            // if we can finish in this call
+            if rate_start_index + output_remaining.len() <= self.parameters.rate {
+                output_remaining.clone_from_slice(
+                    &self.state[self.parameters.capacity + rate_start_index
+                        ..(self.parameters.capacity + output_remaining.len() + rate_start_index)],
+                );
+                self.mode = DuplexSpongeMode::Squeezing {
+                    next_squeeze_index: rate_start_index + output_remaining.len(),
+                };
+                return;
+            }
+            // otherwise squeeze (rate - rate_start_index) elements
+            let num_elements_squeezed = self.parameters.rate - rate_start_index;
+            output_remaining[..num_elements_squeezed].clone_from_slice(
+                &self.state[self.parameters.capacity + rate_start_index
+                    ..(self.parameters.capacity + num_elements_squeezed + rate_start_index)],
+            );
+
+            // More elements remain to be squeezed, so permute.
+            self.permute();
+            // Repeat with updated output slices
+            output_remaining = &mut output_remaining[num_elements_squeezed..];
+            rate_start_index = 0;
+        }
+    }
+}
+
+impl<F: PrimeField> CryptographicSponge<F> for RescueSponge<F> {
+    type Parameters = Arc<RescueParameters<F>>;
+
+    fn new(parameters: &Self::Parameters) -> Self {
+        let state = vec![F::zero(); parameters.rate + parameters.capacity];
+        let mode = DuplexSpongeMode::Absorbing { next_absorb_index: 0 };
+
+        Self {
+            parameters: parameters.clone(),
+            state,
+            mode,
+        }
+    }
+
+    fn absorb(&mut self, input: &[F]) {
+        if input.len() == 0 {
+            return;
+        }
+
+        match self.mode {
+            DuplexSpongeMode::Absorbing { next_absorb_index } => {
+                let mut absorb_index = next_absorb_index;
+                if absorb_index == self.parameters.rate {
+                    self.permute();
+                    absorb_index = 0;
+                }
+                self.absorb_internal(absorb_index, input);
+            }
+            DuplexSpongeMode::Squeezing { next_squeeze_index: _ } => {
+                self.permute();
+                self.absorb_internal(0, input);
+            }
+        };
+    }
+
+    fn squeeze_field_elements(&mut self, num_elements: usize) -> Vec<F> {
+        if num_elements == 0 {
+            return vec![];
+        }
+
+        let mut squeezed_elems = vec![F::zero(); num_elements];
+        match self.mode {
+            DuplexSpongeMode::Absorbing { next_absorb_index: _ } => {
+                self.permute();
+                self.squeeze_internal(0, &mut squeezed_elems);
+            }
+            DuplexSpongeMode::Squeezing { next_squeeze_index } => {
+                let mut squeeze_index = next_squeeze_index;
+                if squeeze_index == self.parameters.rate {
+                    self.permute();
+                    squeeze_index = 0;
+                }
+                self.squeeze_internal(squeeze_index, &mut squeezed_elems);
+            }
+        };
+
+        squeezed_elems
+    }
+}
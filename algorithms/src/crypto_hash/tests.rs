@@ -14,9 +14,27 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::crypto_hash::{CryptographicSponge, PoseidonDefaultParametersField, PoseidonGrainLFSR, PoseidonSponge};
-use snarkvm_curves::bls12_377::Fr;
-use snarkvm_utilities::str::FromStr;
+use crate::crypto_hash::{
+    find_poseidon_round_numbers,
+    find_rescue_round_numbers,
+    generate_poseidon_parameters,
+    generate_rescue_parameters,
+    is_secure_mds_matrix,
+    is_valid_sbox_exponent,
+    CryptographicSponge,
+    PoseidonDefaultParametersField,
+    PoseidonGrainLFSR,
+    PoseidonSponge,
+    RescueParameters,
+    RescueSponge,
+};
+use snarkvm_curves::{
+    bls12_377::{Fq, FqParameters, Fr, FrParameters},
+    edwards_bls12::FrParameters as EdwardsBls12FrParameters,
+    edwards_bw6::FrParameters as EdwardsBw6FrParameters,
+};
+use snarkvm_fields::{Field, One, PoseidonDefaultParameters};
+use snarkvm_utilities::{str::FromStr, test_rng, FromBytes, ToBytes, UniformRand};
 
 use std::sync::Arc;
 
@@ -199,3 +217,149 @@ fn bls12_377_fr_poseidon_default_parameters_test() {
         Fr::from_str("2147366300731764725485276624951065964179916161151487340006324219449683366351").unwrap()
     );
 }
+
+#[test]
+fn test_poseidon_round_numbers_match_defaults() {
+    fn check_round_numbers<P: PoseidonDefaultParameters>() {
+        for entry in P::PARAMS_OPT_FOR_CONSTRAINTS
+            .iter()
+            .chain(P::PARAMS_OPT_FOR_WEIGHTS.iter())
+        {
+            assert_eq!(
+                Some((entry.full_rounds, entry.partial_rounds)),
+                find_poseidon_round_numbers(P::MODULUS_BITS as u64, entry.rate, entry.alpha as u64, 128),
+                "rate = {}, alpha = {}",
+                entry.rate,
+                entry.alpha
+            );
+        }
+    }
+
+    check_round_numbers::<FrParameters>();
+    check_round_numbers::<FqParameters>();
+    check_round_numbers::<EdwardsBls12FrParameters>();
+    check_round_numbers::<EdwardsBw6FrParameters>();
+}
+
+#[test]
+fn test_generate_poseidon_parameters_matches_defaults() {
+    for rate in 2..=6 {
+        assert_eq!(
+            Fr::get_default_poseidon_parameters(rate, false),
+            generate_poseidon_parameters::<Fr>(rate, 17, 128)
+        );
+        assert_eq!(
+            Fq::get_default_poseidon_parameters(rate, false),
+            generate_poseidon_parameters::<Fq>(
+                rate,
+                FqParameters::PARAMS_OPT_FOR_CONSTRAINTS[rate - 2].alpha as u64,
+                128
+            )
+        );
+    }
+}
+
+#[test]
+fn test_generate_poseidon_parameters_for_security_level() {
+    // Higher security levels require at least as many rounds.
+    let parameters_128 = generate_poseidon_parameters::<Fr>(2, 17, 128).unwrap();
+    let parameters_256 = generate_poseidon_parameters::<Fr>(2, 17, 256).unwrap();
+    assert!(parameters_256.partial_rounds > parameters_128.partial_rounds);
+    assert!(parameters_256.full_rounds >= parameters_128.full_rounds);
+    assert!(is_secure_mds_matrix(&parameters_256.mds));
+
+    // The S-box must be a permutation of the field.
+    assert!(!is_valid_sbox_exponent::<Fr>(2));
+    assert!(!is_valid_sbox_exponent::<Fr>(3));
+    assert!(is_valid_sbox_exponent::<Fr>(17));
+    assert!(generate_poseidon_parameters::<Fr>(2, 3, 128).is_none());
+}
+
+#[test]
+fn test_is_secure_mds_matrix() {
+    // A matrix with a singular 2x2 submatrix is not MDS.
+    let not_mds = vec![
+        vec![Fr::from(1u8), Fr::from(2u8), Fr::from(3u8)],
+        vec![Fr::from(2u8), Fr::from(4u8), Fr::from(5u8)],
+        vec![Fr::from(7u8), Fr::from(11u8), Fr::from(13u8)],
+    ];
+    assert!(!is_secure_mds_matrix(&not_mds));
+
+    // This matrix is MDS, but its square is a multiple of the identity, so every subspace is invariant.
+    let invariant = vec![vec![Fr::one(), Fr::one()], vec![Fr::one(), -Fr::one()]];
+    assert!(!is_secure_mds_matrix(&invariant));
+
+    let parameters = Fr::get_default_poseidon_parameters(2, false).unwrap();
+    assert!(is_secure_mds_matrix(&parameters.mds));
+}
+
+#[test]
+fn test_rescue_parameters() {
+    let rng = &mut test_rng();
+
+    assert_eq!(Some(9), find_rescue_round_numbers(2, 1, 17, 128));
+    assert!(generate_rescue_parameters::<Fr>(2, 3, 128).is_none());
+
+    let parameters = generate_rescue_parameters::<Fr>(2, 17, 128).unwrap();
+    assert_eq!(9, parameters.rounds);
+    assert!(is_secure_mds_matrix(&parameters.mds));
+
+    // The inverse S-box must invert the forward S-box.
+    for _ in 0..10 {
+        let element = Fr::rand(rng);
+        assert_eq!(element, element.pow(&[parameters.alpha]).pow(&parameters.alpha_inverse));
+    }
+
+    let candidate_parameters = RescueParameters::<Fr>::read_le(&parameters.to_bytes_le().unwrap()[..]).unwrap();
+    assert_eq!(parameters, candidate_parameters);
+
+    // Parameters with mismatched dimensions are rejected, instead of panicking.
+    let mut malformed_parameters = parameters.clone();
+    malformed_parameters.rounds += 1;
+    assert!(RescueParameters::<Fr>::read_le(&malformed_parameters.to_bytes_le().unwrap()[..]).is_err());
+
+    let mut malformed_parameters = parameters.clone();
+    malformed_parameters.ark[0].pop();
+    assert!(RescueParameters::<Fr>::read_le(&malformed_parameters.to_bytes_le().unwrap()[..]).is_err());
+
+    let mut malformed_parameters = parameters.clone();
+    malformed_parameters.mds.pop();
+    assert!(RescueParameters::<Fr>::read_le(&malformed_parameters.to_bytes_le().unwrap()[..]).is_err());
+
+    let mut malformed_parameters = parameters.clone();
+    malformed_parameters.capacity += 1;
+    assert!(RescueParameters::<Fr>::read_le(&malformed_parameters.to_bytes_le().unwrap()[..]).is_err());
+
+    // An untrusted length is not preallocated.
+    let mut bytes = parameters.to_bytes_le().unwrap();
+    bytes[12..16].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert!(RescueParameters::<Fr>::read_le(&bytes[..]).is_err());
+}
+
+#[test]
+fn test_rescue_sponge() {
+    let parameters = Arc::new(generate_rescue_parameters::<Fr>(2, 17, 128).unwrap());
+    let input = vec![Fr::from(0u8), Fr::from(1u8), Fr::from(2u8)];
+
+    let mut sponge = RescueSponge::<Fr>::new(&parameters);
+    sponge.absorb(&input);
+    let output = sponge.squeeze_field_elements(3);
+
+    // Absorbing in pieces, or squeezing in pieces, does not change the output.
+    let mut candidate_sponge = RescueSponge::<Fr>::new(&parameters);
+    candidate_sponge.absorb(&input[..1]);
+    candidate_sponge.absorb(&input[1..]);
+    let mut candidate_output = candidate_sponge.squeeze_field_elements(1);
+    candidate_output.extend(candidate_sponge.squeeze_field_elements(2));
+    assert_eq!(output, candidate_output);
+
+    // The output differs from that of Poseidon, and on a different input.
+    let mut poseidon_sponge =
+        PoseidonSponge::<Fr>::new(&Arc::new(Fr::get_default_poseidon_parameters(2, false).unwrap()));
+    poseidon_sponge.absorb(&input);
+    assert_ne!(output, poseidon_sponge.squeeze_field_elements(3));
+
+    let mut other_sponge = RescueSponge::<Fr>::new(&parameters);
+    other_sponge.absorb(&input[1..]);
+    assert_ne!(output, other_sponge.squeeze_field_elements(3));
+}
@@ -107,48 +107,33 @@ where
     poseidon_parameters: Arc<PoseidonParameters<TE::BaseField>>,
 }
 
-impl<TE: TwistedEdwardsParameters> EncryptionScheme for ECIESPoseidonEncryption<TE>
+/// The format version prefixed to authenticated ciphertexts.
+pub const ECIES_POSEIDON_AUTHENTICATED_CIPHERTEXT_VERSION: u8 = 1;
+
+pub const ECIES_POSEIDON_MAC_DOMAIN: &str = "AleoECIESPoseidonMAC0";
+
+impl<TE: TwistedEdwardsParameters> ECIESPoseidonEncryption<TE>
 where
     TE::BaseField: PoseidonDefaultParametersField,
 {
-    type Parameters = TEAffine<TE>;
-    type PrivateKey = TE::ScalarField;
-    type PublicKey = TEAffine<TE>;
-    type Randomness = TE::ScalarField;
-
-    fn setup(message: &str) -> Self {
-        let (generator, _, _) = hash_to_curve::<TEAffine<TE>>(message);
-        let poseidon_parameters = Arc::new(
-            <TE::BaseField as PoseidonDefaultParametersField>::get_default_poseidon_parameters(4, false).unwrap(),
-        );
-
-        Self {
-            generator,
-            poseidon_parameters,
-        }
-    }
-
-    fn generate_private_key<R: Rng + CryptoRng>(&self, rng: &mut R) -> <Self as EncryptionScheme>::PrivateKey {
-        Self::PrivateKey::rand(rng)
-    }
-
-    fn generate_public_key(
+    ///
+    /// Returns the randomizer, public key commitment, and ciphertext elements for the given message,
+    /// along with the sponge used to derive the keystream.
+    ///
+    fn encrypt_field_elements(
         &self,
-        private_key: &<Self as EncryptionScheme>::PrivateKey,
-    ) -> <Self as EncryptionScheme>::PublicKey {
-        self.generator.into_projective().mul(*private_key).into_affine()
-    }
-
-    fn generate_randomness<R: Rng + CryptoRng>(&self, rng: &mut R) -> Self::Randomness {
-        Self::Randomness::rand(rng)
-    }
-
-    fn encrypt(
-        &self,
-        public_key: &<Self as EncryptionScheme>::PublicKey,
-        randomness: &Self::Randomness,
+        public_key: &TEAffine<TE>,
+        randomness: &TE::ScalarField,
         message: &[u8],
-    ) -> Result<Vec<u8>, EncryptionError> {
+    ) -> Result<
+        (
+            TE::BaseField,
+            TE::BaseField,
+            Vec<TE::BaseField>,
+            PoseidonSponge<TE::BaseField>,
+        ),
+        EncryptionError,
+    > {
         // Compute the randomizer := G^r
         let randomizer = self
             .generator
@@ -159,11 +144,7 @@ where
 
         // Compute the ECDH value := public_key^r.
         // Note for twisted Edwards curves, only one of (x, y) or (x, -y) is on the curve.
-        let ecdh_value = public_key
-            .into_projective()
-            .mul(*randomness)
-            .into_affine()
-            .to_x_coordinate();
+        let ecdh_value = self.generate_symmetric_key(public_key, randomness);
 
         // Prepare the Poseidon sponge.
         let mut sponge = PoseidonSponge::<TE::BaseField>::new(&self.poseidon_parameters);
@@ -204,89 +185,159 @@ where
         let ciphertext = plaintext_bits
             .chunks(capacity)
             .zip_eq(sponge_randomizers.iter())
-            .flat_map(|(chunk, sponge_randomizer)| {
+            .map(|(chunk, sponge_randomizer)| {
                 let plaintext_element =
                     TE::BaseField::from_repr(<TE::BaseField as PrimeField>::BigInteger::from_bits_le(chunk)).unwrap();
-                (plaintext_element + sponge_randomizer).to_bytes_le().unwrap()
+                plaintext_element + sponge_randomizer
             })
             .collect();
 
-        // Combine the randomizer, public key commitment, and ciphertext elements.
-        Ok([
-            randomizer.to_bytes_le()?,
-            public_key_commitment.to_bytes_le()?,
-            ciphertext,
-        ]
-        .concat())
+        Ok((randomizer, public_key_commitment, ciphertext, sponge))
     }
 
-    fn decrypt(
+    ///
+    /// Returns the MAC over the randomizer, public key commitment, and ciphertext elements,
+    /// keyed by an element squeezed from the ECDH sponge after the keystream.
+    ///
+    fn mac(
+        &self,
+        mac_key: TE::BaseField,
+        randomizer: TE::BaseField,
+        public_key_commitment: TE::BaseField,
+        ciphertext: &[TE::BaseField],
+    ) -> TE::BaseField {
+        let mut sponge = PoseidonSponge::<TE::BaseField>::new(&self.poseidon_parameters);
+        sponge.absorb(&[
+            TE::BaseField::from_bytes_le_mod_order(ECIES_POSEIDON_MAC_DOMAIN.as_bytes()),
+            mac_key,
+            randomizer,
+            public_key_commitment,
+        ]);
+        sponge.absorb(ciphertext);
+        sponge.squeeze_field_elements(1)[0]
+    }
+
+    ///
+    /// Returns the randomizer group element, randomizer, public key commitment, ciphertext elements,
+    /// and MAC (if the ciphertext is authenticated) from the given ciphertext bytes.
+    ///
+    /// The caller selects the expected format, so an authenticated ciphertext can not be
+    /// downgraded to an unauthenticated one by removing its version and MAC.
+    ///
+    fn parse_ciphertext(
         &self,
-        private_key: &<Self as EncryptionScheme>::PrivateKey,
         ciphertext: &[u8],
-    ) -> Result<Vec<u8>, EncryptionError> {
+        is_authenticated: bool,
+    ) -> Result<
+        (
+            TEAffine<TE>,
+            TE::BaseField,
+            TE::BaseField,
+            Vec<TE::BaseField>,
+            Option<TE::BaseField>,
+        ),
+        EncryptionError,
+    > {
         let per_field_element_bytes = TE::BaseField::zero().to_bytes_le()?.len();
-        assert!(ciphertext.len() >= per_field_element_bytes);
+
+        // Unauthenticated ciphertexts consist solely of field elements, while authenticated
+        // ciphertexts are prefixed with a one-byte format version.
+        let (version, field_element_bytes) = match (is_authenticated, ciphertext.len() % per_field_element_bytes) {
+            (false, 0) => (None, ciphertext),
+            (true, 1) => (Some(ciphertext[0]), &ciphertext[1..]),
+            _ => return Err(EncryptionError::Message("The ciphertext is malformed.".to_string())),
+        };
+
+        // Deserialize the field elements.
+        let mut field_elements = field_element_bytes
+            .chunks(per_field_element_bytes)
+            .map(TE::BaseField::from_bytes_le)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let (randomizer, public_key_commitment, given_mac) = match version {
+            None if field_elements.len() >= 3 => (field_elements.remove(0), field_elements.remove(0), None),
+            Some(ECIES_POSEIDON_AUTHENTICATED_CIPHERTEXT_VERSION) if field_elements.len() >= 4 => {
+                (field_elements.remove(0), field_elements.remove(0), field_elements.pop())
+            }
+            Some(version) if version != ECIES_POSEIDON_AUTHENTICATED_CIPHERTEXT_VERSION => {
+                return Err(EncryptionError::UnsupportedCiphertextVersion(version));
+            }
+            _ => return Err(EncryptionError::Message("The ciphertext is malformed.".to_string())),
+        };
 
         // Recover the randomness group element.
-        let random_elem_x = TE::BaseField::from_bytes_le(&ciphertext[..per_field_element_bytes])?;
         let random_elem = {
-            let mut first = TEAffine::<TE>::from_x_coordinate(random_elem_x.clone(), true);
+            let mut first = TEAffine::<TE>::from_x_coordinate(randomizer, true);
             if first.is_some() && !first.unwrap().is_in_correct_subgroup_assuming_on_curve() {
                 first = None;
             }
-            let mut second = TEAffine::<TE>::from_x_coordinate(random_elem_x, false);
+            let mut second = TEAffine::<TE>::from_x_coordinate(randomizer, false);
             if second.is_some() && !second.unwrap().is_in_correct_subgroup_assuming_on_curve() {
                 second = None;
             }
-            let random_elem = first.or(second);
-            if random_elem.is_none() {
-                return Err(EncryptionError::Message("The ciphertext is malformed.".to_string()));
+            match first.or(second) {
+                Some(random_elem) => random_elem,
+                None => return Err(EncryptionError::Message("The ciphertext is malformed.".to_string())),
             }
-            random_elem.unwrap()
         };
 
-        // Compute the ECDH value
-        let ecdh_value = random_elem.into_projective().mul((*private_key).clone()).into_affine();
+        Ok((
+            random_elem,
+            randomizer,
+            public_key_commitment,
+            field_elements,
+            given_mac,
+        ))
+    }
 
+    ///
+    /// Returns the message for the given ciphertext elements, using the ECDH value
+    /// shared between the randomizer and the public key.
+    ///
+    fn decrypt_field_elements(
+        &self,
+        public_key: &TEAffine<TE>,
+        ecdh_value: TE::BaseField,
+        randomizer: TE::BaseField,
+        public_key_commitment: TE::BaseField,
+        field_elements: Vec<TE::BaseField>,
+        given_mac: Option<TE::BaseField>,
+    ) -> Result<Vec<u8>, EncryptionError> {
         // Prepare the Poseidon sponge.
         let mut sponge = PoseidonSponge::<TE::BaseField>::new(&self.poseidon_parameters);
-        sponge.absorb(&[ecdh_value.x]); // For TE curves, only one of (x, y) and (x, -y) would be on the curve.
+        sponge.absorb(&[ecdh_value]);
 
         // Squeeze one element for the commitment randomness.
         let commitment_randomness = sponge.squeeze_field_elements(1)[0];
 
         // Add a commitment to the public key.
-        let public_key_commitment = {
+        let expected_public_key_commitment = {
             let mut sponge = PoseidonSponge::<TE::BaseField>::new(&self.poseidon_parameters);
-            let public_key = self.generate_public_key(&private_key);
             sponge.absorb(&[commitment_randomness, public_key.x]);
             sponge.squeeze_field_elements(1)[0]
         };
-        let given_public_key_commitment =
-            TE::BaseField::from_bytes_le(&ciphertext[per_field_element_bytes..2 * per_field_element_bytes])?;
-        if given_public_key_commitment != public_key_commitment {
+        if public_key_commitment != expected_public_key_commitment {
             return Err(EncryptionError::MismatchingAddress);
         }
 
-        // Compute the number of sponge elements needed.
-        let num_field_elements = (ciphertext.len() - 2 * per_field_element_bytes) / per_field_element_bytes;
-
         // Obtain random field elements from Poseidon.
-        let sponge_field_elements = sponge.squeeze_field_elements(num_field_elements);
+        let sponge_field_elements = sponge.squeeze_field_elements(field_elements.len());
 
-        // Subtract the random field elements to the packed bits.
-        let mut res_field_elements = Vec::with_capacity(num_field_elements);
-        for i in 0..num_field_elements {
-            res_field_elements.push(TE::BaseField::from_bytes_le(
-                &ciphertext[(2 * per_field_element_bytes + i * per_field_element_bytes)
-                    ..(2 * per_field_element_bytes + (i + 1) * per_field_element_bytes)],
-            )?);
-        }
-        for (i, sponge_field_element) in sponge_field_elements.iter().enumerate() {
-            res_field_elements[i] = res_field_elements[i] - sponge_field_element;
+        // Check the MAC before releasing any plaintext.
+        if let Some(given_mac) = given_mac {
+            let mac_key = sponge.squeeze_field_elements(1)[0];
+            if given_mac != self.mac(mac_key, randomizer, public_key_commitment, &field_elements) {
+                return Err(EncryptionError::InvalidMAC);
+            }
         }
 
+        // Subtract the random field elements to the packed bits.
+        let res_field_elements = field_elements
+            .iter()
+            .zip_eq(sponge_field_elements.iter())
+            .map(|(element, sponge_field_element)| *element - sponge_field_element)
+            .collect::<Vec<_>>();
+
         // Unpack the packed bits.
         if res_field_elements.is_empty() {
             return Err(EncryptionError::Message(
@@ -339,6 +390,199 @@ where
         Ok(message)
     }
 
+    ///
+    /// Returns the message for the given ciphertext in the given format, using the private key.
+    ///
+    fn decrypt_with_private_key(
+        &self,
+        private_key: &TE::ScalarField,
+        ciphertext: &[u8],
+        is_authenticated: bool,
+    ) -> Result<Vec<u8>, EncryptionError> {
+        let (random_elem, randomizer, public_key_commitment, field_elements, given_mac) =
+            self.parse_ciphertext(ciphertext, is_authenticated)?;
+
+        // Compute the ECDH value
+        let ecdh_value = random_elem.into_projective().mul(*private_key).into_affine();
+
+        let public_key = self.generate_public_key(private_key);
+        self.decrypt_field_elements(
+            &public_key,
+            ecdh_value.x, // For TE curves, only one of (x, y) and (x, -y) would be on the curve.
+            randomizer,
+            public_key_commitment,
+            field_elements,
+            given_mac,
+        )
+    }
+
+    ///
+    /// Returns the message for the given ciphertext in the given format, using the ECDH value
+    /// shared between its randomizer and the given public key.
+    ///
+    fn decrypt_with_ecdh_value(
+        &self,
+        public_key: &TEAffine<TE>,
+        ecdh_value: TE::BaseField,
+        ciphertext: &[u8],
+        is_authenticated: bool,
+    ) -> Result<Vec<u8>, EncryptionError> {
+        let (_, randomizer, public_key_commitment, field_elements, given_mac) =
+            self.parse_ciphertext(ciphertext, is_authenticated)?;
+
+        self.decrypt_field_elements(
+            public_key,
+            ecdh_value,
+            randomizer,
+            public_key_commitment,
+            field_elements,
+            given_mac,
+        )
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> EncryptionScheme for ECIESPoseidonEncryption<TE>
+where
+    TE::BaseField: PoseidonDefaultParametersField,
+{
+    type Parameters = TEAffine<TE>;
+    type PrivateKey = TE::ScalarField;
+    type PublicKey = TEAffine<TE>;
+    type Randomness = TE::ScalarField;
+    type SymmetricKey = TE::BaseField;
+
+    fn setup(message: &str) -> Self {
+        let (generator, _, _) = hash_to_curve::<TEAffine<TE>>(message);
+        let poseidon_parameters = Arc::new(
+            <TE::BaseField as PoseidonDefaultParametersField>::get_default_poseidon_parameters(4, false).unwrap(),
+        );
+
+        Self {
+            generator,
+            poseidon_parameters,
+        }
+    }
+
+    fn generate_private_key<R: Rng + CryptoRng>(&self, rng: &mut R) -> <Self as EncryptionScheme>::PrivateKey {
+        Self::PrivateKey::rand(rng)
+    }
+
+    fn generate_public_key(
+        &self,
+        private_key: &<Self as EncryptionScheme>::PrivateKey,
+    ) -> <Self as EncryptionScheme>::PublicKey {
+        self.generator.into_projective().mul(*private_key).into_affine()
+    }
+
+    fn generate_randomness<R: Rng + CryptoRng>(&self, rng: &mut R) -> Self::Randomness {
+        Self::Randomness::rand(rng)
+    }
+
+    fn generate_symmetric_key(
+        &self,
+        public_key: &<Self as EncryptionScheme>::PublicKey,
+        randomness: &Self::Randomness,
+    ) -> Self::SymmetricKey {
+        // Compute the ECDH value := public_key^r.
+        public_key
+            .into_projective()
+            .mul(*randomness)
+            .into_affine()
+            .to_x_coordinate()
+    }
+
+    fn encrypt(
+        &self,
+        public_key: &<Self as EncryptionScheme>::PublicKey,
+        randomness: &Self::Randomness,
+        message: &[u8],
+    ) -> Result<Vec<u8>, EncryptionError> {
+        let (randomizer, public_key_commitment, ciphertext, _) =
+            self.encrypt_field_elements(public_key, randomness, message)?;
+
+        // Combine the randomizer, public key commitment, and ciphertext elements.
+        Ok([
+            randomizer.to_bytes_le()?,
+            public_key_commitment.to_bytes_le()?,
+            ciphertext.to_bytes_le()?,
+        ]
+        .concat())
+    }
+
+    fn encrypt_authenticated(
+        &self,
+        public_key: &<Self as EncryptionScheme>::PublicKey,
+        randomness: &Self::Randomness,
+        message: &[u8],
+    ) -> Result<Vec<u8>, EncryptionError> {
+        let (randomizer, public_key_commitment, ciphertext, mut sponge) =
+            self.encrypt_field_elements(public_key, randomness, message)?;
+
+        // Squeeze one element for the MAC key, and authenticate the ciphertext.
+        let mac_key = sponge.squeeze_field_elements(1)[0];
+        let mac = self.mac(mac_key, randomizer, public_key_commitment, &ciphertext);
+
+        // Combine the version, randomizer, public key commitment, ciphertext elements, and MAC.
+        Ok([
+            vec![ECIES_POSEIDON_AUTHENTICATED_CIPHERTEXT_VERSION],
+            randomizer.to_bytes_le()?,
+            public_key_commitment.to_bytes_le()?,
+            ciphertext.to_bytes_le()?,
+            mac.to_bytes_le()?,
+        ]
+        .concat())
+    }
+
+    fn recover_symmetric_key(
+        &self,
+        private_key: &<Self as EncryptionScheme>::PrivateKey,
+        ciphertext: &[u8],
+        is_authenticated: bool,
+    ) -> Result<Self::SymmetricKey, EncryptionError> {
+        let (random_elem, _, _, _, _) = self.parse_ciphertext(ciphertext, is_authenticated)?;
+
+        // Compute the ECDH value
+        Ok(random_elem
+            .into_projective()
+            .mul((*private_key).clone())
+            .into_affine()
+            .to_x_coordinate())
+    }
+
+    fn decrypt(
+        &self,
+        private_key: &<Self as EncryptionScheme>::PrivateKey,
+        ciphertext: &[u8],
+    ) -> Result<Vec<u8>, EncryptionError> {
+        self.decrypt_with_private_key(private_key, ciphertext, false)
+    }
+
+    fn decrypt_authenticated(
+        &self,
+        private_key: &<Self as EncryptionScheme>::PrivateKey,
+        ciphertext: &[u8],
+    ) -> Result<Vec<u8>, EncryptionError> {
+        self.decrypt_with_private_key(private_key, ciphertext, true)
+    }
+
+    fn decrypt_with_symmetric_key(
+        &self,
+        public_key: &<Self as EncryptionScheme>::PublicKey,
+        symmetric_key: &Self::SymmetricKey,
+        ciphertext: &[u8],
+    ) -> Result<Vec<u8>, EncryptionError> {
+        self.decrypt_with_ecdh_value(public_key, *symmetric_key, ciphertext, false)
+    }
+
+    fn decrypt_authenticated_with_symmetric_key(
+        &self,
+        public_key: &<Self as EncryptionScheme>::PublicKey,
+        symmetric_key: &Self::SymmetricKey,
+        ciphertext: &[u8],
+    ) -> Result<Vec<u8>, EncryptionError> {
+        self.decrypt_with_ecdh_value(public_key, *symmetric_key, ciphertext, true)
+    }
+
     fn parameters(&self) -> &<Self as EncryptionScheme>::Parameters {
         &self.generator
     }
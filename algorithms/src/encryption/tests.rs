@@ -15,7 +15,11 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod ecies {
-    use crate::{encryption::ECIESPoseidonEncryption, EncryptionScheme};
+    use crate::{
+        encryption::{ECIESPoseidonEncryption, ECIES_POSEIDON_AUTHENTICATED_CIPHERTEXT_VERSION},
+        EncryptionError,
+        EncryptionScheme,
+    };
     use snarkvm_curves::edwards_bls12::EdwardsParameters;
     use snarkvm_utilities::{FromBytes, ToBytes, UniformRand};
 
@@ -79,4 +83,205 @@ mod ecies {
         // This should fail due to a MAC mismatch.
         encryption_scheme.decrypt(&private_key, &ciphertext).unwrap();
     }
+
+    #[test]
+    fn test_authenticated_encrypt_and_decrypt() {
+        let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+
+        let encryption_scheme = TestEncryptionScheme::setup("authenticated_encryption");
+        let private_key = encryption_scheme.generate_private_key(rng);
+        let public_key = encryption_scheme.generate_public_key(&private_key);
+
+        let randomness = encryption_scheme.generate_randomness(rng);
+        let message = (0..32).map(|_| u8::rand(rng)).collect::<Vec<u8>>();
+
+        let legacy_ciphertext = encryption_scheme.encrypt(&public_key, &randomness, &message).unwrap();
+        let ciphertext = encryption_scheme
+            .encrypt_authenticated(&public_key, &randomness, &message)
+            .unwrap();
+
+        // The authenticated ciphertext is the legacy ciphertext with a version prefix and a MAC suffix.
+        assert_eq!(ECIES_POSEIDON_AUTHENTICATED_CIPHERTEXT_VERSION, ciphertext[0]);
+        assert_eq!(&legacy_ciphertext[..], &ciphertext[1..1 + legacy_ciphertext.len()]);
+
+        let candidate_message = encryption_scheme.decrypt_authenticated(&private_key, &ciphertext).unwrap();
+        assert_eq!(message, candidate_message);
+
+        // Each format is only accepted by its own decryption method.
+        assert!(encryption_scheme.decrypt(&private_key, &ciphertext).is_err());
+        assert!(
+            encryption_scheme
+                .decrypt_authenticated(&private_key, &legacy_ciphertext)
+                .is_err()
+        );
+
+        // Stripping the version and the MAC must not downgrade the ciphertext to the legacy format.
+        let stripped_ciphertext = &ciphertext[1..1 + legacy_ciphertext.len()];
+        assert!(
+            encryption_scheme
+                .decrypt_authenticated(&private_key, stripped_ciphertext)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_authenticated_decrypt_with_wrong_key() {
+        let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+
+        let encryption_scheme = TestEncryptionScheme::setup("authenticated_encryption");
+        let private_key = encryption_scheme.generate_private_key(rng);
+        let public_key = encryption_scheme.generate_public_key(&private_key);
+        let wrong_private_key = encryption_scheme.generate_private_key(rng);
+
+        let randomness = encryption_scheme.generate_randomness(rng);
+        let message = (0..32).map(|_| u8::rand(rng)).collect::<Vec<u8>>();
+        let ciphertext = encryption_scheme
+            .encrypt_authenticated(&public_key, &randomness, &message)
+            .unwrap();
+
+        assert!(matches!(
+            encryption_scheme.decrypt_authenticated(&wrong_private_key, &ciphertext),
+            Err(EncryptionError::MismatchingAddress)
+        ));
+    }
+
+    #[test]
+    fn test_authenticated_ciphertext_manipulation() {
+        let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+
+        let encryption_scheme = TestEncryptionScheme::setup("authenticated_encryption");
+        let private_key = encryption_scheme.generate_private_key(rng);
+        let public_key = encryption_scheme.generate_public_key(&private_key);
+
+        let randomness = encryption_scheme.generate_randomness(rng);
+        let message = (0..32).map(|_| u8::rand(rng)).collect::<Vec<u8>>();
+        let ciphertext = encryption_scheme
+            .encrypt_authenticated(&public_key, &randomness, &message)
+            .unwrap();
+
+        // Flipping any byte of the ciphertext must be rejected.
+        for idx in 0..ciphertext.len() {
+            let mut candidate_ciphertext = ciphertext.clone();
+            candidate_ciphertext[idx] ^= 1u8;
+            assert!(
+                encryption_scheme
+                    .decrypt_authenticated(&private_key, &candidate_ciphertext)
+                    .is_err()
+            );
+        }
+
+        // Flipping a byte of the ciphertext elements must fail on the MAC.
+        let mut candidate_ciphertext = ciphertext.clone();
+        candidate_ciphertext[ciphertext.len() - 64] ^= 1u8;
+        assert!(matches!(
+            encryption_scheme.decrypt_authenticated(&private_key, &candidate_ciphertext),
+            Err(EncryptionError::InvalidMAC)
+        ));
+
+        // Unknown versions must be rejected.
+        let mut candidate_ciphertext = ciphertext.clone();
+        candidate_ciphertext[0] = ECIES_POSEIDON_AUTHENTICATED_CIPHERTEXT_VERSION + 1;
+        assert!(matches!(
+            encryption_scheme.decrypt_authenticated(&private_key, &candidate_ciphertext),
+            Err(EncryptionError::UnsupportedCiphertextVersion(_))
+        ));
+
+        // Truncated ciphertexts must be rejected without panicking.
+        for len in 0..ciphertext.len() {
+            assert!(
+                encryption_scheme
+                    .decrypt_authenticated(&private_key, &ciphertext[..len])
+                    .is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn test_decrypt_with_symmetric_key() {
+        let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+
+        let encryption_scheme = TestEncryptionScheme::setup("symmetric_key_decryption");
+        let private_key = encryption_scheme.generate_private_key(rng);
+        let public_key = encryption_scheme.generate_public_key(&private_key);
+        let wrong_public_key = encryption_scheme.generate_public_key(&encryption_scheme.generate_private_key(rng));
+
+        let randomness = encryption_scheme.generate_randomness(rng);
+        let symmetric_key = encryption_scheme.generate_symmetric_key(&public_key, &randomness);
+        let message = (0..32).map(|_| u8::rand(rng)).collect::<Vec<u8>>();
+
+        let legacy_ciphertext = encryption_scheme.encrypt(&public_key, &randomness, &message).unwrap();
+        let ciphertext = encryption_scheme
+            .encrypt_authenticated(&public_key, &randomness, &message)
+            .unwrap();
+
+        // The private key recovers the same symmetric key from either ciphertext format.
+        for (ciphertext, is_authenticated) in [(&legacy_ciphertext, false), (&ciphertext, true)].iter() {
+            assert_eq!(
+                symmetric_key,
+                encryption_scheme
+                    .recover_symmetric_key(&private_key, ciphertext, *is_authenticated)
+                    .unwrap()
+            );
+            // The ciphertext format is not inferred.
+            assert!(
+                encryption_scheme
+                    .recover_symmetric_key(&private_key, ciphertext, !is_authenticated)
+                    .is_err()
+            );
+        }
+
+        let candidate_message = encryption_scheme
+            .decrypt_with_symmetric_key(&public_key, &symmetric_key, &legacy_ciphertext)
+            .unwrap();
+        assert_eq!(message, candidate_message);
+
+        // The symmetric key only decrypts for the public key it was derived from.
+        assert!(matches!(
+            encryption_scheme.decrypt_with_symmetric_key(&wrong_public_key, &symmetric_key, &legacy_ciphertext),
+            Err(EncryptionError::MismatchingAddress)
+        ));
+
+        // Authenticated ciphertexts are rejected.
+        assert!(
+            encryption_scheme
+                .decrypt_with_symmetric_key(&public_key, &symmetric_key, &ciphertext)
+                .is_err()
+        );
+
+        let candidate_message = encryption_scheme
+            .decrypt_authenticated_with_symmetric_key(&public_key, &symmetric_key, &ciphertext)
+            .unwrap();
+        assert_eq!(message, candidate_message);
+
+        // Unauthenticated ciphertexts are rejected.
+        assert!(
+            encryption_scheme
+                .decrypt_authenticated_with_symmetric_key(&public_key, &symmetric_key, &legacy_ciphertext)
+                .is_err()
+        );
+
+        // A tampered authenticated ciphertext is rejected.
+        let mut tampered_ciphertext = ciphertext.clone();
+        let last_byte = tampered_ciphertext.len() - 1;
+        tampered_ciphertext[last_byte] ^= 1;
+        assert!(
+            encryption_scheme
+                .decrypt_authenticated_with_symmetric_key(&public_key, &symmetric_key, &tampered_ciphertext)
+                .is_err()
+        );
+
+        // A symmetric key from other randomness must be rejected.
+        let wrong_symmetric_key =
+            encryption_scheme.generate_symmetric_key(&public_key, &encryption_scheme.generate_randomness(rng));
+        assert!(
+            encryption_scheme
+                .decrypt_with_symmetric_key(&public_key, &wrong_symmetric_key, &legacy_ciphertext)
+                .is_err()
+        );
+        assert!(
+            encryption_scheme
+                .decrypt_authenticated_with_symmetric_key(&public_key, &wrong_symmetric_key, &ciphertext)
+                .is_err()
+        );
+    }
 }
@@ -22,6 +22,9 @@ pub enum EncryptionError {
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
+    #[error("The ciphertext MAC is invalid")]
+    InvalidMAC,
+
     #[error("Invalid private key")]
     InvalidPrivateKey,
 
@@ -33,6 +36,9 @@ pub enum EncryptionError {
 
     #[error("{}", _0)]
     Message(String),
+
+    #[error("Unsupported ciphertext version {}", _0)]
+    UnsupportedCiphertextVersion(u8),
 }
 
 impl From<std::io::Error> for EncryptionError {
@@ -23,6 +23,9 @@ pub use crh::*;
 pub mod encryption;
 pub use encryption::*;
 
+pub mod hash_to_curve;
+pub use hash_to_curve::*;
+
 pub mod merkle;
 pub use merkle::*;
 
@@ -34,3 +37,6 @@ pub use signature::*;
 
 pub mod snark;
 pub use snark::*;
+
+pub mod vrf;
+pub use vrf::*;
@@ -49,12 +49,12 @@ fn log2(number: usize) -> usize {
 
 /// Defines a domain over which finite field (I)FFTs can be performed. Works
 /// only for fields that have a large multiplicative subgroup of size that is
-/// a power-of-2.
+/// a power-of-2, or a power-of-2 times a power of the field's `SMALL_SUBGROUP_BASE`.
 #[derive(Copy, Clone, Hash, Eq, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct EvaluationDomain<F: FftField> {
     /// The size of the domain.
     pub size: u64,
-    /// `log_2(self.size)`.
+    /// `log_2` of the power-of-2 part of `self.size`, which is `log_2(self.size)` for radix-2 domains.
     pub log_size_of_group: u32,
     /// Size of the domain as a field element.
     pub size_as_field_element: F,
@@ -96,11 +96,23 @@ impl<F: FftField> EvaluationDomain<F> {
             return None;
         }
 
+        Self::from_size(size)
+    }
+
+    /// Construct the smallest domain that is large enough for evaluations of a polynomial
+    /// having `num_coeffs` coefficients, which may be a mixed-radix domain of size
+    /// `2^k * SMALL_SUBGROUP_BASE^j` if the field supports it.
+    pub fn new_mixed_radix(num_coeffs: usize) -> Option<Self> {
+        Self::from_size(Self::compute_size_of_mixed_radix_domain(num_coeffs)? as u64)
+    }
+
+    /// Construct the domain of the given size, if the field has a multiplicative subgroup of that size.
+    pub fn from_size(size: u64) -> Option<Self> {
         // Compute the generator for the multiplicative subgroup.
-        // It should be the 2^(log_size_of_group) root of unity.
+        // It should be the `size`-th root of unity.
         let group_gen = F::get_root_of_unity(size as usize)?;
 
-        // Check that it is indeed the 2^(log_size_of_group) root of unity.
+        // Check that it is indeed the `size`-th root of unity.
         debug_assert_eq!(group_gen.pow([size]), F::one());
 
         let size_as_field_element = F::from(size);
@@ -108,7 +120,7 @@ impl<F: FftField> EvaluationDomain<F> {
 
         Some(EvaluationDomain {
             size,
-            log_size_of_group,
+            log_size_of_group: size.trailing_zeros(),
             size_as_field_element,
             size_inv,
             group_gen,
@@ -128,6 +140,42 @@ impl<F: FftField> EvaluationDomain<F> {
         }
     }
 
+    /// Return the size of the smallest domain that is large enough for evaluations of a polynomial
+    /// having `num_coeffs` coefficients, which may be a mixed-radix domain.
+    pub fn compute_size_of_mixed_radix_domain(num_coeffs: usize) -> Option<usize> {
+        let mut best_size = Self::compute_size_of_domain(num_coeffs);
+
+        if let (Some(base), Some(base_adicity)) = (
+            F::FftParameters::SMALL_SUBGROUP_BASE,
+            F::FftParameters::SMALL_SUBGROUP_BASE_ADICITY,
+        ) {
+            // For each power of the small subgroup base, find the smallest power of 2 that completes the domain.
+            let mut base_part = 1usize;
+            for _ in 0..=base_adicity {
+                let two_part = ((num_coeffs + base_part - 1) / base_part).next_power_of_two();
+                if two_part.trailing_zeros() <= F::FftParameters::TWO_ADICITY {
+                    if let Some(size) = two_part.checked_mul(base_part) {
+                        if best_size.map_or(true, |best_size| size < best_size) {
+                            best_size = Some(size);
+                        }
+                    }
+                }
+
+                base_part = match base_part.checked_mul(base as usize) {
+                    Some(base_part) => base_part,
+                    None => break,
+                };
+            }
+        }
+
+        best_size
+    }
+
+    /// Returns `true` if the size of `self` is a power of 2.
+    pub fn is_radix_2(&self) -> bool {
+        self.size.is_power_of_two()
+    }
+
     /// Return the size of `self`.
     pub fn size(&self) -> usize {
         self.size as usize
@@ -143,7 +191,7 @@ impl<F: FftField> EvaluationDomain<F> {
     /// Compute an FFT, modifying the vector in place.
     pub fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
         coeffs.resize(self.size(), T::zero());
-        best_fft(coeffs, self.group_gen, self.log_size_of_group)
+        self.in_order_fft(coeffs, self.group_gen)
     }
 
     /// Compute an IFFT.
@@ -157,10 +205,18 @@ impl<F: FftField> EvaluationDomain<F> {
     #[inline]
     pub fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
         evals.resize(self.size(), T::zero());
-        best_fft(evals, self.group_gen_inv, self.log_size_of_group);
+        self.in_order_fft(evals, self.group_gen_inv);
         cfg_iter_mut!(evals).for_each(|val| *val *= self.size_inv);
     }
 
+    /// Computes the FFT of the given coefficients, with the given generator of this domain.
+    fn in_order_fft<T: DomainCoeff<F>>(&self, a: &mut [T], omega: F) {
+        match F::FftParameters::SMALL_SUBGROUP_BASE {
+            Some(base) if !self.is_radix_2() => mixed_radix_fft(a, omega, self.log_size_of_group, base as usize),
+            _ => best_fft(a, omega, self.log_size_of_group),
+        }
+    }
+
     /// Compute an FFT over a coset of the domain.
     pub fn coset_fft<T: DomainCoeff<F>>(&self, coeffs: &[T]) -> Vec<T> {
         let mut coeffs = coeffs.to_vec();
@@ -320,8 +376,8 @@ impl<F: FftField> EvaluationDomain<F> {
         // TODO: check if this method can replace parallel compute powers.
         let log_size = log2(self.size as usize);
 
-        // Early exit for short inputs.
-        if log_size <= LOG_ROOTS_OF_UNITY_PARALLEL_SIZE {
+        // Early exit for short inputs, and for mixed-radix domains.
+        if log_size <= LOG_ROOTS_OF_UNITY_PARALLEL_SIZE || !self.is_radix_2() {
             Self::compute_powers_serial((self.size as usize) / 2, root)
         } else {
             let mut tmp = root;
@@ -494,6 +550,41 @@ pub(crate) fn parallel_radix2_fft<T: DomainCoeff<F>, F: FftField>(a: &mut [T], o
         .for_each(|(i, a)| *a = tmp[i % num_chunks][i / num_chunks]);
 }
 
+/// Computes the FFT of `a`, whose length is `2^two_adicity * base^j` for some `j`, where `omega` is
+/// a root of unity of that order.
+///
+/// Each factor of `base` is removed by splitting the input into `base` interleaved subsequences,
+/// whose FFTs are computed in parallel and recombined, until the radix-2 FFT applies.
+pub(crate) fn mixed_radix_fft<T: DomainCoeff<F>, F: FftField>(a: &mut [T], omega: F, two_adicity: u32, base: usize) {
+    let n = a.len();
+    if n == 1 << two_adicity {
+        return best_fft(a, omega, two_adicity);
+    }
+    assert_eq!(n % base, 0);
+    let m = n / base;
+
+    // Decimate in time, into the subsequences of coefficients with the same index modulo `base`.
+    let mut subsequences: Vec<Vec<T>> = (0..base)
+        .map(|s| a.iter().skip(s).step_by(base).copied().collect())
+        .collect();
+    let omega_base = omega.pow([base as u64]);
+    cfg_iter_mut!(subsequences).for_each(|subsequence| mixed_radix_fft(subsequence, omega_base, two_adicity, base));
+
+    // Recombine, as a[k + t * m] = sum_s (omega^(k + t * m))^s * subsequences[s][k].
+    cfg_chunks_mut!(a, m).enumerate().for_each(|(t, chunk)| {
+        let mut x = omega.pow([(t * m) as u64]);
+        for (k, value) in chunk.iter_mut().enumerate() {
+            let mut sum = subsequences[base - 1][k];
+            for subsequence in subsequences[..base - 1].iter().rev() {
+                sum *= x;
+                sum += subsequence[k];
+            }
+            *value = sum;
+            x *= omega;
+        }
+    });
+}
+
 /// An iterator over the elements of the domain.
 pub struct Elements<F: FftField> {
     cur_elem: F,
@@ -698,4 +789,92 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_mixed_radix_domain_size() {
+        // Fr has a subgroup of order 2^47 * 3, so sizes that are 3 times a power of 2 are available.
+        assert_eq!(EvaluationDomain::<Fr>::compute_size_of_mixed_radix_domain(1), Some(1));
+        assert_eq!(EvaluationDomain::<Fr>::compute_size_of_mixed_radix_domain(3), Some(3));
+        assert_eq!(EvaluationDomain::<Fr>::compute_size_of_mixed_radix_domain(4), Some(4));
+        assert_eq!(EvaluationDomain::<Fr>::compute_size_of_mixed_radix_domain(5), Some(6));
+        assert_eq!(EvaluationDomain::<Fr>::compute_size_of_mixed_radix_domain(17), Some(24));
+        assert_eq!(EvaluationDomain::<Fr>::compute_size_of_mixed_radix_domain(25), Some(32));
+        assert_eq!(
+            EvaluationDomain::<Fr>::compute_size_of_mixed_radix_domain(1025),
+            Some(1536)
+        );
+
+        for num_coeffs in 1..200 {
+            let size = EvaluationDomain::<Fr>::compute_size_of_mixed_radix_domain(num_coeffs).unwrap();
+            assert!(size >= num_coeffs);
+            assert!(size <= num_coeffs.next_power_of_two());
+
+            let domain = EvaluationDomain::<Fr>::new_mixed_radix(num_coeffs).unwrap();
+            assert_eq!(domain.size(), size);
+            assert_eq!(domain.is_radix_2(), size.is_power_of_two());
+        }
+    }
+
+    #[test]
+    fn test_mixed_radix_elements_and_vanishing_polynomial() {
+        for &num_coeffs in &[3, 6, 12, 48, 96] {
+            let domain = EvaluationDomain::<Fr>::new_mixed_radix(num_coeffs).unwrap();
+            assert_eq!(domain.size(), num_coeffs);
+            assert_eq!(domain.elements().count(), num_coeffs);
+
+            let z = domain.vanishing_polynomial();
+            for (i, element) in domain.elements().enumerate() {
+                assert_eq!(element, domain.group_gen.pow([i as u64]));
+                assert!(z.evaluate(element).is_zero());
+                if i > 0 {
+                    assert!(!element.is_one());
+                }
+            }
+
+            let roots = domain.roots_of_unity(domain.group_gen);
+            assert_eq!(roots.len(), num_coeffs / 2);
+            for (expected, actual) in domain.elements().zip(&roots) {
+                assert_eq!(expected, *actual);
+            }
+        }
+    }
+
+    /// Tests that the FFTs over mixed-radix domains output the correct result.
+    #[test]
+    fn test_mixed_radix_fft_correctness() {
+        let rng = &mut thread_rng();
+
+        for &degree in &[2, 5, 11, 17, 40, 90] {
+            let random_polynomial = DensePolynomial::<Fr>::rand(degree, rng);
+            let domain = EvaluationDomain::<Fr>::new_mixed_radix(degree + 1).unwrap();
+            assert!(!domain.is_radix_2());
+
+            let polynomial_evaluations = domain.fft(&random_polynomial.coeffs);
+            let polynomial_coset_evaluations = domain.coset_fft(&random_polynomial.coeffs);
+            for (i, x) in domain.elements().enumerate() {
+                let coset_x = Fr::multiplicative_generator() * x;
+
+                assert_eq!(polynomial_evaluations[i], random_polynomial.evaluate(x));
+                assert_eq!(polynomial_coset_evaluations[i], random_polynomial.evaluate(coset_x));
+            }
+
+            let random_polynomial_from_subgroup =
+                DensePolynomial::from_coefficients_vec(domain.ifft(&polynomial_evaluations));
+            let random_polynomial_from_coset =
+                DensePolynomial::from_coefficients_vec(domain.coset_ifft(&polynomial_coset_evaluations));
+
+            assert_eq!(
+                random_polynomial,
+                random_polynomial_from_subgroup,
+                "domain size = {}",
+                domain.size()
+            );
+            assert_eq!(
+                random_polynomial,
+                random_polynomial_from_coset,
+                "domain size = {}",
+                domain.size()
+            );
+        }
+    }
 }
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::hash_to_curve::{cmov, HashToCurve, HashToField};
+use snarkvm_curves::{
+    templates::twisted_edwards_extended::Affine,
+    traits::{Elligator2Parameters, MontgomeryParameters},
+};
+use snarkvm_fields::{Field, One, SquareRootField, Zero};
+
+/// Returns the point `(s, t)` on the Montgomery curve `K * t^2 = s^3 + J * s^2 + s` for `u`,
+/// by the Elligator 2 map of Section 6.7.1 of RFC 9380.
+fn map_to_montgomery_curve<P: Elligator2Parameters>(u: P::BaseField) -> (P::BaseField, P::BaseField)
+where
+    P::BaseField: HashToField,
+{
+    let j = <P::MontgomeryParameters as MontgomeryParameters>::COEFF_A;
+    let k = <P::MontgomeryParameters as MontgomeryParameters>::COEFF_B;
+    // `K` is non-zero for a non-singular curve.
+    let k_inv = k.inverse().unwrap();
+    let (c1, c2) = (j * k_inv, k_inv.square());
+
+    let tv1 = P::ELLIGATOR2_Z * u.square();
+    // If `Z * u^2 = -1`, the map sets `tv1 = 0` to avoid the division by zero below.
+    let tv1 = cmov(tv1, P::BaseField::zero(), tv1 == -P::BaseField::one());
+    let x1 = -c1 * (tv1 + P::BaseField::one()).inverse().unwrap_or_else(P::BaseField::zero);
+    let gx1 = ((x1 + c1) * x1 + c2) * x1;
+    let x2 = -x1 - c1;
+    let gx2 = tv1 * gx1;
+
+    let e2 = gx1.legendre().is_qr() || gx1.is_zero();
+    let x = cmov(x2, x1, e2);
+    // Exactly one of `gx1` and `gx2` is square, so the square root always exists.
+    let y = cmov(gx2, gx1, e2).sqrt().unwrap_or_else(P::BaseField::zero);
+    let y = cmov(y, -y, e2 ^ y.sgn0());
+
+    (x * k, y * k)
+}
+
+/// Returns the image of a point on the Montgomery curve under the rational map
+/// to the twisted Edwards curve, as in Appendix D.1 of RFC 9380.
+fn montgomery_to_twisted_edwards<P: Elligator2Parameters>(s: P::BaseField, t: P::BaseField) -> Affine<P> {
+    let tv1 = s + P::BaseField::one();
+    let tv2 = (tv1 * t).inverse().unwrap_or_else(P::BaseField::zero);
+    let v = tv2 * tv1 * s;
+    let w = tv2 * t * (s - P::BaseField::one());
+    // The exceptional points are sent to the identity `(0, 1)`.
+    let w = cmov(w, P::BaseField::one(), tv2.is_zero());
+    Affine::new(v, w)
+}
+
+impl<P: Elligator2Parameters> HashToCurve for Affine<P>
+where
+    P::BaseField: HashToField,
+{
+    fn map_to_curve(u: P::BaseField) -> Self {
+        let (s, t) = map_to_montgomery_curve::<P>(u);
+        montgomery_to_twisted_edwards::<P>(s, t)
+    }
+}
@@ -18,6 +18,9 @@ use crate::crypto_hash::Blake2Xs;
 use snarkvm_curves::AffineCurve;
 
 /// Runs hash-to-curve and returns the generator, message, and counter on success.
+///
+/// This is the variable-time try-and-increment method, which is kept to derive the existing generators.
+/// See `HashToCurve` for the maps of RFC 9380.
 #[inline]
 pub fn hash_to_curve<G: AffineCurve>(input: &str) -> (G, String, usize) {
     // Attempt to increment counter `k` at most `8 * G::SERIALIZED_SIZE` times.
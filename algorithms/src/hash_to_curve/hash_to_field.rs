@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::errors::HashToCurveError;
+use snarkvm_fields::{
+    Fp2,
+    Fp256,
+    Fp256Parameters,
+    Fp2Parameters,
+    Fp384,
+    Fp384Parameters,
+    PrimeField,
+    SquareRootField,
+    Zero,
+};
+use snarkvm_utilities::biginteger::BigInteger;
+
+use sha2::{Digest, Sha256};
+
+/// The output size of SHA-256 in bytes.
+const B_IN_BYTES: usize = 32;
+/// The input block size of SHA-256 in bytes.
+const S_IN_BYTES: usize = 64;
+/// The maximum length of a domain separation tag, as in Section 5.3.3 of RFC 9380.
+const MAX_DST_LENGTH: usize = 255;
+/// The computational security level `k` in bits.
+const SECURITY_LEVEL: usize = 128;
+
+/// Returns `len_in_bytes` uniformly random bytes derived from `msg` and `dst`,
+/// using `expand_message_xmd` with SHA-256 as in Section 5.3.1 of RFC 9380.
+///
+/// Domain separation tags longer than 255 bytes are hashed as in Section 5.3.3.
+pub fn expand_message_xmd(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Result<Vec<u8>, HashToCurveError> {
+    let ell = (len_in_bytes + B_IN_BYTES - 1) / B_IN_BYTES;
+    if ell > 255 || len_in_bytes > u16::MAX as usize {
+        return Err(HashToCurveError::InvalidOutputLength(len_in_bytes));
+    }
+
+    let mut dst_prime = if dst.len() > MAX_DST_LENGTH {
+        Sha256::new().chain(b"H2C-OVERSIZE-DST-").chain(dst).finalize().to_vec()
+    } else {
+        dst.to_vec()
+    };
+    dst_prime.push(dst_prime.len() as u8);
+
+    let b_0 = Sha256::new()
+        .chain(&[0u8; S_IN_BYTES][..])
+        .chain(msg)
+        .chain((len_in_bytes as u16).to_be_bytes())
+        .chain([0u8])
+        .chain(&dst_prime)
+        .finalize();
+    let mut b_i = Sha256::new().chain(&b_0).chain([1u8]).chain(&dst_prime).finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * B_IN_BYTES);
+    uniform_bytes.extend_from_slice(&b_i);
+    for i in 2..=ell {
+        let xor = b_0.iter().zip(b_i.iter()).map(|(a, b)| a ^ b).collect::<Vec<u8>>();
+        b_i = Sha256::new().chain(&xor).chain([i as u8]).chain(&dst_prime).finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+    uniform_bytes.truncate(len_in_bytes);
+
+    Ok(uniform_bytes)
+}
+
+/// A field that elements can be hashed to, as in Section 5 of RFC 9380.
+pub trait HashToField: SquareRootField {
+    /// The number of uniform bytes used to derive one field element,
+    /// which is `m * L` in the notation of Section 5.
+    const UNIFORM_BYTES: usize;
+
+    /// Returns the field element for the given `UNIFORM_BYTES` uniform bytes.
+    fn from_uniform_bytes(bytes: &[u8]) -> Self;
+
+    /// Returns the sign of the field element, as in Section 4.1.
+    fn sgn0(&self) -> bool;
+}
+
+impl<P: Fp256Parameters> HashToField for Fp256<P> {
+    const UNIFORM_BYTES: usize = (P::MODULUS_BITS as usize + SECURITY_LEVEL + 7) / 8;
+
+    fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        Self::from_bytes_be_mod_order(bytes)
+    }
+
+    fn sgn0(&self) -> bool {
+        self.to_repr().is_odd()
+    }
+}
+
+impl<P: Fp384Parameters> HashToField for Fp384<P> {
+    const UNIFORM_BYTES: usize = (P::MODULUS_BITS as usize + SECURITY_LEVEL + 7) / 8;
+
+    fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        Self::from_bytes_be_mod_order(bytes)
+    }
+
+    fn sgn0(&self) -> bool {
+        self.to_repr().is_odd()
+    }
+}
+
+impl<P: Fp2Parameters> HashToField for Fp2<P>
+where
+    P::Fp: HashToField,
+{
+    const UNIFORM_BYTES: usize = 2 * P::Fp::UNIFORM_BYTES;
+
+    fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        let (c0, c1) = bytes.split_at(P::Fp::UNIFORM_BYTES);
+        Self::new(P::Fp::from_uniform_bytes(c0), P::Fp::from_uniform_bytes(c1))
+    }
+
+    fn sgn0(&self) -> bool {
+        self.c0.sgn0() || (self.c0.is_zero() && self.c1.sgn0())
+    }
+}
+
+/// Returns `count` field elements derived from `msg` and `dst`, as in Section 5.2 of RFC 9380.
+pub fn hash_to_field<F: HashToField>(msg: &[u8], dst: &[u8], count: usize) -> Result<Vec<F>, HashToCurveError> {
+    let uniform_bytes = expand_message_xmd(msg, dst, count * F::UNIFORM_BYTES)?;
+    Ok(uniform_bytes
+        .chunks(F::UNIFORM_BYTES)
+        .map(F::from_uniform_bytes)
+        .collect())
+}
@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::HashToCurveError,
+    hash_to_curve::{hash_to_field, HashToField},
+};
+use snarkvm_curves::AffineCurve;
+use snarkvm_fields::Field;
+
+/// A curve with a deterministic map from its base field, which yields
+/// the hash-to-curve and encode-to-curve constructions of Section 3 of RFC 9380.
+///
+/// The maps are straight-line, so the running time is independent of the input
+/// up to the field arithmetic (inversion and square roots) of the base field.
+pub trait HashToCurve: AffineCurve
+where
+    Self::BaseField: HashToField,
+{
+    /// Returns the curve point for the given field element, which is not necessarily
+    /// in the prime-order subgroup.
+    fn map_to_curve(u: Self::BaseField) -> Self;
+
+    /// Returns a uniformly distributed point in the prime-order subgroup for `msg`,
+    /// under the domain separation tag `dst`.
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Result<Self, HashToCurveError> {
+        let u = hash_to_field::<Self::BaseField>(msg, dst, 2)?;
+        let q = Self::map_to_curve(u[0]) + Self::map_to_curve(u[1]);
+        Ok(q.mul_by_cofactor())
+    }
+
+    /// Returns a point in the prime-order subgroup for `msg`, under the domain separation tag `dst`.
+    ///
+    /// This is cheaper than `hash_to_curve`, but the output is not uniformly distributed.
+    fn encode_to_curve(msg: &[u8], dst: &[u8]) -> Result<Self, HashToCurveError> {
+        let u = hash_to_field::<Self::BaseField>(msg, dst, 1)?;
+        Ok(Self::map_to_curve(u[0]).mul_by_cofactor())
+    }
+}
+
+/// Returns `a` if `condition` is false, and `b` otherwise.
+#[inline]
+pub(super) fn cmov<F: Field>(a: F, b: F, condition: bool) -> F {
+    if condition { b } else { a }
+}
@@ -14,8 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod elligator2;
+
 pub mod hash_to_curve;
 pub use hash_to_curve::*;
 
+pub mod hash_to_field;
+pub use hash_to_field::*;
+
+pub mod map_to_curve;
+pub use map_to_curve::*;
+
+mod swu;
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::hash_to_curve::{cmov, HashToCurve, HashToField};
+use snarkvm_curves::{templates::short_weierstrass_jacobian::Affine, traits::SWUParameters};
+use snarkvm_fields::{Field, One, Zero};
+
+/// Returns whether `u / v` is square, and the square root of `u / v` or `Z * u / v` accordingly,
+/// as in Section F.2.1 of RFC 9380.
+fn sqrt_ratio<F: HashToField>(u: F, v: F, z: F) -> (bool, F) {
+    let ratio = u * v.inverse().unwrap_or_else(F::zero);
+    let is_square = ratio.legendre().is_qr() || ratio.is_zero();
+    let candidate = cmov(z * ratio, ratio, is_square);
+    // The candidate is square by the choice of `Z` as a non-square.
+    (is_square, candidate.sqrt().unwrap_or_else(F::zero))
+}
+
+/// Returns the evaluation of the polynomial with the given coefficients at `x`.
+fn evaluate<F: Field>(coefficients: &[F], x: F) -> F {
+    coefficients
+        .iter()
+        .rev()
+        .fold(F::zero(), |acc, coefficient| acc * x + *coefficient)
+}
+
+/// Returns the point on the isogenous curve for `u`, by the simplified SWU map of Section 6.6.2 of RFC 9380.
+fn map_to_isogenous_curve<P: SWUParameters>(u: P::BaseField) -> (P::BaseField, P::BaseField)
+where
+    P::BaseField: HashToField,
+{
+    let (a, b, z) = (P::ISOGENOUS_COEFF_A, P::ISOGENOUS_COEFF_B, P::SWU_Z);
+
+    let tv1 = z * u.square();
+    let mut tv2 = tv1.square() + tv1;
+    let tv3 = b * (tv2 + P::BaseField::one());
+    let tv4 = a * cmov(z, -tv2, !tv2.is_zero());
+    let mut tv6 = tv4.square();
+    tv2 = (tv3.square() + a * tv6) * tv3;
+    tv6 *= tv4;
+    tv2 += b * tv6;
+
+    let (is_square, y1) = sqrt_ratio(tv2, tv6, z);
+    let x = cmov(tv1 * tv3, tv3, is_square);
+    let y = cmov(tv1 * u * y1, y1, is_square);
+    let y = cmov(-y, y, u.sgn0() == y.sgn0());
+
+    // `tv4` is never zero, as `a` and `Z` are non-zero.
+    (x * tv4.inverse().unwrap(), y)
+}
+
+/// Returns the image of a point on the isogenous curve under the isogeny map of Section 6.6.3 of RFC 9380.
+fn isogeny_map<P: SWUParameters>(x: P::BaseField, y: P::BaseField) -> Affine<P> {
+    let x_denominator = evaluate(P::ISOGENY_X_DENOMINATOR, x);
+    let y_denominator = evaluate(P::ISOGENY_Y_DENOMINATOR, x);
+
+    // The points in the kernel of the isogeny are sent to the point at infinity.
+    match (x_denominator.inverse(), y_denominator.inverse()) {
+        (Some(x_denominator_inv), Some(y_denominator_inv)) => Affine::new(
+            evaluate(P::ISOGENY_X_NUMERATOR, x) * x_denominator_inv,
+            y * evaluate(P::ISOGENY_Y_NUMERATOR, x) * y_denominator_inv,
+            false,
+        ),
+        _ => Affine::zero(),
+    }
+}
+
+impl<P: SWUParameters> HashToCurve for Affine<P>
+where
+    P::BaseField: HashToField,
+{
+    fn map_to_curve(u: P::BaseField) -> Self {
+        let (x, y) = map_to_isogenous_curve::<P>(u);
+        isogeny_map::<P>(x, y)
+    }
+}
@@ -407,3 +407,179 @@ mod bw6_761 {
         );
     }
 }
+
+mod map_to_curve {
+    use crate::hash_to_curve::{expand_message_xmd, HashToCurve, HashToField};
+    use snarkvm_curves::{
+        bls12_377::{Fq, Fq2, G1Affine, G2Affine},
+        edwards_bls12::EdwardsAffine as EdwardsBls12Affine,
+        edwards_bw6::EdwardsAffine as EdwardsBW6Affine,
+        AffineCurve,
+    };
+    use snarkvm_fields::PrimeField;
+    use snarkvm_utilities::rand::{test_rng, UniformRand};
+
+    use std::str::FromStr;
+
+    const ITERATIONS: usize = 100;
+
+    fn check_map_to_curve<G: HashToCurve>()
+    where
+        G::BaseField: UniformRand + HashToField,
+    {
+        let rng = &mut test_rng();
+        for _ in 0..ITERATIONS {
+            let point = G::map_to_curve(G::BaseField::rand(rng));
+            assert!(point.is_on_curve());
+
+            let point = G::encode_to_curve(point.to_x_coordinate().to_string().as_bytes(), b"snarkVM-TEST").unwrap();
+            assert!(point.is_on_curve());
+            assert!(point.is_in_correct_subgroup_assuming_on_curve());
+        }
+    }
+
+    fn check_prime_field_vectors<G: HashToCurve>(dst: &str, vectors: &[(&str, &str, &str)])
+    where
+        G::BaseField: PrimeField + HashToField,
+    {
+        for (msg, x, y) in vectors {
+            let point = G::hash_to_curve(msg.as_bytes(), dst.as_bytes()).unwrap();
+            assert!(point.is_on_curve());
+            assert!(point.is_in_correct_subgroup_assuming_on_curve());
+            assert_eq!(point.to_x_coordinate(), G::BaseField::from_str(x).ok().unwrap());
+            assert_eq!(point.to_y_coordinate(), G::BaseField::from_str(y).ok().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_expand_message_xmd() {
+        // The vectors of Appendix K.1 of RFC 9380.
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        assert_eq!(
+            hex::encode(expand_message_xmd(b"", dst, 0x20).unwrap()),
+            "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"
+        );
+        assert_eq!(
+            hex::encode(expand_message_xmd(b"abc", dst, 0x20).unwrap()),
+            "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"
+        );
+
+        assert!(expand_message_xmd(b"", dst, 255 * 32).is_ok());
+        assert!(expand_message_xmd(b"", dst, 255 * 32 + 1).is_err());
+    }
+
+    #[test]
+    fn test_bls12_377_g1() {
+        check_map_to_curve::<G1Affine>();
+        check_prime_field_vectors::<G1Affine>("QUUX-V01-CS02-with-BLS12377G1_XMD:SHA-256_SSWU_RO_", &[
+            (
+                "",
+                "135644788506464358258987460083253733315216701454015302820622365091034384215029504600911121808261431698664775361980",
+                "188473728890658939293824817979369960495611961853448436263412246502098437457195081666368713413350802862064422404383",
+            ),
+            (
+                "abc",
+                "241885307474838499880951702466750515379875967583952188950591702002665417004604589521407642419637901958341640668385",
+                "44617608618314787533253084393984475277310713214426442276585262024643853000485071919587340622269493705809740753879",
+            ),
+            (
+                "abcdef0123456789",
+                "212782592878570318679625001521729294065968552375627051730442534119139613465587083787714835755134703009030274513924",
+                "127976337522369521207468266036500708036609489662825894083077082907610019855685796685979056114838661176781432076505",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_bls12_377_g2() {
+        check_map_to_curve::<G2Affine>();
+
+        let fq2 = |c: [&str; 2]| Fq2::new(Fq::from_str(c[0]).unwrap(), Fq::from_str(c[1]).unwrap());
+        let dst = "QUUX-V01-CS02-with-BLS12377G2_XMD:SHA-256_SSWU_RO_";
+        for (msg, x, y) in &[
+            (
+                "",
+                [
+                    "231838465121829338322224733957310383723224867940290121002833263716174062362341073538775948978680586645409149753103",
+                    "145353273620331882176930910986967036493573147378724956614164162423865178523065956234868012646685044662949868924832",
+                ],
+                [
+                    "166935876805850977393488874825774431089494994813760805838366666924738677463684126804137672052920666849578353520386",
+                    "145730910348667593972206666269579544846922310257400502616137923038106661498035435434515526181986952464473787412936",
+                ],
+            ),
+            (
+                "abc",
+                [
+                    "161567927589087069876697908643401060289984252135626546641760071157472069123086477488161890766835557927759419120135",
+                    "130310373529268570415323713354509767904781441225226158494151347558959886702898899774574945241909994718863469043904",
+                ],
+                [
+                    "138603331757490683641210792290143238998295510775557481264806241501808608305711020445622374192278042867217869684194",
+                    "102797085457295445804907020475864908945362037784699415604161885887223397064845386450455002992437851074778361721400",
+                ],
+            ),
+            (
+                "abcdef0123456789",
+                [
+                    "123938093518146677947075637436309187133554114192853404142603373513914339215798223990316303530231493681679268305268",
+                    "89911580180838766318519629236055145202059582780243988546081820092657338032232006979514487874573719487593530405472",
+                ],
+                [
+                    "44728983961011346170270097747135425622587722577935358898925520478298054143137854622849839131987563138320923329282",
+                    "221566354125087199524006567078543783521398092797566093745614127295822281457275441805568389440449888933557996325311",
+                ],
+            ),
+        ] {
+            let point = G2Affine::hash_to_curve(msg.as_bytes(), dst.as_bytes()).unwrap();
+            assert!(point.is_on_curve());
+            assert!(point.is_in_correct_subgroup_assuming_on_curve());
+            assert_eq!(point.x, fq2(*x));
+            assert_eq!(point.y, fq2(*y));
+        }
+    }
+
+    #[test]
+    fn test_edwards_bls12() {
+        check_map_to_curve::<EdwardsBls12Affine>();
+        check_prime_field_vectors::<EdwardsBls12Affine>("QUUX-V01-CS02-with-EdwardsBLS12_XMD:SHA-256_ELL2_RO_", &[
+            (
+                "",
+                "5349259833467160312022913993513598859608511596618187612042690225907784543900",
+                "6252056788811004379279575566828096038550446613660569213535107548667476497556",
+            ),
+            (
+                "abc",
+                "7285416432049111407636369137587302475608250523186585340321190544134261705531",
+                "4393976613031030241185520980716698133206625148077227749114096354516518512483",
+            ),
+            (
+                "abcdef0123456789",
+                "6155680363779418728272635587479881180226881793232501757819254517192996489810",
+                "1991428572883677591956973522502152970519614164015699484461800571422216624607",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_edwards_bw6() {
+        check_map_to_curve::<EdwardsBW6Affine>();
+        check_prime_field_vectors::<EdwardsBW6Affine>("QUUX-V01-CS02-with-EdwardsBW6_XMD:SHA-256_ELL2_RO_", &[
+            (
+                "",
+                "236247395191082970725751433344334043248204171171287132332210473811346389634196044644168679136304930487329463231749",
+                "170561697415133960033581387392516832929538378490458402846244092264632869838180226009516962961725009167117497717731",
+            ),
+            (
+                "abc",
+                "185077069404616776391300743009929019975623096247719931813081413514586365266460077088471636718752896503365255324537",
+                "65649630146958586846341487313630070965908971468905442005861257598903874753005539651546253796882617250538593732048",
+            ),
+            (
+                "abcdef0123456789",
+                "88109561414695977788559927893598857337088928454262711378596019964636018662235605426357929319409607981370590858293",
+                "26548731809116894074039460190365053556259862587746164679548629068553922132727575639156335588787372950148909213783",
+            ),
+        ]);
+    }
+}
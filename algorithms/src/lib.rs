@@ -73,6 +73,9 @@ pub mod snark;
 pub mod traits;
 pub use traits::*;
 
+#[cfg(feature = "vrf")]
+pub mod vrf;
+
 pub mod prelude {
     pub use crate::{errors::*, traits::*};
 }
@@ -0,0 +1,410 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::MerkleError,
+    merkle_tree::{MerklePath, MerkleTreeDigest},
+    traits::MerkleParameters,
+};
+use snarkvm_utilities::ToBytes;
+
+use std::sync::Arc;
+
+///
+/// An append-only Merkle tree that stores only its frontier.
+///
+/// The frontier holds, for each level of the tree, the root of the most recently completed
+/// left subtree. Appending a leaf and recomputing the root both take O(depth) hashes.
+///
+/// The roots match those of `MerkleTree` for the same `MerkleParameters` and leaves.
+/// In particular, empty subtrees below the height of the smallest power-of-two tree that
+/// holds the leaves are hashed as full subtrees of empty leaves, while the padding levels
+/// above it use the empty leaf hash as the sibling.
+///
+#[derive(Clone, Debug)]
+pub struct IncrementalMerkleTree<P: MerkleParameters> {
+    /// The root of the most recently completed left subtree, for each level of the tree.
+    frontier: Vec<MerkleTreeDigest<P>>,
+    /// The number of leaves in the tree.
+    num_leaves: u64,
+    /// The hash of the most recently appended leaf.
+    latest_leaf: Option<MerkleTreeDigest<P>>,
+    /// The root of the tree.
+    root: MerkleTreeDigest<P>,
+    /// The roots of the empty subtrees, for each level of the tree.
+    empty_hashes: Arc<Vec<MerkleTreeDigest<P>>>,
+    /// The Merkle tree parameters (e.g. the hash function).
+    parameters: Arc<P>,
+}
+
+impl<P: MerkleParameters> IncrementalMerkleTree<P> {
+    pub const DEPTH: usize = P::DEPTH;
+
+    /// Initializes an empty incremental Merkle tree.
+    pub fn new(parameters: Arc<P>) -> Result<Self, MerkleError> {
+        // Compute the roots of the empty subtrees at each level.
+        let mut empty_hashes = Vec::with_capacity(Self::DEPTH + 1);
+        empty_hashes.push(parameters.hash_empty()?);
+        for level in 0..Self::DEPTH {
+            empty_hashes.push(parameters.hash_inner_node(&empty_hashes[level], &empty_hashes[level])?);
+        }
+
+        let mut tree = Self {
+            frontier: vec![empty_hashes[0]; Self::DEPTH + 1],
+            num_leaves: 0,
+            latest_leaf: None,
+            root: empty_hashes[0],
+            empty_hashes: Arc::new(empty_hashes),
+            parameters,
+        };
+        tree.root = tree.compute_root()?;
+        Ok(tree)
+    }
+
+    /// Initializes an incremental Merkle tree with the given leaves.
+    pub fn from_leaves<L: ToBytes>(parameters: Arc<P>, leaves: &[L]) -> Result<Self, MerkleError> {
+        let mut tree = Self::new(parameters)?;
+        tree.append_all(leaves)?;
+        Ok(tree)
+    }
+
+    /// Appends the given leaf to the tree, returning its index in the tree.
+    pub fn append<L: ToBytes>(&mut self, leaf: &L) -> Result<u64, MerkleError> {
+        let leaf_hash = self.parameters.hash_leaf(leaf)?;
+        self.append_hash(leaf_hash)?;
+        self.root = self.compute_root()?;
+        Ok(self.num_leaves - 1)
+    }
+
+    /// Appends all given leaves to the tree, returning the index of the first leaf in the tree.
+    pub fn append_all<L: ToBytes>(&mut self, leaves: &[L]) -> Result<u64, MerkleError> {
+        let start_index = self.num_leaves;
+        for leaf in leaves {
+            let leaf_hash = self.parameters.hash_leaf(leaf)?;
+            self.append_hash(leaf_hash)?;
+        }
+        self.root = self.compute_root()?;
+        Ok(start_index)
+    }
+
+    /// Returns a witness for the most recently appended leaf, which can be kept up to date
+    /// by appending all subsequent leaves of the tree to it.
+    pub fn witness(&self) -> Result<IncrementalWitness<P>, MerkleError> {
+        let (position, leaf) = match (self.num_leaves.checked_sub(1), self.latest_leaf) {
+            (Some(position), Some(leaf)) => (position, leaf),
+            _ => {
+                return Err(MerkleError::Message(
+                    "Cannot witness a leaf in an empty tree".to_string(),
+                ));
+            }
+        };
+
+        // The left siblings of the leaf are the frontier entries on the levels where the position is odd.
+        // These entries are not overwritten by appending the leaf, as it only updates the lowest even level.
+        let left_siblings = (0..Self::DEPTH)
+            .map(|level| match is_set(position, level) {
+                true => Some(self.frontier[level]),
+                false => None,
+            })
+            .collect();
+
+        Ok(IncrementalWitness {
+            position,
+            leaf,
+            left_siblings,
+            right_siblings: vec![],
+            cursor: Vec::with_capacity(Self::DEPTH),
+            cursor_size: 0,
+            num_leaves: self.num_leaves,
+            empty_hashes: self.empty_hashes.clone(),
+            parameters: self.parameters.clone(),
+        })
+    }
+
+    /// Returns the root of the tree.
+    #[inline]
+    pub fn root(&self) -> &MerkleTreeDigest<P> {
+        &self.root
+    }
+
+    /// Returns the number of leaves in the tree.
+    #[inline]
+    pub fn len(&self) -> u64 {
+        self.num_leaves
+    }
+
+    /// Returns `true` if the tree has no leaves.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.num_leaves == 0
+    }
+
+    /// Returns the Merkle tree parameters.
+    #[inline]
+    pub fn parameters(&self) -> &Arc<P> {
+        &self.parameters
+    }
+
+    /// Appends the given leaf hash to the frontier, without updating the root.
+    fn append_hash(&mut self, leaf_hash: MerkleTreeDigest<P>) -> Result<(), MerkleError> {
+        if is_full(self.num_leaves, Self::DEPTH) {
+            return Err(MerkleError::InvalidTreeDepth(Self::DEPTH + 1, Self::DEPTH));
+        }
+
+        // Merge the completed left subtrees on the path of the new leaf.
+        let mut current = leaf_hash;
+        let mut level = 0;
+        while is_set(self.num_leaves, level) {
+            current = self.parameters.hash_inner_node(&self.frontier[level], &current)?;
+            level += 1;
+        }
+        self.frontier[level] = current;
+
+        self.num_leaves += 1;
+        self.latest_leaf = Some(leaf_hash);
+        Ok(())
+    }
+
+    /// Computes the root of the tree from the frontier.
+    fn compute_root(&self) -> Result<MerkleTreeDigest<P>, MerkleError> {
+        let empty_hash = &self.empty_hashes[0];
+
+        match self.num_leaves {
+            // An empty tree is an empty leaf, padded to the tree depth.
+            0 => {
+                let mut current = *empty_hash;
+                for _ in 0..Self::DEPTH {
+                    current = self.parameters.hash_inner_node(&current, empty_hash)?;
+                }
+                Ok(current)
+            }
+            // A full tree is its completed subtree at the top level.
+            num_leaves if is_full(num_leaves, Self::DEPTH) => Ok(self.frontier[Self::DEPTH]),
+            num_leaves => {
+                let padding_level = padding_level(num_leaves);
+                match subtree_root(
+                    &*self.parameters,
+                    &self.frontier,
+                    num_leaves,
+                    Self::DEPTH,
+                    &self.empty_hashes,
+                    padding_level,
+                )? {
+                    Some(root) => Ok(root),
+                    None => Err(MerkleError::Message(
+                        "Failed to compute the incremental tree root".to_string(),
+                    )),
+                }
+            }
+        }
+    }
+}
+
+///
+/// An authentication path for a single leaf of an `IncrementalMerkleTree`.
+///
+/// The witness is created from the tree when its leaf is appended, and is kept up to date
+/// by appending every subsequent leaf of the tree to it. It stores the left siblings of the
+/// leaf, the completed right siblings, and the frontier of the right sibling being filled.
+///
+#[derive(Clone, Debug)]
+pub struct IncrementalWitness<P: MerkleParameters> {
+    /// The index of the witnessed leaf.
+    position: u64,
+    /// The hash of the witnessed leaf.
+    leaf: MerkleTreeDigest<P>,
+    /// The left sibling of the leaf, for each level where the leaf is on the right.
+    left_siblings: Vec<Option<MerkleTreeDigest<P>>>,
+    /// The completed right siblings of the leaf, in order of increasing level.
+    right_siblings: Vec<MerkleTreeDigest<P>>,
+    /// The frontier of the right sibling that is currently being filled.
+    cursor: Vec<MerkleTreeDigest<P>>,
+    /// The number of leaves in the right sibling that is currently being filled.
+    cursor_size: u64,
+    /// The number of leaves in the tree.
+    num_leaves: u64,
+    /// The roots of the empty subtrees, for each level of the tree.
+    empty_hashes: Arc<Vec<MerkleTreeDigest<P>>>,
+    /// The Merkle tree parameters (e.g. the hash function).
+    parameters: Arc<P>,
+}
+
+impl<P: MerkleParameters> IncrementalWitness<P> {
+    pub const DEPTH: usize = P::DEPTH;
+
+    /// Appends the next leaf of the tree to the witness.
+    pub fn append<L: ToBytes>(&mut self, leaf: &L) -> Result<(), MerkleError> {
+        let leaf_hash = self.parameters.hash_leaf(leaf)?;
+
+        if is_full(self.num_leaves, Self::DEPTH) {
+            return Err(MerkleError::InvalidTreeDepth(Self::DEPTH + 1, Self::DEPTH));
+        }
+        let level = match self.next_right_sibling_level() {
+            Some(level) => level,
+            None => {
+                return Err(MerkleError::Message(
+                    "The witness has no remaining right siblings".to_string(),
+                ));
+            }
+        };
+
+        // Append the leaf to the frontier of the right sibling being filled.
+        if self.cursor.len() < level + 1 {
+            self.cursor.resize(level + 1, self.empty_hashes[0]);
+        }
+        let mut current = leaf_hash;
+        let mut cursor_level = 0;
+        while is_set(self.cursor_size, cursor_level) {
+            current = self.parameters.hash_inner_node(&self.cursor[cursor_level], &current)?;
+            cursor_level += 1;
+        }
+        self.cursor[cursor_level] = current;
+        self.cursor_size += 1;
+        self.num_leaves += 1;
+
+        // Once the right sibling is complete, store its root and reset the cursor.
+        if self.cursor_size == 1 << level {
+            self.right_siblings.push(self.cursor[level]);
+            self.cursor.clear();
+            self.cursor_size = 0;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the index of the witnessed leaf.
+    #[inline]
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns the number of leaves in the tree, as known to the witness.
+    #[inline]
+    pub fn num_leaves(&self) -> u64 {
+        self.num_leaves
+    }
+
+    /// Returns the authentication path of the witnessed leaf, for the current tree.
+    pub fn path(&self) -> Result<MerklePath<P>, MerkleError> {
+        let padding_level = padding_level(self.num_leaves);
+
+        let mut path = Vec::with_capacity(Self::DEPTH);
+        let mut right_siblings = self.right_siblings.iter();
+        let mut is_cursor_pending = self.cursor_size > 0;
+        for level in 0..Self::DEPTH {
+            let sibling = match self.left_siblings[level] {
+                Some(left_sibling) => left_sibling,
+                None => match right_siblings.next() {
+                    Some(right_sibling) => *right_sibling,
+                    // The right sibling being filled is padded with empty subtrees.
+                    None if is_cursor_pending => {
+                        is_cursor_pending = false;
+                        match subtree_root(
+                            &*self.parameters,
+                            &self.cursor,
+                            self.cursor_size,
+                            level,
+                            &self.empty_hashes,
+                            level,
+                        )? {
+                            Some(root) => root,
+                            None => return Err(MerkleError::Message("Failed to compute the witness path".to_string())),
+                        }
+                    }
+                    // The remaining right siblings are empty.
+                    None => match level < padding_level {
+                        true => self.empty_hashes[level],
+                        false => self.empty_hashes[0],
+                    },
+                },
+            };
+            path.push(sibling);
+        }
+
+        Ok(MerklePath {
+            parameters: self.parameters.clone(),
+            path,
+            leaf_index: self.position,
+        })
+    }
+
+    /// Returns the root of the tree, as computed from the authentication path of the witnessed leaf.
+    pub fn root(&self) -> Result<MerkleTreeDigest<P>, MerkleError> {
+        let path = self.path()?;
+
+        let mut current = self.leaf;
+        for (level, sibling) in path.path.iter().enumerate() {
+            current = match is_set(self.position, level) {
+                true => self.parameters.hash_inner_node(sibling, &current)?,
+                false => self.parameters.hash_inner_node(&current, sibling)?,
+            };
+        }
+        Ok(current)
+    }
+
+    /// Returns the level of the right sibling that the next leaf of the tree belongs to.
+    fn next_right_sibling_level(&self) -> Option<usize> {
+        (0..Self::DEPTH)
+            .filter(|level| !is_set(self.position, *level))
+            .nth(self.right_siblings.len())
+    }
+}
+
+/// Returns the root of a subtree of the given height, from the frontier of its first `num_leaves` leaves.
+/// Empty right siblings below `padding_level` are empty subtrees, while those above are empty leaves.
+fn subtree_root<P: MerkleParameters>(
+    parameters: &P,
+    frontier: &[MerkleTreeDigest<P>],
+    num_leaves: u64,
+    height: usize,
+    empty_hashes: &[MerkleTreeDigest<P>],
+    padding_level: usize,
+) -> Result<Option<MerkleTreeDigest<P>>, MerkleError> {
+    let mut current: Option<MerkleTreeDigest<P>> = None;
+    for level in 0..height {
+        let empty_hash = match level < padding_level {
+            true => &empty_hashes[level],
+            false => &empty_hashes[0],
+        };
+
+        current = match (is_set(num_leaves, level), current) {
+            (true, current) => {
+                Some(parameters.hash_inner_node(&frontier[level], current.as_ref().unwrap_or(empty_hash))?)
+            }
+            (false, Some(current)) => Some(parameters.hash_inner_node(&current, empty_hash)?),
+            (false, None) => None,
+        };
+    }
+    Ok(current)
+}
+
+/// Returns the height of the smallest power-of-two tree that holds the given number of leaves.
+#[inline]
+fn padding_level(num_leaves: u64) -> usize {
+    num_leaves.next_power_of_two().trailing_zeros() as usize
+}
+
+/// Returns `true` if the bit at the given level of the given index is set.
+#[inline]
+fn is_set(index: u64, level: usize) -> bool {
+    index.checked_shr(level as u32).unwrap_or(0) & 1 == 1
+}
+
+/// Returns `true` if a tree of the given depth cannot hold another leaf.
+#[inline]
+fn is_full(num_leaves: u64, depth: usize) -> bool {
+    depth < 64 && num_leaves >= 1 << depth
+}
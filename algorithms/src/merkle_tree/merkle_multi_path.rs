@@ -0,0 +1,214 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::MerkleError,
+    merkle_tree::{MerklePath, MerkleTreeDigest},
+    traits::MerkleParameters,
+};
+use snarkvm_utilities::{error, FromBytes, ToBytes};
+
+use std::{
+    collections::BTreeMap,
+    io::{Read, Result as IoResult, Write},
+    sync::Arc,
+};
+
+/// Stores the hashes required to verify the paths of multiple leaves to the same root.
+/// Sibling hashes that are shared between the paths, or that can be computed from the
+/// given leaves, are only stored once.
+#[derive(Clone, Debug)]
+pub struct MerkleMultiPath<P: MerkleParameters> {
+    pub parameters: Arc<P>,
+    /// The sorted and deduplicated indices of the leaves.
+    pub leaf_indices: Vec<u64>,
+    /// The sibling hashes that are not computable from the leaves, ordered by level from bottom to top,
+    /// and by index within each level.
+    pub auxiliary: Vec<MerkleTreeDigest<P>>,
+}
+
+impl<P: MerkleParameters> MerkleMultiPath<P> {
+    /// Returns a multi-path from the given Merkle paths, which must be for the same tree,
+    /// and sorted by strictly increasing leaf index.
+    pub fn new(paths: &[MerklePath<P>]) -> Result<Self, MerkleError> {
+        let parameters = match paths.first() {
+            Some(path) => path.parameters.clone(),
+            None => {
+                return Err(MerkleError::Message(
+                    "Missing Merkle paths for the multi-path".to_string(),
+                ));
+            }
+        };
+
+        // Map each leaf index to its path, ensuring the leaf indices are sorted and unique,
+        // as `verify` expects the leaves in the same order.
+        let mut current_level = BTreeMap::new();
+        for path in paths {
+            if path.path.len() != P::DEPTH {
+                return Err(MerkleError::IncorrectPathLength(path.path.len()));
+            }
+            if current_level
+                .keys()
+                .next_back()
+                .map_or(false, |previous| *previous >= path.leaf_index)
+            {
+                return Err(MerkleError::Message(
+                    "The leaf indices of the multi-path must be sorted and unique".to_string(),
+                ));
+            }
+            current_level.insert(path.leaf_index, path);
+        }
+        let leaf_indices: Vec<u64> = current_level.keys().copied().collect();
+
+        // Store each sibling hash that will not be computed during verification.
+        let mut auxiliary = vec![];
+        for level in 0..P::DEPTH {
+            let mut next_level = BTreeMap::new();
+            for (index, path) in current_level.iter() {
+                if !current_level.contains_key(&(index ^ 1)) {
+                    auxiliary.push(path.path[level]);
+                }
+                next_level.entry(index >> 1).or_insert(*path);
+            }
+            current_level = next_level;
+        }
+
+        Ok(Self {
+            parameters,
+            leaf_indices,
+            auxiliary,
+        })
+    }
+
+    /// Returns `true` if the given leaves, ordered by their leaf indices, are in the tree with the given root.
+    pub fn verify<L: ToBytes>(&self, root_hash: &MerkleTreeDigest<P>, leaves: &[L]) -> Result<bool, MerkleError> {
+        if self.leaf_indices.is_empty() || self.leaf_indices.len() != leaves.len() {
+            return Ok(false);
+        }
+
+        // Hash the leaves, ensuring the leaf indices are sorted and unique.
+        let mut current_level = BTreeMap::new();
+        for (leaf_index, leaf) in self.leaf_indices.iter().zip(leaves) {
+            if current_level
+                .keys()
+                .next_back()
+                .map_or(false, |previous| previous >= leaf_index)
+            {
+                return Ok(false);
+            }
+            current_level.insert(*leaf_index, self.parameters.hash_leaf(leaf)?);
+        }
+
+        // Compute the nodes of each level from bottom to top, using the auxiliary hashes for missing siblings.
+        let mut auxiliary = self.auxiliary.iter();
+        for _ in 0..P::DEPTH {
+            let mut next_level = BTreeMap::new();
+            for (index, node) in current_level.iter() {
+                // Skip the right child when its left sibling has already been hashed with it.
+                if index & 1 == 1 && current_level.contains_key(&(index ^ 1)) {
+                    continue;
+                }
+
+                let sibling = match current_level.get(&(index ^ 1)) {
+                    Some(sibling) => sibling,
+                    None => match auxiliary.next() {
+                        Some(sibling) => sibling,
+                        None => return Ok(false),
+                    },
+                };
+
+                let parent = match index & 1 == 0 {
+                    true => self.parameters.hash_inner_node(node, sibling)?,
+                    false => self.parameters.hash_inner_node(sibling, node)?,
+                };
+                next_level.insert(index >> 1, parent);
+            }
+            current_level = next_level;
+        }
+
+        // Ensure every auxiliary hash was used, and the computed root matches.
+        if auxiliary.next().is_some() {
+            return Ok(false);
+        }
+        Ok(current_level.len() == 1 && current_level.get(&0) == Some(root_hash))
+    }
+}
+
+impl<P: MerkleParameters> FromBytes for MerkleMultiPath<P> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // The parameters are recovered from their setup message, as in `MerklePath`.
+        let parameters = {
+            let setup_message_length: u64 = FromBytes::read_le(&mut reader)?;
+
+            // Read the setup message without preallocating its untrusted length.
+            let mut setup_message_bytes = Vec::new();
+            (&mut reader)
+                .take(setup_message_length)
+                .read_to_end(&mut setup_message_bytes)?;
+            if setup_message_bytes.len() as u64 != setup_message_length {
+                return Err(error("Missing bytes in the setup message for Merkle parameters"));
+            }
+            let setup_message = String::from_utf8(setup_message_bytes)
+                .map_err(|_| error("Failed to parse setup message for Merkle parameters"))?;
+
+            Arc::new(P::setup(&setup_message))
+        };
+
+        // A tree of depth `DEPTH` has at most `2^DEPTH` leaves.
+        let num_leaf_indices: u64 = FromBytes::read_le(&mut reader)?;
+        if P::DEPTH < 64 && num_leaf_indices > 1u64 << P::DEPTH {
+            return Err(error("Too many leaf indices for the Merkle tree depth"));
+        }
+        let mut leaf_indices = Vec::new();
+        for _ in 0..num_leaf_indices {
+            leaf_indices.push(FromBytes::read_le(&mut reader)?);
+        }
+
+        // Each leaf contributes at most one auxiliary hash per level.
+        let num_auxiliary: u64 = FromBytes::read_le(&mut reader)?;
+        if num_auxiliary > num_leaf_indices.saturating_mul(P::DEPTH as u64) {
+            return Err(error("Too many auxiliary hashes for the number of leaves"));
+        }
+        let mut auxiliary = Vec::new();
+        for _ in 0..num_auxiliary {
+            auxiliary.push(FromBytes::read_le(&mut reader)?);
+        }
+
+        Ok(Self {
+            parameters,
+            leaf_indices,
+            auxiliary,
+        })
+    }
+}
+
+impl<P: MerkleParameters> ToBytes for MerkleMultiPath<P> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        let setup_message_bytes: &[u8] = self.parameters.setup_message().as_bytes();
+        let setup_message_length: u64 = setup_message_bytes.len() as u64;
+
+        setup_message_length.write_le(&mut writer)?;
+        setup_message_bytes.write_le(&mut writer)?;
+
+        (self.leaf_indices.len() as u64).write_le(&mut writer)?;
+        self.leaf_indices.write_le(&mut writer)?;
+
+        (self.auxiliary.len() as u64).write_le(&mut writer)?;
+        self.auxiliary.write_le(&mut writer)
+    }
+}
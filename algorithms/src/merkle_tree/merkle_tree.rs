@@ -16,7 +16,7 @@
 
 use crate::{
     errors::MerkleError,
-    merkle_tree::{MerklePath, MerkleTreeDigest},
+    merkle_tree::{MerkleMultiPath, MerklePath, MerkleTreeDigest},
     traits::{MerkleParameters, CRH},
 };
 use snarkvm_utilities::ToBytes;
@@ -298,6 +298,34 @@ impl<P: MerkleParameters + Send + Sync> MerkleTree<P> {
         }
     }
 
+    /// Returns a multi-path for the leaves at the given indices, where `leaves[i]` is the leaf at `indices[i]`.
+    /// The indices must be sorted and unique, as the multi-path verifies the leaves in this order.
+    pub fn generate_multi_proof<L: ToBytes>(
+        &self,
+        indices: &[usize],
+        leaves: &[L],
+    ) -> Result<MerkleMultiPath<P>, MerkleError> {
+        if indices.len() != leaves.len() {
+            return Err(MerkleError::Message(format!(
+                "Mismatched number of indices ({}) and leaves ({})",
+                indices.len(),
+                leaves.len()
+            )));
+        }
+        if indices.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(MerkleError::Message(
+                "The indices of the multi-path must be sorted and unique".to_string(),
+            ));
+        }
+
+        let paths = indices
+            .iter()
+            .zip(leaves)
+            .map(|(index, leaf)| self.generate_proof(*index, leaf))
+            .collect::<Result<Vec<_>, _>>()?;
+        MerkleMultiPath::new(&paths)
+    }
+
     fn hash_row<L: ToBytes + Send + Sync>(
         parameters: &P,
         leaves: &[L],
@@ -15,6 +15,9 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{MerkleParameters, CRH};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use std::io::{Error, ErrorKind, Read, Result as IoResult, Write};
 
 /// Defines a Merkle tree using the provided hash and depth.
 #[derive(Clone, PartialEq, Eq, Debug)]
@@ -44,3 +47,29 @@ impl<H: CRH, const DEPTH: usize> MerkleParameters for MerkleTreeParameters<H, DE
         &self.0
     }
 }
+
+impl<H: CRH, const DEPTH: usize> ToBytes for MerkleTreeParameters<H, DEPTH> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.0.write_le(&mut writer)?;
+
+        let setup_message_bytes: &[u8] = self.1.as_bytes();
+        (setup_message_bytes.len() as u64).write_le(&mut writer)?;
+        setup_message_bytes.write_le(&mut writer)
+    }
+}
+
+impl<H: CRH, const DEPTH: usize> FromBytes for MerkleTreeParameters<H, DEPTH> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let crh = FromBytes::read_le(&mut reader)?;
+
+        let setup_message_length: u64 = FromBytes::read_le(&mut reader)?;
+        let mut setup_message_bytes = vec![0u8; setup_message_length as usize];
+        reader.read_exact(&mut setup_message_bytes)?;
+        let message = String::from_utf8(setup_message_bytes)
+            .map_err(|_| Error::new(ErrorKind::InvalidData, "Invalid setup message for Merkle parameters"))?;
+
+        Ok(Self(crh, message))
+    }
+}
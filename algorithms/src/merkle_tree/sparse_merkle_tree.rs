@@ -0,0 +1,232 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{errors::MerkleError, merkle_tree::MerkleTreeDigest, traits::MerkleParameters};
+use snarkvm_utilities::ToBytes;
+
+use std::{collections::HashMap, sync::Arc};
+
+///
+/// A key-addressed sparse Merkle tree.
+///
+/// Each key is placed at the leaf whose index is given by every bit of its leaf hash, so the depth
+/// of the tree is the bit length of the leaf hash, and `P::DEPTH` is not used. An occupied leaf
+/// stores the leaf hash of its key, while an unoccupied leaf stores the empty hash, so a path to
+/// an unoccupied leaf proves that a key is not in the tree. Only the nodes that differ from the
+/// corresponding empty subtree are stored.
+///
+#[derive(Clone, Debug)]
+pub struct SparseMerkleTree<P: MerkleParameters> {
+    /// The non-empty nodes of the tree, indexed by (level, index), where the leaves are at level 0,
+    /// and the index of a node is given by the leaf index bits from its level upwards.
+    nodes: HashMap<(usize, Vec<bool>), MerkleTreeDigest<P>>,
+    /// The roots of the empty subtrees, for each level of the tree.
+    empty_hashes: Vec<MerkleTreeDigest<P>>,
+    /// The Merkle tree parameters (e.g. the hash function).
+    parameters: Arc<P>,
+}
+
+impl<P: MerkleParameters> SparseMerkleTree<P> {
+    /// Initializes an empty sparse Merkle tree.
+    pub fn new(parameters: Arc<P>) -> Result<Self, MerkleError> {
+        let depth = leaf_index_size_in_bits(&*parameters)?;
+
+        // Compute the roots of the empty subtrees at each level.
+        let mut empty_hashes = Vec::with_capacity(depth + 1);
+        empty_hashes.push(parameters.hash_empty()?);
+        for level in 0..depth {
+            empty_hashes.push(parameters.hash_inner_node(&empty_hashes[level], &empty_hashes[level])?);
+        }
+
+        Ok(Self {
+            nodes: Default::default(),
+            empty_hashes,
+            parameters,
+        })
+    }
+
+    /// Inserts the given key into the tree. Returns `false` if the key already exists in the tree.
+    pub fn insert<K: ToBytes>(&mut self, key: &K) -> Result<bool, MerkleError> {
+        let (leaf_hash, index) = key_to_leaf(&*self.parameters, key)?;
+
+        match self.nodes.get(&(0, index.clone())) {
+            Some(_) => Ok(false),
+            None => {
+                self.update(&index, leaf_hash)?;
+                Ok(true)
+            }
+        }
+    }
+
+    /// Removes the given key from the tree. Returns `false` if the key does not exist in the tree.
+    pub fn delete<K: ToBytes>(&mut self, key: &K) -> Result<bool, MerkleError> {
+        let (_, index) = key_to_leaf(&*self.parameters, key)?;
+
+        match self.nodes.contains_key(&(0, index.clone())) {
+            true => {
+                self.update(&index, self.empty_hashes[0])?;
+                Ok(true)
+            }
+            false => Ok(false),
+        }
+    }
+
+    /// Returns `true` if the given key exists in the tree.
+    pub fn contains<K: ToBytes>(&self, key: &K) -> Result<bool, MerkleError> {
+        let (_, index) = key_to_leaf(&*self.parameters, key)?;
+        Ok(self.nodes.contains_key(&(0, index)))
+    }
+
+    /// Returns the root of the tree.
+    #[inline]
+    pub fn root(&self) -> &MerkleTreeDigest<P> {
+        self.node(self.depth(), &[])
+    }
+
+    /// Returns the depth of the tree, which is the bit length of a leaf hash.
+    #[inline]
+    pub fn depth(&self) -> usize {
+        self.empty_hashes.len() - 1
+    }
+
+    /// Returns the Merkle tree parameters.
+    #[inline]
+    pub fn parameters(&self) -> &Arc<P> {
+        &self.parameters
+    }
+
+    /// Returns the path to the leaf of the given key, which proves either its membership
+    /// or its non-membership in the tree.
+    pub fn generate_proof<K: ToBytes>(&self, key: &K) -> Result<SparseMerklePath<P>, MerkleError> {
+        let (_, index) = key_to_leaf(&*self.parameters, key)?;
+
+        let path = (0..self.depth())
+            .map(|level| *self.node(level, &sibling_index(&index, level)))
+            .collect();
+
+        Ok(SparseMerklePath {
+            parameters: self.parameters.clone(),
+            path,
+        })
+    }
+
+    /// Returns the node at the given level and index.
+    fn node(&self, level: usize, index: &[bool]) -> &MerkleTreeDigest<P> {
+        self.nodes
+            .get(&(level, index.to_vec()))
+            .unwrap_or(&self.empty_hashes[level])
+    }
+
+    /// Sets the leaf at the given index, and recomputes the nodes on its path to the root.
+    fn update(&mut self, index: &[bool], leaf_hash: MerkleTreeDigest<P>) -> Result<(), MerkleError> {
+        let depth = self.depth();
+
+        // Compute the new nodes before writing them, so the tree is not altered in case of failure.
+        let mut updates = Vec::with_capacity(depth + 1);
+        updates.push(leaf_hash);
+        for level in 0..depth {
+            let current = &updates[level];
+            let sibling = self.node(level, &sibling_index(index, level));
+            let parent = match index[level] {
+                false => self.parameters.hash_inner_node(current, sibling)?,
+                true => self.parameters.hash_inner_node(sibling, current)?,
+            };
+            updates.push(parent);
+        }
+
+        for (level, node) in updates.into_iter().enumerate() {
+            let key = (level, index[level..].to_vec());
+            match node == self.empty_hashes[level] {
+                true => self.nodes.remove(&key),
+                false => self.nodes.insert(key, node),
+            };
+        }
+
+        Ok(())
+    }
+}
+
+///
+/// A path to the leaf of a key in a `SparseMerkleTree`, which proves
+/// either its membership or its non-membership in the tree.
+///
+/// The position of the leaf is not stored, as it is recomputed from the key on verification.
+///
+#[derive(Clone, Debug)]
+pub struct SparseMerklePath<P: MerkleParameters> {
+    pub parameters: Arc<P>,
+    /// `path[i]` is the sibling of the node at level `i` on the path, from the leaf to the root.
+    pub path: Vec<MerkleTreeDigest<P>>,
+}
+
+impl<P: MerkleParameters> SparseMerklePath<P> {
+    /// Returns `true` if the path proves the given key is in the tree with the given root.
+    pub fn verify_membership<K: ToBytes>(&self, root: &MerkleTreeDigest<P>, key: &K) -> Result<bool, MerkleError> {
+        let (leaf_hash, index) = key_to_leaf(&*self.parameters, key)?;
+        Ok(self.compute_root(leaf_hash, &index)? == *root)
+    }
+
+    /// Returns `true` if the path proves the given key is not in the tree with the given root.
+    pub fn verify_non_membership<K: ToBytes>(&self, root: &MerkleTreeDigest<P>, key: &K) -> Result<bool, MerkleError> {
+        let (_, index) = key_to_leaf(&*self.parameters, key)?;
+        let empty_hash = self.parameters.hash_empty()?;
+        Ok(self.compute_root(empty_hash, &index)? == *root)
+    }
+
+    /// Returns the root of the tree, given the hash of the leaf at the end of the path, and its index.
+    fn compute_root(&self, leaf_hash: MerkleTreeDigest<P>, index: &[bool]) -> Result<MerkleTreeDigest<P>, MerkleError> {
+        if self.path.len() != index.len() {
+            return Err(MerkleError::IncorrectPathLength(self.path.len()));
+        }
+
+        let mut current = leaf_hash;
+        for (is_right, sibling) in index.iter().zip(self.path.iter()) {
+            current = match is_right {
+                false => self.parameters.hash_inner_node(&current, sibling)?,
+                true => self.parameters.hash_inner_node(sibling, &current)?,
+            };
+        }
+        Ok(current)
+    }
+}
+
+/// Returns the bit length of a leaf hash, which is the depth of a sparse Merkle tree.
+fn leaf_index_size_in_bits<P: MerkleParameters>(parameters: &P) -> Result<usize, MerkleError> {
+    Ok(parameters.hash_empty()?.to_bytes_le()?.len() * 8)
+}
+
+/// Returns the index of the sibling of the node at the given level on the path to the given leaf.
+fn sibling_index(index: &[bool], level: usize) -> Vec<bool> {
+    let mut sibling = index[level..].to_vec();
+    sibling[0] = !sibling[0];
+    sibling
+}
+
+/// Returns the leaf hash of the given key, and the index of its leaf,
+/// which is given by the little-endian bits of the leaf hash.
+fn key_to_leaf<P: MerkleParameters, K: ToBytes>(
+    parameters: &P,
+    key: &K,
+) -> Result<(MerkleTreeDigest<P>, Vec<bool>), MerkleError> {
+    let leaf_hash = parameters.hash_leaf(key)?;
+    let index = leaf_hash
+        .to_bytes_le()?
+        .iter()
+        .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1))
+        .collect();
+
+    Ok((leaf_hash, index))
+}
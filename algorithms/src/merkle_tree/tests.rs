@@ -16,10 +16,18 @@
 
 use crate::{
     crh::{PedersenCRH, PedersenCompressedCRH},
-    merkle_tree::{MerkleTree, MerkleTreeParameters},
+    merkle_tree::{
+        IncrementalMerkleTree,
+        IncrementalWitness,
+        MaskedMerkleTreeParameters,
+        MerkleMultiPath,
+        MerkleTree,
+        MerkleTreeParameters,
+        SparseMerkleTree,
+    },
     traits::{MerkleParameters, CRH},
 };
-use snarkvm_utilities::{to_bytes_le, ToBytes};
+use snarkvm_utilities::{to_bytes_le, FromBytes, ToBytes};
 
 use rand::{thread_rng, Rng};
 
@@ -152,6 +160,170 @@ fn padded_merkle_tree_test<P: MerkleParameters>() {
     assert_eq!(merkle_tree_root, &expected_root);
 }
 
+/// Ensures the incremental Merkle tree roots and witness paths match the Merkle tree for every prefix of the leaves.
+fn run_incremental_merkle_tree_test<P: MerkleParameters>(num_leaves: usize) {
+    let parameters = Arc::new(P::setup("merkle_tree_test"));
+    let leaves = generate_random_leaves!(num_leaves, 8);
+
+    let mut tree = IncrementalMerkleTree::<P>::new(parameters.clone()).unwrap();
+    let mut witnesses: Vec<IncrementalWitness<P>> = vec![];
+
+    let expected_tree = MerkleTree::<P>::new(parameters.clone(), &Vec::<[u8; 8]>::new()).unwrap();
+    assert_eq!(expected_tree.root(), tree.root());
+
+    for (index, leaf) in leaves.iter().enumerate() {
+        // Update the existing witnesses, and witness the new leaf.
+        for witness in witnesses.iter_mut() {
+            witness.append(leaf).unwrap();
+        }
+        assert_eq!(index as u64, tree.append(leaf).unwrap());
+        witnesses.push(tree.witness().unwrap());
+
+        // Ensure the root matches the Merkle tree with the same leaves.
+        let expected_tree = MerkleTree::<P>::new(parameters.clone(), &leaves[..=index]).unwrap();
+        assert_eq!(expected_tree.root(), tree.root());
+
+        // Ensure each witness matches the Merkle tree path, and verifies against the root.
+        for (position, witness) in witnesses.iter().enumerate() {
+            let path = witness.path().unwrap();
+            let expected_path = expected_tree.generate_proof(position, &leaves[position]).unwrap();
+            assert_eq!(expected_path.path, path.path);
+            assert_eq!(position as u64, path.leaf_index);
+            assert!(path.verify(tree.root(), &leaves[position]).unwrap());
+            assert_eq!(*tree.root(), witness.root().unwrap());
+        }
+    }
+
+    // Ensure appending all leaves at once produces the same root.
+    let candidate_tree = IncrementalMerkleTree::<P>::from_leaves(parameters, &leaves).unwrap();
+    assert_eq!(tree.root(), candidate_tree.root());
+    assert_eq!(num_leaves as u64, candidate_tree.len());
+}
+
+fn run_full_incremental_merkle_tree_test<P: MerkleParameters>() {
+    let parameters = Arc::new(P::setup("merkle_tree_test"));
+    let leaves = generate_random_leaves!(1 << P::DEPTH, 8);
+
+    let mut tree = IncrementalMerkleTree::<P>::from_leaves(parameters.clone(), &leaves).unwrap();
+    let expected_tree = MerkleTree::<P>::new(parameters, &leaves).unwrap();
+    assert_eq!(expected_tree.root(), tree.root());
+
+    // Ensure the tree rejects leaves beyond its capacity.
+    assert!(tree.append(&[0u8; 8]).is_err());
+    assert_eq!(expected_tree.root(), tree.root());
+}
+
+/// Ensures the sparse Merkle tree proves membership of inserted keys, and non-membership of absent and deleted keys.
+fn run_sparse_merkle_tree_test<P: MerkleParameters>(num_keys: usize) {
+    let parameters = Arc::new(P::setup("merkle_tree_test"));
+    let keys = generate_random_leaves!(num_keys, 32);
+    let absent_keys = generate_random_leaves!(num_keys, 32);
+
+    let mut tree = SparseMerkleTree::<P>::new(parameters.clone()).unwrap();
+    let empty_root = *tree.root();
+
+    for key in keys.iter() {
+        assert!(tree.insert(key).unwrap());
+        assert!(!tree.insert(key).unwrap());
+    }
+
+    // Ensure the root is independent of the insertion order.
+    let mut reversed_tree = SparseMerkleTree::<P>::new(parameters).unwrap();
+    for key in keys.iter().rev() {
+        assert!(reversed_tree.insert(key).unwrap());
+    }
+    assert_eq!(tree.root(), reversed_tree.root());
+
+    let root = *tree.root();
+    for key in keys.iter() {
+        assert!(tree.contains(key).unwrap());
+        let proof = tree.generate_proof(key).unwrap();
+        assert!(proof.verify_membership(&root, key).unwrap());
+        assert!(!proof.verify_non_membership(&root, key).unwrap());
+        assert!(!proof.verify_membership(&empty_root, key).unwrap());
+    }
+
+    for key in absent_keys.iter() {
+        assert!(!tree.contains(key).unwrap());
+        let proof = tree.generate_proof(key).unwrap();
+        assert!(proof.verify_non_membership(&root, key).unwrap());
+        assert!(!proof.verify_membership(&root, key).unwrap());
+    }
+
+    // Ensure a path of the wrong length is rejected.
+    let mut proof = tree.generate_proof(&keys[0]).unwrap();
+    assert_eq!(tree.depth(), proof.path.len());
+    proof.path.pop();
+    assert!(proof.verify_membership(&root, &keys[0]).is_err());
+
+    // Ensure a deleted key is proven absent, and deleting every key restores the empty root.
+    for key in keys.iter() {
+        assert!(tree.delete(key).unwrap());
+        assert!(!tree.delete(key).unwrap());
+
+        let proof = tree.generate_proof(key).unwrap();
+        assert!(proof.verify_non_membership(tree.root(), key).unwrap());
+    }
+    assert_eq!(&empty_root, tree.root());
+}
+
+/// Ensures the multi-path verifies the given leaves, and is smaller than their individual paths.
+fn run_merkle_multi_path_test<P: MerkleParameters>(num_leaves: usize, indices: &[usize]) {
+    let parameters = Arc::new(P::setup("merkle_tree_test"));
+    let leaves = generate_random_leaves!(num_leaves, 8);
+    let tree = MerkleTree::<P>::new(parameters, &leaves).unwrap();
+
+    let selected_leaves: Vec<_> = indices.iter().map(|index| leaves[*index]).collect();
+    let multi_path = tree.generate_multi_proof(indices, &selected_leaves).unwrap();
+    assert!(multi_path.verify(tree.root(), &selected_leaves).unwrap());
+
+    // Ensure the multi-path is no larger than the individual paths, and strictly smaller when they share siblings.
+    let paths: Vec<_> = indices
+        .iter()
+        .map(|index| tree.generate_proof(*index, &leaves[*index]).unwrap())
+        .collect();
+    let num_siblings: usize = paths.iter().map(|path| path.path.len()).sum();
+    assert!(multi_path.auxiliary.len() <= num_siblings);
+    if indices.len() > 1 {
+        assert!(multi_path.auxiliary.len() < num_siblings);
+        let paths_size: usize = paths.iter().map(|path| to_bytes_le![path].unwrap().len()).sum();
+        assert!(to_bytes_le![multi_path].unwrap().len() < paths_size);
+    }
+
+    // Ensure the multi-path serializes correctly.
+    let candidate = MerkleMultiPath::<P>::read_le(&to_bytes_le![multi_path].unwrap()[..]).unwrap();
+    assert_eq!(multi_path.leaf_indices, candidate.leaf_indices);
+    assert_eq!(multi_path.auxiliary, candidate.auxiliary);
+    assert!(candidate.verify(tree.root(), &selected_leaves).unwrap());
+
+    // Ensure the multi-path rejects an incorrect root, an incorrect leaf, or a missing leaf.
+    assert!(!multi_path.verify(&Default::default(), &selected_leaves).unwrap());
+    let mut bad_leaves = selected_leaves.clone();
+    bad_leaves[0] = [0u8; 8];
+    assert!(!multi_path.verify(tree.root(), &bad_leaves).unwrap());
+    assert!(!multi_path.verify(tree.root(), &selected_leaves[1..]).unwrap());
+
+    // Ensure unsorted or duplicate indices are rejected.
+    if indices.len() > 1 {
+        let reversed_indices: Vec<_> = indices.iter().rev().copied().collect();
+        let reversed_leaves: Vec<_> = selected_leaves.iter().rev().copied().collect();
+        assert!(tree.generate_multi_proof(&reversed_indices, &reversed_leaves).is_err());
+    }
+    let duplicate_indices = [indices[0], indices[0]];
+    let duplicate_leaves = [selected_leaves[0], selected_leaves[0]];
+    assert!(tree.generate_multi_proof(&duplicate_indices, &duplicate_leaves).is_err());
+
+    // Ensure malformed counts are rejected without allocating them.
+    let mut bytes = to_bytes_le![multi_path].unwrap();
+    let setup_message_length = P::setup("merkle_tree_test").setup_message().len();
+    let num_leaf_indices_offset = 8 + setup_message_length;
+    bytes[num_leaf_indices_offset..num_leaf_indices_offset + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+    assert!(MerkleMultiPath::<P>::read_le(&bytes[..]).is_err());
+    let mut bytes = to_bytes_le![multi_path].unwrap();
+    bytes[..8].copy_from_slice(&u64::MAX.to_le_bytes());
+    assert!(MerkleMultiPath::<P>::read_le(&bytes[..]).is_err());
+}
+
 mod pedersen_crh_on_projective {
     use super::*;
     use snarkvm_curves::edwards_bls12::EdwardsProjective as Edwards;
@@ -229,6 +401,56 @@ mod pedersen_compressed_crh_on_projective {
         padded_merkle_tree_test::<MTParameters>();
     }
 
+    #[test]
+    fn incremental_merkle_tree_test() {
+        type MTParameters = MerkleTreeParameters<PedersenCompressedCRH<Edwards, NUM_WINDOWS, WINDOW_SIZE>, 5>;
+        run_incremental_merkle_tree_test::<MTParameters>(20);
+    }
+
+    #[test]
+    fn incremental_merkle_tree_padded_test() {
+        type MTParameters = MerkleTreeParameters<PedersenCompressedCRH<Edwards, NUM_WINDOWS, WINDOW_SIZE>, 32>;
+        run_incremental_merkle_tree_test::<MTParameters>(9);
+    }
+
+    #[test]
+    fn full_incremental_merkle_tree_test() {
+        type MTParameters = MerkleTreeParameters<PedersenCompressedCRH<Edwards, NUM_WINDOWS, WINDOW_SIZE>, 3>;
+        run_full_incremental_merkle_tree_test::<MTParameters>();
+    }
+
+    #[test]
+    fn sparse_merkle_tree_test() {
+        type MTParameters = MerkleTreeParameters<PedersenCompressedCRH<Edwards, NUM_WINDOWS, WINDOW_SIZE>, 32>;
+        run_sparse_merkle_tree_test::<MTParameters>(10);
+    }
+
+    #[test]
+    fn merkle_multi_path_test() {
+        type MTParameters = MerkleTreeParameters<PedersenCompressedCRH<Edwards, NUM_WINDOWS, WINDOW_SIZE>, 32>;
+        run_merkle_multi_path_test::<MTParameters>(16, &[0]);
+        run_merkle_multi_path_test::<MTParameters>(16, &[0, 1, 2, 3]);
+        run_merkle_multi_path_test::<MTParameters>(16, &[1, 6, 7, 12, 15]);
+        run_merkle_multi_path_test::<MTParameters>(13, &(0..13).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn merkle_tree_parameters_serialization_test() {
+        type MTParameters = MerkleTreeParameters<PedersenCompressedCRH<Edwards, NUM_WINDOWS, WINDOW_SIZE>, 32>;
+        type MaskedMTParameters =
+            MaskedMerkleTreeParameters<PedersenCompressedCRH<Edwards, NUM_WINDOWS, WINDOW_SIZE>, 32>;
+
+        let parameters = MTParameters::setup("merkle_tree_test");
+        let recovered_parameters = MTParameters::read_le(&parameters.to_bytes_le().unwrap()[..]).unwrap();
+        assert_eq!(parameters, recovered_parameters);
+        assert_eq!("merkle_tree_test", recovered_parameters.setup_message());
+
+        let parameters = MaskedMTParameters::setup("masked_merkle_tree_test");
+        let recovered_parameters = MaskedMTParameters::read_le(&parameters.to_bytes_le().unwrap()[..]).unwrap();
+        assert_eq!(parameters, recovered_parameters);
+        assert_eq!("masked_merkle_tree_test", recovered_parameters.setup_message());
+    }
+
     #[test]
     fn merkle_tree_rebuild_test() {
         type MTParameters = MerkleTreeParameters<PedersenCompressedCRH<Edwards, NUM_WINDOWS, WINDOW_SIZE>, 32>;
@@ -0,0 +1,159 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_curves::traits::{AffineCurve, Group, ProjectiveCurve};
+use snarkvm_fields::{FieldParameters, PrimeField, Zero};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Computes the multi-scalar multiplication using signed-digit windows, with the buckets
+/// of each window accumulated in affine form using batch inversion.
+pub fn msm_batched<G: AffineCurve>(
+    bases: &[G],
+    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+) -> G::Projective {
+    let size = std::cmp::min(bases.len(), scalars.len());
+    let (bases, scalars) = (&bases[..size], &scalars[..size]);
+
+    let c = window_size(size);
+    let num_windows = num_signed_digits::<G>(c);
+
+    let digits: Vec<Vec<i64>> = cfg_iter!(scalars)
+        .map(|scalar| signed_digits(scalar.as_ref(), c, num_windows))
+        .collect();
+
+    // Each window is of size `c`, and is processed in parallel.
+    let window_sums: Vec<_> = cfg_into_iter!(0..num_windows)
+        .map(|w| bucket_sum(bases.iter().zip(&digits).map(|(base, digits)| (digits[w], base)), c))
+        .collect();
+
+    // We're traversing windows from high to low.
+    window_sums.iter().rev().fold(G::Projective::zero(), |mut total, sum| {
+        for _ in 0..c {
+            total.double_in_place();
+        }
+        total + sum
+    })
+}
+
+/// Returns the window size for the given number of scalars.
+pub(crate) fn window_size(num_scalars: usize) -> usize {
+    if num_scalars < 32 {
+        3
+    } else {
+        (2.0 / 3.0 * (f64::from(num_scalars as u32)).log2() + 2.0).ceil() as usize
+    }
+}
+
+/// Returns the number of signed digits required to represent a scalar with windows of size `c`.
+/// The additional digit holds the carry of the most significant window.
+pub(crate) fn num_signed_digits<G: AffineCurve>(c: usize) -> usize {
+    <G::ScalarField as PrimeField>::Parameters::MODULUS_BITS as usize / c + 1
+}
+
+/// Decomposes the given scalar into `num_digits` signed digits in base `2^c`, from the least
+/// significant digit, where each digit is in the range `(-2^(c-1), 2^(c-1)]`.
+///
+/// Negative digits are handled by negating the base, which halves the number of buckets.
+pub(crate) fn signed_digits(limbs: &[u64], c: usize, num_digits: usize) -> Vec<i64> {
+    let radix = 1i64 << c;
+    let half_radix = radix >> 1;
+
+    let mut carry = 0;
+    (0..num_digits)
+        .map(|i| {
+            let window = read_bits(limbs, i * c, c) as i64 + carry;
+            if window > half_radix {
+                carry = 1;
+                window - radix
+            } else {
+                carry = 0;
+                window
+            }
+        })
+        .collect()
+}
+
+/// Returns the `num_bits` bits of the given limbs starting at bit `start`.
+fn read_bits(limbs: &[u64], start: usize, num_bits: usize) -> u64 {
+    let (limb, offset) = (start / 64, start % 64);
+    if limb >= limbs.len() {
+        return 0;
+    }
+
+    let mut bits = limbs[limb] >> offset;
+    if offset + num_bits > 64 && limb + 1 < limbs.len() {
+        bits |= limbs[limb + 1] << (64 - offset);
+    }
+    bits & ((1 << num_bits) - 1)
+}
+
+/// Returns the sum of `digit * base` over the given pairs, where each digit is a signed digit for windows of size `c`.
+pub(crate) fn bucket_sum<'a, G: AffineCurve>(points: impl Iterator<Item = (i64, &'a G)>, c: usize) -> G::Projective {
+    // We don't need the "zero" bucket, and negative digits share the buckets of positive digits.
+    let mut buckets = vec![Vec::new(); 1 << (c - 1)];
+    for (digit, base) in points {
+        if digit > 0 {
+            buckets[(digit - 1) as usize].push(*base);
+        } else if digit < 0 {
+            buckets[(-digit - 1) as usize].push(-*base);
+        }
+    }
+
+    let mut res = G::Projective::zero();
+    let mut running_sum = G::Projective::zero();
+    for bucket in batch_add_buckets(buckets).iter().rev() {
+        running_sum.add_assign_mixed(bucket);
+        res += running_sum;
+    }
+    res
+}
+
+/// Returns the sum of the points in each bucket.
+///
+/// In each round, the points within each bucket are added in pairs, and the additions of
+/// all buckets share a single batch inversion, until each bucket holds at most one point.
+fn batch_add_buckets<G: AffineCurve>(mut buckets: Vec<Vec<G>>) -> Vec<G> {
+    let mut lhs = vec![];
+    let mut rhs = vec![];
+    let mut num_pairs = vec![0; buckets.len()];
+
+    while buckets.iter().any(|bucket| bucket.len() > 1) {
+        lhs.clear();
+        rhs.clear();
+
+        for (bucket, num_pairs) in buckets.iter_mut().zip(num_pairs.iter_mut()) {
+            *num_pairs = bucket.len() / 2;
+            for _ in 0..*num_pairs {
+                rhs.push(bucket.pop().unwrap());
+                lhs.push(bucket.pop().unwrap());
+            }
+        }
+
+        G::batch_add_assign(&mut lhs, &rhs);
+
+        let mut sums = lhs.iter();
+        for (bucket, num_pairs) in buckets.iter_mut().zip(num_pairs.iter()) {
+            bucket.extend(sums.by_ref().take(*num_pairs));
+        }
+    }
+
+    buckets
+        .into_iter()
+        .map(|mut bucket| bucket.pop().unwrap_or_else(G::zero))
+        .collect()
+}
@@ -23,7 +23,12 @@ use snarkvm_curves::{bls12_377::G1Affine, traits::AffineCurve};
 use snarkvm_fields::{PrimeField, Zero};
 use snarkvm_utilities::BitIteratorBE;
 
-mod standard;
+pub mod batched;
+
+mod precomputed;
+pub use precomputed::*;
+
+pub mod standard;
 
 #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
 mod cuda;
@@ -62,7 +67,7 @@ impl VariableBaseMSM {
                 }
             }
         }
-        standard::msm_standard(bases, scalars)
+        batched::msm_batched(bases, scalars)
     }
 }
 
@@ -97,6 +102,57 @@ mod tests {
         assert_eq!(rust, naive);
     }
 
+    #[test]
+    fn test_batched() {
+        for (i, samples) in [1, 31, 100, 1 << 10].iter().enumerate() {
+            let (bases, scalars) = test_data(334563456 + i as u64, *samples);
+            let batched = batched::msm_batched(bases.as_slice(), scalars.as_slice());
+            let naive = VariableBaseMSM::msm_naive(bases.as_slice(), scalars.as_slice());
+            assert_eq!(naive.into_affine(), batched.into_affine());
+        }
+    }
+
+    #[test]
+    fn test_signed_digits() {
+        let (_, scalars) = test_data(334563456, 10);
+        for scalar in scalars {
+            for c in 2..16 {
+                let digits = batched::signed_digits(scalar.as_ref(), c, batched::num_signed_digits::<G1Affine>(c));
+
+                // Ensure the digits are in range, and recompose the scalar.
+                let mut recomposed = Fr::zero();
+                for digit in digits.iter().rev() {
+                    assert!(*digit > -(1 << (c - 1)) && *digit <= 1 << (c - 1));
+                    recomposed *= Fr::from(1u64 << c);
+                    match *digit >= 0 {
+                        true => recomposed += Fr::from(*digit as u64),
+                        false => recomposed -= Fr::from((-*digit) as u64),
+                    }
+                }
+                assert_eq!(scalar, recomposed.to_repr());
+            }
+        }
+    }
+
+    #[test]
+    fn test_precomputed_bases() {
+        let (bases, scalars) = test_data(334563456, 1 << 8);
+        let naive = VariableBaseMSM::msm_naive(bases.as_slice(), scalars.as_slice());
+
+        let precomputed = PrecomputedBases::new(&bases);
+        assert_eq!(
+            naive.into_affine(),
+            precomputed.multi_scalar_mul(&scalars).into_affine()
+        );
+
+        // Ensure the precomputed bases may be reused for fewer scalars.
+        let naive = VariableBaseMSM::msm_naive(&bases[..10], &scalars[..10]);
+        assert_eq!(
+            naive.into_affine(),
+            precomputed.multi_scalar_mul(&scalars[..10]).into_affine()
+        );
+    }
+
     #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
     #[test]
     fn test_msm_cuda() {
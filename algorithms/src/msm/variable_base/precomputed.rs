@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::batched::{bucket_sum, num_signed_digits, signed_digits, window_size};
+use snarkvm_curves::traits::{AffineCurve, Group, ProjectiveCurve};
+use snarkvm_fields::{PrimeField, Zero};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// A set of fixed bases, such as the powers of an SRS, with the multiples of each base
+/// precomputed for every window of a signed-digit multi-scalar multiplication.
+///
+/// As each window has its own copy of the bases, the windows share a single set of buckets,
+/// and the doublings between windows are avoided. This trades memory for speed, so it is
+/// intended for bases that are reused across many multi-scalar multiplications.
+#[derive(Clone, Debug)]
+pub struct PrecomputedBases<G: AffineCurve> {
+    /// `tables[i * num_windows + j]` is `2^(j * window_size) * bases[i]`.
+    tables: Vec<G>,
+    /// The number of bases.
+    num_bases: usize,
+    /// The size of each window.
+    window_size: usize,
+    /// The number of windows of each scalar.
+    num_windows: usize,
+}
+
+impl<G: AffineCurve> PrecomputedBases<G> {
+    /// Precomputes the multiples of the given bases, with a window size chosen for the number of bases.
+    pub fn new(bases: &[G]) -> Self {
+        Self::with_window_size(bases, window_size(bases.len()))
+    }
+
+    /// Precomputes the multiples of the given bases, for windows of the given size.
+    pub fn with_window_size(bases: &[G], window_size: usize) -> Self {
+        assert!(
+            window_size > 0 && window_size < 32,
+            "Invalid window size {}",
+            window_size
+        );
+        let num_windows = num_signed_digits::<G>(window_size);
+
+        let tables: Vec<Vec<G::Projective>> = cfg_iter!(bases)
+            .map(|base| {
+                let mut multiple = base.into_projective();
+                (0..num_windows)
+                    .map(|_| {
+                        let current = multiple;
+                        for _ in 0..window_size {
+                            multiple.double_in_place();
+                        }
+                        current
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            tables: G::Projective::batch_normalization_into_affine(tables.concat()),
+            num_bases: bases.len(),
+            window_size,
+            num_windows,
+        }
+    }
+
+    /// Returns the number of bases.
+    pub fn len(&self) -> usize {
+        self.num_bases
+    }
+
+    /// Returns `true` if there are no bases.
+    pub fn is_empty(&self) -> bool {
+        self.num_bases == 0
+    }
+
+    /// Returns the size of each window.
+    pub fn window_size(&self) -> usize {
+        self.window_size
+    }
+
+    /// Computes the multi-scalar multiplication of the bases with the given scalars.
+    /// If there are fewer scalars than bases, only the leading bases are used.
+    pub fn multi_scalar_mul(&self, scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
+        let size = std::cmp::min(self.num_bases, scalars.len());
+        let tables = &self.tables[..size * self.num_windows];
+
+        // Each chunk of bases is summed into its own set of buckets, in parallel.
+        let chunk_size = std::cmp::max(1, size / num_threads());
+        let sums: Vec<_> = cfg_chunks!(scalars[..size], chunk_size)
+            .zip(cfg_chunks!(tables, chunk_size * self.num_windows))
+            .map(|(scalars, tables)| {
+                let points = scalars
+                    .iter()
+                    .zip(tables.chunks(self.num_windows))
+                    .flat_map(|(scalar, multiples)| {
+                        signed_digits(scalar.as_ref(), self.window_size, self.num_windows)
+                            .into_iter()
+                            .zip(multiples)
+                    });
+                bucket_sum(points, self.window_size)
+            })
+            .collect();
+
+        sums.into_iter().fold(G::Projective::zero(), |total, sum| total + sum)
+    }
+}
+
+#[cfg(feature = "parallel")]
+fn num_threads() -> usize {
+    rayon::current_num_threads()
+}
+
+#[cfg(not(feature = "parallel"))]
+fn num_threads() -> usize {
+    1
+}
@@ -17,6 +17,7 @@
 use crate::{
     crypto_hash::{PoseidonCryptoHash, PoseidonDefaultParametersField},
     hash_to_curve::hash_to_curve,
+    msm::VariableBaseMSM,
     CryptoHash,
     SignatureError,
     SignatureScheme,
@@ -29,7 +30,7 @@ use snarkvm_curves::{
     ProjectiveCurve,
     TwistedEdwardsParameters,
 };
-use snarkvm_fields::{ConstraintFieldError, Field, FieldParameters, PrimeField, ToConstraintField};
+use snarkvm_fields::{ConstraintFieldError, Field, FieldParameters, PrimeField, ToConstraintField, Zero};
 use snarkvm_utilities::{
     io::{Read, Result as IoResult, Write},
     ops::Mul,
@@ -44,6 +45,10 @@ use snarkvm_utilities::{
 use anyhow::Result;
 use itertools::Itertools;
 use rand::{CryptoRng, Rng};
+use std::collections::HashSet;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 #[derive(Derivative)]
 #[derivative(
@@ -62,6 +67,21 @@ pub struct AleoSignature<TE: TwistedEdwardsParameters> {
 }
 
 impl<TE: TwistedEdwardsParameters> AleoSignature<TE> {
+    /// Returns a new signature from the given prover response, verifier challenge, and root keys.
+    pub(crate) fn new(
+        prover_response: TE::ScalarField,
+        verifier_challenge: TE::ScalarField,
+        root_public_key: &TEAffine<TE>,
+        root_randomizer: &TEAffine<TE>,
+    ) -> Self {
+        Self {
+            prover_response,
+            verifier_challenge,
+            root_public_key: root_public_key.x,
+            root_randomizer: root_randomizer.x,
+        }
+    }
+
     #[inline]
     pub fn size() -> usize {
         2 * TE::ScalarField::SERIALIZED_SIZE + 2 * TE::BaseField::SERIALIZED_SIZE
@@ -200,16 +220,8 @@ where
         // Compute G^r_sig.
         let g_r_sig = self.g_scalar_multiply(r_sig);
 
-        // Compute sk_prf := RO(G^sk_sig || G^r_sig).
-        let sk_prf = self.hash_to_scalar_field(&[g_sk_sig.x, g_r_sig.x]);
-
-        // Compute G^sk_prf.
-        let g_sk_prf = self.g_scalar_multiply(&sk_prf);
-
         // Compute G^sk_sig G^r_sig G^sk_prf.
-        let public_key = g_sk_sig + g_r_sig + g_sk_prf;
-
-        public_key
+        self.compute_public_key(&g_sk_sig, &g_r_sig)
     }
 
     ///
@@ -238,27 +250,11 @@ where
         // Compute G^r_sig.
         let g_r_sig = self.g_scalar_multiply(r_sig);
 
-        // Compute sk_prf := RO(G^sk_sig || G^r_sig).
-        let sk_prf = self.hash_to_scalar_field(&[g_sk_sig.x, g_r_sig.x]);
-
-        // Compute G^sk_prf.
-        let g_sk_prf = self.g_scalar_multiply(&sk_prf);
-
         // Compute G^sk_sig G^r_sig G^sk_prf.
-        let public_key = g_sk_sig + g_r_sig + g_sk_prf;
+        let public_key = self.compute_public_key(&g_sk_sig, &g_r_sig);
 
         // Compute the verifier challenge.
-        let verifier_challenge = {
-            // Construct the hash input (G^sk_sig G^r_sig G^sk_prf, G^r, message).
-            let mut preimage = vec![];
-            preimage.extend_from_slice(&public_key.x.to_field_elements()?);
-            preimage.extend_from_slice(&g_r.x.to_field_elements()?);
-            preimage.push(TE::BaseField::from(message.len() as u128));
-            preimage.extend_from_slice(&message.to_field_elements()?);
-
-            // Hash to derive the verifier challenge.
-            self.hash_to_scalar_field(&preimage)
-        };
+        let verifier_challenge = self.compute_verifier_challenge(&public_key, &g_r, message)?;
 
         // Compute the prover response.
         let prover_response = r - (verifier_challenge * sk_sig);
@@ -294,32 +290,13 @@ where
         let g_r = self.g_scalar_multiply(&prover_response) + g_sk_sig_c;
 
         // Compute the candidate verifier challenge.
-        let candidate_verifier_challenge = {
-            // Construct the hash input (G^sk_sig G^r_sig G^sk_prf, G^r, message).
-            let mut preimage = vec![];
-            preimage.extend_from_slice(&public_key.x.to_field_elements()?);
-            preimage.extend_from_slice(&g_r.x.to_field_elements()?);
-            preimage.push(TE::BaseField::from(message.len() as u128));
-            preimage.extend_from_slice(&message.to_field_elements()?);
-
-            // Hash to derive the verifier challenge.
-            self.hash_to_scalar_field(&preimage)
-        };
+        let candidate_verifier_challenge = self.compute_verifier_challenge(public_key, &g_r, message)?;
 
         // Recover G^r_sig.
         let g_r_sig = Self::recover_from_x_coordinate(root_randomizer)?;
 
         // Compute the candidate public key as (G^sk_sig G^r_sig G^sk_prf).
-        let candidate_public_key = {
-            // Compute sk_prf := RO(G^sk_sig || G^r_sig).
-            let sk_prf = self.hash_to_scalar_field(&[g_sk_sig.x, g_r_sig.x]);
-
-            // Compute G^sk_prf.
-            let g_sk_prf = self.g_scalar_multiply(&sk_prf);
-
-            // Compute G^sk_sig G^r_sig G^sk_prf.
-            g_sk_sig + g_r_sig + g_sk_prf
-        };
+        let candidate_public_key = self.compute_public_key(&g_sk_sig, &g_r_sig);
 
         Ok(*verifier_challenge == candidate_verifier_challenge && *public_key == candidate_public_key)
     }
@@ -376,11 +353,46 @@ impl<TE: TwistedEdwardsParameters> AleoSignatureScheme<TE>
 where
     TE::BaseField: PoseidonDefaultParametersField,
 {
-    fn scalar_multiply(&self, base: TEProjective<TE>, scalar: &TE::ScalarField) -> TEAffine<TE> {
+    pub(crate) fn scalar_multiply(&self, base: TEProjective<TE>, scalar: &TE::ScalarField) -> TEAffine<TE> {
         base.mul(*scalar).into_affine()
     }
 
-    fn recover_from_x_coordinate(x_coordinate: &TE::BaseField) -> Result<TEAffine<TE>> {
+    ///
+    /// Returns the public key as (G^sk_sig G^r_sig G^sk_prf), where:
+    ///     sk_prf := RO(G^sk_sig || G^r_sig)
+    ///
+    pub(crate) fn compute_public_key(&self, g_sk_sig: &TEAffine<TE>, g_r_sig: &TEAffine<TE>) -> TEAffine<TE> {
+        // Compute sk_prf := RO(G^sk_sig || G^r_sig).
+        let sk_prf = self.hash_to_scalar_field(&[g_sk_sig.x, g_r_sig.x]);
+
+        // Compute G^sk_prf.
+        let g_sk_prf = self.g_scalar_multiply(&sk_prf);
+
+        // Compute G^sk_sig G^r_sig G^sk_prf.
+        *g_sk_sig + *g_r_sig + g_sk_prf
+    }
+
+    ///
+    /// Returns the verifier challenge as Hash(G^sk_sig G^r_sig G^sk_prf, G^r, message).
+    ///
+    pub(crate) fn compute_verifier_challenge(
+        &self,
+        public_key: &TEAffine<TE>,
+        g_r: &TEAffine<TE>,
+        message: &[u8],
+    ) -> Result<TE::ScalarField> {
+        // Construct the hash input (G^sk_sig G^r_sig G^sk_prf, G^r, message).
+        let mut preimage = vec![];
+        preimage.extend_from_slice(&public_key.x.to_field_elements()?);
+        preimage.extend_from_slice(&g_r.x.to_field_elements()?);
+        preimage.push(TE::BaseField::from(message.len() as u128));
+        preimage.extend_from_slice(&message.to_field_elements()?);
+
+        // Hash to derive the verifier challenge.
+        Ok(self.hash_to_scalar_field(&preimage))
+    }
+
+    pub(crate) fn recover_from_x_coordinate(x_coordinate: &TE::BaseField) -> Result<TEAffine<TE>> {
         if let Some(element) = TEAffine::<TE>::from_x_coordinate(*x_coordinate, true) {
             if element.is_in_correct_subgroup_assuming_on_curve() {
                 return Ok(element);
@@ -395,6 +407,146 @@ where
 
         Err(SignatureError::Message("Failed to recover from x coordinate".into()).into())
     }
+
+    ///
+    /// Verifies a batch of (public_key, message, signature) tuples, and returns the indices
+    /// of the invalid signatures in ascending order, which is empty if every signature is valid.
+    ///
+    /// The verifier challenge of each signature is checked individually, as the signature
+    /// does not contain G^r. The public key relations are checked together as a random linear combination:
+    ///     \sum_i rho_i * (public_key_i - G^sk_sig_i - G^r_sig_i) == (\sum_i rho_i * sk_prf_i) * G
+    /// for random 128-bit rho_i, using one multi-scalar multiplication. If the combination fails,
+    /// the batch is bisected to find the invalid signatures.
+    ///
+    /// A random linear combination can not detect a public key that is offset by a point of small order,
+    /// so each distinct public key is checked to be in the prime-order subgroup.
+    ///
+    /// This is not faster than sequential `verify`. Each signature still requires its own
+    /// G^s G^sk_sig^c to recompute G^r for the challenge hash, so only the fixed-base
+    /// multiplication of the public key relation is amortized, and the subgroup check of each
+    /// public key costs more than it saves. On one core, the `aleo_signature_batch_verify` benchmark
+    /// measures 217 ms for a batch of 128 signatures, against 168 ms to verify them sequentially.
+    /// Use `batch_verify` to find the invalid signatures in a batch, not to verify a batch faster.
+    ///
+    pub fn batch_verify<M: AsRef<[u8]> + Sync, R: Rng + CryptoRng>(
+        &self,
+        batch: &[(TEAffine<TE>, M, AleoSignature<TE>)],
+        rng: &mut R,
+    ) -> Result<Vec<usize>> {
+        // Check each distinct public key is on the curve and in the prime-order subgroup.
+        let public_keys = batch
+            .iter()
+            .map(|(public_key, _, _)| *public_key)
+            .collect::<HashSet<_>>();
+        let invalid_public_keys = cfg_into_iter!(public_keys)
+            .filter(|public_key| !(public_key.is_on_curve() && public_key.is_in_correct_subgroup_assuming_on_curve()))
+            .collect::<HashSet<_>>();
+
+        // Check the verifier challenge of each signature, and compute the terms of its public key relation.
+        let relations = cfg_iter!(batch)
+            .map(|(public_key, message, signature)| {
+                if invalid_public_keys.contains(public_key) {
+                    return Ok(None);
+                }
+
+                // Recover G^sk_sig and G^r_sig.
+                let (g_sk_sig, g_r_sig) = match (
+                    Self::recover_from_x_coordinate(&signature.root_public_key),
+                    Self::recover_from_x_coordinate(&signature.root_randomizer),
+                ) {
+                    (Ok(g_sk_sig), Ok(g_r_sig)) => (g_sk_sig, g_r_sig),
+                    _ => return Ok(None),
+                };
+
+                // Compute G^r := G^s G^sk_sig^c.
+                let g_r = self.g_scalar_multiply(&signature.prover_response)
+                    + self.scalar_multiply(g_sk_sig.into_projective(), &signature.verifier_challenge);
+
+                // Check the verifier challenge.
+                if signature.verifier_challenge
+                    != self.compute_verifier_challenge(public_key, &g_r, message.as_ref())?
+                {
+                    return Ok(None);
+                }
+
+                // Compute sk_prf := RO(G^sk_sig || G^r_sig).
+                let sk_prf = self.hash_to_scalar_field(&[g_sk_sig.x, g_r_sig.x]);
+
+                // Compute public_key - G^sk_sig - G^r_sig, which should equal G^sk_prf.
+                let difference = public_key.into_projective() - g_sk_sig.into_projective() - g_r_sig.into_projective();
+
+                Ok(Some((difference, sk_prf)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Separate the signatures that failed the individual checks.
+        let mut invalid = Vec::new();
+        let mut indices = Vec::with_capacity(relations.len());
+        let mut differences = Vec::with_capacity(relations.len());
+        let mut sk_prfs = Vec::with_capacity(relations.len());
+        for (index, relation) in relations.into_iter().enumerate() {
+            match relation {
+                Some((difference, sk_prf)) => {
+                    indices.push(index);
+                    differences.push(difference);
+                    sk_prfs.push(sk_prf);
+                }
+                None => invalid.push(index),
+            }
+        }
+
+        // Sample the random coefficients, and check the public key relations in one combination.
+        let relations = indices
+            .into_iter()
+            .zip_eq(TEProjective::<TE>::batch_normalization_into_affine(differences))
+            .zip_eq(sk_prfs)
+            .map(|((index, difference), sk_prf)| (index, difference, sk_prf, TE::ScalarField::from(rng.gen::<u128>())))
+            .collect::<Vec<_>>();
+        self.batch_check_public_keys(&relations, &mut invalid);
+
+        invalid.sort_unstable();
+        Ok(invalid)
+    }
+
+    ///
+    /// Checks the random linear combination of the given public key relations, given as
+    /// (index, public_key - G^sk_sig - G^r_sig, sk_prf, rho), and bisects the relations if it fails.
+    /// The indices of the invalid relations are appended to `invalid`.
+    ///
+    fn batch_check_public_keys(
+        &self,
+        relations: &[(usize, TEAffine<TE>, TE::ScalarField, TE::ScalarField)],
+        invalid: &mut Vec<usize>,
+    ) {
+        match relations {
+            [] => {}
+            // A single relation is checked exactly, as its coefficient is no longer needed.
+            [(index, difference, sk_prf, _)] => {
+                if *difference != self.g_scalar_multiply(sk_prf) {
+                    invalid.push(*index);
+                }
+            }
+            _ => {
+                // Compute \sum_i rho_i * (public_key_i - G^sk_sig_i - G^r_sig_i) - (\sum_i rho_i * sk_prf_i) * G.
+                let mut bases = Vec::with_capacity(relations.len() + 1);
+                let mut scalars = Vec::with_capacity(relations.len() + 1);
+                let mut g_scalar = TE::ScalarField::zero();
+                for (_, difference, sk_prf, rho) in relations {
+                    bases.push(*difference);
+                    scalars.push(rho.to_repr());
+                    g_scalar += *rho * sk_prf;
+                }
+                bases.push(self.g_bases[0].into_affine());
+                scalars.push((-g_scalar).to_repr());
+
+                if !VariableBaseMSM::multi_scalar_mul(&bases, &scalars).is_zero() {
+                    let (left, right) = relations.split_at(relations.len() / 2);
+                    self.batch_check_public_keys(left, invalid);
+                    self.batch_check_public_keys(right, invalid);
+                }
+            }
+        }
+    }
 }
 
 impl<TE: TwistedEdwardsParameters> From<Vec<TEProjective<TE>>> for AleoSignatureScheme<TE>
@@ -0,0 +1,909 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A threshold (M-of-N) signing protocol for the Aleo signature scheme.
+//!
+//! The group secret key `sk_sig` is shared among `N` participants using a distributed key generation,
+//! such that any `M` participants may jointly produce a standard `AleoSignature` in two rounds.
+//! The resulting signature is indistinguishable from a single-signer signature, and verifies
+//! with `AleoSignatureScheme::verify` and `AleoSignatureSchemeGadget::verify` unchanged.
+//!
+//! A multisig account has no view key, as no participant learns `sk_sig` or `r_sig`, and there is
+//! no joint decryption protocol. Records owned by a multisig address can therefore not be decrypted.
+
+use crate::{
+    crypto_hash::PoseidonDefaultParametersField,
+    signature::{AleoSignature, AleoSignatureScheme},
+    SignatureError,
+    SignatureScheme,
+    SignatureSchemeOperations,
+};
+use snarkvm_curves::{
+    templates::twisted_edwards_extended::{Affine as TEAffine, Projective as TEProjective},
+    AffineCurve,
+    ProjectiveCurve,
+    TwistedEdwardsParameters,
+};
+use snarkvm_fields::{Field, One, PrimeField, ToConstraintField, Zero};
+use snarkvm_utilities::{
+    io::{Read, Result as IoResult, Write},
+    ops::Mul,
+    rand::UniformRand,
+    FromBytes,
+    ToBytes,
+};
+
+use anyhow::Result;
+use rand::{CryptoRng, Rng};
+use std::collections::HashSet;
+
+const ALEO_MULTISIG_KEYGEN_DOMAIN: &str = "AleoMultisigKeyGen0";
+const ALEO_MULTISIG_BINDING_DOMAIN: &str = "AleoMultisigBinding0";
+
+///
+/// The secret state of a participant during distributed key generation.
+///
+/// Each participant samples a secret polynomial of degree `threshold - 1`, whose constant coefficient
+/// is the contribution of the participant to the group secret key `sk_sig`.
+///
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "TE: TwistedEdwardsParameters"),
+    Debug(bound = "TE: TwistedEdwardsParameters")
+)]
+pub struct AleoMultisigKeyGenerator<TE: TwistedEdwardsParameters> {
+    index: u16,
+    threshold: u16,
+    num_participants: u16,
+    coefficients: Vec<TE::ScalarField>,
+}
+
+///
+/// The public package broadcast by a participant during distributed key generation.
+///
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "TE: TwistedEdwardsParameters"),
+    Debug(bound = "TE: TwistedEdwardsParameters"),
+    PartialEq(bound = "TE: TwistedEdwardsParameters"),
+    Eq(bound = "TE: TwistedEdwardsParameters")
+)]
+pub struct AleoMultisigKeyGenPackage<TE: TwistedEdwardsParameters> {
+    /// The index of the participant.
+    index: u16,
+    /// The commitments to the coefficients of the secret polynomial, as G^a_k.
+    coefficient_commitments: Vec<TEAffine<TE>>,
+    /// The share of the root randomizer, as G^r_sig_i.
+    root_randomizer_share: TEAffine<TE>,
+    /// The proof of knowledge of the constant coefficient, as (G^k, k - c * a_0).
+    proof_randomizer: TEAffine<TE>,
+    proof_response: TE::ScalarField,
+    /// The proof of knowledge of the share of the root randomizer, as (G^k', k' - c * r_sig_i).
+    root_randomizer_proof_randomizer: TEAffine<TE>,
+    root_randomizer_proof_response: TE::ScalarField,
+}
+
+///
+/// The aggregated public key of a multisig account.
+///
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "TE: TwistedEdwardsParameters"),
+    Debug(bound = "TE: TwistedEdwardsParameters"),
+    PartialEq(bound = "TE: TwistedEdwardsParameters"),
+    Eq(bound = "TE: TwistedEdwardsParameters")
+)]
+pub struct AleoMultisigPublicKey<TE: TwistedEdwardsParameters> {
+    /// The minimum number of participants required to sign.
+    threshold: u16,
+    /// The group root public key, as G^sk_sig.
+    root_public_key: TEAffine<TE>,
+    /// The group root randomizer, as G^r_sig.
+    root_randomizer: TEAffine<TE>,
+    /// The verification shares of each participant, as G^s_i, ordered by participant index.
+    verification_shares: Vec<TEAffine<TE>>,
+}
+
+///
+/// The secret key share of a participant in a multisig account.
+///
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "TE: TwistedEdwardsParameters"),
+    Debug(bound = "TE: TwistedEdwardsParameters")
+)]
+pub struct AleoMultisigKeyShare<TE: TwistedEdwardsParameters> {
+    index: u16,
+    secret_share: TE::ScalarField,
+    public_key: AleoMultisigPublicKey<TE>,
+}
+
+///
+/// The secret signing nonces of a participant, valid for a single signing session.
+///
+#[derive(Derivative)]
+#[derivative(Debug(bound = "TE: TwistedEdwardsParameters"))]
+pub struct AleoMultisigNonces<TE: TwistedEdwardsParameters> {
+    index: u16,
+    hiding: TE::ScalarField,
+    binding: TE::ScalarField,
+}
+
+///
+/// The public commitment to the signing nonces of a participant.
+///
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "TE: TwistedEdwardsParameters"),
+    Clone(bound = "TE: TwistedEdwardsParameters"),
+    Debug(bound = "TE: TwistedEdwardsParameters"),
+    PartialEq(bound = "TE: TwistedEdwardsParameters"),
+    Eq(bound = "TE: TwistedEdwardsParameters")
+)]
+pub struct AleoMultisigCommitment<TE: TwistedEdwardsParameters> {
+    index: u16,
+    hiding: TEAffine<TE>,
+    binding: TEAffine<TE>,
+}
+
+///
+/// The signature share produced by a participant.
+///
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "TE: TwistedEdwardsParameters"),
+    Clone(bound = "TE: TwistedEdwardsParameters"),
+    Debug(bound = "TE: TwistedEdwardsParameters"),
+    PartialEq(bound = "TE: TwistedEdwardsParameters"),
+    Eq(bound = "TE: TwistedEdwardsParameters")
+)]
+pub struct AleoMultisigSignatureShare<TE: TwistedEdwardsParameters> {
+    index: u16,
+    prover_response: TE::ScalarField,
+}
+
+impl<TE: TwistedEdwardsParameters> AleoMultisigKeyGenerator<TE> {
+    /// Returns the index of the participant.
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the secret share for the given recipient, as f_i(recipient).
+    /// This share must be sent to the recipient over a private channel.
+    pub fn share_for(&self, recipient: u16) -> Result<TE::ScalarField> {
+        if recipient == 0 || recipient > self.num_participants {
+            return Err(SignatureError::Message(format!("Invalid multisig participant index {}", recipient)).into());
+        }
+        Ok(evaluate_polynomial::<TE>(&self.coefficients, recipient))
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> AleoMultisigKeyGenPackage<TE> {
+    /// Returns the index of the participant.
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> AleoMultisigPublicKey<TE> {
+    /// Returns the minimum number of participants required to sign.
+    pub fn threshold(&self) -> u16 {
+        self.threshold
+    }
+
+    /// Returns the number of participants.
+    pub fn num_participants(&self) -> u16 {
+        self.verification_shares.len() as u16
+    }
+
+    /// Returns the group root public key, as G^sk_sig.
+    pub fn root_public_key(&self) -> &TEAffine<TE> {
+        &self.root_public_key
+    }
+
+    /// Returns the group root randomizer, as G^r_sig.
+    pub fn root_randomizer(&self) -> &TEAffine<TE> {
+        &self.root_randomizer
+    }
+
+    /// Returns the verification share of the given participant, as G^s_i.
+    pub fn verification_share(&self, index: u16) -> Result<&TEAffine<TE>> {
+        match index {
+            0 => Err(SignatureError::Message("Invalid multisig participant index 0".into()).into()),
+            index => self
+                .verification_shares
+                .get(index as usize - 1)
+                .ok_or_else(|| SignatureError::Message(format!("Invalid multisig participant index {}", index)).into()),
+        }
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> AleoMultisigKeyShare<TE> {
+    /// Returns the index of the participant.
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the aggregated public key of the multisig account.
+    pub fn public_key(&self) -> &AleoMultisigPublicKey<TE> {
+        &self.public_key
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> AleoMultisigCommitment<TE> {
+    /// Returns the index of the participant.
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> AleoMultisigSignatureShare<TE> {
+    /// Returns the index of the participant.
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> AleoSignatureScheme<TE>
+where
+    TE::BaseField: PoseidonDefaultParametersField,
+{
+    ///
+    /// Key generation, round 1.
+    ///
+    /// Samples the secret polynomial of the participant, and returns the secret key generator
+    /// along with the public package that is broadcast to all other participants.
+    ///
+    pub fn multisig_keygen_begin<R: Rng + CryptoRng>(
+        &self,
+        index: u16,
+        threshold: u16,
+        num_participants: u16,
+        rng: &mut R,
+    ) -> Result<(AleoMultisigKeyGenerator<TE>, AleoMultisigKeyGenPackage<TE>)> {
+        if threshold == 0 || threshold > num_participants {
+            return Err(SignatureError::Message(format!(
+                "Invalid multisig threshold {} for {} participants",
+                threshold, num_participants
+            ))
+            .into());
+        }
+        if index == 0 || index > num_participants {
+            return Err(SignatureError::Message(format!("Invalid multisig participant index {}", index)).into());
+        }
+
+        // Sample the secret polynomial f_i(x) := a_0 + a_1 x + ... + a_{t-1} x^{t-1}.
+        let coefficients: Vec<TE::ScalarField> = (0..threshold).map(|_| TE::ScalarField::rand(rng)).collect();
+        // Sample the share of r_sig.
+        let r_sig = TE::ScalarField::rand(rng);
+
+        // Compute the commitments to the coefficients as G^a_k.
+        let coefficient_commitments: Vec<_> = coefficients.iter().map(|a| self.g_scalar_multiply(a)).collect();
+
+        let root_randomizer_share = self.g_scalar_multiply(&r_sig);
+
+        // Prove knowledge of the constant coefficient and of the share of r_sig, to prevent rogue-key attacks.
+        let k = TE::ScalarField::rand(rng);
+        let k_r_sig = TE::ScalarField::rand(rng);
+        let proof_randomizer = self.g_scalar_multiply(&k);
+        let root_randomizer_proof_randomizer = self.g_scalar_multiply(&k_r_sig);
+        let challenge = self.multisig_keygen_challenge(
+            index,
+            &coefficient_commitments,
+            &root_randomizer_share,
+            &proof_randomizer,
+            &root_randomizer_proof_randomizer,
+        );
+
+        let package = AleoMultisigKeyGenPackage {
+            index,
+            coefficient_commitments,
+            root_randomizer_share,
+            proof_randomizer,
+            proof_response: k - (challenge * coefficients[0]),
+            root_randomizer_proof_randomizer,
+            root_randomizer_proof_response: k_r_sig - (challenge * r_sig),
+        };
+
+        let generator = AleoMultisigKeyGenerator {
+            index,
+            threshold,
+            num_participants,
+            coefficients,
+        };
+
+        Ok((generator, package))
+    }
+
+    ///
+    /// Key generation, round 2.
+    ///
+    /// Verifies the public packages of all participants and the secret shares received from each of them,
+    /// and returns the secret key share of the participant along with the aggregated public key.
+    ///
+    pub fn multisig_keygen_finalize(
+        &self,
+        generator: AleoMultisigKeyGenerator<TE>,
+        packages: &[AleoMultisigKeyGenPackage<TE>],
+        received_shares: &[(u16, TE::ScalarField)],
+    ) -> Result<AleoMultisigKeyShare<TE>> {
+        let public_key = self.multisig_aggregate_public_key(generator.threshold, packages)?;
+        if public_key.num_participants() != generator.num_participants {
+            return Err(SignatureError::Message(format!(
+                "Expected {} multisig key generation packages, found {}",
+                generator.num_participants,
+                packages.len()
+            ))
+            .into());
+        }
+
+        // Ensure exactly one share was received from each participant.
+        let senders: HashSet<u16> = received_shares.iter().map(|(sender, _)| *sender).collect();
+        if senders.len() != received_shares.len() || received_shares.len() != packages.len() {
+            return Err(SignatureError::Message("Expected one multisig key share from each participant".into()).into());
+        }
+
+        // Verify each received share against the coefficient commitments of the sender,
+        // and compute the secret share as s_j := \sum_i f_i(j).
+        let mut secret_share = TE::ScalarField::zero();
+        for (sender, share) in received_shares {
+            let package = packages
+                .iter()
+                .find(|package| package.index == *sender)
+                .ok_or_else(|| {
+                    SignatureError::Message(format!("Missing multisig package from participant {}", sender))
+                })?;
+
+            let expected = evaluate_commitments::<TE>(&package.coefficient_commitments, generator.index);
+            if self.g_scalar_multiply(share) != expected {
+                return Err(
+                    SignatureError::Message(format!("Invalid multisig key share from participant {}", sender)).into(),
+                );
+            }
+
+            secret_share += share;
+        }
+
+        // Ensure the secret share matches the verification share.
+        if self.g_scalar_multiply(&secret_share) != *public_key.verification_share(generator.index)? {
+            return Err(
+                SignatureError::Message("Multisig key share does not match its verification share".into()).into(),
+            );
+        }
+
+        Ok(AleoMultisigKeyShare {
+            index: generator.index,
+            secret_share,
+            public_key,
+        })
+    }
+
+    ///
+    /// Returns the aggregated public key from the public packages of all participants.
+    /// This method may be invoked by any observer to derive the multisig account.
+    ///
+    pub fn multisig_aggregate_public_key(
+        &self,
+        threshold: u16,
+        packages: &[AleoMultisigKeyGenPackage<TE>],
+    ) -> Result<AleoMultisigPublicKey<TE>> {
+        let num_participants = packages.len() as u16;
+        if threshold == 0 || threshold > num_participants || packages.len() > u16::MAX as usize {
+            return Err(SignatureError::Message(format!(
+                "Invalid multisig threshold {} for {} participants",
+                threshold,
+                packages.len()
+            ))
+            .into());
+        }
+
+        // Ensure the packages are from distinct participants, and are each well-formed.
+        let indices: HashSet<u16> = packages.iter().map(|package| package.index).collect();
+        if indices.len() != packages.len() || indices.iter().any(|index| *index == 0 || *index > num_participants) {
+            return Err(SignatureError::Message("Multisig packages contain invalid participant indices".into()).into());
+        }
+        for package in packages {
+            if package.coefficient_commitments.len() != threshold as usize {
+                return Err(SignatureError::Message(format!(
+                    "Multisig package from participant {} contains an incorrect number of commitments",
+                    package.index
+                ))
+                .into());
+            }
+
+            // Verify the proofs of knowledge as G^k == G^z G^{a_0 c} and G^k' == G^z' G^{r_sig_i c}.
+            let challenge = self.multisig_keygen_challenge(
+                package.index,
+                &package.coefficient_commitments,
+                &package.root_randomizer_share,
+                &package.proof_randomizer,
+                &package.root_randomizer_proof_randomizer,
+            );
+            let candidate_randomizer = self.g_scalar_multiply(&package.proof_response)
+                + self.scalar_multiply(package.coefficient_commitments[0].into_projective(), &challenge);
+            let candidate_root_randomizer_proof_randomizer = self
+                .g_scalar_multiply(&package.root_randomizer_proof_response)
+                + self.scalar_multiply(package.root_randomizer_share.into_projective(), &challenge);
+            if candidate_randomizer != package.proof_randomizer
+                || candidate_root_randomizer_proof_randomizer != package.root_randomizer_proof_randomizer
+            {
+                return Err(SignatureError::Message(format!(
+                    "Multisig package from participant {} contains an invalid proof of knowledge",
+                    package.index
+                ))
+                .into());
+            }
+        }
+
+        // Compute G^sk_sig := \sum_i G^a_{i,0}, and G^r_sig := \sum_i G^r_sig_i.
+        let root_public_key = packages
+            .iter()
+            .map(|package| package.coefficient_commitments[0].into_projective())
+            .sum::<TEProjective<TE>>()
+            .into_affine();
+        let root_randomizer = packages
+            .iter()
+            .map(|package| package.root_randomizer_share.into_projective())
+            .sum::<TEProjective<TE>>()
+            .into_affine();
+
+        // Compute the verification share of each participant as G^s_j := \sum_i \sum_k G^a_{i,k} j^k.
+        let verification_shares = (1..=num_participants)
+            .map(|index| {
+                packages
+                    .iter()
+                    .map(|package| {
+                        evaluate_commitments::<TE>(&package.coefficient_commitments, index).into_projective()
+                    })
+                    .sum::<TEProjective<TE>>()
+                    .into_affine()
+            })
+            .collect();
+
+        Ok(AleoMultisigPublicKey {
+            threshold,
+            root_public_key,
+            root_randomizer,
+            verification_shares,
+        })
+    }
+
+    ///
+    /// Returns the public key of the multisig account, as (G^sk_sig G^r_sig G^sk_prf).
+    ///
+    pub fn multisig_public_key(&self, public_key: &AleoMultisigPublicKey<TE>) -> TEAffine<TE> {
+        self.compute_public_key(&public_key.root_public_key, &public_key.root_randomizer)
+    }
+
+    ///
+    /// Signing, round 1.
+    ///
+    /// Samples the signing nonces of the participant, and returns them along with the public commitment
+    /// that is sent to all other signers. The nonces must never be used for more than one signature.
+    ///
+    pub fn multisig_commit<R: Rng + CryptoRng>(
+        &self,
+        key_share: &AleoMultisigKeyShare<TE>,
+        rng: &mut R,
+    ) -> (AleoMultisigNonces<TE>, AleoMultisigCommitment<TE>) {
+        let nonces = AleoMultisigNonces {
+            index: key_share.index,
+            hiding: TE::ScalarField::rand(rng),
+            binding: TE::ScalarField::rand(rng),
+        };
+
+        let commitment = AleoMultisigCommitment {
+            index: key_share.index,
+            hiding: self.g_scalar_multiply(&nonces.hiding),
+            binding: self.g_scalar_multiply(&nonces.binding),
+        };
+
+        (nonces, commitment)
+    }
+
+    ///
+    /// Signing, round 2.
+    ///
+    /// Returns the signature share of the participant, as s_i := d_i + e_i * p_i - c * l_i * sk_i, where:
+    ///     p_i := Hash(i, message, commitments)
+    ///     c := Hash(G^sk_sig G^r_sig G^sk_prf, \sum_j D_j E_j^p_j, message)
+    ///     l_i := the Lagrange coefficient of participant i over the signers
+    ///
+    pub fn multisig_sign(
+        &self,
+        key_share: &AleoMultisigKeyShare<TE>,
+        nonces: AleoMultisigNonces<TE>,
+        commitments: &[AleoMultisigCommitment<TE>],
+        message: &[u8],
+    ) -> Result<AleoMultisigSignatureShare<TE>> {
+        let commitments = self.multisig_prepare_commitments(&key_share.public_key, commitments)?;
+
+        // Ensure the nonces belong to this participant, and match the commitment of this participant.
+        let commitment = commitments
+            .iter()
+            .find(|commitment| commitment.index == key_share.index)
+            .ok_or_else(|| SignatureError::Message("Multisig commitments do not include the signer".into()))?;
+        if nonces.index != key_share.index
+            || commitment.hiding != self.g_scalar_multiply(&nonces.hiding)
+            || commitment.binding != self.g_scalar_multiply(&nonces.binding)
+        {
+            return Err(SignatureError::Message("Multisig nonces do not match the signer commitment".into()).into());
+        }
+
+        let (binding_factors, verifier_challenge) =
+            self.multisig_challenge(&key_share.public_key, &commitments, message)?;
+        let binding_factor = binding_factors[commitments.iter().position(|c| c.index == key_share.index).unwrap()];
+        let lagrange_coefficient = lagrange_coefficient::<TE>(key_share.index, &commitments)?;
+
+        let prover_response = nonces.hiding + (nonces.binding * binding_factor)
+            - (verifier_challenge * lagrange_coefficient * key_share.secret_share);
+
+        Ok(AleoMultisigSignatureShare {
+            index: key_share.index,
+            prover_response,
+        })
+    }
+
+    ///
+    /// Returns `true` if the signature share is valid for the given participant, by checking
+    ///     D_i E_i^p_i == G^s_i (G^sk_i)^{c * l_i}
+    ///
+    pub fn multisig_verify_share(
+        &self,
+        public_key: &AleoMultisigPublicKey<TE>,
+        commitments: &[AleoMultisigCommitment<TE>],
+        message: &[u8],
+        signature_share: &AleoMultisigSignatureShare<TE>,
+    ) -> Result<bool> {
+        let commitments = self.multisig_prepare_commitments(public_key, commitments)?;
+        let position = match commitments.iter().position(|c| c.index == signature_share.index) {
+            Some(position) => position,
+            None => return Ok(false),
+        };
+
+        let (binding_factors, verifier_challenge) = self.multisig_challenge(public_key, &commitments, message)?;
+        let lagrange_coefficient = lagrange_coefficient::<TE>(signature_share.index, &commitments)?;
+
+        let commitment = &commitments[position];
+        let expected =
+            commitment.hiding.into_projective() + commitment.binding.into_projective().mul(binding_factors[position]);
+
+        let verification_share = public_key.verification_share(signature_share.index)?;
+        let candidate = self
+            .g_scalar_multiply(&signature_share.prover_response)
+            .into_projective()
+            + verification_share
+                .into_projective()
+                .mul(verifier_challenge * lagrange_coefficient);
+
+        Ok(expected == candidate)
+    }
+
+    ///
+    /// Returns the aggregated signature from the signature shares of at least `threshold` signers.
+    /// The aggregated signature is a standard `AleoSignature` for the multisig account public key.
+    ///
+    pub fn multisig_aggregate(
+        &self,
+        public_key: &AleoMultisigPublicKey<TE>,
+        commitments: &[AleoMultisigCommitment<TE>],
+        signature_shares: &[AleoMultisigSignatureShare<TE>],
+        message: &[u8],
+    ) -> Result<AleoSignature<TE>> {
+        let commitments = self.multisig_prepare_commitments(public_key, commitments)?;
+
+        // Ensure there is exactly one signature share for each commitment.
+        let indices: HashSet<u16> = signature_shares.iter().map(|share| share.index).collect();
+        if indices.len() != signature_shares.len()
+            || signature_shares.len() != commitments.len()
+            || commitments
+                .iter()
+                .any(|commitment| !indices.contains(&commitment.index))
+        {
+            return Err(
+                SignatureError::Message("Expected one multisig signature share from each signer".into()).into(),
+            );
+        }
+
+        // Ensure each signature share is valid, to identify misbehaving signers.
+        for signature_share in signature_shares {
+            if !self.multisig_verify_share(public_key, &commitments, message, signature_share)? {
+                return Err(SignatureError::Message(format!(
+                    "Invalid multisig signature share from participant {}",
+                    signature_share.index
+                ))
+                .into());
+            }
+        }
+
+        let (_, verifier_challenge) = self.multisig_challenge(public_key, &commitments, message)?;
+        let prover_response = signature_shares
+            .iter()
+            .fold(TE::ScalarField::zero(), |sum, share| sum + share.prover_response);
+
+        let signature = AleoSignature::new(
+            prover_response,
+            verifier_challenge,
+            &public_key.root_public_key,
+            &public_key.root_randomizer,
+        );
+
+        // Ensure the aggregated signature is valid.
+        match self.verify(&self.multisig_public_key(public_key), message, &signature)? {
+            true => Ok(signature),
+            false => Err(SignatureError::Message("Failed to aggregate the multisig signature".into()).into()),
+        }
+    }
+
+    /// Returns the proof of knowledge challenge for key generation,
+    /// as Hash(domain, i, t, G^a_0, ..., G^a_{t-1}, G^r_sig_i, G^k, G^k').
+    fn multisig_keygen_challenge(
+        &self,
+        index: u16,
+        coefficient_commitments: &[TEAffine<TE>],
+        root_randomizer_share: &TEAffine<TE>,
+        randomizer: &TEAffine<TE>,
+        root_randomizer_randomizer: &TEAffine<TE>,
+    ) -> TE::ScalarField {
+        let mut preimage = vec![
+            TE::BaseField::from_bytes_le_mod_order(ALEO_MULTISIG_KEYGEN_DOMAIN.as_bytes()),
+            TE::BaseField::from(index as u128),
+            TE::BaseField::from(coefficient_commitments.len() as u128),
+        ];
+        preimage.extend(coefficient_commitments.iter().map(|commitment| commitment.x));
+        preimage.push(root_randomizer_share.x);
+        preimage.push(randomizer.x);
+        preimage.push(root_randomizer_randomizer.x);
+
+        self.hash_to_scalar_field(&preimage)
+    }
+
+    /// Returns the commitments sorted by participant index, after ensuring they are well-formed.
+    fn multisig_prepare_commitments(
+        &self,
+        public_key: &AleoMultisigPublicKey<TE>,
+        commitments: &[AleoMultisigCommitment<TE>],
+    ) -> Result<Vec<AleoMultisigCommitment<TE>>> {
+        let indices: HashSet<u16> = commitments.iter().map(|commitment| commitment.index).collect();
+        if indices.len() != commitments.len() {
+            return Err(SignatureError::Message("Multisig commitments contain duplicate signers".into()).into());
+        }
+        if commitments.len() < public_key.threshold as usize {
+            return Err(SignatureError::Message(format!(
+                "Expected at least {} multisig signers, found {}",
+                public_key.threshold,
+                commitments.len()
+            ))
+            .into());
+        }
+        if indices
+            .iter()
+            .any(|index| *index == 0 || *index > public_key.num_participants())
+        {
+            return Err(
+                SignatureError::Message("Multisig commitments contain invalid participant indices".into()).into(),
+            );
+        }
+
+        let mut commitments = commitments.to_vec();
+        commitments.sort_by_key(|commitment| commitment.index);
+        Ok(commitments)
+    }
+
+    /// Returns the binding factors of each signer, and the verifier challenge.
+    fn multisig_challenge(
+        &self,
+        public_key: &AleoMultisigPublicKey<TE>,
+        commitments: &[AleoMultisigCommitment<TE>],
+        message: &[u8],
+    ) -> Result<(Vec<TE::ScalarField>, TE::ScalarField)> {
+        // Construct the binding preimage (domain, message, commitments).
+        let mut preimage = vec![TE::BaseField::from_bytes_le_mod_order(
+            ALEO_MULTISIG_BINDING_DOMAIN.as_bytes(),
+        )];
+        preimage.push(TE::BaseField::from(message.len() as u128));
+        preimage.extend_from_slice(&message.to_field_elements()?);
+        for commitment in commitments {
+            preimage.push(TE::BaseField::from(commitment.index as u128));
+            preimage.push(commitment.hiding.x);
+            preimage.push(commitment.binding.x);
+        }
+
+        // Compute the binding factor of each signer as p_i := Hash(i, preimage).
+        let binding_factors: Vec<_> = commitments
+            .iter()
+            .map(|commitment| {
+                let mut input = Vec::with_capacity(preimage.len() + 1);
+                input.push(TE::BaseField::from(commitment.index as u128));
+                input.extend_from_slice(&preimage);
+                self.hash_to_scalar_field(&input)
+            })
+            .collect();
+
+        // Compute the group commitment as G^r := \sum_i D_i E_i^p_i.
+        let g_r = commitments
+            .iter()
+            .zip(&binding_factors)
+            .map(|(commitment, binding_factor)| {
+                commitment.hiding.into_projective() + commitment.binding.into_projective().mul(*binding_factor)
+            })
+            .sum::<TEProjective<TE>>()
+            .into_affine();
+
+        let verifier_challenge =
+            self.compute_verifier_challenge(&self.multisig_public_key(public_key), &g_r, message)?;
+
+        Ok((binding_factors, verifier_challenge))
+    }
+}
+
+/// Returns f(x) := a_0 + a_1 x + ... + a_{t-1} x^{t-1}, using Horner's method.
+fn evaluate_polynomial<TE: TwistedEdwardsParameters>(coefficients: &[TE::ScalarField], x: u16) -> TE::ScalarField {
+    let x = TE::ScalarField::from(x as u128);
+    coefficients
+        .iter()
+        .rev()
+        .fold(TE::ScalarField::zero(), |result, coefficient| result * x + coefficient)
+}
+
+/// Returns G^f(x) := \sum_k (G^a_k)^{x^k}, from the commitments to the coefficients of f.
+fn evaluate_commitments<TE: TwistedEdwardsParameters>(commitments: &[TEAffine<TE>], x: u16) -> TEAffine<TE> {
+    let x = TE::ScalarField::from(x as u128);
+    commitments
+        .iter()
+        .rev()
+        .fold(TEProjective::<TE>::zero(), |result, commitment| {
+            let mut result = result.mul(x);
+            result.add_assign_mixed(commitment);
+            result
+        })
+        .into_affine()
+}
+
+/// Returns the Lagrange coefficient at zero for the given participant, over the set of signers.
+fn lagrange_coefficient<TE: TwistedEdwardsParameters>(
+    index: u16,
+    commitments: &[AleoMultisigCommitment<TE>],
+) -> Result<TE::ScalarField> {
+    let x_i = TE::ScalarField::from(index as u128);
+
+    let mut numerator = TE::ScalarField::one();
+    let mut denominator = TE::ScalarField::one();
+    for commitment in commitments.iter().filter(|commitment| commitment.index != index) {
+        let x_j = TE::ScalarField::from(commitment.index as u128);
+        numerator *= x_j;
+        denominator *= x_j - x_i;
+    }
+
+    match denominator.inverse() {
+        Some(inverse) => Ok(numerator * inverse),
+        None => Err(SignatureError::Message("Failed to compute the multisig Lagrange coefficient".into()).into()),
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> FromBytes for AleoMultisigKeyGenPackage<TE> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let index = FromBytes::read_le(&mut reader)?;
+
+        let num_commitments: u16 = FromBytes::read_le(&mut reader)?;
+        let mut coefficient_commitments = Vec::with_capacity(num_commitments as usize);
+        for _ in 0..num_commitments {
+            coefficient_commitments.push(FromBytes::read_le(&mut reader)?);
+        }
+
+        let root_randomizer_share = FromBytes::read_le(&mut reader)?;
+        let proof_randomizer = FromBytes::read_le(&mut reader)?;
+        let proof_response = FromBytes::read_le(&mut reader)?;
+        let root_randomizer_proof_randomizer = FromBytes::read_le(&mut reader)?;
+        let root_randomizer_proof_response = FromBytes::read_le(&mut reader)?;
+
+        Ok(Self {
+            index,
+            coefficient_commitments,
+            root_randomizer_share,
+            proof_randomizer,
+            proof_response,
+            root_randomizer_proof_randomizer,
+            root_randomizer_proof_response,
+        })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ToBytes for AleoMultisigKeyGenPackage<TE> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.index.write_le(&mut writer)?;
+        (self.coefficient_commitments.len() as u16).write_le(&mut writer)?;
+        self.coefficient_commitments.write_le(&mut writer)?;
+        self.root_randomizer_share.write_le(&mut writer)?;
+        self.proof_randomizer.write_le(&mut writer)?;
+        self.proof_response.write_le(&mut writer)?;
+        self.root_randomizer_proof_randomizer.write_le(&mut writer)?;
+        self.root_randomizer_proof_response.write_le(&mut writer)
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> FromBytes for AleoMultisigPublicKey<TE> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let threshold = FromBytes::read_le(&mut reader)?;
+        let root_public_key = FromBytes::read_le(&mut reader)?;
+        let root_randomizer = FromBytes::read_le(&mut reader)?;
+
+        let num_participants: u16 = FromBytes::read_le(&mut reader)?;
+        let mut verification_shares = Vec::with_capacity(num_participants as usize);
+        for _ in 0..num_participants {
+            verification_shares.push(FromBytes::read_le(&mut reader)?);
+        }
+
+        Ok(Self {
+            threshold,
+            root_public_key,
+            root_randomizer,
+            verification_shares,
+        })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ToBytes for AleoMultisigPublicKey<TE> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.threshold.write_le(&mut writer)?;
+        self.root_public_key.write_le(&mut writer)?;
+        self.root_randomizer.write_le(&mut writer)?;
+        (self.verification_shares.len() as u16).write_le(&mut writer)?;
+        self.verification_shares.write_le(&mut writer)
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> FromBytes for AleoMultisigCommitment<TE> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let index = FromBytes::read_le(&mut reader)?;
+        let hiding = FromBytes::read_le(&mut reader)?;
+        let binding = FromBytes::read_le(&mut reader)?;
+
+        Ok(Self { index, hiding, binding })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ToBytes for AleoMultisigCommitment<TE> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.index.write_le(&mut writer)?;
+        self.hiding.write_le(&mut writer)?;
+        self.binding.write_le(&mut writer)
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> FromBytes for AleoMultisigSignatureShare<TE> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let index = FromBytes::read_le(&mut reader)?;
+        let prover_response = FromBytes::read_le(&mut reader)?;
+
+        Ok(Self { index, prover_response })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ToBytes for AleoMultisigSignatureShare<TE> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.index.write_le(&mut writer)?;
+        self.prover_response.write_le(&mut writer)
+    }
+}
@@ -17,5 +17,8 @@
 pub mod aleo;
 pub use aleo::*;
 
+pub mod aleo_multisig;
+pub use aleo_multisig::*;
+
 #[cfg(test)]
 mod tests;
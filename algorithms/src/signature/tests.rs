@@ -47,11 +47,21 @@ fn signature_scheme_serialization<S: SignatureScheme>() {
 
 mod aleo {
     use super::*;
-    use crate::signature::AleoSignatureScheme;
+    use crate::{
+        crypto_hash::PoseidonDefaultParametersField,
+        signature::{AleoMultisigCommitment, AleoMultisigPublicKey, AleoSignatureScheme},
+    };
     use snarkvm_curves::{
         edwards_bls12::EdwardsParameters as EdwardsBls12,
         edwards_bw6::EdwardsParameters as EdwardsBW6,
+        templates::twisted_edwards_extended::Affine as TEAffine,
+        AffineCurve,
+        ModelParameters,
+        ProjectiveCurve,
+        TwistedEdwardsParameters,
     };
+    use snarkvm_fields::{One, Zero};
+    use snarkvm_utilities::{ToBytes, UniformRand};
 
     #[test]
     fn test_aleo_signature_on_edwards_bls12_377() {
@@ -71,9 +81,367 @@ mod aleo {
         failed_verification::<TestSignature>(message.as_bytes(), b"Bad message");
     }
 
+    #[test]
+    fn test_aleo_batch_verify_on_edwards_bls12_377() {
+        batch::batch_verify::<EdwardsBls12>();
+    }
+
+    #[test]
+    fn test_aleo_batch_verify_on_edwards_bw6() {
+        batch::batch_verify::<EdwardsBW6>();
+    }
+
     #[test]
     fn aleo_signature_scheme_serialization() {
         signature_scheme_serialization::<AleoSignatureScheme<EdwardsBls12>>();
         signature_scheme_serialization::<AleoSignatureScheme<EdwardsBW6>>();
     }
+
+    #[test]
+    fn test_aleo_multisig_on_edwards_bls12_377() {
+        let message = "Hi, I am an Aleo multisig signature!".as_bytes();
+        multisig::sign_and_verify::<EdwardsBls12>(2, 3, &[1, 3], message);
+        multisig::sign_and_verify::<EdwardsBls12>(3, 5, &[5, 2, 4], message);
+        multisig::sign_and_verify::<EdwardsBls12>(3, 3, &[1, 2, 3], message);
+        multisig::sign_and_verify::<EdwardsBls12>(1, 1, &[1], message);
+    }
+
+    #[test]
+    fn test_aleo_multisig_on_edwards_bw6() {
+        let message = "Hi, I am an Aleo multisig signature!".as_bytes();
+        multisig::sign_and_verify::<EdwardsBW6>(2, 3, &[2, 3], message);
+    }
+
+    #[test]
+    fn test_aleo_multisig_insufficient_signers() {
+        let rng = &mut thread_rng();
+        let signature_scheme = AleoSignatureScheme::<EdwardsBls12>::setup("aleo_multisig_insufficient_signers");
+        let key_shares = multisig::keygen(&signature_scheme, 3, 4);
+
+        let (_, commitment_1) = signature_scheme.multisig_commit(&key_shares[0], rng);
+        let (nonces_2, commitment_2) = signature_scheme.multisig_commit(&key_shares[1], rng);
+        assert!(
+            signature_scheme
+                .multisig_sign(&key_shares[1], nonces_2, &[commitment_1, commitment_2], b"message")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_aleo_multisig_invalid_signature_share() {
+        let rng = &mut thread_rng();
+        let message = "Hi, I am an Aleo multisig signature!".as_bytes();
+        let signature_scheme = AleoSignatureScheme::<EdwardsBls12>::setup("aleo_multisig_invalid_signature_share");
+        let key_shares = multisig::keygen(&signature_scheme, 2, 3);
+        let public_key = key_shares[0].public_key().clone();
+
+        let (nonces_1, commitment_1) = signature_scheme.multisig_commit(&key_shares[0], rng);
+        let (nonces_2, commitment_2) = signature_scheme.multisig_commit(&key_shares[1], rng);
+        let commitments = [commitment_1, commitment_2];
+
+        // The second signer signs a different message.
+        let share_1 = signature_scheme
+            .multisig_sign(&key_shares[0], nonces_1, &commitments, message)
+            .unwrap();
+        let share_2 = signature_scheme
+            .multisig_sign(&key_shares[1], nonces_2, &commitments, b"Bad message")
+            .unwrap();
+
+        assert!(
+            signature_scheme
+                .multisig_verify_share(&public_key, &commitments, message, &share_1)
+                .unwrap()
+        );
+        assert!(
+            !signature_scheme
+                .multisig_verify_share(&public_key, &commitments, message, &share_2)
+                .unwrap()
+        );
+        assert!(
+            signature_scheme
+                .multisig_aggregate(&public_key, &commitments, &[share_1, share_2], message)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_aleo_multisig_invalid_keygen_share() {
+        let rng = &mut thread_rng();
+        let signature_scheme = AleoSignatureScheme::<EdwardsBls12>::setup("aleo_multisig_invalid_keygen_share");
+
+        let (generators, packages): (Vec<_>, Vec<_>) = (1..=3)
+            .map(|index| signature_scheme.multisig_keygen_begin(index, 2, 3, rng).unwrap())
+            .unzip();
+
+        // Participant 1 sends a share to participant 3 that is inconsistent with its commitments.
+        let mut received_shares: Vec<_> = generators
+            .iter()
+            .map(|generator| (generator.index(), generator.share_for(3).unwrap()))
+            .collect();
+        received_shares[0].1 += &<EdwardsBls12 as ModelParameters>::ScalarField::one();
+
+        let generator = generators[2].clone();
+        assert!(
+            signature_scheme
+                .multisig_keygen_finalize(generator, &packages, &received_shares)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_aleo_multisig_rogue_root_randomizer_share() {
+        let rng = &mut thread_rng();
+        let signature_scheme = AleoSignatureScheme::<EdwardsBls12>::setup("aleo_multisig_rogue_root_randomizer_share");
+
+        let mut packages: Vec<_> = (1..=3)
+            .map(|index| signature_scheme.multisig_keygen_begin(index, 2, 3, rng).unwrap().1)
+            .collect();
+        assert!(signature_scheme.multisig_aggregate_public_key(2, &packages).is_ok());
+
+        // Participant 3 replaces its share of the root randomizer with a point of unknown discrete logarithm.
+        let rogue_share = (TEAffine::<EdwardsBls12>::prime_subgroup_generator().into_projective()
+            * <EdwardsBls12 as ModelParameters>::ScalarField::rand(rng))
+        .into_affine()
+        .to_bytes_le()
+        .unwrap();
+        let offset = 2 + 2 + 2 * rogue_share.len();
+        let mut package_bytes = packages[2].to_bytes_le().unwrap();
+        package_bytes[offset..offset + rogue_share.len()].copy_from_slice(&rogue_share);
+        packages[2] = FromBytes::read_le(&package_bytes[..]).unwrap();
+
+        assert!(signature_scheme.multisig_aggregate_public_key(2, &packages).is_err());
+    }
+
+    #[test]
+    fn test_aleo_multisig_serialization() {
+        let signature_scheme = AleoSignatureScheme::<EdwardsBls12>::setup("aleo_multisig_serialization");
+        let key_shares = multisig::keygen(&signature_scheme, 2, 3);
+
+        let public_key = key_shares[0].public_key();
+        let recovered_public_key: AleoMultisigPublicKey<EdwardsBls12> =
+            FromBytes::read_le(&public_key.to_bytes_le().unwrap()[..]).unwrap();
+        assert_eq!(public_key, &recovered_public_key);
+
+        let (_, commitment) = signature_scheme.multisig_commit(&key_shares[1], &mut thread_rng());
+        let recovered_commitment: AleoMultisigCommitment<EdwardsBls12> =
+            FromBytes::read_le(&commitment.to_bytes_le().unwrap()[..]).unwrap();
+        assert_eq!(commitment, recovered_commitment);
+    }
+
+    /// Batch verification of valid and adversarial batches.
+    mod batch {
+        use super::*;
+        use crate::{signature::AleoSignature, SignatureSchemeOperations};
+
+        /// Signs the message as if the given public key belonged to the private key.
+        fn sign_as<TE: TwistedEdwardsParameters>(
+            signature_scheme: &AleoSignatureScheme<TE>,
+            private_key: &(TE::ScalarField, TE::ScalarField),
+            public_key: &TEAffine<TE>,
+            message: &[u8],
+        ) -> AleoSignature<TE>
+        where
+            TE::BaseField: PoseidonDefaultParametersField,
+        {
+            let (sk_sig, r_sig) = private_key;
+            let r = TE::ScalarField::rand(&mut thread_rng());
+            let g_r = signature_scheme.g_scalar_multiply(&r);
+
+            let verifier_challenge = signature_scheme
+                .compute_verifier_challenge(public_key, &g_r, message)
+                .unwrap();
+            let prover_response = r - (verifier_challenge * sk_sig);
+
+            AleoSignature::new(
+                prover_response,
+                verifier_challenge,
+                &signature_scheme.g_scalar_multiply(sk_sig),
+                &signature_scheme.g_scalar_multiply(r_sig),
+            )
+        }
+
+        pub(super) fn batch_verify<TE: TwistedEdwardsParameters>()
+        where
+            TE::BaseField: PoseidonDefaultParametersField,
+        {
+            let rng = &mut thread_rng();
+            let signature_scheme = AleoSignatureScheme::<TE>::setup("aleo_batch_verify");
+
+            let private_keys: Vec<_> = (0..16).map(|_| signature_scheme.generate_private_key(rng)).collect();
+            let mut batch: Vec<_> = private_keys
+                .iter()
+                .enumerate()
+                .map(|(i, private_key)| {
+                    let message = format!("Hi, I am Aleo signature {}!", i).into_bytes();
+                    let signature = signature_scheme.sign(private_key, &message, rng).unwrap();
+                    (signature_scheme.generate_public_key(private_key), message, signature)
+                })
+                .collect();
+
+            // Ensure valid batches pass.
+            assert!(
+                signature_scheme
+                    .batch_verify::<Vec<u8>, _>(&[], rng)
+                    .unwrap()
+                    .is_empty()
+            );
+            assert!(signature_scheme.batch_verify(&batch[..1], rng).unwrap().is_empty());
+            assert!(signature_scheme.batch_verify(&batch, rng).unwrap().is_empty());
+
+            // Tamper with a prover response.
+            batch[1].2.prover_response += TE::ScalarField::one();
+
+            // Replace a message.
+            batch[3].1 = b"Bad message".to_vec();
+
+            // Swap the public keys of two signatures.
+            let (public_key_5, public_key_6) = (batch[5].0, batch[6].0);
+            batch[5].0 = public_key_6;
+            batch[6].0 = public_key_5;
+
+            // Sign under two public keys with opposite offsets, which cancel in an unweighted sum.
+            let offset = signature_scheme.g_scalar_multiply(&TE::ScalarField::rand(rng));
+            for (i, offset) in [(8, offset), (9, -offset)] {
+                let public_key = (batch[i].0.into_projective() + offset.into_projective()).into_affine();
+                batch[i].2 = sign_as(&signature_scheme, &private_keys[i], &public_key, &batch[i].1);
+                batch[i].0 = public_key;
+            }
+
+            // Sign under two public keys offset by the point of order two, which cancel in any even combination.
+            let torsion = TEAffine::<TE>::new(TE::BaseField::zero(), -TE::BaseField::one());
+            for i in [11, 14] {
+                let public_key = (batch[i].0.into_projective() + torsion.into_projective()).into_affine();
+                batch[i].2 = sign_as(&signature_scheme, &private_keys[i], &public_key, &batch[i].1);
+                batch[i].0 = public_key;
+            }
+
+            // Ensure exactly the tampered signatures are reported, in agreement with individual verification.
+            let invalid = signature_scheme.batch_verify(&batch, rng).unwrap();
+            assert_eq!(invalid, vec![1, 3, 5, 6, 8, 9, 11, 14]);
+
+            let expected: Vec<_> = batch
+                .iter()
+                .enumerate()
+                .filter(|(_, (public_key, message, signature))| {
+                    !signature_scheme.verify(public_key, message, signature).unwrap()
+                })
+                .map(|(i, _)| i)
+                .collect();
+            assert_eq!(invalid, expected);
+
+            // Ensure a batch of only invalid signatures is fully reported.
+            let invalid_batch: Vec<_> = invalid.iter().map(|i| batch[*i].clone()).collect();
+            assert_eq!(
+                signature_scheme.batch_verify(&invalid_batch, rng).unwrap(),
+                (0..invalid.len()).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    /// An in-process harness that simulates the participants of a multisig account.
+    mod multisig {
+        use super::*;
+        use crate::signature::{AleoMultisigKeyShare, AleoSignature};
+
+        /// Runs the distributed key generation for all participants.
+        pub(super) fn keygen<TE: TwistedEdwardsParameters>(
+            signature_scheme: &AleoSignatureScheme<TE>,
+            threshold: u16,
+            num_participants: u16,
+        ) -> Vec<AleoMultisigKeyShare<TE>>
+        where
+            TE::BaseField: PoseidonDefaultParametersField,
+        {
+            let rng = &mut thread_rng();
+
+            // Round 1: Each participant broadcasts its package.
+            let (generators, packages): (Vec<_>, Vec<_>) = (1..=num_participants)
+                .map(|index| {
+                    signature_scheme
+                        .multisig_keygen_begin(index, threshold, num_participants, rng)
+                        .unwrap()
+                })
+                .unzip();
+
+            // Round 2: Each participant sends a secret share to every other participant, and finalizes.
+            generators
+                .iter()
+                .map(|recipient| {
+                    let received_shares: Vec<_> = generators
+                        .iter()
+                        .map(|sender| (sender.index(), sender.share_for(recipient.index()).unwrap()))
+                        .collect();
+                    signature_scheme
+                        .multisig_keygen_finalize(recipient.clone(), &packages, &received_shares)
+                        .unwrap()
+                })
+                .collect()
+        }
+
+        /// Runs the signing protocol for the given signers.
+        pub(super) fn sign<TE: TwistedEdwardsParameters>(
+            signature_scheme: &AleoSignatureScheme<TE>,
+            key_shares: &[AleoMultisigKeyShare<TE>],
+            signers: &[u16],
+            message: &[u8],
+        ) -> AleoSignature<TE>
+        where
+            TE::BaseField: PoseidonDefaultParametersField,
+        {
+            let rng = &mut thread_rng();
+            let signers: Vec<_> = signers.iter().map(|index| &key_shares[*index as usize - 1]).collect();
+
+            // Round 1: Each signer commits to its nonces.
+            let (nonces, commitments): (Vec<_>, Vec<_>) = signers
+                .iter()
+                .map(|key_share| signature_scheme.multisig_commit(key_share, rng))
+                .unzip();
+
+            // Round 2: Each signer produces its signature share.
+            let signature_shares: Vec<_> = signers
+                .iter()
+                .zip(nonces)
+                .map(|(key_share, nonces)| {
+                    signature_scheme
+                        .multisig_sign(key_share, nonces, &commitments, message)
+                        .unwrap()
+                })
+                .collect();
+
+            // Any party may aggregate the signature shares.
+            signature_scheme
+                .multisig_aggregate(signers[0].public_key(), &commitments, &signature_shares, message)
+                .unwrap()
+        }
+
+        pub(super) fn sign_and_verify<TE: TwistedEdwardsParameters>(
+            threshold: u16,
+            num_participants: u16,
+            signers: &[u16],
+            message: &[u8],
+        ) where
+            TE::BaseField: PoseidonDefaultParametersField,
+        {
+            let signature_scheme = AleoSignatureScheme::<TE>::setup("aleo_multisig_sign_and_verify");
+            let key_shares = keygen(&signature_scheme, threshold, num_participants);
+
+            // Ensure all participants derived the same public key.
+            let public_key = key_shares[0].public_key();
+            assert!(key_shares.iter().all(|key_share| key_share.public_key() == public_key));
+
+            // Ensure the signature verifies under the aggregated public key.
+            let signature = sign(&signature_scheme, &key_shares, signers, message);
+            let aggregated_public_key = signature_scheme.multisig_public_key(public_key);
+            assert!(
+                signature_scheme
+                    .verify(&aggregated_public_key, message, &signature)
+                    .unwrap()
+            );
+            assert!(
+                !signature_scheme
+                    .verify(&aggregated_public_key, b"Bad message", &signature)
+                    .unwrap()
+            );
+        }
+    }
 }
@@ -0,0 +1,137 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::msm::VariableBaseMSM;
+use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::PrimeField;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// A pair of commitment keys `(g^{a^i}, g^{b^i})`, over the powers of the two secrets of the SRS.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommitmentKey<G: AffineCurve> {
+    pub a: Vec<G>,
+    pub b: Vec<G>,
+}
+
+impl<G: AffineCurve> CommitmentKey<G> {
+    /// Returns the number of elements that `self` commits to.
+    pub fn len(&self) -> usize {
+        self.a.len()
+    }
+
+    /// Returns `true` if `self` commits to no elements.
+    pub fn is_empty(&self) -> bool {
+        self.a.is_empty()
+    }
+
+    /// Returns the left and right halves of `self`, split at the given index.
+    pub fn split(&self, at: usize) -> (Self, Self) {
+        let (a_left, a_right) = self.a.split_at(at);
+        let (b_left, b_right) = self.b.split_at(at);
+        (
+            Self {
+                a: a_left.to_vec(),
+                b: b_left.to_vec(),
+            },
+            Self {
+                a: a_right.to_vec(),
+                b: b_right.to_vec(),
+            },
+        )
+    }
+
+    /// Returns the key whose `i`-th elements are the `i`-th elements of `self` scaled by `scalars[i]`.
+    pub fn scale(&self, scalars: &[G::ScalarField]) -> Self {
+        assert_eq!(self.len(), scalars.len());
+        let scale = |bases: &[G]| {
+            G::Projective::batch_normalization_into_affine(
+                cfg_iter!(bases)
+                    .zip(scalars)
+                    .map(|(base, scalar)| base.into_projective() * *scalar)
+                    .collect(),
+            )
+        };
+        Self {
+            a: scale(&self.a),
+            b: scale(&self.b),
+        }
+    }
+
+    /// Returns the key `left + right * challenge`, where `left` and `right` are the halves of `self`.
+    pub fn fold(&self, challenge: G::ScalarField) -> Self {
+        let fold = |bases: &[G]| {
+            let (left, right) = bases.split_at(bases.len() / 2);
+            fold_vector(left, right, challenge)
+        };
+        Self {
+            a: fold(&self.a),
+            b: fold(&self.b),
+        }
+    }
+
+    /// Returns the first pair of elements of `self`, which is the folded key once `self` has length 1.
+    pub fn first(&self) -> (G, G) {
+        (self.a[0], self.b[0])
+    }
+}
+
+/// Returns the vector `left + right * challenge`.
+pub(crate) fn fold_vector<G: AffineCurve>(left: &[G], right: &[G], challenge: G::ScalarField) -> Vec<G> {
+    assert_eq!(left.len(), right.len());
+    G::Projective::batch_normalization_into_affine(
+        cfg_iter!(left)
+            .zip(right)
+            .map(|(left, right)| left.into_projective() + right.into_projective() * challenge)
+            .collect(),
+    )
+}
+
+/// Returns the product of the pairings `e(left[i], right[i])`.
+pub(crate) fn pairing_product<E: PairingEngine>(left: &[E::G1Affine], right: &[E::G2Affine]) -> E::Fqk {
+    assert_eq!(left.len(), right.len());
+    let prepared = cfg_iter!(left)
+        .zip(right)
+        .map(|(left, right)| (left.prepare(), right.prepare()))
+        .collect::<Vec<_>>();
+    E::product_of_pairings(prepared.iter().map(|(left, right)| (left, right)))
+}
+
+/// Returns the multi-scalar multiplication of `bases` by `scalars`.
+pub(crate) fn multi_scalar_mul<G: AffineCurve>(bases: &[G], scalars: &[G::ScalarField]) -> G::Projective {
+    assert_eq!(bases.len(), scalars.len());
+    let scalars = cfg_iter!(scalars).map(|scalar| scalar.to_repr()).collect::<Vec<_>>();
+    VariableBaseMSM::multi_scalar_mul(bases, &scalars)
+}
+
+/// Commits to a vector in G1 under the key `v` in G2, as `(prod e(c_i, v.a_i), prod e(c_i, v.b_i))`.
+pub(crate) fn commit_single<E: PairingEngine>(v: &CommitmentKey<E::G2Affine>, c: &[E::G1Affine]) -> (E::Fqk, E::Fqk) {
+    (pairing_product::<E>(c, &v.a), pairing_product::<E>(c, &v.b))
+}
+
+/// Commits to a pair of vectors in G1 and G2, under the keys `v` in G2 and `w` in G1, as
+/// `(prod e(a_i, v.a_i) * e(w.a_i, b_i), prod e(a_i, v.b_i) * e(w.b_i, b_i))`.
+pub(crate) fn commit_double<E: PairingEngine>(
+    v: &CommitmentKey<E::G2Affine>,
+    w: &CommitmentKey<E::G1Affine>,
+    a: &[E::G1Affine],
+    b: &[E::G2Affine],
+) -> (E::Fqk, E::Fqk) {
+    let (v_a, v_b) = commit_single::<E>(v, a);
+    let (w_a, w_b) = (pairing_product::<E>(&w.a, b), pairing_product::<E>(&w.b, b));
+    (v_a * w_a, v_b * w_b)
+}
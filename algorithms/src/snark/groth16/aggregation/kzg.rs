@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::multi_scalar_mul;
+use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{Field, One, Zero};
+
+use core::ops::Neg;
+
+/// Returns the coefficients of `prod_j (1 + challenges[j] * X^{2^{k - 1 - j}})`, where `k = challenges.len()`,
+/// which is the polynomial that a commitment key folded with these challenges is evaluated at.
+pub(crate) fn folding_polynomial_coefficients<F: Field>(challenges: &[F]) -> Vec<F> {
+    let mut coefficients = vec![F::one()];
+    for challenge in challenges.iter().rev() {
+        let shifted = coefficients
+            .iter()
+            .map(|coefficient| *coefficient * challenge)
+            .collect::<Vec<_>>();
+        coefficients.extend(shifted);
+    }
+    coefficients
+}
+
+/// Evaluates `prod_j (1 + challenges[j] * X^{2^{k - 1 - j}})` at `point`, in time logarithmic in its degree.
+pub(crate) fn evaluate_folding_polynomial<F: Field>(challenges: &[F], point: F) -> F {
+    let mut power = point;
+    let mut evaluation = F::one();
+    for challenge in challenges.iter().rev() {
+        evaluation *= F::one() + *challenge * power;
+        power.square_in_place();
+    }
+    evaluation
+}
+
+/// Returns the challenges `x_j * r^{-2^{k - 1 - j}}` of the folding polynomial of the `w` key,
+/// which is scaled by the inverse powers of `r` before it is folded.
+pub(crate) fn wkey_folding_challenges<F: Field>(challenges: &[F], r_inverse: F, num_proofs: usize) -> Vec<F> {
+    challenges
+        .iter()
+        .enumerate()
+        .map(|(j, challenge)| *challenge * r_inverse.pow([(num_proofs >> (j + 1)) as u64]))
+        .collect()
+}
+
+/// Returns the KZG opening `g^{q(s)}` of the polynomial with the given coefficients at `point`,
+/// from the powers `g^{s^i}`, where `q(X) = (f(X) - f(point)) / (X - point)`.
+pub(crate) fn open<G: AffineCurve>(powers: &[G], coefficients: &[G::ScalarField], point: G::ScalarField) -> G {
+    if coefficients.len() < 2 {
+        return G::zero();
+    }
+
+    let mut quotient = vec![G::ScalarField::zero(); coefficients.len() - 1];
+    let mut carry = G::ScalarField::zero();
+    for i in (1..coefficients.len()).rev() {
+        carry = coefficients[i] + carry * point;
+        quotient[i - 1] = carry;
+    }
+
+    multi_scalar_mul(&powers[..quotient.len()], &quotient).into_affine()
+}
+
+/// Returns `true` if `proof` opens the commitment `h^{f(s)}` in G2 to `evaluation` at `point`,
+/// by checking `e(g^s / g^point, proof) == e(g, h^{f(s)} / h^evaluation)`.
+pub(crate) fn verify_g2_opening<E: PairingEngine>(
+    g: E::G1Affine,
+    g_secret: E::G1Affine,
+    h: E::G2Affine,
+    commitment: E::G2Affine,
+    point: E::Fr,
+    evaluation: E::Fr,
+    proof: E::G2Affine,
+) -> bool {
+    let g_shifted = (g_secret.into_projective() - g.into_projective() * point).into_affine();
+    let commitment_shifted = (commitment.into_projective() - h.into_projective() * evaluation).into_affine();
+
+    E::product_of_pairings(
+        [
+            (&g_shifted.prepare(), &proof.prepare()),
+            (&g.neg().prepare(), &commitment_shifted.prepare()),
+        ]
+        .iter()
+        .copied(),
+    )
+    .is_one()
+}
+
+/// Returns `true` if `proof` opens the commitment `g^{f(s)}` in G1 to `evaluation` at `point`,
+/// by checking `e(proof, h^s / h^point) == e(g^{f(s)} / g^evaluation, h)`.
+pub(crate) fn verify_g1_opening<E: PairingEngine>(
+    g: E::G1Affine,
+    h: E::G2Affine,
+    h_secret: E::G2Affine,
+    commitment: E::G1Affine,
+    point: E::Fr,
+    evaluation: E::Fr,
+    proof: E::G1Affine,
+) -> bool {
+    let h_shifted = (h_secret.into_projective() - h.into_projective() * point).into_affine();
+    let commitment_shifted = (commitment.into_projective() - g.into_projective() * evaluation).into_affine();
+
+    E::product_of_pairings(
+        [
+            (&proof.prepare(), &h_shifted.prepare()),
+            (&commitment_shifted.prepare(), &h.neg().prepare()),
+        ]
+        .iter()
+        .copied(),
+    )
+    .is_one()
+}
@@ -0,0 +1,277 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Aggregation of Groth16 proofs under the same verifying key, following [`SnarkPack`].
+//!
+//! The aggregate proof commits to the proofs with pairing-based commitments, and proves the
+//! random linear combination of their verification equations with a combined TIPP and MIPP
+//! inner-pairing-product argument, so its size and verification time are logarithmic in the
+//! number of proofs.
+//!
+//! [`SnarkPack`]: https://eprint.iacr.org/2021/529.pdf
+
+use snarkvm_curves::traits::PairingEngine;
+use snarkvm_fields::Field;
+use snarkvm_r1cs::SynthesisError;
+use snarkvm_utilities::{error, fmt, str::FromStr, FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::io::{Read, Result as IoResult, Write};
+
+/// Pairing-based commitments to vectors of group elements.
+mod commitment;
+pub use commitment::*;
+
+/// KZG openings of the folded commitment keys.
+mod kzg;
+
+/// Aggregate Groth16 proofs.
+mod prover;
+pub use prover::*;
+
+/// The structured reference string for the aggregation.
+mod srs;
+pub use srs::*;
+
+/// The Fiat-Shamir transcript of the aggregation.
+mod transcript;
+use transcript::*;
+
+/// Verify aggregate Groth16 proofs.
+mod verifier;
+pub use verifier::*;
+
+/// The domain separator of the aggregation transcript.
+const AGGREGATION_DOMAIN: &[u8] = b"SNARKVM_GROTH16_AGGREGATION";
+
+/// A commitment to one or two vectors, as a pair of elements in the target group.
+pub type PairCommitment<E> = (<E as PairingEngine>::Fqk, <E as PairingEngine>::Fqk);
+
+/// An aggregate of Groth16 proofs under the same verifying key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AggregateProof<E: PairingEngine> {
+    /// The number of aggregated proofs, padded to a power of two.
+    pub num_proofs: u32,
+    /// The commitment to the `A` and `B` elements of the proofs.
+    pub com_ab: PairCommitment<E>,
+    /// The commitment to the `C` elements of the proofs.
+    pub com_c: PairCommitment<E>,
+    /// The inner pairing product `prod e(A_i, B_i)^{r^i}`.
+    pub ip_ab: E::Fqk,
+    /// The linear combination `sum r^i C_i`.
+    pub agg_c: E::G1Affine,
+    /// The proof that `ip_ab` and `agg_c` are computed from the committed vectors.
+    pub gipa: GipaProof<E>,
+    /// The openings of the final commitment key in G2, for the powers of `a` and of `b`.
+    pub vkey_opening: (E::G2Affine, E::G2Affine),
+    /// The openings of the final commitment key in G1, for the powers of `a` and of `b`.
+    pub wkey_opening: (E::G1Affine, E::G1Affine),
+}
+
+/// The generalized inner product argument for the `A`, `B` and `C` elements of the proofs, which
+/// halves the committed vectors in each round until a single element of each remains.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GipaProof<E: PairingEngine> {
+    /// The left and right cross commitments to the `A` and `B` elements, in each round.
+    pub comms_ab: Vec<(PairCommitment<E>, PairCommitment<E>)>,
+    /// The left and right cross commitments to the `C` elements, in each round.
+    pub comms_c: Vec<(PairCommitment<E>, PairCommitment<E>)>,
+    /// The left and right cross pairing products of the `A` and `B` elements, in each round.
+    pub z_ab: Vec<(E::Fqk, E::Fqk)>,
+    /// The left and right cross linear combinations of the `C` elements, in each round.
+    pub z_c: Vec<(E::G1Affine, E::G1Affine)>,
+    pub final_a: E::G1Affine,
+    pub final_b: E::G2Affine,
+    pub final_c: E::G1Affine,
+    pub final_vkey: (E::G2Affine, E::G2Affine),
+    pub final_wkey: (E::G1Affine, E::G1Affine),
+}
+
+impl<E: PairingEngine> GipaProof<E> {
+    /// Absorbs the final folded elements and commitment keys into the transcript.
+    fn append_final_values(&self, transcript: &mut Transcript) -> Result<(), SynthesisError> {
+        transcript.append(&self.final_a)?;
+        transcript.append(&self.final_b)?;
+        transcript.append(&self.final_c)?;
+        transcript.append(&self.final_vkey)?;
+        transcript.append(&self.final_wkey)
+    }
+}
+
+impl<E: PairingEngine> ToBytes for GipaProof<E> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        (self.comms_ab.len() as u32).write_le(&mut writer)?;
+        self.comms_ab.write_le(&mut writer)?;
+        self.comms_c.write_le(&mut writer)?;
+        self.z_ab.write_le(&mut writer)?;
+        self.z_c.write_le(&mut writer)?;
+        self.final_a.write_le(&mut writer)?;
+        self.final_b.write_le(&mut writer)?;
+        self.final_c.write_le(&mut writer)?;
+        self.final_vkey.write_le(&mut writer)?;
+        self.final_wkey.write_le(&mut writer)
+    }
+}
+
+impl<E: PairingEngine> FromBytes for GipaProof<E> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let num_rounds: u32 = FromBytes::read_le(&mut reader)?;
+        // Each round halves the number of proofs, so a valid proof has at most 32 rounds.
+        if num_rounds > 32 {
+            return Err(error("Invalid number of GIPA rounds"));
+        }
+
+        let mut comms_ab = Vec::with_capacity(num_rounds as usize);
+        for _ in 0..num_rounds {
+            comms_ab.push(FromBytes::read_le(&mut reader)?);
+        }
+        let mut comms_c = Vec::with_capacity(num_rounds as usize);
+        for _ in 0..num_rounds {
+            comms_c.push(FromBytes::read_le(&mut reader)?);
+        }
+        let mut z_ab = Vec::with_capacity(num_rounds as usize);
+        for _ in 0..num_rounds {
+            z_ab.push(FromBytes::read_le(&mut reader)?);
+        }
+        let mut z_c = Vec::with_capacity(num_rounds as usize);
+        for _ in 0..num_rounds {
+            z_c.push(FromBytes::read_le(&mut reader)?);
+        }
+
+        Ok(Self {
+            comms_ab,
+            comms_c,
+            z_ab,
+            z_c,
+            final_a: FromBytes::read_le(&mut reader)?,
+            final_b: FromBytes::read_le(&mut reader)?,
+            final_c: FromBytes::read_le(&mut reader)?,
+            final_vkey: FromBytes::read_le(&mut reader)?,
+            final_wkey: FromBytes::read_le(&mut reader)?,
+        })
+    }
+}
+
+impl<E: PairingEngine> ToBytes for AggregateProof<E> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.num_proofs.write_le(&mut writer)?;
+        self.com_ab.write_le(&mut writer)?;
+        self.com_c.write_le(&mut writer)?;
+        self.ip_ab.write_le(&mut writer)?;
+        self.agg_c.write_le(&mut writer)?;
+        self.gipa.write_le(&mut writer)?;
+        self.vkey_opening.write_le(&mut writer)?;
+        self.wkey_opening.write_le(&mut writer)
+    }
+}
+
+impl<E: PairingEngine> FromBytes for AggregateProof<E> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self {
+            num_proofs: FromBytes::read_le(&mut reader)?,
+            com_ab: FromBytes::read_le(&mut reader)?,
+            com_c: FromBytes::read_le(&mut reader)?,
+            ip_ab: FromBytes::read_le(&mut reader)?,
+            agg_c: FromBytes::read_le(&mut reader)?,
+            gipa: FromBytes::read_le(&mut reader)?,
+            vkey_opening: FromBytes::read_le(&mut reader)?,
+            wkey_opening: FromBytes::read_le(&mut reader)?,
+        })
+    }
+}
+
+impl<E: PairingEngine> FromStr for AggregateProof<E> {
+    type Err = anyhow::Error;
+
+    #[inline]
+    fn from_str(proof_hex: &str) -> Result<Self, Self::Err> {
+        Self::from_bytes_le(&hex::decode(proof_hex)?)
+    }
+}
+
+impl<E: PairingEngine> fmt::Display for AggregateProof<E> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let proof_hex = hex::encode(self.to_bytes_le().expect("Failed to convert aggregate proof to bytes"));
+        write!(f, "{}", proof_hex)
+    }
+}
+
+impl<E: PairingEngine> Serialize for AggregateProof<E> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => serializer.collect_str(self),
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, E: PairingEngine> Deserialize<'de> for AggregateProof<E> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let s: String = Deserialize::deserialize(deserializer)?;
+                FromStr::from_str(&s).map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "aggregate proof"),
+        }
+    }
+}
+
+/// Absorbs the statement of an aggregate proof into the transcript, which is the padded number of proofs,
+/// their public inputs padded in the same way, and the commitments to the proofs.
+fn append_statement<E: PairingEngine>(
+    transcript: &mut Transcript,
+    num_proofs: usize,
+    public_inputs: &[Vec<E::Fr>],
+    com_ab: &PairCommitment<E>,
+    com_c: &PairCommitment<E>,
+) -> Result<(), SynthesisError> {
+    transcript.append(&(num_proofs as u64))?;
+    for inputs in padded_public_inputs(public_inputs, num_proofs) {
+        transcript.append(&(inputs.len() as u64))?;
+        transcript.append(inputs)?;
+    }
+    transcript.append(com_ab)?;
+    transcript.append(com_c)
+}
+
+/// Returns the public inputs padded to `num_proofs` by repeating the last one, like the aggregated proofs.
+fn padded_public_inputs<F: Field>(public_inputs: &[Vec<F>], num_proofs: usize) -> impl Iterator<Item = &Vec<F>> {
+    let padding = public_inputs
+        .last()
+        .into_iter()
+        .cycle()
+        .take(num_proofs - public_inputs.len());
+    public_inputs.iter().chain(padding)
+}
+
+/// Returns `[1, x, x^2, ..., x^{n - 1}]`.
+fn powers<F: Field>(x: F, n: usize) -> Vec<F> {
+    let mut powers = Vec::with_capacity(n);
+    let mut power = F::one();
+    for _ in 0..n {
+        powers.push(power);
+        power *= x;
+    }
+    powers
+}
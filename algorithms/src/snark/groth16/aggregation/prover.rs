@@ -0,0 +1,216 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    append_statement,
+    commit_double,
+    commit_single,
+    fold_vector,
+    kzg,
+    multi_scalar_mul,
+    pairing_product,
+    powers,
+    AggregateProof,
+    AggregationSRS,
+    GipaProof,
+    Transcript,
+    AGGREGATION_DOMAIN,
+};
+use crate::snark::groth16::Proof;
+use snarkvm_curves::traits::{AffineCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{batch_inversion, Field, Zero};
+use snarkvm_profiler::{end_timer, start_timer};
+use snarkvm_r1cs::SynthesisError;
+
+use anyhow::anyhow;
+use std::iter;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Aggregates the given Groth16 proofs, which verify under the same verifying key for the given public inputs.
+///
+/// The proofs are padded to a power of two by repeating the last proof, and the padded number of proofs
+/// must be supported by `srs`.
+pub fn aggregate_proofs<E: PairingEngine>(
+    srs: &AggregationSRS<E>,
+    proofs: &[Proof<E>],
+    public_inputs: &[Vec<E::Fr>],
+) -> Result<AggregateProof<E>, SynthesisError> {
+    let aggregation_time = start_timer!(|| format!("Aggregate {} proofs", proofs.len()));
+
+    let last = proofs
+        .last()
+        .ok_or_else(|| anyhow!("Cannot aggregate an empty set of proofs"))?;
+    if proofs.len() != public_inputs.len() {
+        return Err(anyhow!(
+            "Found {} proofs for {} public inputs",
+            proofs.len(),
+            public_inputs.len()
+        )
+        .into());
+    }
+    let num_proofs = proofs.len().next_power_of_two();
+    if num_proofs > srs.max_num_proofs() {
+        return Err(anyhow!(
+            "The aggregation SRS supports up to {} proofs, found {}",
+            srs.max_num_proofs(),
+            num_proofs
+        )
+        .into());
+    }
+
+    // Pad the proofs to a power of two by repeating the last proof.
+    let padded_proofs = || proofs.iter().chain(iter::repeat(last).take(num_proofs - proofs.len()));
+    let a = padded_proofs().map(|proof| proof.a).collect::<Vec<_>>();
+    let b = padded_proofs().map(|proof| proof.b).collect::<Vec<_>>();
+    let c = padded_proofs().map(|proof| proof.c).collect::<Vec<_>>();
+
+    let vkey = srs.vkey(num_proofs);
+    let wkey = srs.wkey(num_proofs);
+
+    // Commit to the proofs, and derive the challenge `r` for their random linear combination.
+    let commit_time = start_timer!(|| "Commit to the proofs");
+    let com_ab = commit_double::<E>(&vkey, &wkey, &a, &b);
+    let com_c = commit_single::<E>(&vkey, &c);
+    end_timer!(commit_time);
+
+    let mut transcript = Transcript::new(AGGREGATION_DOMAIN);
+    append_statement::<E>(&mut transcript, num_proofs, public_inputs, &com_ab, &com_c)?;
+    let r = transcript.challenge::<E::Fr>();
+    let r_inverse = r.inverse().ok_or(SynthesisError::DivisionByZero)?;
+    let r_powers = powers(r, num_proofs);
+    let r_inverse_powers = powers(r_inverse, num_proofs);
+
+    // Scale `B` by the powers of `r`, and its commitment key by the inverse powers, which leaves `com_ab` unchanged.
+    let b_r = E::G2Projective::batch_normalization_into_affine(
+        cfg_iter!(b)
+            .zip(&r_powers)
+            .map(|(b, r)| b.into_projective() * *r)
+            .collect(),
+    );
+    let wkey_r_inverse = wkey.scale(&r_inverse_powers);
+
+    let ip_ab = pairing_product::<E>(&a, &b_r);
+    let agg_c = multi_scalar_mul(&c, &r_powers).into_affine();
+    transcript.append(&ip_ab)?;
+    transcript.append(&agg_c)?;
+
+    // Prove that `ip_ab` and `agg_c` are computed from the committed proofs, by halving all vectors in each round.
+    let gipa_time = start_timer!(|| "Prove the inner product arguments");
+    let (mut m_a, mut m_b, mut m_c, mut m_r) = (a, b_r, c, r_powers.clone());
+    let (mut vkey, mut wkey) = (vkey, wkey_r_inverse);
+
+    let num_rounds = num_proofs.trailing_zeros() as usize;
+    let mut comms_ab = Vec::with_capacity(num_rounds);
+    let mut comms_c = Vec::with_capacity(num_rounds);
+    let mut z_ab = Vec::with_capacity(num_rounds);
+    let mut z_c = Vec::with_capacity(num_rounds);
+    let mut challenges = Vec::with_capacity(num_rounds);
+
+    while m_a.len() > 1 {
+        let split = m_a.len() / 2;
+        let (a_left, a_right) = m_a.split_at(split);
+        let (b_left, b_right) = m_b.split_at(split);
+        let (c_left, c_right) = m_c.split_at(split);
+        let (r_left, r_right) = m_r.split_at(split);
+        let (vkey_left, vkey_right) = vkey.split(split);
+        let (wkey_left, wkey_right) = wkey.split(split);
+
+        // The left cross terms are scaled by the challenge when folding, and the right ones by its inverse.
+        let com_ab_left = commit_double::<E>(&vkey_left, &wkey_right, a_right, b_left);
+        let com_ab_right = commit_double::<E>(&vkey_right, &wkey_left, a_left, b_right);
+        let com_c_left = commit_single::<E>(&vkey_left, c_right);
+        let com_c_right = commit_single::<E>(&vkey_right, c_left);
+        let z_ab_left = pairing_product::<E>(a_right, b_left);
+        let z_ab_right = pairing_product::<E>(a_left, b_right);
+        let z_c_left = multi_scalar_mul(c_right, r_left).into_affine();
+        let z_c_right = multi_scalar_mul(c_left, r_right).into_affine();
+
+        transcript.append(&(com_ab_left, com_ab_right))?;
+        transcript.append(&(com_c_left, com_c_right))?;
+        transcript.append(&(z_ab_left, z_ab_right))?;
+        transcript.append(&(z_c_left, z_c_right))?;
+        let challenge = transcript.challenge::<E::Fr>();
+        let challenge_inverse = challenge.inverse().ok_or(SynthesisError::DivisionByZero)?;
+
+        m_a = fold_vector(a_left, a_right, challenge);
+        m_b = fold_vector(b_left, b_right, challenge_inverse);
+        m_c = fold_vector(c_left, c_right, challenge);
+        m_r = cfg_iter!(r_left)
+            .zip(r_right)
+            .map(|(left, right)| *left + *right * challenge_inverse)
+            .collect();
+        vkey = vkey.fold(challenge_inverse);
+        wkey = wkey.fold(challenge);
+
+        comms_ab.push((com_ab_left, com_ab_right));
+        comms_c.push((com_c_left, com_c_right));
+        z_ab.push((z_ab_left, z_ab_right));
+        z_c.push((z_c_left, z_c_right));
+        challenges.push(challenge);
+    }
+
+    let gipa = GipaProof {
+        comms_ab,
+        comms_c,
+        z_ab,
+        z_c,
+        final_a: m_a[0],
+        final_b: m_b[0],
+        final_c: m_c[0],
+        final_vkey: vkey.first(),
+        final_wkey: wkey.first(),
+    };
+    end_timer!(gipa_time);
+
+    // Open the final commitment keys at a random point, to prove that they are folded from the SRS.
+    let opening_time = start_timer!(|| "Open the final commitment keys");
+    gipa.append_final_values(&mut transcript)?;
+    let point = transcript.challenge::<E::Fr>();
+
+    // The final `v` key is `h^{f_v(a)}`, with `f_v(X) = prod_j (1 + x_j^{-1} * X^{2^{k - 1 - j}})`.
+    let mut challenges_inverse = challenges.clone();
+    batch_inversion(&mut challenges_inverse);
+    let vkey_coefficients = kzg::folding_polynomial_coefficients(&challenges_inverse);
+    let vkey_opening = (
+        kzg::open(&srs.h_alpha_powers, &vkey_coefficients, point),
+        kzg::open(&srs.h_beta_powers, &vkey_coefficients, point),
+    );
+
+    // The final `w` key is `g^{a^n * f_w(a)}`, with `f_w(X) = prod_j (1 + x_j * (X / r)^{2^{k - 1 - j}})`.
+    let wkey_challenges = kzg::wkey_folding_challenges(&challenges, r_inverse, num_proofs);
+    let mut wkey_coefficients = vec![E::Fr::zero(); num_proofs];
+    wkey_coefficients.extend(kzg::folding_polynomial_coefficients(&wkey_challenges));
+    let wkey_opening = (
+        kzg::open(&srs.g_alpha_powers, &wkey_coefficients, point),
+        kzg::open(&srs.g_beta_powers, &wkey_coefficients, point),
+    );
+    end_timer!(opening_time);
+
+    end_timer!(aggregation_time);
+
+    Ok(AggregateProof {
+        num_proofs: num_proofs as u32,
+        com_ab,
+        com_c,
+        ip_ab,
+        agg_c,
+        gipa,
+        vkey_opening,
+        wkey_opening,
+    })
+}
@@ -0,0 +1,219 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{powers, CommitmentKey};
+use crate::msm::FixedBaseMSM;
+use snarkvm_curves::traits::{PairingEngine, ProjectiveCurve};
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::SynthesisError;
+use snarkvm_utilities::{error, rand::UniformRand, FromBytes, ToBytes};
+
+use anyhow::anyhow;
+use rand::Rng;
+use std::io::{Read, Result as IoResult, Write};
+
+/// The structured reference string for aggregating Groth16 proofs, made of the powers of two
+/// secrets `a` and `b` in both source groups. It supports aggregating up to `n` proofs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AggregationSRS<E: PairingEngine> {
+    /// `g^{a^i}`, for `i` in `0..2n`.
+    pub g_alpha_powers: Vec<E::G1Affine>,
+    /// `g^{b^i}`, for `i` in `0..2n`.
+    pub g_beta_powers: Vec<E::G1Affine>,
+    /// `h^{a^i}`, for `i` in `0..n`.
+    pub h_alpha_powers: Vec<E::G2Affine>,
+    /// `h^{b^i}`, for `i` in `0..n`.
+    pub h_beta_powers: Vec<E::G2Affine>,
+}
+
+/// The elements of the aggregation SRS needed to verify an aggregate proof.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AggregationVerifierSRS<E: PairingEngine> {
+    pub g: E::G1Affine,
+    pub h: E::G2Affine,
+    pub g_alpha: E::G1Affine,
+    pub g_beta: E::G1Affine,
+    pub h_alpha: E::G2Affine,
+    pub h_beta: E::G2Affine,
+}
+
+impl<E: PairingEngine> AggregationSRS<E> {
+    /// Samples an SRS supporting the aggregation of up to `max_num_proofs` proofs.
+    ///
+    /// The secrets are sampled from `rng` and discarded, so the resulting SRS is only as trustworthy
+    /// as `rng`. Deployments should instead derive the SRS from the transcript of a ceremony.
+    pub fn setup<R: Rng>(max_num_proofs: usize, rng: &mut R) -> Result<Self, SynthesisError> {
+        if max_num_proofs == 0 {
+            return Err(anyhow!("The aggregation SRS must support at least one proof").into());
+        }
+        // The verifier needs `h^a` and `h^b`, so the SRS supports at least two proofs.
+        let n = max_num_proofs.next_power_of_two().max(2);
+
+        let alpha = E::Fr::rand(rng);
+        let beta = E::Fr::rand(rng);
+
+        let alpha_powers = powers(alpha, 2 * n);
+        let beta_powers = powers(beta, 2 * n);
+
+        let scalar_bits = E::Fr::size_in_bits();
+
+        let g1_window = FixedBaseMSM::get_mul_window_size(4 * n);
+        let g1_table = FixedBaseMSM::get_window_table::<E::G1Projective>(
+            scalar_bits,
+            g1_window,
+            E::G1Projective::prime_subgroup_generator(),
+        );
+        let g1_powers = |scalars: &[E::Fr]| {
+            E::G1Projective::batch_normalization_into_affine(FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(
+                scalar_bits,
+                g1_window,
+                &g1_table,
+                scalars,
+            ))
+        };
+
+        let g2_window = FixedBaseMSM::get_mul_window_size(2 * n);
+        let g2_table = FixedBaseMSM::get_window_table::<E::G2Projective>(
+            scalar_bits,
+            g2_window,
+            E::G2Projective::prime_subgroup_generator(),
+        );
+        let g2_powers = |scalars: &[E::Fr]| {
+            E::G2Projective::batch_normalization_into_affine(FixedBaseMSM::multi_scalar_mul::<E::G2Projective>(
+                scalar_bits,
+                g2_window,
+                &g2_table,
+                scalars,
+            ))
+        };
+
+        Ok(Self {
+            g_alpha_powers: g1_powers(&alpha_powers),
+            g_beta_powers: g1_powers(&beta_powers),
+            h_alpha_powers: g2_powers(&alpha_powers[..n]),
+            h_beta_powers: g2_powers(&beta_powers[..n]),
+        })
+    }
+
+    /// Returns the maximum number of proofs that can be aggregated with `self`.
+    pub fn max_num_proofs(&self) -> usize {
+        self.h_alpha_powers.len()
+    }
+
+    /// Returns the elements of `self` needed to verify aggregate proofs.
+    pub fn verifier_srs(&self) -> AggregationVerifierSRS<E> {
+        AggregationVerifierSRS {
+            g: self.g_alpha_powers[0],
+            h: self.h_alpha_powers[0],
+            g_alpha: self.g_alpha_powers[1],
+            g_beta: self.g_beta_powers[1],
+            h_alpha: self.h_alpha_powers[1],
+            h_beta: self.h_beta_powers[1],
+        }
+    }
+
+    /// Returns the commitment key `(h^{a^i}, h^{b^i})` for `i` in `0..num_proofs`, to commit to vectors in G1.
+    pub(crate) fn vkey(&self, num_proofs: usize) -> CommitmentKey<E::G2Affine> {
+        CommitmentKey {
+            a: self.h_alpha_powers[..num_proofs].to_vec(),
+            b: self.h_beta_powers[..num_proofs].to_vec(),
+        }
+    }
+
+    /// Returns the commitment key `(g^{a^{n + i}}, g^{b^{n + i}})` for `i` in `0..n`, with `n = num_proofs`,
+    /// to commit to vectors in G2.
+    pub(crate) fn wkey(&self, num_proofs: usize) -> CommitmentKey<E::G1Affine> {
+        CommitmentKey {
+            a: self.g_alpha_powers[num_proofs..2 * num_proofs].to_vec(),
+            b: self.g_beta_powers[num_proofs..2 * num_proofs].to_vec(),
+        }
+    }
+}
+
+impl<E: PairingEngine> ToBytes for AggregationSRS<E> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        (self.h_alpha_powers.len() as u32).write_le(&mut writer)?;
+        for power in self.g_alpha_powers.iter().chain(&self.g_beta_powers) {
+            power.write_le(&mut writer)?;
+        }
+        for power in self.h_alpha_powers.iter().chain(&self.h_beta_powers) {
+            power.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: PairingEngine> FromBytes for AggregationSRS<E> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let n: u32 = FromBytes::read_le(&mut reader)?;
+        if n < 2 || !n.is_power_of_two() || n > u32::MAX / 2 {
+            return Err(error("Invalid aggregation SRS size"));
+        }
+
+        // The powers are not preallocated, as `n` is not trusted until they are read.
+        let mut g_alpha_powers = Vec::new();
+        for _ in 0..2 * n {
+            g_alpha_powers.push(FromBytes::read_le(&mut reader)?);
+        }
+        let mut g_beta_powers = Vec::new();
+        for _ in 0..2 * n {
+            g_beta_powers.push(FromBytes::read_le(&mut reader)?);
+        }
+        let mut h_alpha_powers = Vec::new();
+        for _ in 0..n {
+            h_alpha_powers.push(FromBytes::read_le(&mut reader)?);
+        }
+        let mut h_beta_powers = Vec::new();
+        for _ in 0..n {
+            h_beta_powers.push(FromBytes::read_le(&mut reader)?);
+        }
+
+        Ok(Self {
+            g_alpha_powers,
+            g_beta_powers,
+            h_alpha_powers,
+            h_beta_powers,
+        })
+    }
+}
+
+impl<E: PairingEngine> ToBytes for AggregationVerifierSRS<E> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.g.write_le(&mut writer)?;
+        self.h.write_le(&mut writer)?;
+        self.g_alpha.write_le(&mut writer)?;
+        self.g_beta.write_le(&mut writer)?;
+        self.h_alpha.write_le(&mut writer)?;
+        self.h_beta.write_le(&mut writer)
+    }
+}
+
+impl<E: PairingEngine> FromBytes for AggregationVerifierSRS<E> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self {
+            g: FromBytes::read_le(&mut reader)?,
+            h: FromBytes::read_le(&mut reader)?,
+            g_alpha: FromBytes::read_le(&mut reader)?,
+            g_beta: FromBytes::read_le(&mut reader)?,
+            h_alpha: FromBytes::read_le(&mut reader)?,
+            h_beta: FromBytes::read_le(&mut reader)?,
+        })
+    }
+}
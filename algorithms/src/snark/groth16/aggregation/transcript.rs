@@ -0,0 +1,54 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::SynthesisError;
+use snarkvm_utilities::ToBytes;
+
+/// A Fiat-Shamir transcript over BLAKE2s, from which the challenges of the aggregation are derived.
+pub(crate) struct Transcript {
+    state: blake2s_simd::State,
+}
+
+impl Transcript {
+    /// Initializes a new transcript with the given domain separator.
+    pub(crate) fn new(domain: &[u8]) -> Self {
+        let mut state = blake2s_simd::State::new();
+        state.update(&(domain.len() as u64).to_le_bytes());
+        state.update(domain);
+        Self { state }
+    }
+
+    /// Absorbs the given element into the transcript.
+    pub(crate) fn append<T: ToBytes>(&mut self, element: &T) -> Result<(), SynthesisError> {
+        self.state.update(&element.to_bytes_le()?);
+        Ok(())
+    }
+
+    /// Returns a non-zero challenge derived from the elements absorbed so far,
+    /// and absorbs it into the transcript.
+    pub(crate) fn challenge<F: PrimeField>(&mut self) -> F {
+        loop {
+            let digest = self.state.finalize();
+            self.state.update(digest.as_bytes());
+
+            let challenge = F::from_bytes_le_mod_order(digest.as_bytes());
+            if !challenge.is_zero() {
+                return challenge;
+            }
+        }
+    }
+}
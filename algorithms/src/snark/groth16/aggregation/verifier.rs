@@ -0,0 +1,193 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    append_statement,
+    kzg,
+    multi_scalar_mul,
+    padded_public_inputs,
+    pairing_product,
+    powers,
+    AggregateProof,
+    AggregationVerifierSRS,
+    Transcript,
+    AGGREGATION_DOMAIN,
+};
+use crate::snark::groth16::PreparedVerifyingKey;
+use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{batch_inversion, Field, PrimeField, Zero};
+use snarkvm_profiler::{end_timer, start_timer};
+use snarkvm_r1cs::SynthesisError;
+
+use anyhow::anyhow;
+
+/// Verifies an aggregate of Groth16 proofs under the given verifying key, for the given public inputs.
+pub fn verify_aggregate_proof<E: PairingEngine>(
+    srs: &AggregationVerifierSRS<E>,
+    pvk: &PreparedVerifyingKey<E>,
+    public_inputs: &[Vec<E::Fr>],
+    proof: &AggregateProof<E>,
+) -> Result<bool, SynthesisError> {
+    let verification_time = start_timer!(|| format!("Verify aggregate of {} proofs", public_inputs.len()));
+
+    if public_inputs.is_empty() {
+        return Err(anyhow!("Cannot verify an aggregate of an empty set of proofs").into());
+    }
+    for inputs in public_inputs {
+        if (inputs.len() + 1) != pvk.gamma_abc_g1().len() {
+            return Err(SynthesisError::MalformedVerifyingKey(
+                inputs.len() + 1,
+                pvk.gamma_abc_g1().len(),
+            ));
+        }
+    }
+
+    let num_proofs = public_inputs.len().next_power_of_two();
+    let num_rounds = num_proofs.trailing_zeros() as usize;
+    let gipa = &proof.gipa;
+    if proof.num_proofs as usize != num_proofs
+        || gipa.comms_ab.len() != num_rounds
+        || gipa.comms_c.len() != num_rounds
+        || gipa.z_ab.len() != num_rounds
+        || gipa.z_c.len() != num_rounds
+    {
+        return Ok(false);
+    }
+
+    let mut transcript = Transcript::new(AGGREGATION_DOMAIN);
+    append_statement::<E>(&mut transcript, num_proofs, public_inputs, &proof.com_ab, &proof.com_c)?;
+    let r = transcript.challenge::<E::Fr>();
+    let r_inverse = r.inverse().ok_or(SynthesisError::DivisionByZero)?;
+    transcript.append(&proof.ip_ab)?;
+    transcript.append(&proof.agg_c)?;
+
+    // Check the linear combination of the Groth16 verification equations with the powers of `r`, which is
+    // `ip_ab = e(alpha, beta)^{sum r^i} * e(sum r^i * S_i, gamma) * e(agg_c, delta)`,
+    // where `S_i` is the linear combination of `gamma_abc_g1` with the public inputs of the `i`-th proof.
+    let groth16_time = start_timer!(|| "Check the Groth16 verification equations");
+    let r_powers = powers(r, num_proofs);
+    let mut scalars = vec![E::Fr::zero(); pvk.gamma_abc_g1().len()];
+    for (inputs, r_power) in padded_public_inputs(public_inputs, num_proofs).zip(&r_powers) {
+        scalars[0] += r_power;
+        for (scalar, input) in scalars[1..].iter_mut().zip(inputs) {
+            *scalar += *input * r_power;
+        }
+    }
+    let g_ic = multi_scalar_mul(pvk.gamma_abc_g1(), &scalars).into_affine();
+
+    let qap = E::miller_loop(
+        [
+            (&g_ic.prepare(), &pvk.gamma_g2_neg_pc),
+            (&proof.agg_c.prepare(), &pvk.delta_g2_neg_pc),
+        ]
+        .iter()
+        .copied(),
+    );
+    let test = E::final_exponentiation(&qap).ok_or(SynthesisError::UnexpectedIdentity)?;
+    let groth16_valid = proof.ip_ab * test == pvk.alpha_g1_beta_g2.pow(scalars[0].to_repr());
+    end_timer!(groth16_time);
+
+    // Replay the inner product arguments, folding the commitments and products with each challenge.
+    let gipa_time = start_timer!(|| "Verify the inner product arguments");
+    let (mut com_ab, mut com_c) = (proof.com_ab, proof.com_c);
+    let mut z_ab = proof.ip_ab;
+    let mut z_c = proof.agg_c.into_projective();
+    let mut challenges = Vec::with_capacity(num_rounds);
+
+    for (((comms_ab, comms_c), z_ab_lr), z_c_lr) in
+        gipa.comms_ab.iter().zip(&gipa.comms_c).zip(&gipa.z_ab).zip(&gipa.z_c)
+    {
+        transcript.append(comms_ab)?;
+        transcript.append(comms_c)?;
+        transcript.append(z_ab_lr)?;
+        transcript.append(z_c_lr)?;
+        let challenge = transcript.challenge::<E::Fr>();
+        let challenge_inverse = challenge.inverse().ok_or(SynthesisError::DivisionByZero)?;
+        let (x, x_inverse) = (challenge.to_repr(), challenge_inverse.to_repr());
+
+        let ((com_ab_left, com_ab_right), (com_c_left, com_c_right)) = (comms_ab, comms_c);
+        com_ab.0 *= com_ab_left.0.pow(x) * com_ab_right.0.pow(x_inverse);
+        com_ab.1 *= com_ab_left.1.pow(x) * com_ab_right.1.pow(x_inverse);
+        com_c.0 *= com_c_left.0.pow(x) * com_c_right.0.pow(x_inverse);
+        com_c.1 *= com_c_left.1.pow(x) * com_c_right.1.pow(x_inverse);
+        z_ab *= z_ab_lr.0.pow(x) * z_ab_lr.1.pow(x_inverse);
+        z_c += z_c_lr.0.into_projective() * challenge + z_c_lr.1.into_projective() * challenge_inverse;
+
+        challenges.push(challenge);
+    }
+
+    // The final elements must open the folded commitments and products, where the final `r` is `f_v(r)`.
+    let mut challenges_inverse = challenges.clone();
+    batch_inversion(&mut challenges_inverse);
+    let final_r = kzg::evaluate_folding_polynomial(&challenges_inverse, r);
+
+    let (final_vkey, final_wkey) = (gipa.final_vkey, gipa.final_wkey);
+    let gipa_valid = com_ab.0 == pairing_product::<E>(&[gipa.final_a, final_wkey.0], &[final_vkey.0, gipa.final_b])
+        && com_ab.1 == pairing_product::<E>(&[gipa.final_a, final_wkey.1], &[final_vkey.1, gipa.final_b])
+        && com_c.0 == E::pairing(gipa.final_c, final_vkey.0)
+        && com_c.1 == E::pairing(gipa.final_c, final_vkey.1)
+        && z_ab == E::pairing(gipa.final_a, gipa.final_b)
+        && z_c.into_affine() == (gipa.final_c.into_projective() * final_r).into_affine();
+    end_timer!(gipa_time);
+
+    // Check that the final commitment keys are folded from the SRS, by opening them at a random point.
+    let opening_time = start_timer!(|| "Verify the openings of the final commitment keys");
+    gipa.append_final_values(&mut transcript)?;
+    let point = transcript.challenge::<E::Fr>();
+
+    let vkey_evaluation = kzg::evaluate_folding_polynomial(&challenges_inverse, point);
+    let wkey_challenges = kzg::wkey_folding_challenges(&challenges, r_inverse, num_proofs);
+    let wkey_evaluation = point.pow([num_proofs as u64]) * kzg::evaluate_folding_polynomial(&wkey_challenges, point);
+
+    let openings_valid = kzg::verify_g2_opening::<E>(
+        srs.g,
+        srs.g_alpha,
+        srs.h,
+        final_vkey.0,
+        point,
+        vkey_evaluation,
+        proof.vkey_opening.0,
+    ) && kzg::verify_g2_opening::<E>(
+        srs.g,
+        srs.g_beta,
+        srs.h,
+        final_vkey.1,
+        point,
+        vkey_evaluation,
+        proof.vkey_opening.1,
+    ) && kzg::verify_g1_opening::<E>(
+        srs.g,
+        srs.h,
+        srs.h_alpha,
+        final_wkey.0,
+        point,
+        wkey_evaluation,
+        proof.wkey_opening.0,
+    ) && kzg::verify_g1_opening::<E>(
+        srs.g,
+        srs.h,
+        srs.h_beta,
+        final_wkey.1,
+        point,
+        wkey_evaluation,
+        proof.wkey_opening.1,
+    );
+    end_timer!(opening_time);
+
+    end_timer!(verification_time);
+
+    Ok(groth16_valid && gipa_valid && openings_valid)
+}
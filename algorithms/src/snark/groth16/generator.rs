@@ -171,7 +171,8 @@ where
     let domain_time = start_timer!(|| "Constructing evaluation domain");
 
     let domain_size = assembly.num_constraints() + (assembly.num_public_variables - 1) + 1;
-    let domain = EvaluationDomain::<E::Fr>::new(domain_size).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+    let domain =
+        EvaluationDomain::<E::Fr>::new_mixed_radix(domain_size).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
     let t = domain.sample_element_outside_domain(rng);
 
     end_timer!(domain_time);
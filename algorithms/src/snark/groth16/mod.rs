@@ -44,6 +44,9 @@ use std::io::{
 /// Reduce an R1CS instance to a *Quadratic Arithmetic Program* instance.
 mod r1cs_to_qap;
 
+/// Aggregate Groth16 proofs under the same verifying key.
+pub mod aggregation;
+
 /// Groth16 zkSNARK construction.
 pub mod snark;
 pub use snark::*;
@@ -57,6 +60,9 @@ mod prover;
 /// Verify proofs for the Groth16 zkSNARK construction.
 mod verifier;
 
+/// Update the parameters of the Groth16 zkSNARK construction in a multi-party ceremony.
+pub mod mpc;
+
 #[cfg(test)]
 mod tests;
 
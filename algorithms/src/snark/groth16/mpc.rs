@@ -0,0 +1,322 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::ProvingKey;
+use crate::msm::VariableBaseMSM;
+use snarkvm_curves::traits::{AffineCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{Field, PrimeField, Zero};
+use snarkvm_r1cs::SynthesisError;
+use snarkvm_utilities::{rand::UniformRand, FromBytes, ToBytes};
+
+use anyhow::anyhow;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaChaRng;
+use std::io::{Read, Result as IoResult, Write};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The BLAKE2s digest of a ceremony transcript or contribution.
+pub type ContributionHash = [u8; 32];
+
+/// The public key of a contribution to the ceremony, which proves knowledge of the
+/// factor `d` that the contribution multiplied `delta` by.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicKey<E: PairingEngine> {
+    /// `delta` in G1 after the contribution.
+    pub delta_after: E::G1Affine,
+    /// A random element `s` of G1.
+    pub s: E::G1Affine,
+    /// `s * d`.
+    pub s_delta: E::G1Affine,
+    /// `r * d`, where `r` in G2 is derived from `s`, `s_delta` and `transcript`.
+    pub r_delta: E::G2Affine,
+    /// The digest of the ceremony transcript before the contribution.
+    pub transcript: ContributionHash,
+}
+
+impl<E: PairingEngine> PublicKey<E> {
+    /// Returns the hash of this contribution, which participants publish to attest to their contribution.
+    pub fn hash(&self) -> Result<ContributionHash, SynthesisError> {
+        Ok(to_contribution_hash(blake2s_simd::blake2s(&self.to_bytes_le()?)))
+    }
+}
+
+impl<E: PairingEngine> ToBytes for PublicKey<E> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.delta_after.write_le(&mut writer)?;
+        self.s.write_le(&mut writer)?;
+        self.s_delta.write_le(&mut writer)?;
+        self.r_delta.write_le(&mut writer)?;
+        self.transcript.write_le(&mut writer)
+    }
+}
+
+impl<E: PairingEngine> FromBytes for PublicKey<E> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self {
+            delta_after: FromBytes::read_le(&mut reader)?,
+            s: FromBytes::read_le(&mut reader)?,
+            s_delta: FromBytes::read_le(&mut reader)?,
+            r_delta: FromBytes::read_le(&mut reader)?,
+            transcript: FromBytes::read_le(&mut reader)?,
+        })
+    }
+}
+
+/// The parameters of a circuit-specific (Phase 2) Groth16 setup ceremony.
+///
+/// Each participant multiplies `delta` by a secret factor, and divides the `h_query` and `l_query`
+/// of the proving key by it, so the final `delta` is unknown unless every participant colludes.
+/// The remaining elements of the proving key are circuit-independent (Phase 1) and are not updated.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MPCParameters<E: PairingEngine> {
+    /// The proving key after the latest contribution.
+    pub params: ProvingKey<E>,
+    /// The digest of the proving key before the first contribution.
+    pub cs_hash: ContributionHash,
+    /// The public keys of the contributions, in order.
+    pub contributions: Vec<PublicKey<E>>,
+}
+
+impl<E: PairingEngine> MPCParameters<E> {
+    /// Initializes a ceremony from the given parameters, which are the parameters before the first contribution.
+    pub fn new(params: ProvingKey<E>) -> Result<Self, SynthesisError> {
+        let cs_hash = to_contribution_hash(blake2s_simd::blake2s(&params.to_bytes_le()?));
+        Ok(Self {
+            params,
+            cs_hash,
+            contributions: vec![],
+        })
+    }
+
+    /// Returns the parameters after the latest contribution.
+    pub fn get_params(&self) -> &ProvingKey<E> {
+        &self.params
+    }
+
+    /// Contributes a new secret factor to `delta`, and returns the hash of the contribution.
+    /// The secret factor is dropped once the parameters are updated.
+    pub fn contribute<R: Rng>(&mut self, rng: &mut R) -> Result<ContributionHash, SynthesisError> {
+        // Sample the secret factor, and prove knowledge of it.
+        let delta = loop {
+            let delta = E::Fr::rand(rng);
+            if !delta.is_zero() {
+                break delta;
+            }
+        };
+        let delta_inverse = delta.inverse().ok_or(SynthesisError::UnexpectedIdentity)?;
+
+        let s = E::G1Projective::rand(rng).into_affine();
+        let s_delta = (s.into_projective() * delta).into_affine();
+        let transcript = self.transcript_digest()?;
+        let r_delta = (hash_to_g2::<E>(&s, &s_delta, &transcript)? * delta).into_affine();
+
+        // Update the parameters.
+        let batch_mul = |bases: &mut [E::G1Affine], scalar: E::Fr| {
+            let mut projective = cfg_iter!(bases)
+                .map(|base| base.into_projective() * scalar)
+                .collect::<Vec<_>>();
+            E::G1Projective::batch_normalization(&mut projective);
+            cfg_iter_mut!(bases)
+                .zip(projective)
+                .for_each(|(base, projective)| *base = projective.into_affine());
+        };
+        batch_mul(&mut self.params.h_query, delta_inverse);
+        batch_mul(&mut self.params.l_query, delta_inverse);
+        self.params.delta_g1 = (self.params.delta_g1.into_projective() * delta).into_affine();
+        self.params.vk.delta_g2 = (self.params.vk.delta_g2.into_projective() * delta).into_affine();
+
+        let public_key = PublicKey {
+            delta_after: self.params.delta_g1,
+            s,
+            s_delta,
+            r_delta,
+            transcript,
+        };
+        let contribution_hash = public_key.hash()?;
+        self.contributions.push(public_key);
+
+        Ok(contribution_hash)
+    }
+
+    /// Verifies the transcript of contributions against the parameters before the first contribution,
+    /// and returns the hashes of the contributions, in order.
+    pub fn verify<R: Rng>(
+        &self,
+        initial_params: &ProvingKey<E>,
+        rng: &mut R,
+    ) -> Result<Vec<ContributionHash>, SynthesisError> {
+        let params = &self.params;
+
+        // The contributions only update `delta`, `h_query` and `l_query`.
+        let cs_hash = to_contribution_hash(blake2s_simd::blake2s(&initial_params.to_bytes_le()?));
+        if cs_hash != self.cs_hash
+            || initial_params.vk.alpha_g1 != params.vk.alpha_g1
+            || initial_params.vk.beta_g2 != params.vk.beta_g2
+            || initial_params.vk.gamma_g2 != params.vk.gamma_g2
+            || initial_params.vk.gamma_abc_g1 != params.vk.gamma_abc_g1
+            || initial_params.beta_g1 != params.beta_g1
+            || initial_params.a_query != params.a_query
+            || initial_params.b_g1_query != params.b_g1_query
+            || initial_params.b_g2_query != params.b_g2_query
+            || initial_params.h_query.len() != params.h_query.len()
+            || initial_params.l_query.len() != params.l_query.len()
+        {
+            return Err(anyhow!("The parameters do not extend the initial parameters").into());
+        }
+
+        // Each contribution must prove knowledge of the factor it multiplied `delta` by.
+        let mut delta_before = initial_params.delta_g1;
+        let mut contribution_hashes = Vec::with_capacity(self.contributions.len());
+        for (i, public_key) in self.contributions.iter().enumerate() {
+            if public_key.transcript != transcript_digest(&self.cs_hash, &self.contributions[..i])? {
+                return Err(anyhow!("Contribution {} has an invalid transcript", i).into());
+            }
+            if public_key.delta_after.is_zero() || public_key.s.is_zero() || public_key.s_delta.is_zero() {
+                return Err(anyhow!("Contribution {} has a zero element", i).into());
+            }
+
+            let r = hash_to_g2::<E>(&public_key.s, &public_key.s_delta, &public_key.transcript)?.into_affine();
+            if !same_ratio::<E>((public_key.s, public_key.s_delta), (r, public_key.r_delta))
+                || !same_ratio::<E>((delta_before, public_key.delta_after), (r, public_key.r_delta))
+            {
+                return Err(anyhow!("Contribution {} has an invalid proof of knowledge", i).into());
+            }
+
+            delta_before = public_key.delta_after;
+            contribution_hashes.push(public_key.hash()?);
+        }
+
+        // The final parameters must be consistent with the final `delta`.
+        if params.delta_g1 != delta_before
+            || !same_ratio::<E>(
+                (initial_params.delta_g1, params.delta_g1),
+                (initial_params.vk.delta_g2, params.vk.delta_g2),
+            )
+        {
+            return Err(anyhow!("The final delta is inconsistent with the contributions").into());
+        }
+        if !same_ratio::<E>(
+            merge_pairs::<E, _>(&initial_params.h_query, &params.h_query, rng),
+            (params.vk.delta_g2, initial_params.vk.delta_g2),
+        ) || !same_ratio::<E>(
+            merge_pairs::<E, _>(&initial_params.l_query, &params.l_query, rng),
+            (params.vk.delta_g2, initial_params.vk.delta_g2),
+        ) {
+            return Err(anyhow!("The final queries are inconsistent with the final delta").into());
+        }
+
+        Ok(contribution_hashes)
+    }
+
+    /// Returns the digest of the ceremony transcript so far.
+    fn transcript_digest(&self) -> Result<ContributionHash, SynthesisError> {
+        transcript_digest(&self.cs_hash, &self.contributions)
+    }
+}
+
+impl<E: PairingEngine> ToBytes for MPCParameters<E> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.params.write_le(&mut writer)?;
+        self.cs_hash.write_le(&mut writer)?;
+        (self.contributions.len() as u32).write_le(&mut writer)?;
+        for public_key in &self.contributions {
+            public_key.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: PairingEngine> FromBytes for MPCParameters<E> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let params = ProvingKey::read(&mut reader, true)?;
+        let cs_hash = FromBytes::read_le(&mut reader)?;
+
+        // The number of contributions is untrusted, so the contributions are not preallocated.
+        let num_contributions: u32 = FromBytes::read_le(&mut reader)?;
+        let mut contributions = Vec::new();
+        for _ in 0..num_contributions {
+            contributions.push(FromBytes::read_le(&mut reader)?);
+        }
+
+        Ok(Self {
+            params,
+            cs_hash,
+            contributions,
+        })
+    }
+}
+
+/// Returns the given BLAKE2s digest as an array.
+fn to_contribution_hash(hash: blake2s_simd::Hash) -> ContributionHash {
+    let mut contribution_hash = ContributionHash::default();
+    contribution_hash.copy_from_slice(hash.as_bytes());
+    contribution_hash
+}
+
+/// Returns the digest of the transcript made of `cs_hash` and the given contributions.
+fn transcript_digest<E: PairingEngine>(
+    cs_hash: &ContributionHash,
+    contributions: &[PublicKey<E>],
+) -> Result<ContributionHash, SynthesisError> {
+    let mut state = blake2s_simd::State::new();
+    state.update(cs_hash);
+    for public_key in contributions {
+        state.update(&public_key.to_bytes_le()?);
+    }
+    Ok(to_contribution_hash(state.finalize()))
+}
+
+/// Derives the element `r` of G2 for a proof of knowledge, with an unknown discrete logarithm.
+fn hash_to_g2<E: PairingEngine>(
+    s: &E::G1Affine,
+    s_delta: &E::G1Affine,
+    transcript: &ContributionHash,
+) -> Result<E::G2Projective, SynthesisError> {
+    let mut state = blake2s_simd::State::new();
+    state.update(&s.to_bytes_le()?);
+    state.update(&s_delta.to_bytes_le()?);
+    state.update(transcript);
+    Ok(E::G2Projective::rand(&mut ChaChaRng::from_seed(to_contribution_hash(
+        state.finalize(),
+    ))))
+}
+
+/// Returns `true` if `g1.1 = g1.0 * x` and `g2.1 = g2.0 * x` for the same `x`, by checking
+/// `e(g1.0, g2.1) == e(g1.1, g2.0)`.
+fn same_ratio<E: PairingEngine>(g1: (E::G1Affine, E::G1Affine), g2: (E::G2Affine, E::G2Affine)) -> bool {
+    E::pairing(g1.0, g2.1) == E::pairing(g1.1, g2.0)
+}
+
+/// Returns a random linear combination of `left` and the same combination of `right`, which have
+/// the same ratio with overwhelming probability only if each pair of elements has that ratio.
+fn merge_pairs<E: PairingEngine, R: Rng>(
+    left: &[E::G1Affine],
+    right: &[E::G1Affine],
+    rng: &mut R,
+) -> (E::G1Affine, E::G1Affine) {
+    assert_eq!(left.len(), right.len());
+    let scalars = (0..left.len()).map(|_| E::Fr::rand(rng).to_repr()).collect::<Vec<_>>();
+    (
+        VariableBaseMSM::multi_scalar_mul(left, &scalars).into_affine(),
+        VariableBaseMSM::multi_scalar_mul(right, &scalars).into_affine(),
+    )
+}
@@ -14,10 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use super::{push_constraints, r1cs_to_qap::R1CStoQAP, Proof, ProvingKey};
-use crate::{cfg_into_iter, msm::VariableBaseMSM};
+use super::{push_constraints, r1cs_to_qap::R1CStoQAP, Proof, ProvingKey, VerifyingKey};
+use crate::msm::{PrecomputedBases, VariableBaseMSM};
 use snarkvm_curves::traits::{AffineCurve, PairingEngine, ProjectiveCurve};
-use snarkvm_fields::{One, PrimeField, Zero};
+use snarkvm_fields::{Field, One, PrimeField, Zero};
 use snarkvm_r1cs::errors::SynthesisError;
 
 use snarkvm_profiler::{end_timer, start_timer};
@@ -30,6 +30,44 @@ use rand::Rng;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+/// The precomputed multiples of the bases of a proving key, which may be reused across proofs
+/// for the same proving key, in exchange for the memory of the precomputed tables.
+#[derive(Clone, Debug)]
+pub struct PrecomputedProvingKey<E: PairingEngine> {
+    a_query: PrecomputedBases<E::G1Affine>,
+    b_g1_query: PrecomputedBases<E::G1Affine>,
+    b_g2_query: PrecomputedBases<E::G2Affine>,
+    h_query: PrecomputedBases<E::G1Affine>,
+    l_query: PrecomputedBases<E::G1Affine>,
+}
+
+impl<E: PairingEngine> PrecomputedProvingKey<E> {
+    /// Precomputes the multiples of the bases of the given proving key.
+    pub fn new(params: &ProvingKey<E>) -> Self {
+        // The first element of the `a` and `b` queries is added directly, and is not part of the multi-scalar multiplication.
+        fn skip_first<G>(query: &[G]) -> &[G] {
+            query.get(1..).unwrap_or(&[])
+        }
+
+        Self {
+            a_query: PrecomputedBases::new(skip_first(&params.a_query)),
+            b_g1_query: PrecomputedBases::new(skip_first(&params.b_g1_query)),
+            b_g2_query: PrecomputedBases::new(skip_first(&params.b_g2_query)),
+            h_query: PrecomputedBases::new(&params.h_query),
+            l_query: PrecomputedBases::new(&params.l_query),
+        }
+    }
+
+    /// Returns `true` if the precomputed bases have the sizes of the queries of the given proving key.
+    fn is_for(&self, params: &ProvingKey<E>) -> bool {
+        self.a_query.len() == params.a_query.len().saturating_sub(1)
+            && self.b_g1_query.len() == params.b_g1_query.len().saturating_sub(1)
+            && self.b_g2_query.len() == params.b_g2_query.len().saturating_sub(1)
+            && self.h_query.len() == params.h_query.len()
+            && self.l_query.len() == params.l_query.len()
+    }
+}
+
 pub struct ProvingAssignment<E: PairingEngine> {
     // Constraints
     pub(crate) at: Vec<Vec<(E::Fr, Index)>>,
@@ -131,6 +169,24 @@ where
     create_proof::<E, C>(circuit, params, r, s)
 }
 
+/// Creates a proof as `create_random_proof`, using the precomputed bases of the proving key.
+pub fn create_random_proof_with_precomputed_bases<E, C, R>(
+    circuit: &C,
+    params: &ProvingKey<E>,
+    precomputed: &PrecomputedProvingKey<E>,
+    rng: &mut R,
+) -> Result<Proof<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+    R: Rng,
+{
+    let r = E::Fr::rand(rng);
+    let s = E::Fr::rand(rng);
+
+    create_proof_with_precomputed_bases::<E, C>(circuit, params, precomputed, r, s)
+}
+
 pub fn create_proof_no_zk<E, C>(circuit: &C, params: &ProvingKey<E>) -> Result<Proof<E>, SynthesisError>
 where
     E: PairingEngine,
@@ -139,7 +195,72 @@ where
     create_proof::<E, C>(circuit, params, E::Fr::zero(), E::Fr::zero())
 }
 
+/// Returns a fresh proof of the same statement as the given proof, which cannot be linked to it.
+///
+/// For uniformly random non-zero `r_1` and `r_2`, the rerandomized proof is `A' = A / r_1`,
+/// `B' = r_1 * B + r_1 * r_2 * delta_g2`, and `C' = C + r_2 * A`, following figure 1 of
+/// [`BKSV20`](https://eprint.iacr.org/2020/811.pdf).
+pub fn rerandomize_proof<E, R>(vk: &VerifyingKey<E>, proof: &Proof<E>, rng: &mut R) -> Proof<E>
+where
+    E: PairingEngine,
+    R: Rng,
+{
+    let sample_non_zero = |rng: &mut R| loop {
+        let scalar = E::Fr::rand(rng);
+        if !scalar.is_zero() {
+            return scalar;
+        }
+    };
+    let r_1 = sample_non_zero(rng);
+    let r_2 = sample_non_zero(rng);
+
+    let a = proof.a.into_projective().mul(r_1.inverse().unwrap());
+    let b = proof.b.into_projective().mul(r_1) + vk.delta_g2.into_projective().mul(r_1 * r_2);
+    let c = proof.a.into_projective().mul(r_2) + proof.c.into_projective();
+
+    Proof {
+        a: a.into_affine(),
+        b: b.into_affine(),
+        c: c.into_affine(),
+        compressed: proof.compressed,
+    }
+}
+
 pub fn create_proof<E, C>(circuit: &C, params: &ProvingKey<E>, r: E::Fr, s: E::Fr) -> Result<Proof<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+{
+    create_proof_internal(circuit, params, None, r, s)
+}
+
+/// Creates a proof as `create_proof`, using the precomputed bases of the proving key.
+pub fn create_proof_with_precomputed_bases<E, C>(
+    circuit: &C,
+    params: &ProvingKey<E>,
+    precomputed: &PrecomputedProvingKey<E>,
+    r: E::Fr,
+    s: E::Fr,
+) -> Result<Proof<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+{
+    if !precomputed.is_for(params) {
+        return Err(SynthesisError::AnyhowError(anyhow::anyhow!(
+            "The precomputed bases do not match the proving key"
+        )));
+    }
+    create_proof_internal(circuit, params, Some(precomputed), r, s)
+}
+
+fn create_proof_internal<E, C>(
+    circuit: &C,
+    params: &ProvingKey<E>,
+    precomputed: Option<&PrecomputedProvingKey<E>>,
+    r: E::Fr,
+    s: E::Fr,
+) -> Result<Proof<E>, SynthesisError>
 where
     E: PairingEngine,
     C: ConstraintSynthesizer<E::Fr>,
@@ -162,7 +283,8 @@ where
     end_timer!(synthesis_time);
 
     let witness_map_time = start_timer!(|| "R1CS to QAP witness map");
-    let h = R1CStoQAP::witness_map::<E>(&prover)?;
+    // The proving key holds one element of `h_query` for each power of `x` below `domain_size - 1`.
+    let h = R1CStoQAP::witness_map::<E>(&prover, params.h_query.len() + 1)?;
     end_timer!(witness_map_time);
 
     let input_assignment = prover
@@ -185,7 +307,13 @@ where
     let a_query = &params.a_query;
     let r_g1 = params.delta_g1.mul(r);
 
-    let g_a = calculate_coeff(r_g1.into(), a_query, params.vk.alpha_g1, &assignment);
+    let g_a = calculate_coeff(
+        r_g1.into(),
+        a_query,
+        precomputed.map(|precomputed| &precomputed.a_query),
+        params.vk.alpha_g1,
+        &assignment,
+    );
 
     end_timer!(a_acc_time);
 
@@ -195,7 +323,13 @@ where
         let s_g1 = params.delta_g1.mul(s);
         let b_query = &params.b_g1_query;
 
-        let g1_b = calculate_coeff(s_g1.into(), b_query, params.beta_g1, &assignment);
+        let g1_b = calculate_coeff(
+            s_g1.into(),
+            b_query,
+            precomputed.map(|precomputed| &precomputed.b_g1_query),
+            params.beta_g1,
+            &assignment,
+        );
 
         end_timer!(b_g1_acc_time);
 
@@ -208,7 +342,13 @@ where
     let b_g2_acc_time = start_timer!(|| "Compute B in G2");
     let b_query = &params.b_g2_query;
     let s_g2 = params.vk.delta_g2.mul(s);
-    let g2_b = calculate_coeff(s_g2.into(), &b_query, params.vk.beta_g2, &assignment);
+    let g2_b = calculate_coeff(
+        s_g2.into(),
+        &b_query,
+        precomputed.map(|precomputed| &precomputed.b_g2_query),
+        params.vk.beta_g2,
+        &assignment,
+    );
 
     end_timer!(b_g2_acc_time);
 
@@ -216,10 +356,18 @@ where
     let c_acc_time = start_timer!(|| "Compute C");
 
     let h_query = &params.h_query;
-    let h_acc = VariableBaseMSM::multi_scalar_mul(&h_query, &h_assignment);
+    let h_acc = multi_scalar_mul(
+        &h_query,
+        precomputed.map(|precomputed| &precomputed.h_query),
+        &h_assignment,
+    );
 
     let l_aux_source = &params.l_query;
-    let l_aux_acc = VariableBaseMSM::multi_scalar_mul(l_aux_source, &aux_assignment);
+    let l_aux_acc = multi_scalar_mul(
+        l_aux_source,
+        precomputed.map(|precomputed| &precomputed.l_query),
+        &aux_assignment,
+    );
 
     let s_g_a = g_a.mul(s);
     let r_g1_b = g1_b.mul(r);
@@ -245,11 +393,12 @@ where
 fn calculate_coeff<G: AffineCurve>(
     initial: G::Projective,
     query: &[G],
+    precomputed_query: Option<&PrecomputedBases<G>>,
     vk_param: G,
     assignment: &[<G::ScalarField as PrimeField>::BigInteger],
 ) -> G::Projective {
     let el = query[0];
-    let acc = VariableBaseMSM::multi_scalar_mul(&query[1..], assignment);
+    let acc = multi_scalar_mul(&query[1..], precomputed_query, assignment);
 
     let mut res = initial;
     res.add_assign_mixed(&el);
@@ -258,3 +407,16 @@ fn calculate_coeff<G: AffineCurve>(
 
     res
 }
+
+/// Returns the multi-scalar multiplication of the bases with the given scalars,
+/// using the precomputed multiples of the bases if they are given.
+fn multi_scalar_mul<G: AffineCurve>(
+    bases: &[G],
+    precomputed_bases: Option<&PrecomputedBases<G>>,
+    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+) -> G::Projective {
+    match precomputed_bases {
+        Some(precomputed_bases) => precomputed_bases.multi_scalar_mul(scalars),
+        None => VariableBaseMSM::multi_scalar_mul(bases, scalars),
+    }
+}
@@ -50,7 +50,8 @@ impl R1CStoQAP {
         t: &E::Fr,
     ) -> SynthesisResult<(Vec<E::Fr>, Vec<E::Fr>, Vec<E::Fr>, E::Fr, usize, usize)> {
         let domain_size = assembly.num_constraints() + (assembly.num_public_variables - 1) + 1;
-        let domain = EvaluationDomain::<E::Fr>::new(domain_size).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let domain =
+            EvaluationDomain::<E::Fr>::new_mixed_radix(domain_size).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
         let domain_size = domain.size();
 
         let zt = domain.evaluate_vanishing_polynomial(*t);
@@ -100,16 +101,24 @@ impl R1CStoQAP {
         Ok((a, b, c, zt, qap_num_variables, domain_size))
     }
 
+    /// Computes the coefficients of `h(X)` over the evaluation domain of the given size,
+    /// which is the domain that the proving key was generated for.
     #[inline]
-    pub(crate) fn witness_map<E: PairingEngine>(prover: &ProvingAssignment<E>) -> SynthesisResult<Vec<E::Fr>> {
+    pub(crate) fn witness_map<E: PairingEngine>(
+        prover: &ProvingAssignment<E>,
+        domain_size: usize,
+    ) -> SynthesisResult<Vec<E::Fr>> {
         let zero = E::Fr::zero();
         let num_inputs = prover.public_variables.len();
         let num_constraints = prover.num_constraints();
 
         let full_input_assignment = [&prover.public_variables[..], &prover.private_variables[..]].concat();
 
-        let domain = EvaluationDomain::<E::Fr>::new(num_constraints + num_inputs)
-            .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        if domain_size < num_constraints + num_inputs {
+            return Err(SynthesisError::PolynomialDegreeTooLarge);
+        }
+        let domain =
+            EvaluationDomain::<E::Fr>::from_size(domain_size as u64).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
         let domain_size = domain.size();
 
         let mut a = vec![zero; domain_size];
@@ -50,17 +50,30 @@ impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for MySillyCircuit<C
 
 mod bls12_377 {
     use super::*;
-    use crate::snark::groth16::{
-        create_random_proof,
-        generate_random_parameters,
-        prepare_verifying_key,
-        verify_proof,
-        Proof,
+    use crate::{
+        snark::{
+            groth16::{
+                create_proof,
+                create_proof_with_precomputed_bases,
+                create_random_proof,
+                generate_random_parameters,
+                prepare_verifying_key,
+                rerandomize_proof,
+                verify_proof,
+                Groth16,
+                PrecomputedProvingKey,
+                Proof,
+            },
+            PreparedVerifyingKeyCache,
+        },
+        SNARK,
+        SRS,
     };
     use snarkvm_curves::bls12_377::{Bls12_377, Fr};
     use snarkvm_utilities::{str::FromStr, FromBytes, ToBytes, UniformRand};
 
     use rand::thread_rng;
+    use std::sync::Arc;
 
     #[test]
     fn prove_and_verify() {
@@ -80,6 +93,100 @@ mod bls12_377 {
         }
     }
 
+    #[test]
+    fn prove_with_precomputed_bases() {
+        let rng = &mut thread_rng();
+        let parameters =
+            generate_random_parameters::<Bls12_377, _, _>(&MySillyCircuit { a: None, b: None }, rng).unwrap();
+        let pvk = prepare_verifying_key::<Bls12_377>(parameters.vk.clone());
+        let precomputed = PrecomputedProvingKey::new(&parameters);
+
+        for _ in 0..10 {
+            let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+            let (r, s) = (Fr::rand(rng), Fr::rand(rng));
+            let circuit = MySillyCircuit { a: Some(a), b: Some(b) };
+
+            let proof = create_proof_with_precomputed_bases(&circuit, &parameters, &precomputed, r, s).unwrap();
+            assert_eq!(create_proof(&circuit, &parameters, r, s).unwrap(), proof);
+            assert!(verify_proof(&pvk, &proof, &[a * b]).unwrap());
+        }
+
+        // Ensure precomputed bases of a different size are rejected.
+        let mut other_parameters = parameters.clone();
+        other_parameters.h_query.pop();
+        let circuit = MySillyCircuit { a: Some(Fr::rand(rng)), b: Some(Fr::rand(rng)) };
+        let (r, s) = (Fr::rand(rng), Fr::rand(rng));
+        assert!(create_proof_with_precomputed_bases(&circuit, &other_parameters, &precomputed, r, s).is_err());
+    }
+
+    #[test]
+    fn rerandomize_and_verify() {
+        let rng = &mut thread_rng();
+        let parameters =
+            generate_random_parameters::<Bls12_377, _, _>(&MySillyCircuit { a: None, b: None }, rng).unwrap();
+        let pvk = prepare_verifying_key::<Bls12_377>(parameters.vk.clone());
+
+        let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+        let c = a * b;
+
+        let proof = create_random_proof(&MySillyCircuit { a: Some(a), b: Some(b) }, &parameters, rng).unwrap();
+        assert!(verify_proof(&pvk, &proof, &[c]).unwrap());
+
+        let mut rerandomized_proof = proof.clone();
+        for _ in 0..10 {
+            rerandomized_proof = rerandomize_proof(&parameters.vk, &rerandomized_proof, rng);
+            assert_ne!(proof, rerandomized_proof);
+            assert_ne!(proof.a, rerandomized_proof.a);
+            assert_ne!(proof.b, rerandomized_proof.b);
+            assert_ne!(proof.c, rerandomized_proof.c);
+
+            assert!(verify_proof(&pvk, &rerandomized_proof, &[c]).unwrap());
+            assert!(!verify_proof(&pvk, &rerandomized_proof, &[a]).unwrap());
+        }
+    }
+
+    #[test]
+    fn prepared_verifying_key_cache() {
+        type TestSNARK = Groth16<Bls12_377, Vec<Fr>>;
+
+        let rng = &mut thread_rng();
+        let cache = PreparedVerifyingKeyCache::<TestSNARK>::new(2);
+        assert!(cache.is_empty().unwrap());
+
+        let mut keys = vec![];
+        for _ in 0..3 {
+            let circuit = MySillyCircuit { a: None, b: None };
+            keys.push(TestSNARK::setup(&circuit, &mut SRS::CircuitSpecific(rng)).unwrap());
+        }
+
+        for (proving_key, verifying_key) in &keys {
+            let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+            let proof = TestSNARK::prove(proving_key, &MySillyCircuit { a: Some(a), b: Some(b) }, rng).unwrap();
+
+            assert!(!cache.contains(verifying_key).unwrap());
+            assert!(cache.verify(verifying_key, &vec![a * b], &proof).unwrap());
+            assert!(cache.contains(verifying_key).unwrap());
+            assert!(!cache.verify(verifying_key, &vec![a], &proof).unwrap());
+
+            // The cached prepared verifying key is reused, and works with `verify_prepared`.
+            let prepared_verifying_key = cache.get_or_prepare(verifying_key).unwrap();
+            assert!(Arc::ptr_eq(
+                &prepared_verifying_key,
+                &cache.get_or_prepare(verifying_key).unwrap()
+            ));
+            assert!(TestSNARK::verify_prepared(&prepared_verifying_key, &vec![a * b], &proof).unwrap());
+        }
+
+        // The cache evicts the oldest prepared verifying key once it is full.
+        assert_eq!(2, cache.len().unwrap());
+        assert!(!cache.contains(&keys[0].1).unwrap());
+        assert!(cache.contains(&keys[1].1).unwrap());
+        assert!(cache.contains(&keys[2].1).unwrap());
+
+        cache.clear().unwrap();
+        assert!(cache.is_empty().unwrap());
+    }
+
     #[test]
     fn test_serde_json() {
         let expected_proof = {
@@ -303,3 +410,222 @@ mod serialization {
         assert_eq!(recovered_proof.compressed, false);
     }
 }
+
+mod aggregation {
+    use super::*;
+    use crate::snark::groth16::{
+        aggregation::{aggregate_proofs, verify_aggregate_proof, AggregateProof, AggregationSRS, GipaProof},
+        create_random_proof,
+        generate_random_parameters,
+        prepare_verifying_key,
+        Proof,
+    };
+    use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+    use snarkvm_utilities::{rand::UniformRand, str::FromStr, FromBytes, ToBytes};
+
+    use rand::{thread_rng, Rng};
+
+    fn create_proofs<R: Rng>(
+        num_proofs: usize,
+        parameters: &crate::snark::groth16::ProvingKey<Bls12_377>,
+        rng: &mut R,
+    ) -> (Vec<Proof<Bls12_377>>, Vec<Vec<Fr>>) {
+        (0..num_proofs)
+            .map(|_| {
+                let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+                let proof = create_random_proof(&MySillyCircuit { a: Some(a), b: Some(b) }, parameters, rng).unwrap();
+                (proof, vec![a * b])
+            })
+            .unzip()
+    }
+
+    #[test]
+    fn aggregate_and_verify() {
+        let rng = &mut thread_rng();
+        let parameters =
+            generate_random_parameters::<Bls12_377, _, _>(&MySillyCircuit { a: None, b: None }, rng).unwrap();
+        let pvk = prepare_verifying_key::<Bls12_377>(parameters.vk.clone());
+
+        let srs = AggregationSRS::<Bls12_377>::setup(8, rng).unwrap();
+        let verifier_srs = srs.verifier_srs();
+
+        for &num_proofs in &[1, 2, 5, 8] {
+            let (proofs, public_inputs) = create_proofs(num_proofs, &parameters, rng);
+
+            let aggregate_proof = aggregate_proofs(&srs, &proofs, &public_inputs).unwrap();
+            assert!(verify_aggregate_proof(&verifier_srs, &pvk, &public_inputs, &aggregate_proof).unwrap());
+
+            // The aggregate proof does not verify for other public inputs.
+            let mut wrong_public_inputs = public_inputs.clone();
+            wrong_public_inputs[num_proofs - 1] = vec![Fr::rand(rng)];
+            assert!(!verify_aggregate_proof(&verifier_srs, &pvk, &wrong_public_inputs, &aggregate_proof).unwrap());
+
+            // The aggregate proof does not verify for a subset of the public inputs.
+            if num_proofs > 1 {
+                let subset = &public_inputs[..num_proofs - 1];
+                assert!(!verify_aggregate_proof(&verifier_srs, &pvk, subset, &aggregate_proof).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn aggregate_invalid_proofs() {
+        let rng = &mut thread_rng();
+        let parameters =
+            generate_random_parameters::<Bls12_377, _, _>(&MySillyCircuit { a: None, b: None }, rng).unwrap();
+        let pvk = prepare_verifying_key::<Bls12_377>(parameters.vk.clone());
+
+        let srs = AggregationSRS::<Bls12_377>::setup(4, rng).unwrap();
+        let verifier_srs = srs.verifier_srs();
+
+        // Swapping two proofs makes each of them invalid for its public inputs.
+        let (mut proofs, public_inputs) = create_proofs(4, &parameters, rng);
+        proofs.swap(1, 2);
+        let aggregate_proof = aggregate_proofs(&srs, &proofs, &public_inputs).unwrap();
+        assert!(!verify_aggregate_proof(&verifier_srs, &pvk, &public_inputs, &aggregate_proof).unwrap());
+
+        // The SRS does not support more proofs than it was sampled for.
+        let (proofs, public_inputs) = create_proofs(5, &parameters, rng);
+        assert!(aggregate_proofs(&srs, &proofs, &public_inputs).is_err());
+        assert!(aggregate_proofs(&srs, &proofs[..4], &public_inputs).is_err());
+        assert!(aggregate_proofs::<Bls12_377>(&srs, &[], &[]).is_err());
+    }
+
+    #[test]
+    fn test_aggregate_proof_serialization() {
+        let rng = &mut thread_rng();
+        let parameters =
+            generate_random_parameters::<Bls12_377, _, _>(&MySillyCircuit { a: None, b: None }, rng).unwrap();
+        let pvk = prepare_verifying_key::<Bls12_377>(parameters.vk.clone());
+
+        let srs = AggregationSRS::<Bls12_377>::setup(4, rng).unwrap();
+        let verifier_srs = srs.verifier_srs();
+
+        let (proofs, public_inputs) = create_proofs(4, &parameters, rng);
+        let expected_proof = aggregate_proofs(&srs, &proofs, &public_inputs).unwrap();
+
+        // Bytes
+        let expected_bytes = expected_proof.to_bytes_le().unwrap();
+        let candidate_proof = AggregateProof::<Bls12_377>::read_le(&expected_bytes[..]).unwrap();
+        assert_eq!(expected_proof, candidate_proof);
+        assert!(verify_aggregate_proof(&verifier_srs, &pvk, &public_inputs, &candidate_proof).unwrap());
+
+        // String
+        assert_eq!(
+            expected_proof,
+            AggregateProof::from_str(&expected_proof.to_string()).unwrap()
+        );
+
+        // Serde
+        let candidate_string = serde_json::to_string(&expected_proof).unwrap();
+        assert_eq!(expected_proof, serde_json::from_str(&candidate_string).unwrap());
+        let candidate_bytes = bincode::serialize(&expected_proof).unwrap();
+        assert_eq!(expected_proof, bincode::deserialize(&candidate_bytes[..]).unwrap());
+
+        // The SRS round-trips through bytes.
+        let srs_bytes = srs.to_bytes_le().unwrap();
+        assert_eq!(srs, AggregationSRS::read_le(&srs_bytes[..]).unwrap());
+    }
+
+    #[test]
+    fn test_aggregation_deserialization_rejects_invalid_lengths() {
+        // A proof with more than 32 rounds is rejected before it is read.
+        assert!(GipaProof::<Bls12_377>::read_le(&u32::MAX.to_le_bytes()[..]).is_err());
+        assert!(GipaProof::<Bls12_377>::read_le(&33u32.to_le_bytes()[..]).is_err());
+
+        // An SRS of a size that is not a power of two, or is too large, is rejected.
+        assert!(AggregationSRS::<Bls12_377>::read_le(&3u32.to_le_bytes()[..]).is_err());
+        assert!(AggregationSRS::<Bls12_377>::read_le(&(1u32 << 31).to_le_bytes()[..]).is_err());
+    }
+}
+
+mod mpc {
+    use super::*;
+    use crate::snark::groth16::{
+        create_random_proof,
+        generate_random_parameters,
+        mpc::MPCParameters,
+        prepare_verifying_key,
+        verify_proof,
+    };
+    use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+    use snarkvm_utilities::{FromBytes, ToBytes, UniformRand};
+
+    use rand::thread_rng;
+
+    #[test]
+    fn ceremony_with_simulated_participants() {
+        let rng = &mut thread_rng();
+        let initial_params =
+            generate_random_parameters::<Bls12_377, _, _>(&MySillyCircuit { a: None, b: None }, rng).unwrap();
+
+        // Each participant receives the serialized parameters, contributes, and passes them on.
+        let mut bytes = MPCParameters::new(initial_params.clone())
+            .unwrap()
+            .to_bytes_le()
+            .unwrap();
+        let mut contribution_hashes = vec![];
+        for _ in 0..3 {
+            let mut mpc = MPCParameters::<Bls12_377>::read_le(&bytes[..]).unwrap();
+            contribution_hashes.push(mpc.contribute(rng).unwrap());
+            bytes = mpc.to_bytes_le().unwrap();
+        }
+
+        // The verifier checks the full transcript against the initial parameters.
+        let mpc = MPCParameters::<Bls12_377>::read_le(&bytes[..]).unwrap();
+        assert_eq!(contribution_hashes, mpc.verify(&initial_params, rng).unwrap());
+
+        let params = mpc.get_params();
+        assert_ne!(initial_params.delta_g1, params.delta_g1);
+        assert_ne!(initial_params.vk.delta_g2, params.vk.delta_g2);
+
+        // The final parameters produce valid proofs.
+        let pvk = prepare_verifying_key::<Bls12_377>(params.vk.clone());
+        let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+        let proof = create_random_proof(&MySillyCircuit { a: Some(a), b: Some(b) }, params, rng).unwrap();
+        assert!(verify_proof(&pvk, &proof, &[a * b]).unwrap());
+        assert!(!verify_proof(&pvk, &proof, &[a]).unwrap());
+    }
+
+    #[test]
+    fn ceremony_rejects_invalid_contributions() {
+        let rng = &mut thread_rng();
+        let initial_params =
+            generate_random_parameters::<Bls12_377, _, _>(&MySillyCircuit { a: None, b: None }, rng).unwrap();
+
+        let mut mpc = MPCParameters::new(initial_params.clone()).unwrap();
+        assert!(mpc.verify(&initial_params, rng).unwrap().is_empty());
+        mpc.contribute(rng).unwrap();
+        mpc.contribute(rng).unwrap();
+        assert_eq!(2, mpc.verify(&initial_params, rng).unwrap().len());
+
+        // The transcript must start from the initial parameters.
+        let other_params =
+            generate_random_parameters::<Bls12_377, _, _>(&MySillyCircuit { a: None, b: None }, rng).unwrap();
+        assert!(mpc.verify(&other_params, rng).is_err());
+
+        // A contribution cannot be dropped from the transcript.
+        let mut dropped = mpc.clone();
+        dropped.contributions.remove(0);
+        assert!(dropped.verify(&initial_params, rng).is_err());
+
+        // The proof of knowledge must match the contribution.
+        let mut swapped = mpc.clone();
+        swapped.contributions[1].s_delta = swapped.contributions[0].s_delta;
+        assert!(swapped.verify(&initial_params, rng).is_err());
+
+        // The queries must be updated with the final delta.
+        let mut tampered = mpc.clone();
+        tampered.params.h_query[0] = initial_params.h_query[0];
+        assert!(tampered.verify(&initial_params, rng).is_err());
+        let mut tampered = mpc.clone();
+        tampered.params.l_query[0] = initial_params.l_query[0];
+        assert!(tampered.verify(&initial_params, rng).is_err());
+
+        // A transcript that claims more contributions than it contains fails to deserialize.
+        let mut bytes = MPCParameters::new(initial_params).unwrap().to_bytes_le().unwrap();
+        let num_contributions_offset = bytes.len() - 4;
+        bytes[num_contributions_offset..].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(MPCParameters::<Bls12_377>::read_le(&bytes[..]).is_err());
+    }
+}
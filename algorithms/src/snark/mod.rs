@@ -15,3 +15,6 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 pub mod groth16;
+
+pub mod verifying_key_cache;
+pub use verifying_key_cache::*;
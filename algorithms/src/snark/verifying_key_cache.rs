@@ -0,0 +1,146 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Prepare, SNARKError, SNARK};
+use snarkvm_utilities::ToBytes;
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+/// The default number of prepared verifying keys held by a cache.
+const DEFAULT_CAPACITY: usize = 64;
+
+/// The digest of a verifying key, which is its key in the cache.
+type Digest = [u8; 32];
+
+#[derive(Derivative)]
+#[derivative(Default(bound = ""))]
+struct Entries<T> {
+    prepared_verifying_keys: HashMap<Digest, Arc<T>>,
+    /// The digests of the cached verifying keys, from the oldest to the newest.
+    order: VecDeque<Digest>,
+}
+
+/// A cache of prepared verifying keys, keyed by the BLAKE2s digest of their verifying key,
+/// which evicts the oldest prepared verifying key once it holds `capacity` of them.
+pub struct PreparedVerifyingKeyCache<S: SNARK> {
+    capacity: usize,
+    entries: RwLock<Entries<S::PreparedVerifyingKey>>,
+}
+
+impl<S: SNARK> PreparedVerifyingKeyCache<S> {
+    /// Initializes a new cache, which holds up to `capacity` prepared verifying keys.
+    pub fn new(capacity: usize) -> Self {
+        assert!(
+            capacity > 0,
+            "The capacity of the prepared verifying key cache must be positive"
+        );
+        Self {
+            capacity,
+            entries: Default::default(),
+        }
+    }
+
+    /// Returns the prepared verifying key of the given verifying key,
+    /// preparing and caching it if it is not in the cache.
+    pub fn get_or_prepare(&self, verifying_key: &S::VerifyingKey) -> Result<Arc<S::PreparedVerifyingKey>, SNARKError> {
+        let digest = Self::digest(verifying_key)?;
+
+        if let Some(prepared_verifying_key) = self.read()?.prepared_verifying_keys.get(&digest) {
+            return Ok(prepared_verifying_key.clone());
+        }
+
+        let prepared_verifying_key = Arc::new(verifying_key.prepare());
+
+        let mut entries = self.write()?;
+        if !entries.prepared_verifying_keys.contains_key(&digest) {
+            if entries.prepared_verifying_keys.len() >= self.capacity {
+                if let Some(oldest) = entries.order.pop_front() {
+                    entries.prepared_verifying_keys.remove(&oldest);
+                }
+            }
+            entries
+                .prepared_verifying_keys
+                .insert(digest, prepared_verifying_key.clone());
+            entries.order.push_back(digest);
+        }
+
+        Ok(prepared_verifying_key)
+    }
+
+    /// Verifies the proof for the given input with `S::verify_prepared`,
+    /// using the cached prepared verifying key of `verifying_key`.
+    pub fn verify(
+        &self,
+        verifying_key: &S::VerifyingKey,
+        input: &S::VerifierInput,
+        proof: &S::Proof,
+    ) -> Result<bool, SNARKError> {
+        let prepared_verifying_key = self.get_or_prepare(verifying_key)?;
+        S::verify_prepared(&prepared_verifying_key, input, proof)
+    }
+
+    /// Returns `true` if the prepared verifying key of the given verifying key is in the cache.
+    pub fn contains(&self, verifying_key: &S::VerifyingKey) -> Result<bool, SNARKError> {
+        let digest = Self::digest(verifying_key)?;
+        Ok(self.read()?.prepared_verifying_keys.contains_key(&digest))
+    }
+
+    /// Returns the number of prepared verifying keys in the cache.
+    pub fn len(&self) -> Result<usize, SNARKError> {
+        Ok(self.read()?.prepared_verifying_keys.len())
+    }
+
+    /// Returns `true` if the cache holds no prepared verifying keys.
+    pub fn is_empty(&self) -> Result<bool, SNARKError> {
+        Ok(self.len()? == 0)
+    }
+
+    /// Removes all prepared verifying keys from the cache.
+    pub fn clear(&self) -> Result<(), SNARKError> {
+        let mut entries = self.write()?;
+        entries.prepared_verifying_keys.clear();
+        entries.order.clear();
+        Ok(())
+    }
+
+    /// Returns the digest of the given verifying key.
+    fn digest(verifying_key: &S::VerifyingKey) -> Result<Digest, SNARKError> {
+        let mut digest = Digest::default();
+        digest.copy_from_slice(blake2s_simd::blake2s(&verifying_key.to_bytes_le()?).as_bytes());
+        Ok(digest)
+    }
+
+    fn read(&self) -> Result<RwLockReadGuard<Entries<S::PreparedVerifyingKey>>, SNARKError> {
+        self.entries
+            .read()
+            .map_err(|_| SNARKError::Message("The prepared verifying key cache is poisoned".into()))
+    }
+
+    fn write(&self) -> Result<RwLockWriteGuard<Entries<S::PreparedVerifyingKey>>, SNARKError> {
+        self.entries
+            .write()
+            .map_err(|_| SNARKError::Message("The prepared verifying key cache is poisoned".into()))
+    }
+}
+
+impl<S: SNARK> Default for PreparedVerifyingKeyCache<S> {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
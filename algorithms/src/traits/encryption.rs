@@ -27,6 +27,7 @@ pub trait EncryptionScheme:
     type PrivateKey: Clone + Debug + Default + Eq + Hash + ToBytes + FromBytes + ToBits + UniformRand;
     type PublicKey: Copy + Clone + Debug + Default + Eq + ToBytes + FromBytes;
     type Randomness: Copy + Clone + Debug + Default + Eq + Hash + ToBytes + FromBytes + UniformRand;
+    type SymmetricKey: Copy + Clone + Debug + Default + Eq + Hash + ToBytes + FromBytes + Send + Sync;
 
     fn setup(message: &str) -> Self;
 
@@ -39,6 +40,14 @@ pub trait EncryptionScheme:
 
     fn generate_randomness<R: Rng + CryptoRng>(&self, rng: &mut R) -> Self::Randomness;
 
+    /// Returns the symmetric key shared between the given public key and randomness,
+    /// which suffices to decrypt the ciphertext produced from them.
+    fn generate_symmetric_key(
+        &self,
+        public_key: &<Self as EncryptionScheme>::PublicKey,
+        randomness: &Self::Randomness,
+    ) -> Self::SymmetricKey;
+
     fn encrypt(
         &self,
         public_key: &<Self as EncryptionScheme>::PublicKey,
@@ -46,12 +55,58 @@ pub trait EncryptionScheme:
         message: &[u8],
     ) -> Result<Vec<u8>, EncryptionError>;
 
+    /// Encrypts the given message, appending a MAC so that tampered ciphertexts are rejected by `decrypt_authenticated`.
+    fn encrypt_authenticated(
+        &self,
+        public_key: &<Self as EncryptionScheme>::PublicKey,
+        randomness: &Self::Randomness,
+        message: &[u8],
+    ) -> Result<Vec<u8>, EncryptionError>;
+
+    /// Returns the symmetric key of the given ciphertext for the given private key,
+    /// which matches the symmetric key from `generate_symmetric_key` for the corresponding public key.
+    /// The ciphertext must be in the authenticated format if and only if `is_authenticated` is set.
+    fn recover_symmetric_key(
+        &self,
+        private_key: &<Self as EncryptionScheme>::PrivateKey,
+        ciphertext: &[u8],
+        is_authenticated: bool,
+    ) -> Result<Self::SymmetricKey, EncryptionError>;
+
+    /// Decrypts the given ciphertext from `encrypt`. Authenticated ciphertexts are rejected.
     fn decrypt(
         &self,
         private_key: &<Self as EncryptionScheme>::PrivateKey,
         ciphertext: &[u8],
     ) -> Result<Vec<u8>, EncryptionError>;
 
+    /// Decrypts the given ciphertext from `encrypt_authenticated`, after checking its MAC.
+    /// Unauthenticated ciphertexts are rejected.
+    fn decrypt_authenticated(
+        &self,
+        private_key: &<Self as EncryptionScheme>::PrivateKey,
+        ciphertext: &[u8],
+    ) -> Result<Vec<u8>, EncryptionError>;
+
+    /// Decrypts the given ciphertext from `encrypt` for the given public key,
+    /// using the symmetric key in place of the private key. Authenticated ciphertexts are rejected.
+    fn decrypt_with_symmetric_key(
+        &self,
+        public_key: &<Self as EncryptionScheme>::PublicKey,
+        symmetric_key: &Self::SymmetricKey,
+        ciphertext: &[u8],
+    ) -> Result<Vec<u8>, EncryptionError>;
+
+    /// Decrypts the given ciphertext from `encrypt_authenticated` for the given public key,
+    /// using the symmetric key in place of the private key, after checking its MAC.
+    /// Unauthenticated ciphertexts are rejected.
+    fn decrypt_authenticated_with_symmetric_key(
+        &self,
+        public_key: &<Self as EncryptionScheme>::PublicKey,
+        symmetric_key: &Self::SymmetricKey,
+        ciphertext: &[u8],
+    ) -> Result<Vec<u8>, EncryptionError>;
+
     fn parameters(&self) -> &<Self as EncryptionScheme>::Parameters;
 
     fn private_key_size_in_bits() -> usize;
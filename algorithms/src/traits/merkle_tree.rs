@@ -15,11 +15,11 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{errors::MerkleError, CRH};
-use snarkvm_utilities::{to_bytes_le, ToBytes};
+use snarkvm_utilities::{to_bytes_le, FromBytes, ToBytes};
 
 use std::fmt::Debug;
 
-pub trait MerkleParameters: Clone + Debug + Send + Sync {
+pub trait MerkleParameters: Clone + Debug + ToBytes + FromBytes + Send + Sync {
     type H: CRH;
 
     const DEPTH: usize;
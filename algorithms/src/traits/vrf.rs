@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use anyhow::Result;
+use rand::{CryptoRng, Rng};
+use std::{fmt::Debug, hash::Hash};
+
+/// A verifiable random function, whose output on an input is unique for a public key,
+/// and is accompanied by a proof that it was computed with the corresponding private key.
+pub trait VRF:
+    Sized + ToBytes + FromBytes + Debug + Clone + Eq + Send + Sync + From<<Self as VRF>::Parameters>
+{
+    type Parameters: Clone + Debug + Eq;
+    type PublicKey: Clone + Debug + Default + ToBytes + FromBytes + Hash + Eq + Send + Sync;
+    type PrivateKey: Clone + Debug + Default + ToBytes + FromBytes + PartialEq + Eq;
+    type Proof: Clone + Debug + Default + ToBytes + FromBytes + Send + Sync + PartialEq + Eq;
+    type Output: Copy + Clone + Debug + Default + ToBytes + FromBytes + Hash + Eq + Send + Sync;
+
+    fn setup(message: &str) -> Self;
+
+    fn parameters(&self) -> &Self::Parameters;
+
+    fn generate_public_key(&self, private_key: &Self::PrivateKey) -> Self::PublicKey;
+
+    /// Returns a proof of the evaluation of the VRF on the given input.
+    fn prove<R: Rng + CryptoRng>(
+        &self,
+        private_key: &Self::PrivateKey,
+        input: &[u8],
+        rng: &mut R,
+    ) -> Result<Self::Proof>;
+
+    /// Returns `true` if the proof is a valid evaluation of the VRF on the given input.
+    fn verify(&self, public_key: &Self::PublicKey, input: &[u8], proof: &Self::Proof) -> Result<bool>;
+
+    /// Returns the output of the VRF from the given proof.
+    fn proof_to_hash(&self, proof: &Self::Proof) -> Result<Self::Output>;
+}
@@ -0,0 +1,320 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    crypto_hash::{PoseidonCryptoHash, PoseidonDefaultParametersField},
+    hash_to_curve::{HashToCurve, HashToField},
+    signature::AleoSignatureScheme,
+    CryptoHash,
+    SignatureScheme,
+    SignatureSchemeOperations,
+    VRFError,
+    VRF,
+};
+use snarkvm_curves::{
+    templates::twisted_edwards_extended::{Affine as TEAffine, Projective as TEProjective},
+    AffineCurve,
+    Elligator2Parameters,
+    TwistedEdwardsParameters,
+};
+use snarkvm_fields::{PrimeField, ToConstraintField};
+use snarkvm_utilities::{
+    io::{Read, Result as IoResult, Write},
+    rand::UniformRand,
+    FromBytes,
+    ToBytes,
+};
+
+use anyhow::Result;
+use rand::{CryptoRng, Rng};
+
+/// The domain separator for hashing the VRF input to the curve.
+pub const ALEO_VRF_HASH_TO_CURVE_DOMAIN: &str = "AleoVRFHashToCurve0";
+/// The domain separator for the VRF proof challenge.
+pub const ALEO_VRF_CHALLENGE_DOMAIN: &str = "AleoVRFChallenge0";
+/// The domain separator for the VRF output.
+pub const ALEO_VRF_OUTPUT_DOMAIN: &str = "AleoVRFOutput0";
+
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "TE: TwistedEdwardsParameters"),
+    Clone(bound = "TE: TwistedEdwardsParameters"),
+    PartialEq(bound = "TE: TwistedEdwardsParameters"),
+    Eq(bound = "TE: TwistedEdwardsParameters"),
+    Debug(bound = "TE: TwistedEdwardsParameters"),
+    Default(bound = "TE: TwistedEdwardsParameters")
+)]
+pub struct AleoVRFProof<TE: TwistedEdwardsParameters> {
+    gamma: TE::BaseField,
+    pub challenge: TE::ScalarField,
+    pub response: TE::ScalarField,
+}
+
+impl<TE: TwistedEdwardsParameters> AleoVRFProof<TE> {
+    /// Returns a new proof from the given gamma, challenge, and response.
+    pub(crate) fn new(gamma: &TEAffine<TE>, challenge: TE::ScalarField, response: TE::ScalarField) -> Self {
+        Self {
+            gamma: gamma.x,
+            challenge,
+            response,
+        }
+    }
+
+    /// Returns gamma := H^sk, where H is the hash of the public key and input on the curve.
+    #[inline]
+    pub fn gamma(&self) -> Result<TEAffine<TE>>
+    where
+        TE::BaseField: PoseidonDefaultParametersField,
+    {
+        AleoSignatureScheme::<TE>::recover_from_x_coordinate(&self.gamma)
+            .map_err(|_| VRFError::Message("Failed to read the VRF proof gamma".into()).into())
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> FromBytes for AleoVRFProof<TE> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let gamma = TE::BaseField::read_le(&mut reader)?;
+        let challenge = TE::ScalarField::read_le(&mut reader)?;
+        let response = TE::ScalarField::read_le(&mut reader)?;
+
+        Ok(Self {
+            gamma,
+            challenge,
+            response,
+        })
+    }
+}
+
+impl<TE: TwistedEdwardsParameters> ToBytes for AleoVRFProof<TE> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.gamma.write_le(&mut writer)?;
+        self.challenge.write_le(&mut writer)?;
+        self.response.write_le(&mut writer)
+    }
+}
+
+///
+/// An elliptic curve VRF over a twisted Edwards curve, in the style of ECVRF (RFC 9381).
+///
+/// The VRF uses the generator and the random oracle of `AleoSignatureScheme`,
+/// so that an account address is the VRF public key of the account decryption key.
+/// The input is hashed to the curve with Poseidon and the Elligator 2 map, so that
+/// the proof can be verified efficiently in a circuit.
+///
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "TE: Elligator2Parameters"),
+    Debug(bound = "TE: Elligator2Parameters"),
+    PartialEq(bound = "TE: Elligator2Parameters"),
+    Eq(bound = "TE: Elligator2Parameters")
+)]
+pub struct AleoVRF<TE: Elligator2Parameters>
+where
+    TE::BaseField: PoseidonDefaultParametersField,
+{
+    signature_scheme: AleoSignatureScheme<TE>,
+    crypto_hash: PoseidonCryptoHash<TE::BaseField, 4, false>,
+}
+
+impl<TE: Elligator2Parameters> VRF for AleoVRF<TE>
+where
+    TE::BaseField: PoseidonDefaultParametersField + HashToField,
+{
+    type Output = TE::BaseField;
+    type Parameters = Vec<TEProjective<TE>>;
+    type PrivateKey = TE::ScalarField;
+    type Proof = AleoVRFProof<TE>;
+    type PublicKey = TEAffine<TE>;
+
+    fn setup(message: &str) -> Self {
+        Self {
+            signature_scheme: AleoSignatureScheme::setup(message),
+            crypto_hash: PoseidonCryptoHash::<TE::BaseField, 4, false>::setup(),
+        }
+    }
+
+    fn parameters(&self) -> &Self::Parameters {
+        self.signature_scheme.parameters()
+    }
+
+    ///
+    /// Returns public key as G^sk.
+    ///
+    fn generate_public_key(&self, private_key: &Self::PrivateKey) -> Self::PublicKey {
+        self.signature_scheme.g_scalar_multiply(private_key)
+    }
+
+    ///
+    /// Returns proof (gamma, c, s), where:
+    ///     H := HashToCurve(G^sk, input)
+    ///     gamma := H^sk
+    ///     c := Hash(G^sk, H, gamma, G^k, H^k)
+    ///     s := k - c * sk
+    ///
+    fn prove<R: Rng + CryptoRng>(
+        &self,
+        private_key: &Self::PrivateKey,
+        input: &[u8],
+        rng: &mut R,
+    ) -> Result<Self::Proof> {
+        // Compute G^sk.
+        let public_key = self.generate_public_key(private_key);
+
+        // Compute H := HashToCurve(G^sk, input).
+        let h = self.hash_to_curve(&public_key, input)?;
+
+        // Compute gamma := H^sk.
+        let gamma = self.signature_scheme.scalar_multiply(h.into_projective(), private_key);
+
+        // Sample a random nonce, and compute G^k and H^k.
+        let k = TE::ScalarField::rand(rng);
+        let g_k = self.signature_scheme.g_scalar_multiply(&k);
+        let h_k = self.signature_scheme.scalar_multiply(h.into_projective(), &k);
+
+        // Compute the challenge and the response.
+        let challenge = self.compute_challenge(&public_key, &h, &gamma, &g_k, &h_k);
+        let response = k - (challenge * private_key);
+
+        Ok(AleoVRFProof::new(&gamma, challenge, response))
+    }
+
+    ///
+    /// Verifies c == Hash(G^sk, H, gamma, G^s G^sk^c, H^s gamma^c), where H := HashToCurve(G^sk, input).
+    ///
+    /// The public key is assumed to be in the prime-order subgroup, as is the case for an account address.
+    ///
+    fn verify(&self, public_key: &Self::PublicKey, input: &[u8], proof: &Self::Proof) -> Result<bool> {
+        // Recover gamma.
+        let gamma = proof.gamma()?;
+
+        // Compute H := HashToCurve(G^sk, input).
+        let h = self.hash_to_curve(public_key, input)?;
+
+        // Compute G^k := G^s G^sk^c.
+        let g_k = self.signature_scheme.g_scalar_multiply(&proof.response)
+            + self
+                .signature_scheme
+                .scalar_multiply(public_key.into_projective(), &proof.challenge);
+
+        // Compute H^k := H^s gamma^c.
+        let h_k = self
+            .signature_scheme
+            .scalar_multiply(h.into_projective(), &proof.response)
+            + self
+                .signature_scheme
+                .scalar_multiply(gamma.into_projective(), &proof.challenge);
+
+        Ok(proof.challenge == self.compute_challenge(public_key, &h, &gamma, &g_k, &h_k))
+    }
+
+    ///
+    /// Returns the output as Hash(gamma).
+    ///
+    fn proof_to_hash(&self, proof: &Self::Proof) -> Result<Self::Output> {
+        let gamma = proof.gamma()?;
+        Ok(self.crypto_hash.evaluate(&[
+            TE::BaseField::from_bytes_le_mod_order(ALEO_VRF_OUTPUT_DOMAIN.as_bytes()),
+            gamma.x,
+        ]))
+    }
+}
+
+impl<TE: Elligator2Parameters> AleoVRF<TE>
+where
+    TE::BaseField: PoseidonDefaultParametersField + HashToField,
+{
+    ///
+    /// Returns H := HashToCurve(G^sk, input), which maps two field elements derived with Poseidon
+    /// to the curve with Elligator 2, and clears the cofactor of their sum.
+    ///
+    pub(crate) fn hash_to_curve(&self, public_key: &TEAffine<TE>, input: &[u8]) -> Result<TEAffine<TE>> {
+        // Construct the hash input (G^sk, input).
+        let mut preimage = vec![public_key.x];
+        preimage.push(TE::BaseField::from(input.len() as u128));
+        preimage.extend_from_slice(&input.to_field_elements()?);
+
+        // Compute u_i := Hash(domain_i, G^sk, input), for i in {0, 1}.
+        let u = (0..2)
+            .map(|i| {
+                let domain = format!("{}_{}", ALEO_VRF_HASH_TO_CURVE_DOMAIN, i);
+                let mut input = vec![TE::BaseField::from_bytes_le_mod_order(domain.as_bytes())];
+                input.extend_from_slice(&preimage);
+                self.crypto_hash.evaluate(&input)
+            })
+            .collect::<Vec<_>>();
+
+        let q = TEAffine::<TE>::map_to_curve(u[0]) + TEAffine::<TE>::map_to_curve(u[1]);
+        Ok(q.mul_by_cofactor())
+    }
+
+    ///
+    /// Returns the challenge as Hash(G^sk, H, gamma, G^k, H^k).
+    ///
+    pub(crate) fn compute_challenge(
+        &self,
+        public_key: &TEAffine<TE>,
+        h: &TEAffine<TE>,
+        gamma: &TEAffine<TE>,
+        g_k: &TEAffine<TE>,
+        h_k: &TEAffine<TE>,
+    ) -> TE::ScalarField {
+        self.signature_scheme.hash_to_scalar_field(&[
+            TE::BaseField::from_bytes_le_mod_order(ALEO_VRF_CHALLENGE_DOMAIN.as_bytes()),
+            public_key.x,
+            h.x,
+            gamma.x,
+            g_k.x,
+            h_k.x,
+        ])
+    }
+}
+
+impl<TE: Elligator2Parameters> From<Vec<TEProjective<TE>>> for AleoVRF<TE>
+where
+    TE::BaseField: PoseidonDefaultParametersField,
+{
+    fn from(g_bases: Vec<TEProjective<TE>>) -> Self {
+        Self {
+            signature_scheme: AleoSignatureScheme::from(g_bases),
+            crypto_hash: PoseidonCryptoHash::<TE::BaseField, 4, false>::setup(),
+        }
+    }
+}
+
+impl<TE: Elligator2Parameters> ToBytes for AleoVRF<TE>
+where
+    TE::BaseField: PoseidonDefaultParametersField,
+{
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.signature_scheme.write_le(writer)
+    }
+}
+
+impl<TE: Elligator2Parameters> FromBytes for AleoVRF<TE>
+where
+    TE::BaseField: PoseidonDefaultParametersField,
+{
+    #[inline]
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        let signature_scheme = AleoSignatureScheme::<TE>::read_le(reader)?;
+        Ok(Self {
+            signature_scheme,
+            crypto_hash: PoseidonCryptoHash::<TE::BaseField, 4, false>::setup(),
+        })
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    crypto_hash::PoseidonDefaultParametersField,
+    hash_to_curve::HashToField,
+    vrf::{AleoVRF, AleoVRFProof},
+    VRF,
+};
+use snarkvm_curves::{
+    edwards_bls12::EdwardsParameters as EdwardsBls12,
+    edwards_bw6::EdwardsParameters as EdwardsBW6,
+    Elligator2Parameters,
+};
+use snarkvm_fields::{One, Zero};
+use snarkvm_utilities::{FromBytes, ToBytes, UniformRand};
+
+use rand::thread_rng;
+
+fn prove_and_verify<TE: Elligator2Parameters>()
+where
+    TE::BaseField: PoseidonDefaultParametersField + HashToField,
+{
+    let rng = &mut thread_rng();
+    let vrf = AleoVRF::<TE>::setup("aleo_vrf_prove_and_verify");
+
+    let private_key = TE::ScalarField::rand(rng);
+    let public_key = vrf.generate_public_key(&private_key);
+    let input = b"Hi, I am an Aleo VRF input!";
+
+    let proof = vrf.prove(&private_key, input, rng).unwrap();
+    assert!(vrf.verify(&public_key, input, &proof).unwrap());
+
+    // Ensure the output is unique, even though the proofs are randomized.
+    let other_proof = vrf.prove(&private_key, input, rng).unwrap();
+    assert_ne!(proof, other_proof);
+    assert!(vrf.verify(&public_key, input, &other_proof).unwrap());
+    assert_eq!(
+        vrf.proof_to_hash(&proof).unwrap(),
+        vrf.proof_to_hash(&other_proof).unwrap()
+    );
+
+    // Ensure the output depends on the input and the private key.
+    let other_input_proof = vrf.prove(&private_key, b"Another input", rng).unwrap();
+    assert_ne!(
+        vrf.proof_to_hash(&proof).unwrap(),
+        vrf.proof_to_hash(&other_input_proof).unwrap()
+    );
+    let other_private_key = TE::ScalarField::rand(rng);
+    let other_key_proof = vrf.prove(&other_private_key, input, rng).unwrap();
+    assert_ne!(
+        vrf.proof_to_hash(&proof).unwrap(),
+        vrf.proof_to_hash(&other_key_proof).unwrap()
+    );
+}
+
+fn failed_verification<TE: Elligator2Parameters>()
+where
+    TE::BaseField: PoseidonDefaultParametersField + HashToField,
+{
+    let rng = &mut thread_rng();
+    let vrf = AleoVRF::<TE>::setup("aleo_vrf_failed_verification");
+
+    let private_key = TE::ScalarField::rand(rng);
+    let public_key = vrf.generate_public_key(&private_key);
+    let input = b"Hi, I am an Aleo VRF input!";
+    let proof = vrf.prove(&private_key, input, rng).unwrap();
+
+    // Verify under a different input.
+    assert!(!vrf.verify(&public_key, b"Bad input", &proof).unwrap());
+
+    // Verify under a different public key.
+    let other_public_key = vrf.generate_public_key(&TE::ScalarField::rand(rng));
+    assert!(!vrf.verify(&other_public_key, input, &proof).unwrap());
+
+    // Verify a proof with a tampered response.
+    let mut tampered_proof = proof;
+    tampered_proof.response += TE::ScalarField::one();
+    assert!(!vrf.verify(&public_key, input, &tampered_proof).unwrap());
+
+    // Verify a proof whose gamma is computed under another private key for the same input,
+    // which would yield a different output for the same public key.
+    let other_gamma_proof = vrf.prove(&TE::ScalarField::rand(rng), input, rng).unwrap();
+    let mut tampered_bytes = proof.to_bytes_le().unwrap();
+    let gamma_size = TE::BaseField::zero().to_bytes_le().unwrap().len();
+    tampered_bytes[..gamma_size].copy_from_slice(&other_gamma_proof.to_bytes_le().unwrap()[..gamma_size]);
+    let tampered_proof: AleoVRFProof<TE> = FromBytes::read_le(&tampered_bytes[..]).unwrap();
+    assert!(!vrf.verify(&public_key, input, &tampered_proof).unwrap());
+}
+
+fn serialization<TE: Elligator2Parameters>()
+where
+    TE::BaseField: PoseidonDefaultParametersField + HashToField,
+{
+    let rng = &mut thread_rng();
+    let vrf = AleoVRF::<TE>::setup("aleo_vrf_serialization");
+    let recovered_vrf: AleoVRF<TE> = FromBytes::read_le(&vrf.to_bytes_le().unwrap()[..]).unwrap();
+    assert_eq!(vrf, recovered_vrf);
+
+    let proof = vrf.prove(&TE::ScalarField::rand(rng), b"input", rng).unwrap();
+    let recovered_proof: AleoVRFProof<TE> = FromBytes::read_le(&proof.to_bytes_le().unwrap()[..]).unwrap();
+    assert_eq!(proof, recovered_proof);
+}
+
+#[test]
+fn test_aleo_vrf_on_edwards_bls12_377() {
+    prove_and_verify::<EdwardsBls12>();
+    failed_verification::<EdwardsBls12>();
+}
+
+#[test]
+fn test_aleo_vrf_on_edwards_bw6() {
+    prove_and_verify::<EdwardsBW6>();
+    failed_verification::<EdwardsBW6>();
+}
+
+#[test]
+fn test_aleo_vrf_serialization() {
+    serialization::<EdwardsBls12>();
+    serialization::<EdwardsBW6>();
+}
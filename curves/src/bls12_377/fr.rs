@@ -38,6 +38,8 @@ use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
 /// assert g.multiplicative_order() == o
 /// g2 = g ** (o/2**s)
 /// assert g2.multiplicative_order() == 2**s
+/// g3 = g ** (o/(2**s * 3))
+/// assert g3.multiplicative_order() == 2**s * 3
 /// def into_chunks(val, width, n):
 ///     return [int(int(val) // (2 ** (width * i)) % 2 ** width) for i in range(n)]
 /// print("Gen (g % q): ", g % q)
@@ -46,6 +48,8 @@ use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
 /// print("2-adic gen (g2 % q): ", g2 % q)
 /// print("2-adic gen (g2 * R % q): ", g2 * R % q)
 /// print("2-adic gen into_chunks(g2 * R % q): ", into_chunks(g2 * R % q, 64, 4))
+/// print("large subgroup gen (g3 % q): ", g3 % q)
+/// print("large subgroup gen into_chunks(g3 * R % q): ", into_chunks(g3 * R % q, 64, 4))
 /// ```
 pub type Fr = Fp256<FrParameters>;
 
@@ -56,6 +60,20 @@ impl Fp256Parameters for FrParameters {}
 impl FftParameters for FrParameters {
     type BigInteger = BigInteger;
 
+    /// LARGE_SUBGROUP_ROOT_OF_UNITY = 4745010758872139845238200295841730218141082559516036141034422680643841032105
+    /// Encoded in Montgomery form, the value is
+    /// (4745010758872139845238200295841730218141082559516036141034422680643841032105 * R % q) =
+    /// 6305670270485671394103200713230422010059347173612490824610048904823110729716
+    #[rustfmt::skip]
+    const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<BigInteger> = Some(BigInteger([
+        16312050644684472308u64,
+        16226885886700552844u64,
+        8981803609415491252u64,
+        1004551230217910552u64,
+    ]));
+    /// r - 1 = 2^47 * 3 * t', with t' coprime to 6.
+    const SMALL_SUBGROUP_BASE: Option<u32> = Some(3);
+    const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = Some(1);
     #[rustfmt::skip]
     const TWO_ADICITY: u32 = 47;
     /// TWO_ADIC_ROOT_OF_UNITY = 8065159656716812877374967518403273466521432693661810619979959746626482506078
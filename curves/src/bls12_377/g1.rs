@@ -19,7 +19,7 @@ use snarkvm_utilities::biginteger::{BigInteger256, BigInteger384};
 
 use crate::{
     bls12_377::{Fq, Fr},
-    traits::{ModelParameters, ShortWeierstrassParameters},
+    traits::{ModelParameters, SWUParameters, ShortWeierstrassParameters},
 };
 
 #[derive(Clone, Default, PartialEq, Eq)]
@@ -67,6 +67,178 @@ impl ShortWeierstrassParameters for Bls12_377G1Parameters {
     }
 }
 
+impl SWUParameters for Bls12_377G1Parameters {
+    /// ISOGENOUS_COEFF_A = -15
+    const ISOGENOUS_COEFF_A: Fq = field!(
+        Fq,
+        BigInteger384([
+            8005640918860302573,
+            12713514003070977187,
+            4783777178294134989,
+            5265655741464679543,
+            6688503545619719304,
+            8483428697112459
+        ])
+    );
+    /// ISOGENOUS_COEFF_B = 22
+    const ISOGENOUS_COEFF_B: Fq = field!(
+        Fq,
+        BigInteger384([
+            11130294635325289193,
+            6502679372128844082,
+            15863297759487624914,
+            16270683149854112145,
+            3560014356538878812,
+            27923742146399959
+        ])
+    );
+    /// The x-coordinate denominator `x - 2`.
+    const ISOGENY_X_DENOMINATOR: &'static [Fq] = &[
+        field!(
+            Fq,
+            BigInteger384([
+                9181924846534132017,
+                8397557703332855966,
+                16138643615274420251,
+                2559639447746137475,
+                3193573585304698730,
+                41497228061779894
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                202099033278250856,
+                5854854902718660529,
+                11492539364873682930,
+                8885205928937022213,
+                5545221690922665192,
+                39800542322357402
+            ])
+        ),
+    ];
+    /// The isogeny map of degree 2, with x-coordinate numerator `(x^2 - 2x - 3) / 4`.
+    const ISOGENY_X_NUMERATOR: &'static [Fq] = &[
+        field!(
+            Fq,
+            BigInteger384([
+                14046234656559333491,
+                6492754294876405819,
+                16669259766084360202,
+                13666146858917415242,
+                901728662103254412,
+                91247905964726647
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                261701359596732493,
+                17179840057410846760,
+                5707336552020617222,
+                15887448341151670796,
+                11511406121688696518,
+                101198041545315997
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                4662210776746950618,
+                10687085762534440940,
+                7484820859645808636,
+                2221301482234255553,
+                10609677459585442106,
+                9950135580589350
+            ])
+        ),
+    ];
+    /// The y-coordinate denominator `(x - 2)^2`.
+    const ISOGENY_Y_DENOMINATOR: &'static [Fq] = &[
+        field!(
+            Fq,
+            BigInteger384([
+                9669017293731921311,
+                3312152102104465091,
+                6846435114472945609,
+                15210772410127906951,
+                7896869796540631654,
+                38103856582934910
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                18363849693068264034,
+                16795115406665711932,
+                13830543156839288886,
+                5119278895492274951,
+                6387147170609397460,
+                82994456123559788
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                202099033278250856,
+                5854854902718660529,
+                11492539364873682930,
+                8885205928937022213,
+                5545221690922665192,
+                39800542322357402
+            ])
+        ),
+    ];
+    /// The y-coordinate numerator `(x^2 - 4x + 7) / 8`.
+    const ISOGENY_Y_NUMERATOR: &'static [Fq] = &[
+        field!(
+            Fq,
+            BigInteger384([
+                16317737718614327163,
+                511312021451440058,
+                16973500971905554420,
+                7774555187819894436,
+                240382961129944139,
+                34825474532062727
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                261701359596732493,
+                17179840057410846760,
+                5707336552020617222,
+                15887448341151670796,
+                11511406121688696518,
+                101198041545315997
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                2331105388373475309,
+                5343542881267220470,
+                12965782466677680126,
+                1110650741117127776,
+                5304838729792721053,
+                4975067790294675
+            ])
+        ),
+    ];
+    /// SWU_Z = -11
+    const SWU_Z: Fq = field!(
+        Fq,
+        BigInteger384([
+            17674658212592223884,
+            16025666105175442278,
+            11630212292767080598,
+            2029684077883034878,
+            14585373342160350959,
+            46587285280047369
+        ])
+    );
+}
+
 ///
 /// G1_GENERATOR_X =
 /// 89363714989903307245735717098563574705733591463163614225748337416674727625843187853442697973404985688481508350822
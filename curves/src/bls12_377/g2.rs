@@ -18,8 +18,8 @@ use snarkvm_fields::{field, Zero};
 use snarkvm_utilities::biginteger::{BigInteger256, BigInteger384};
 
 use crate::{
-    bls12_377::{g1::Bls12_377G1Parameters, Fq, Fq2, Fr},
-    traits::{ModelParameters, ShortWeierstrassParameters},
+    bls12_377::{g1::Bls12_377G1Parameters, g2_isogeny, Fq, Fq2, Fr},
+    traits::{ModelParameters, SWUParameters, ShortWeierstrassParameters},
 };
 
 #[derive(Clone, Default, PartialEq, Eq)]
@@ -86,6 +86,17 @@ impl ShortWeierstrassParameters for Bls12_377G2Parameters {
     }
 }
 
+/// The 23-isogenous curve and the isogeny map are given in `g2_isogeny`.
+impl SWUParameters for Bls12_377G2Parameters {
+    const ISOGENOUS_COEFF_A: Fq2 = g2_isogeny::ISOGENOUS_COEFF_A;
+    const ISOGENOUS_COEFF_B: Fq2 = g2_isogeny::ISOGENOUS_COEFF_B;
+    const ISOGENY_X_DENOMINATOR: &'static [Fq2] = &g2_isogeny::ISOGENY_X_DENOMINATOR;
+    const ISOGENY_X_NUMERATOR: &'static [Fq2] = &g2_isogeny::ISOGENY_X_NUMERATOR;
+    const ISOGENY_Y_DENOMINATOR: &'static [Fq2] = &g2_isogeny::ISOGENY_Y_DENOMINATOR;
+    const ISOGENY_Y_NUMERATOR: &'static [Fq2] = &g2_isogeny::ISOGENY_Y_NUMERATOR;
+    const SWU_Z: Fq2 = g2_isogeny::SWU_Z;
+}
+
 pub const G2_GENERATOR_X: Fq2 = field!(Fq2, G2_GENERATOR_X_C0, G2_GENERATOR_X_C1);
 pub const G2_GENERATOR_Y: Fq2 = field!(Fq2, G2_GENERATOR_Y_C0, G2_GENERATOR_Y_C1);
 
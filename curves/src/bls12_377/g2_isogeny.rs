@@ -0,0 +1,2948 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The 23-isogeny used by the simplified SWU map to BLS12-377 G2.
+//!
+//! As G2 has `a = 0` and no rational isogeny of small degree to a curve with `a * b != 0`,
+//! the map is instantiated on a 23-isogenous curve `E'`. The isogeny `E' -> G2` is the dual
+//! of the isogeny with kernel generated by a 23-torsion point defined over `Fq2^11`, and the
+//! coefficients below follow the layout of Appendix E of RFC 9380.
+
+use crate::bls12_377::{Fq, Fq2};
+use snarkvm_fields::field;
+use snarkvm_utilities::biginteger::BigInteger384;
+
+/// The coefficient `a'` of the 23-isogenous curve `E'`.
+pub(crate) const ISOGENOUS_COEFF_A: Fq2 = field!(
+    Fq2,
+    field!(
+        Fq,
+        BigInteger384([
+            13983927228758744968,
+            13622101454688283831,
+            15644611660656478480,
+            2164018710187676252,
+            8648920738735058260,
+            95438242172398420
+        ])
+    ),
+    field!(
+        Fq,
+        BigInteger384([
+            4685038298343214284,
+            4479236367881937898,
+            2596021168165930350,
+            3312225256687104569,
+            14596571727860750301,
+            92985220183609535
+        ])
+    )
+);
+
+/// The coefficient `b'` of the 23-isogenous curve `E'`.
+pub(crate) const ISOGENOUS_COEFF_B: Fq2 = field!(
+    Fq2,
+    field!(
+        Fq,
+        BigInteger384([
+            12029570793737858519,
+            11483740422453486250,
+            5712107361665908098,
+            15086261807457653477,
+            15697279393909917038,
+            69808353649068029
+        ])
+    ),
+    field!(
+        Fq,
+        BigInteger384([
+            964843982745517750,
+            15578175925258902203,
+            10439749668434830725,
+            15949719614843905751,
+            6036834497872637867,
+            1535145524989335
+        ])
+    )
+);
+
+/// SWU_Z = u, the first non-square in the sequence of Appendix H.2 of RFC 9380 that is valid for `E'`.
+pub(crate) const SWU_Z: Fq2 = field!(
+    Fq2,
+    field!(Fq, BigInteger384([0, 0, 0, 0, 0, 0])),
+    field!(
+        Fq,
+        BigInteger384([
+            202099033278250856,
+            5854854902718660529,
+            11492539364873682930,
+            8885205928937022213,
+            5545221690922665192,
+            39800542322357402
+        ])
+    )
+);
+
+/// The numerator of the x-coordinate map, of degree 23.
+pub(crate) const ISOGENY_X_NUMERATOR: [Fq2; 24] = [
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                8661095935184827394,
+                15997893433768090557,
+                18131967218687645067,
+                17231448726437021534,
+                8985854542604148942,
+                12509151031900572
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                1517622047365499871,
+                16186014170448679030,
+                9928640819857912969,
+                8609230098411367067,
+                15143665186633889433,
+                17499603179908754
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                13220499542077016759,
+                2640381014352127162,
+                11371595381615676041,
+                3051676385197010137,
+                13055602978098627381,
+                59843560754855324
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                15360737965322308569,
+                10181372543698625475,
+                4838504419796764388,
+                7979869386471259574,
+                10583111257156491188,
+                95529077738084972
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                12073661748183385121,
+                10540312016729511380,
+                12727952671512777563,
+                17800034290755828178,
+                7489681838918345020,
+                114800677545647624
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                2000569067124976001,
+                3789375257827531326,
+                4853646248573455365,
+                2997852784205845109,
+                9731153957374524728,
+                20773774105417394
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                8056658974211134643,
+                17012772651064614081,
+                18443280571722101457,
+                7253090263838937408,
+                12062443101488706942,
+                80917724382210576
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                1036779604097441475,
+                297272492975456045,
+                5088208027214259972,
+                7327089994383313009,
+                1918674448600925532,
+                31635684729026713
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                16689357838205958402,
+                4156800905638491868,
+                1254008076416949529,
+                811200319837097921,
+                15592499854242027860,
+                23779752593057193
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                3390432783176175902,
+                12579986524980589493,
+                14257763071018285608,
+                14022507979448929092,
+                9705983841682274352,
+                64608437968657143
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                11323911145434477644,
+                15120741494349994779,
+                773806577893767404,
+                17576767464472737966,
+                17761063713659103683,
+                69898349528089150
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                3303680101257266933,
+                10152135444229156972,
+                13868726489559389419,
+                14375451221826198606,
+                17224746324804231861,
+                106870701299237704
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                6488852338959650268,
+                8867038255147405404,
+                18127007533298868346,
+                7397282037494055698,
+                7749605890822870216,
+                78220115640316488
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                2142933787635641826,
+                3650631745550456090,
+                17725074280251563642,
+                10772365787461333413,
+                535770840939471804,
+                23336343773495656
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                12112181809156724161,
+                1009518341287591452,
+                7645597894141247147,
+                14149600656875692647,
+                7627710486678782306,
+                46489050407947914
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                3696721027838904819,
+                16349745084926142045,
+                7013799721790186670,
+                12954077257642506202,
+                560694321143713325,
+                102676502981006070
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                6294854358100253776,
+                2437853903608524058,
+                16844662506593546294,
+                16361311362579411776,
+                1300121347886048074,
+                59501318883223053
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                17187143684136519779,
+                16434710984811655784,
+                7569878469641208495,
+                8451796541037211714,
+                16628379642070877028,
+                33174029603763132
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                2180426447411114365,
+                12898557433569455069,
+                4902880886351863644,
+                1756090380986187120,
+                18215144841299552706,
+                6807191152164007
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                4972574340759350739,
+                2573575606703733901,
+                13314030623412432146,
+                15689883768532905006,
+                9275239186270718540,
+                100437390449620452
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                4152126890018064541,
+                16943691835539141973,
+                5142620945976648956,
+                6698166934670954966,
+                16460552300337902408,
+                92138842825502415
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                6771064594141538484,
+                15989649912456730267,
+                5738803827099998423,
+                7420098095030187948,
+                17182461266012432190,
+                119620332282322484
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                18091027475985380859,
+                4844378665944240495,
+                4535675225462384375,
+                12926142936742157871,
+                14888641780087833005,
+                115977161977802552
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                15102339492034216222,
+                6279720631936392617,
+                5986061728599459258,
+                5844750295365420306,
+                5654868805603883476,
+                46215283194343685
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                5183503683690186038,
+                5953314519326725971,
+                2460525150553278029,
+                11681366442196271929,
+                13839600132385646352,
+                113383513506153630
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                1527352026926141681,
+                12617830186665518684,
+                5622132317431699468,
+                14882908472981965327,
+                15211181011732351686,
+                14248915691425755
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                2577997943567592473,
+                16958712512281278999,
+                18373988673743867376,
+                1348931365515590120,
+                1986300564409073211,
+                13953616422969079
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                16842575512611163473,
+                10655443047935020378,
+                7878954556321788637,
+                1968530637152415969,
+                202145148920818114,
+                13222880307005657
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                13067473471798631885,
+                13938148869230994574,
+                3115082492836968597,
+                11400786403065070316,
+                1883862614857295493,
+                83928220612126841
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                17605960498168628050,
+                17969104893274987550,
+                10418217212353908904,
+                4987058372104350566,
+                12448656325047129826,
+                62963035318261194
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                3078470611384958314,
+                4413755912942246759,
+                750742181004856643,
+                13821840048871745526,
+                13216089667738465849,
+                47281717010353681
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                13763596969283143044,
+                5530717344412727136,
+                15454224363957525796,
+                10549420451010802212,
+                14650903317282910649,
+                26904675517849057
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                4947644019679174142,
+                6534083052695096502,
+                13239683583986223688,
+                15227822017781605999,
+                2658773582145823281,
+                56116538833712993
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                8946202365348926404,
+                14212240430317148619,
+                15613165344734469765,
+                9992155823741325756,
+                16834241369546561066,
+                107281896680048155
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                751527745578079911,
+                8072068032106593024,
+                2876667123372394216,
+                14215841253754481695,
+                15438650857105410114,
+                28120895635240870
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                9164423333409346863,
+                12011696451824247402,
+                231072749748622969,
+                14103045076591472609,
+                1282647696746321295,
+                58918620559485857
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                5004421985147823626,
+                2573318008678075657,
+                10273177553596713437,
+                17252471750070210926,
+                16953480744388962579,
+                1759963458251224
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                5959751173977558222,
+                702214408375382735,
+                226411428941730907,
+                12293609479848396353,
+                4453995268999070042,
+                111261417871985131
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                10364440307703516642,
+                15866266424119148380,
+                14566294442491425460,
+                17292111270762807816,
+                9562659904494093477,
+                109832742431532461
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                7683859901473725253,
+                7264347849949976049,
+                15939476389377243258,
+                15354253381200799324,
+                13813043944392608311,
+                59267022041290214
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                883443804593946078,
+                9400327234279448445,
+                11765981910368738889,
+                508577176402969608,
+                11509920764915353236,
+                51654107924458759
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                10375599804396002116,
+                9333786718995997429,
+                16571250217248144763,
+                14051236502179013046,
+                5182808725952192773,
+                43230177909152068
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                2078530443540662781,
+                3746801315031118350,
+                15961666065118014754,
+                17415357766122583566,
+                2651615479629850904,
+                112152259170504612
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                16619398943868134559,
+                6031858422497059612,
+                10355109563097618966,
+                12345070398234396898,
+                9248151468621788608,
+                108338480547832054
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                7470490227543006538,
+                2212790970550746464,
+                6798789289913375336,
+                16961348701650702093,
+                16207582218147848304,
+                103694082716869734
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                16440997769263095152,
+                10087648503287577215,
+                4479656183365359012,
+                1383450347849827012,
+                1743635574831490042,
+                42211205078180454
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                1248939357627541637,
+                12334340488799307327,
+                11050748069796731115,
+                15173202753074947188,
+                11331412456882142726,
+                81228900212055570
+            ])
+        ),
+        field!(Fq, BigInteger384([0, 0, 0, 0, 0, 0]))
+    ),
+];
+
+/// The monic denominator of the x-coordinate map, of degree 22.
+pub(crate) const ISOGENY_X_DENOMINATOR: [Fq2; 23] = [
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                14348201799464016403,
+                5345530482888211488,
+                15300263874664864081,
+                4265511041530511374,
+                15249345162695864298,
+                25031274796861489
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                8440798489617332678,
+                11011231930086363131,
+                804488711656470060,
+                4622929873429674438,
+                7988729274563862581,
+                82739376277775074
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                17104008270047982942,
+                40020278302693076,
+                12673884392750208795,
+                14464750299511996908,
+                2390028639894982667,
+                113147598588883398
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                1288586100218885920,
+                16223098148620315990,
+                15645835791751513527,
+                17271037094558132372,
+                1828514540494183916,
+                1423626556625992
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                1229310450449546237,
+                12114625799017959152,
+                15339345219640600442,
+                16649397516875771671,
+                1951500852635748179,
+                54943854068273598
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                5788537027069648421,
+                2225828862149562387,
+                16569660957819272696,
+                2732938685473935837,
+                15616808126107775302,
+                95110274375464739
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                2815963408187475160,
+                12258089403100170045,
+                6535857164577251301,
+                9470376057254281543,
+                1937576270569829724,
+                94507070191761163
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                17571840641419015673,
+                9004263380807131968,
+                13335704850947128556,
+                6608379968214798888,
+                15674368509350348700,
+                25921899604765599
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                9616754985538393929,
+                6369692181680499578,
+                6665397337912921869,
+                2666003343626135197,
+                16261314941284260789,
+                14774547389927328
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                13409309201495952660,
+                17334563929720105504,
+                3433673526856355215,
+                2484393657493077029,
+                6475602818318873160,
+                88872697066418495
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                4807219156035824119,
+                16044213486753212677,
+                713323056805675958,
+                2548494061050511915,
+                9321854448324940180,
+                10422039933426557
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                2256745991591962086,
+                8238660810696569938,
+                3849020255146227728,
+                7883468733584466765,
+                7085549962179441843,
+                85370937896226908
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                855457667505569775,
+                15158018881980587066,
+                2233118045102211776,
+                18005196151429599234,
+                4268939968422522106,
+                84126428264981803
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                14986505588927654923,
+                3843436241626576214,
+                1053417668508550454,
+                12931492124699917875,
+                11698988927212044527,
+                3618127368020782
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                15106400050639076735,
+                8300395348593905614,
+                1579541311827400104,
+                12161822783660867519,
+                10341536359903271623,
+                50599419915217416
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                12242633247270407234,
+                3144502350324332227,
+                7413339887191830295,
+                11769869800909962475,
+                14670074106412577117,
+                1445430185489483
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                17137392650956890159,
+                977119362256607708,
+                18376262949902828900,
+                16953909530046067235,
+                1977345367858331305,
+                110627179409605576
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                14062210831635100345,
+                9908941140850376074,
+                9893272904349427195,
+                92694501117724104,
+                11788227534313650934,
+                57981727976712383
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                17013291014438194949,
+                372701538569631996,
+                14289081819044740513,
+                12569985681035845447,
+                9932043510010431173,
+                83537306933524944
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                16518584450030417021,
+                14208311988244397085,
+                2358655402010447141,
+                3053640946492559907,
+                2453219419091012772,
+                115757997061089435
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                7237936327255267965,
+                1934106006736176015,
+                7548566657507884795,
+                16252005922669332272,
+                17381210877727555877,
+                27330211054928838
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                11271604587456699539,
+                10466847359264211202,
+                5990732765804599608,
+                2370892264849901222,
+                2309190046560388246,
+                109964441002386620
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                3196980252568571073,
+                3610729734663515755,
+                2259130831302127055,
+                952911926954559230,
+                6172990861295684551,
+                4320683715144018
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                13650319016646049057,
+                1517708832490649388,
+                13225263102845151382,
+                12699695981436960232,
+                1967966016195510955,
+                119724771440137723
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                17619671259457831619,
+                4641007235968116004,
+                10463877573507389539,
+                17174646329699626768,
+                6477852046420626821,
+                9760859427674230
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                7120509742198509131,
+                11726152360819333486,
+                1328285325209213872,
+                12454020702300988613,
+                12631244311143398132,
+                86278155672793372
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                10326031260868827354,
+                11695458021565647745,
+                7507506386892093520,
+                1297492558400938649,
+                13144216399836418366,
+                48681109340226513
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                17932717054636504365,
+                1217937716408816058,
+                7105120100581595658,
+                17226345283910213406,
+                3109007460481223569,
+                34642377548138525
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                14513267621987303147,
+                1184147169724294024,
+                286263514069511114,
+                17506687518523098993,
+                6572302618252714091,
+                106491666224988807
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                13680577414012678366,
+                10378696974886989596,
+                16292228325137342176,
+                1761227238746427448,
+                10352044238222782840,
+                40300469033132354
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                3710225909195056824,
+                17928498423875567393,
+                14087055862466251146,
+                704821242145477924,
+                7939826888043070139,
+                54396598574211863
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                5275453594354451283,
+                3230305742202181611,
+                9276815854763568545,
+                10972606492088119580,
+                3099845833589320701,
+                80406231951866356
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                7450185274573717082,
+                13452501200489697908,
+                8236909245009974360,
+                3980398804470606804,
+                6960864736470945943,
+                29231941897984495
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                10641895136459801627,
+                10412738738524080324,
+                11913517905615905632,
+                14107236787623709703,
+                13360122321735109334,
+                74286675923378815
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                6463041235175212281,
+                8361355811184911458,
+                17847858176633717272,
+                13665044862432112678,
+                8145328888468069296,
+                21002140478107575
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                17621967426137832801,
+                17296060252841307450,
+                13262262240126312668,
+                2893715445524490736,
+                12045413424737238296,
+                3035900180279217
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                6926331215924779918,
+                8896585490992609981,
+                12102327951581917127,
+                5981789724445948641,
+                8218071260877165009,
+                43875134497784941
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                7171795599192900891,
+                5734948425692016524,
+                6544303098628122409,
+                827431814982887036,
+                2244939340351170171,
+                27402942379254156
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                10013446980108053223,
+                1541580737963742636,
+                6086429926955631957,
+                14253071601838086452,
+                2359356456696619979,
+                79659807789273061
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                16320552046509221538,
+                850735577288588760,
+                1218763725232533029,
+                2453408109015556602,
+                17381221476959867263,
+                25459983848462806
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                877355973029906085,
+                244556833479759183,
+                904318056584818900,
+                4386474174480890263,
+                189001580400562913,
+                53388736351344758
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                18013482966349604506,
+                10584867217367561076,
+                6380292591133891297,
+                9089783456791582848,
+                8638049597020791973,
+                10976573224443225
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                12401609659373852257,
+                15424360936973217773,
+                17494181339203128843,
+                11490986296404855083,
+                2817191264125917160,
+                67062228845148567
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                3267955163368451140,
+                4605713336743531697,
+                4914489311593264600,
+                16211257161116227264,
+                15496380349340262589,
+                106521305181299999
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                202099033278250856,
+                5854854902718660529,
+                11492539364873682930,
+                8885205928937022213,
+                5545221690922665192,
+                39800542322357402
+            ])
+        ),
+        field!(Fq, BigInteger384([0, 0, 0, 0, 0, 0]))
+    ),
+];
+
+/// The numerator of the y-coordinate map, of degree 33.
+pub(crate) const ISOGENY_Y_NUMERATOR: [Fq2; 34] = [
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                11996914051734258412,
+                6886387127568183313,
+                3941981799359162260,
+                9401787036936333981,
+                4821149333977690241,
+                95079946196301298
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                15860135581715610416,
+                18418038011229406416,
+                17633034005100608531,
+                18334126870474809290,
+                16563736905669212561,
+                108805957187515520
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                11339334873478373464,
+                1056466197529371753,
+                4428386965084339900,
+                3149995958822731156,
+                15496683332930641221,
+                108151712506686425
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                4069102546680495203,
+                14592421650545863505,
+                15566499131225171357,
+                7233433024436022064,
+                15820164780528989270,
+                96608318617296031
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                17989074170497768337,
+                3254836881004804273,
+                8780742521817828676,
+                9264069998243827635,
+                15874754936356861116,
+                53018810056714873
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                2507289493916865313,
+                12875275143306265438,
+                6235935325220287028,
+                2815929110028679181,
+                14022411545242954854,
+                31847433233719486
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                11102872677636940477,
+                7259707491832120635,
+                15762106554929055417,
+                11953669837448425094,
+                4761174250838394276,
+                17635020192800663
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                15874048484349590673,
+                17591859345286434981,
+                8457076332020657222,
+                10663024994399394002,
+                14571747165050062726,
+                62051513844206108
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                11695426920984648587,
+                11690536024817120266,
+                6633236711355766548,
+                18396992161179779635,
+                7419064272605241377,
+                55231840961193648
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                10840807159760830351,
+                1326490405818733097,
+                1520780634852580720,
+                13393053136397230758,
+                14241474860870879624,
+                80481993218251934
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                14420826970649571229,
+                18257487363061310954,
+                11622100627988438784,
+                9508041610872163909,
+                8824157024147752754,
+                66782690677549343
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                13750549867477603980,
+                7510034172164544690,
+                6231330371534734891,
+                5329617852897837527,
+                8083068996855383521,
+                58692362542589938
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                16345564982820066502,
+                4207181646194691128,
+                15029594472292936106,
+                12591672117879213489,
+                795041589373292776,
+                57639416820579205
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                780099363688164965,
+                1190146702459771775,
+                12590277901163311301,
+                15301503950831420791,
+                16499149757507309812,
+                101078530717074829
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                6048981421534693957,
+                15640999761855377014,
+                6546657925511547403,
+                12146680749520167217,
+                16155181126802204732,
+                9853166761694465
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                16998849525295446260,
+                11980299004477087302,
+                17009915509469448058,
+                11967832189956236103,
+                15558520070113938902,
+                51152855962335965
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                16020931897242435710,
+                17585285318325959955,
+                4674728859838530233,
+                3883347444093044059,
+                6760340194406812961,
+                55684490782722542
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                14626544580710011504,
+                16296989600861843697,
+                14857084669370187501,
+                462644735859225998,
+                16231287002772015034,
+                26968977160529875
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                2017259349964914479,
+                3599455504996683138,
+                16684691980606550424,
+                16154269151954619932,
+                15546960231329905358,
+                5100327457806001
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                10214810160054943379,
+                16034457285259150780,
+                4670073961982201885,
+                10373722150971459922,
+                8953018177921212459,
+                48287538248108705
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                13472006497959052511,
+                11416973509652293228,
+                5875224804626305231,
+                9562140905613586351,
+                3138472965658714601,
+                91142203463451398
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                6000453792777317104,
+                15708987270215620082,
+                17833198211980426945,
+                6273190625397484222,
+                9387649722174983609,
+                104787625754408294
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                7288261672081476762,
+                16217800225117887447,
+                11236883938364058336,
+                18427969374723510042,
+                8469132619369590515,
+                74054116155603032
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                4313310080795007812,
+                2587793544709500204,
+                6985074077767041329,
+                11055383234049494306,
+                1283762961433166234,
+                104902545141064395
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                7993494929485976454,
+                17376797434450263184,
+                5177930655831835859,
+                12752419042752073901,
+                9448010161429686961,
+                4315352976829719
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                14182484787363425330,
+                3450213921486870067,
+                2755396009612746499,
+                8418076148803341087,
+                13607255033443296150,
+                81458326900509550
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                5289475640354694636,
+                9385985332318473304,
+                17797251168357397866,
+                10481353310954122908,
+                5315967765918820932,
+                25323591073887549
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                15791037873778292779,
+                17844521475525351084,
+                8499157829914914524,
+                2840315810500036071,
+                2722437425751037747,
+                16011143179930529
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                5477823353254638466,
+                17778827534129284810,
+                2756735436985947165,
+                7127984666551878583,
+                6690503046569483050,
+                96033936009312486
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                3395134933619889545,
+                1215225499808325974,
+                16929780339799761964,
+                7823270481237532786,
+                6493614714185228682,
+                24785883663496687
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                15261574825579365460,
+                14037882152347721091,
+                17141224225826258512,
+                4160234570397142671,
+                1699397237194983682,
+                6412950158164694
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                14296264436457617127,
+                16491024139605507866,
+                2266161023980416493,
+                15337327919373033885,
+                5162444010553784174,
+                119281517031243149
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                9842512819058750210,
+                1178488728862458580,
+                5642467680649482575,
+                12314620828354855581,
+                2903682042052038424,
+                26491840926605033
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                1010675346772830331,
+                6697010119462521550,
+                12059528697930240578,
+                9911579373221817952,
+                1040547064399876770,
+                53586659256112212
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                10076955312006223293,
+                14722948296014164367,
+                18344438488627049169,
+                13942571113437846720,
+                11715678986642927195,
+                40352153811753343
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                14494931517840154292,
+                7676333002429969388,
+                17560598257385859380,
+                16747758924058221053,
+                11515700215738493689,
+                65549329867130045
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                10397302443141077903,
+                520003821047311099,
+                1831464178304927621,
+                4002294175423221350,
+                10524386173280950622,
+                85131997189258235
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                1702659853877214952,
+                5175064617831957976,
+                15437280645431745097,
+                15366696708534568279,
+                9196709844837790194,
+                68901706808475423
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                9863943863656094492,
+                7372594049937292014,
+                16475925562855063286,
+                11861262416306790580,
+                7078780258048940526,
+                101408201890991029
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                2343293284904573778,
+                5339289153243209542,
+                7067695261673627903,
+                8063545910680282410,
+                14840675886462820842,
+                56642596037985500
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                17343735678474383597,
+                11772798374760832903,
+                7485003258200308214,
+                420978279018806566,
+                15106473667436876616,
+                67423441753944598
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                3129392164217902809,
+                9304966762998897363,
+                6740449491677911252,
+                1428532083156098929,
+                13250609186286867012,
+                501984267615447
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                7126878956382876583,
+                3644184761223685278,
+                10554023856223593129,
+                13179123510226371347,
+                5700920201128637212,
+                9916563341611559
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                747349861740912741,
+                15737134727694488703,
+                2142147477832932111,
+                3381099254716594004,
+                5433287699638235569,
+                47728766036818168
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                8839953237282981672,
+                10074308997131171031,
+                11691989369009902972,
+                7958369339684431060,
+                15121980702897961565,
+                93088697252590985
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                6603856701373143803,
+                1297791262819851879,
+                765327344126997267,
+                18180973208681843695,
+                1476518685227600443,
+                104541123578637131
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                7838130985647943306,
+                15325192161810886135,
+                1928107942073978116,
+                762167866006390212,
+                8243043467844129336,
+                99207763692339632
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                16949740157180672937,
+                767609961022249114,
+                6228165858248321517,
+                15506874566981977927,
+                10223007423409202377,
+                28179717855488848
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                14253426969621677825,
+                14084212442939316389,
+                12124084435180523658,
+                15235703783790826126,
+                5878934019852293942,
+                82549350109162910
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                13963661688707114297,
+                9591128080310070989,
+                14990940951763184163,
+                1287023747833705800,
+                10562277013394024787,
+                38147656574537154
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                15845289486711737755,
+                14885890001945261832,
+                8743843808210176626,
+                16435831215214465189,
+                10173870915443483834,
+                61492653020427084
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                13409298027003619761,
+                13459774761702940559,
+                678855050370887179,
+                18092867024928083278,
+                14734016589645233358,
+                26653872373149126
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                16112984368725632180,
+                11269896170245043675,
+                8234055634949584248,
+                13861300090726454240,
+                14843421230274558647,
+                73876837508691075
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                7022659731132329286,
+                14285032285356098046,
+                15217457767132367665,
+                6270227086682450123,
+                12540695551170551580,
+                40459625364037322
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                16956099778251229812,
+                8252572361594213553,
+                14861434819327376263,
+                15746175146250123659,
+                1384464380777251358,
+                87375193196674459
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                6485138280221184743,
+                4659377526535273226,
+                16148211990153953156,
+                17626058317619187389,
+                2053259578229113149,
+                45949747148339567
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                9757345625186692070,
+                7161767505250147325,
+                808308042488257250,
+                3843821016007371458,
+                5913611730791153523,
+                49083504294351855
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                10285420891103714700,
+                996090703073369266,
+                3598435674949151138,
+                12474202578862044472,
+                15386089965024430754,
+                80673115807132197
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                6907376588766164755,
+                15336127764630504711,
+                11691517285189224964,
+                2294242797909343421,
+                5599538800412068393,
+                99243584495579795
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                10781397468063133412,
+                14689680182904656109,
+                12572790791669427929,
+                13541049172151708602,
+                2951397279124684362,
+                75661616099800834
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                10928295849777803860,
+                14957493851251832971,
+                8029078691526945863,
+                7370200620864824586,
+                13585667627620447293,
+                103449333140259745
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                7707773656480449290,
+                6552288539838349802,
+                16661887440190675328,
+                3451141370468486067,
+                5335486164657102706,
+                81720389691212550
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                11695652416623129199,
+                15206191675615181029,
+                13581786561320387586,
+                4371169920500320382,
+                1405851883413471240,
+                70346273121357217
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                3238040432111999033,
+                12812708161679914238,
+                11584127220738285589,
+                17591806673592488260,
+                4232731611996021971,
+                70395254233305386
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                11321929822519593706,
+                2513461909038031783,
+                11366540615436059833,
+                12297306311227938608,
+                15303986546302314126,
+                55630682493919071
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                2581015883657561094,
+                16963961366941489160,
+                9719922603943661316,
+                12544185835392611998,
+                4180881851697505766,
+                58019293861364597
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                18250508560718013179,
+                7486164413457419330,
+                8732750857092323232,
+                457033717118918321,
+                12167888022606617966,
+                20277559260742340
+            ])
+        ),
+        field!(Fq, BigInteger384([0, 0, 0, 0, 0, 0]))
+    ),
+];
+
+/// The monic denominator of the y-coordinate map, of degree 33.
+pub(crate) const ISOGENY_Y_DENOMINATOR: [Fq2; 34] = [
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                10382853317280112663,
+                165674687570063694,
+                9854414292789995950,
+                10669610363865676761,
+                4543722422550991520,
+                18868630496189498
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                12054601780822975147,
+                14000322165118069783,
+                3597959547142963660,
+                2726295465157978782,
+                14647894131620914090,
+                13304148685780455
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                12010512317508597713,
+                15142194732470646275,
+                10340767267134283146,
+                10928578567265157293,
+                10180774764781167341,
+                59733664098305663
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                6113980226039321823,
+                10608008589885501426,
+                6458185282017862240,
+                11255228750334784182,
+                3540901586611494079,
+                80642469610667378
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                11967266175570580175,
+                13662700299023263406,
+                11878658722646499965,
+                14730405170171677340,
+                9491704727661470114,
+                117092788071634957
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                13257378324785597590,
+                7119604904691910382,
+                11718173767350188898,
+                15549634677160987117,
+                4832264521883466243,
+                18194115406194223
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                3937897211963386599,
+                6081997773408601591,
+                15526209853176595229,
+                7310592398615742310,
+                7811587245430700443,
+                85161541326362606
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                3264782107993454832,
+                9744395236960022504,
+                9604146070019909729,
+                11953145584032134790,
+                17908213813103852405,
+                23879117094533151
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                3813417174425150637,
+                13057339048563419494,
+                5177259307270775970,
+                9881362050996629125,
+                2487745624396641591,
+                110499085936113071
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                9767044543632758488,
+                14160599490889047676,
+                10661338042279288637,
+                8106528522133050162,
+                7094863086408744015,
+                111450296580330893
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                13258495913727899394,
+                2736847238756511066,
+                15978113793954930905,
+                7090380151365394917,
+                14533224273147042865,
+                38685994470685543
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                12068857377461101872,
+                17791629813777470795,
+                3755739007898085473,
+                6450227762124193955,
+                2684578653030470141,
+                336461723974075
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                2927913821038948438,
+                9744511948214608841,
+                6496208095645240908,
+                11158270453661436113,
+                6473304512102555541,
+                60909014587381269
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                1480467517240566653,
+                9062676688871456083,
+                7596988125873921520,
+                10553820256087544936,
+                8317923626668867489,
+                12513591452010115
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                2482104690008119054,
+                14772418099416188190,
+                6723620940559622818,
+                6833412807179994991,
+                16482075143747545438,
+                91143826635231424
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                14794542522217078784,
+                8491612876790922117,
+                7283213750794453941,
+                3294467446926105329,
+                15528483909876504028,
+                29482223304050197
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                2562693267452005973,
+                3832508759376009859,
+                17877244022393491420,
+                9626950875924710466,
+                269616351492701975,
+                70397699360816818
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                12267769602281514934,
+                11029887437686043291,
+                13035115694005361935,
+                6881833506413343819,
+                1530966501661824482,
+                50515446640659899
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                15006136374093879783,
+                16889027064303682543,
+                4714029020353818482,
+                2278506581538751553,
+                15595546295891335176,
+                99795936330194184
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                4786276769738457928,
+                8526832965976926262,
+                4728295359368405454,
+                1745832807419327585,
+                6110045369913342758,
+                112688924729883219
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                2810309433444737910,
+                3511338807873211071,
+                4660360873796277657,
+                11614360974044791452,
+                679000048132412591,
+                98095390263988468
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                14545500807368856832,
+                5109773033254726671,
+                6276824060277909008,
+                5019000451870774787,
+                2809426895180248534,
+                105789593728570765
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                11343770470453577153,
+                16741892892419181242,
+                11171321899367539153,
+                11235479123544912811,
+                58864260369713568,
+                23328063502836899
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                2632257443749131156,
+                1598376195185218122,
+                9663796512018621485,
+                2680123341039210537,
+                1444034033536323781,
+                61598674131570008
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                7478148471125706371,
+                17199172235510247647,
+                8625039513451435922,
+                15193612340979805898,
+                12168268534412771708,
+                55664989560240451
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                7034271288938427242,
+                4794349003912415697,
+                16453360751804975014,
+                15789432819455568437,
+                15707652303800886494,
+                60090495591968591
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                10614382393724343727,
+                4662671645292455508,
+                15145755433255107954,
+                10165517783139774707,
+                13541121796948910080,
+                62790798457299395
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                546247987570727649,
+                2326206307323760893,
+                6916189983242444108,
+                17245791459491032486,
+                9714802556180878640,
+                45723572039551479
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                13627053649006304188,
+                10407804347486821690,
+                1809829365024371155,
+                507055348519557675,
+                17739193215015056293,
+                100544171006488241
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                16000944678147778596,
+                11495098734092161267,
+                8893190347298301741,
+                1197487471332419400,
+                453557888139574409,
+                14549798187271467
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                17335160318297761821,
+                6731079601906316462,
+                14854106312699104278,
+                7445245093220684009,
+                13101785163224645338,
+                110180668545495634
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                12925051829120699917,
+                18340452527006238869,
+                6453760743574692317,
+                11878856494068564756,
+                10871573853906988104,
+                112759973739324702
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                15368679350276373120,
+                7937600435588201468,
+                7520862636971431510,
+                17373078708853573916,
+                12551242201814336479,
+                19433457601970887
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                7243919428047302190,
+                13596103551773719290,
+                13301869474991954881,
+                14019459343270955652,
+                11125041559702814955,
+                17365840655081775
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                16278888158259135329,
+                16569346617338820401,
+                12072307301895081465,
+                14518307566806433162,
+                12010589986914104488,
+                71848768335617518
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                11000436204231462447,
+                18433995902277183055,
+                8337968970205719895,
+                9724476314847326957,
+                7597511486381802680,
+                85552860320137561
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                5239891638243110983,
+                11931979516305273676,
+                8173744451379779464,
+                15485495975798380670,
+                6088289765294704779,
+                33040780955432708
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                4445866839729477824,
+                7862876770318323994,
+                5393196914865031352,
+                2671049853610471418,
+                3594172586411040464,
+                24581700936844393
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                2960573020502453555,
+                8154129666031937957,
+                8929681834231813030,
+                1040932329205534025,
+                7393705138288987031,
+                104423149152440780
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                14456447082395567195,
+                7693386595817069596,
+                13321814329057988504,
+                1879337221391150314,
+                15759801975951964124,
+                55906182203412811
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                9661186005104469789,
+                16968506827449802324,
+                14873583232382271545,
+                6383941577993046299,
+                9540625154535530365,
+                43535605357577990
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                12853656747002083614,
+                10648825763703287467,
+                7595464540100838808,
+                9965483464587086981,
+                2796036559303149556,
+                36633871639993657
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                16223129715417693813,
+                10827510728064501267,
+                11506124412784584663,
+                13052324019271386617,
+                12347517062525045668,
+                44563754562324610
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                10365231223289648719,
+                15344586867036551868,
+                13157137522944361838,
+                11280719636599578943,
+                10746640454936589624,
+                118919323290964878
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                12890033433497691332,
+                14001228313369662839,
+                9638903016358307835,
+                7017111127859193491,
+                13707782334882980175,
+                81879187565082487
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                12569920159388874116,
+                10160207265429218740,
+                7379838134550709981,
+                11520754881731091365,
+                5584627786513274320,
+                94590454301104615
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                3441074365646160144,
+                1059990188214035283,
+                5637050643824202623,
+                15215811716038879284,
+                17703070640444591940,
+                92200539040170706
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                5167557751533227451,
+                18152635399826580456,
+                6090541112913479601,
+                4595774761758511048,
+                7476355549288128517,
+                39447380557319729
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                172236199685881081,
+                13838002422123386343,
+                11763154629757115896,
+                8790420209224063806,
+                6746579470602162929,
+                68417985884146178
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                3505355809360720684,
+                4895786634168953509,
+                15743914298308876501,
+                11522741421111748377,
+                10957277067164332317,
+                39870709188875990
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                6798001026402375290,
+                9977845732611536932,
+                12042496818399315490,
+                173371586099968222,
+                14088711363756271582,
+                60233653576368872
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                16455958866431413825,
+                13927408670146467032,
+                15940195822300150314,
+                6984138056062390892,
+                3636229704322701715,
+                77707536571269095
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                6204407898338650169,
+                14670977350964178808,
+                2593364116410547344,
+                14514896491622361576,
+                13774337158767376818,
+                68715271668861737
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                13637421935732406295,
+                9070769465928703008,
+                3146496598630540667,
+                9548604083112805971,
+                2943467627375920281,
+                63031327817817706
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                4159112513459663770,
+                5621242747220415673,
+                14292645848909997132,
+                8346152326550713220,
+                7889389277982100004,
+                89703486393249706
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                13066404057473371293,
+                9373859683663217562,
+                12304459086178502443,
+                15522513285367570263,
+                6677844669720553950,
+                58991588180213232
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                17916751890290714788,
+                13768404094835280883,
+                11544106979802349902,
+                11469154322751889633,
+                7209506882916515866,
+                115761591707661767
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                15130762135081490810,
+                5594649779757381729,
+                15951100811546387559,
+                6799839403170539842,
+                18110133228002466229,
+                60391830493033530
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                2176134527764247757,
+                5000833614038591965,
+                16080808350022511566,
+                2103739733147921632,
+                1120445889524848864,
+                93323714299045147
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                17181810649556595352,
+                4062083263115797777,
+                11572289893870247371,
+                15235927388345635050,
+                5152628283216901009,
+                86119873921802931
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                12845379111485457258,
+                3276333694183317074,
+                14072239339405889517,
+                605885086025910278,
+                11139657238217399389,
+                78617652584079074
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                14440425893552292755,
+                11223519512395010872,
+                5039953953058335323,
+                11903827132785987640,
+                7091668339867420162,
+                76061704318430908
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                5935287264961779567,
+                17654721142034598628,
+                5287523227843065845,
+                14270439746265882894,
+                15599240508269374378,
+                47757831565806792
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                11182352719490507774,
+                6704021030901581904,
+                10774266551352376751,
+                13094617543377168671,
+                1579833888655932985,
+                86168177791547489
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                4585980995660685713,
+                13082907651074738148,
+                6679410836293800209,
+                7071453791797191674,
+                6307150449468636991,
+                40044186914475501
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                4539181868816818789,
+                5248046570054672137,
+                5141499769787214020,
+                13210206472908934801,
+                18183925593715140577,
+                38683645065455300
+            ])
+        )
+    ),
+    field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                202099033278250856,
+                5854854902718660529,
+                11492539364873682930,
+                8885205928937022213,
+                5545221690922665192,
+                39800542322357402
+            ])
+        ),
+        field!(Fq, BigInteger384([0, 0, 0, 0, 0, 0]))
+    ),
+];
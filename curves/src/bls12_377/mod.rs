@@ -44,6 +44,8 @@ pub mod g2;
 #[doc(inline)]
 pub use g2::*;
 
+mod g2_isogeny;
+
 pub mod parameters;
 #[doc(inline)]
 pub use parameters::*;
@@ -18,7 +18,7 @@ use crate::{
     edwards_bls12::{Fq, Fr},
     errors::GroupError,
     templates::twisted_edwards_extended::{Affine, Projective},
-    traits::{AffineCurve, ModelParameters, MontgomeryParameters, TwistedEdwardsParameters},
+    traits::{AffineCurve, Elligator2Parameters, ModelParameters, MontgomeryParameters, TwistedEdwardsParameters},
 };
 use snarkvm_fields::field;
 use snarkvm_utilities::biginteger::BigInteger256;
@@ -108,6 +108,19 @@ impl MontgomeryParameters for EdwardsParameters {
     );
 }
 
+impl Elligator2Parameters for EdwardsParameters {
+    /// ELLIGATOR2_Z = 11
+    const ELLIGATOR2_Z: Fq = field!(
+        Fq,
+        BigInteger256([
+            1855201571499933546,
+            8511318076631809892,
+            6222514765367795509,
+            1122129207579058019,
+        ])
+    );
+}
+
 impl FromStr for EdwardsAffine {
     type Err = GroupError;
 
@@ -18,7 +18,7 @@ use crate::{
     edwards_bw6::{Fq, Fr},
     errors::GroupError,
     templates::twisted_edwards_extended::{Affine, Projective},
-    traits::{AffineCurve, ModelParameters, MontgomeryParameters, TwistedEdwardsParameters},
+    traits::{AffineCurve, Elligator2Parameters, ModelParameters, MontgomeryParameters, TwistedEdwardsParameters},
 };
 use snarkvm_fields::field;
 use snarkvm_utilities::biginteger::BigInteger384 as BigInteger;
@@ -118,6 +118,21 @@ impl MontgomeryParameters for EdwardsParameters {
     );
 }
 
+impl Elligator2Parameters for EdwardsParameters {
+    /// ELLIGATOR2_Z = 5
+    const ELLIGATOR2_Z: Fq = field!(
+        Fq,
+        BigInteger([
+            9871116327010172167,
+            9167007004823125620,
+            18338974479346628539,
+            5649234265355377548,
+            13442091487463296847,
+            77904398905292312,
+        ])
+    );
+}
+
 impl FromStr for EdwardsAffine {
     type Err = GroupError;
 
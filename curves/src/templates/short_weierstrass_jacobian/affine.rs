@@ -19,7 +19,7 @@ use crate::{
     templates::short_weierstrass_jacobian::Projective,
     traits::{AffineCurve, Group, ProjectiveCurve, ShortWeierstrassParameters as Parameters},
 };
-use snarkvm_fields::{impl_add_sub_from_field_ref, Field, One, PrimeField, SquareRootField, Zero};
+use snarkvm_fields::{batch_inversion, impl_add_sub_from_field_ref, Field, One, PrimeField, SquareRootField, Zero};
 use snarkvm_utilities::{
     bititerator::BitIteratorBE,
     rand::UniformRand,
@@ -186,6 +186,55 @@ impl<P: Parameters> AffineCurve for Affine<P> {
             y2 == x3b
         }
     }
+
+    /// Adds each element of `other` to the corresponding element of `bases`, in place,
+    /// using the affine addition formulas with a single batch inversion of the slopes.
+    fn batch_add_assign(bases: &mut [Self], other: &[Self]) {
+        // Compute the denominator of each slope, or zero if the sum does not require one.
+        let mut denominators: Vec<P::BaseField> = bases
+            .iter()
+            .zip(other)
+            .map(|(a, b)| {
+                if a.infinity || b.infinity {
+                    P::BaseField::zero()
+                } else if a.x == b.x {
+                    match a.y == b.y {
+                        true => a.y.double(),
+                        false => P::BaseField::zero(),
+                    }
+                } else {
+                    b.x - a.x
+                }
+            })
+            .collect();
+        batch_inversion(&mut denominators);
+
+        for ((a, b), inverse) in bases.iter_mut().zip(other).zip(denominators) {
+            if b.infinity {
+                continue;
+            } else if a.infinity {
+                *a = *b;
+                continue;
+            }
+
+            let lambda = if a.x == b.x {
+                if a.y != b.y || a.y.is_zero() {
+                    // The points are inverses of each other.
+                    *a = Self::zero();
+                    continue;
+                }
+                // Double the point, with slope (3x^2 + a) / 2y.
+                let x_squared = a.x.square();
+                (x_squared.double() + x_squared + P::COEFF_A) * inverse
+            } else {
+                (b.y - a.y) * inverse
+            };
+
+            let x = lambda.square() - a.x - b.x;
+            let y = lambda * (a.x - x) - a.y;
+            *a = Self::new(x, y, false);
+        }
+    }
 }
 
 impl<P: Parameters> ToMinimalBits for Affine<P> {
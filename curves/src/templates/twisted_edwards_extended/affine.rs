@@ -19,7 +19,7 @@ use crate::{
     templates::twisted_edwards_extended::Projective,
     traits::{AffineCurve, Group, MontgomeryParameters, ProjectiveCurve, TwistedEdwardsParameters as Parameters},
 };
-use snarkvm_fields::{impl_add_sub_from_field_ref, Field, One, PrimeField, SquareRootField, Zero};
+use snarkvm_fields::{batch_inversion, impl_add_sub_from_field_ref, Field, One, PrimeField, SquareRootField, Zero};
 use snarkvm_utilities::{
     bititerator::BitIteratorBE,
     rand::UniformRand,
@@ -215,6 +215,25 @@ impl<P: Parameters> AffineCurve for Affine<P> {
 
         lhs == rhs
     }
+
+    /// Adds each element of `other` to the corresponding element of `bases`, in place,
+    /// using the complete affine addition formulas with a single batch inversion.
+    fn batch_add_assign(bases: &mut [Self], other: &[Self]) {
+        // Compute the two denominators of each sum, which are nonzero as the addition law is complete.
+        let mut denominators = Vec::with_capacity(2 * bases.len());
+        for (a, b) in bases.iter().zip(other) {
+            let dx1x2y1y2 = P::COEFF_D * a.x * b.x * a.y * b.y;
+            denominators.push(P::BaseField::one() + dx1x2y1y2);
+            denominators.push(P::BaseField::one() - dx1x2y1y2);
+        }
+        batch_inversion(&mut denominators);
+
+        for ((a, b), inverses) in bases.iter_mut().zip(other).zip(denominators.chunks(2)) {
+            let x = (a.x * b.y + a.y * b.x) * inverses[0];
+            let y = (a.y * b.y - P::mul_by_a(&(a.x * b.x))) * inverses[1];
+            *a = Self::new(x, y);
+        }
+    }
 }
 
 impl<P: Parameters> ToMinimalBits for Affine<P> {
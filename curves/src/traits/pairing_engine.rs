@@ -228,6 +228,25 @@ pub trait AffineCurve:
 
     /// Checks that the current point is on the elliptic curve.
     fn is_on_curve(&self) -> bool;
+
+    /// Adds each element of `other` to the corresponding element of `bases`, in place.
+    ///
+    /// The sums are normalized with a single batch inversion, which makes this cheaper
+    /// than adding each pair of affine elements individually.
+    fn batch_add_assign(bases: &mut [Self], other: &[Self]) {
+        let mut sums: Vec<Self::Projective> = bases
+            .iter()
+            .zip(other)
+            .map(|(base, other)| {
+                let mut sum = base.into_projective();
+                sum.add_assign_mixed(other);
+                sum
+            })
+            .collect();
+        Self::Projective::batch_normalization(&mut sums);
+
+        bases.iter_mut().zip(sums).for_each(|(base, sum)| *base = sum.into());
+    }
 }
 
 pub trait PairingCurve: AffineCurve {
@@ -360,3 +379,32 @@ pub trait MontgomeryParameters: ModelParameters {
 
     type TwistedEdwardsParameters: TwistedEdwardsParameters<BaseField = Self::BaseField>;
 }
+
+/// The parameters of the simplified SWU map to a short Weierstrass curve, as in Section 6.6.3 of RFC 9380.
+///
+/// As the map requires `a * b != 0`, it maps to an isogenous curve `y^2 = x^3 + a'x + b'`,
+/// and the result is sent to this curve by the isogeny map.
+pub trait SWUParameters: ShortWeierstrassParameters {
+    /// The coefficient `a'` of the isogenous curve.
+    const ISOGENOUS_COEFF_A: Self::BaseField;
+    /// The coefficient `b'` of the isogenous curve.
+    const ISOGENOUS_COEFF_B: Self::BaseField;
+    /// The non-square `Z` of the map, chosen as in Appendix H.2 of RFC 9380.
+    const SWU_Z: Self::BaseField;
+    /// The coefficients of the numerator of the x-coordinate of the isogeny map, from the constant term up.
+    const ISOGENY_X_NUMERATOR: &'static [Self::BaseField];
+    /// The coefficients of the denominator of the x-coordinate of the isogeny map, from the constant term up.
+    const ISOGENY_X_DENOMINATOR: &'static [Self::BaseField];
+    /// The coefficients of the numerator of the y-coordinate of the isogeny map, from the constant term up.
+    const ISOGENY_Y_NUMERATOR: &'static [Self::BaseField];
+    /// The coefficients of the denominator of the y-coordinate of the isogeny map, from the constant term up.
+    const ISOGENY_Y_DENOMINATOR: &'static [Self::BaseField];
+}
+
+/// The parameters of the Elligator 2 map to a twisted Edwards curve, as in Section 6.8.2 of RFC 9380.
+///
+/// The map is applied to the birationally equivalent Montgomery curve given by `MontgomeryParameters`.
+pub trait Elligator2Parameters: TwistedEdwardsParameters {
+    /// The non-square `Z` of the map, chosen as in Appendix H.3 of RFC 9380.
+    const ELLIGATOR2_Z: Self::BaseField;
+}
@@ -228,6 +228,32 @@ fn random_transformation_test<G: ProjectiveCurve>() {
     }
 }
 
+fn random_batch_addition_test<G: ProjectiveCurve>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let a = G::rand(&mut rng).into_affine();
+        let b = G::rand(&mut rng).into_affine();
+
+        // Cover random sums, doublings, inverses, and sums with zero.
+        let mut bases = vec![a, a, a, G::Affine::zero(), a, G::Affine::zero()];
+        let other = vec![b, a, -a, b, G::Affine::zero(), G::Affine::zero()];
+
+        let expected = bases
+            .iter()
+            .zip(&other)
+            .map(|(base, other)| {
+                let mut sum = base.into_projective();
+                sum.add_assign_mixed(other);
+                sum.into_affine()
+            })
+            .collect::<Vec<_>>();
+
+        G::Affine::batch_add_assign(&mut bases, &other);
+        assert_eq!(bases, expected);
+    }
+}
+
 pub fn curve_tests<G: ProjectiveCurve>() {
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
 
@@ -282,4 +308,5 @@ pub fn curve_tests<G: ProjectiveCurve>() {
     random_doubling_test::<G>();
     random_negation_test::<G>();
     random_transformation_test::<G>();
+    random_batch_addition_test::<G>();
 }
@@ -360,6 +360,19 @@ pub fn fft_field_test<F: PrimeField + FftField>() {
     assert!(!two_adic_root_of_unity.is_zero());
     assert_eq!(two_adic_root_of_unity.pow([1 << two_adicity]), F::one());
     assert_eq!(generator.pow(trace.to_repr().as_ref()), two_adic_root_of_unity);
+
+    // large_subgroup_root_of_unity^(small_subgroup_base^small_subgroup_base_adicity) == two-adic root of unity
+    if let Some(large_subgroup_root_of_unity) = F::large_subgroup_root_of_unity() {
+        let base = F::FftParameters::SMALL_SUBGROUP_BASE.unwrap() as u64;
+        let base_adicity = F::FftParameters::SMALL_SUBGROUP_BASE_ADICITY.unwrap();
+
+        let mut omega = large_subgroup_root_of_unity;
+        for _ in 0..base_adicity {
+            assert_ne!(omega.pow([1 << two_adicity]), F::one());
+            omega = omega.pow([base]);
+        }
+        assert_eq!(omega, two_adic_root_of_unity);
+    }
 }
 
 pub fn primefield_test<F: PrimeField>() {
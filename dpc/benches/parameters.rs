@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#[macro_use]
+extern crate criterion;
+
+use snarkvm_algorithms::prelude::*;
+use snarkvm_dpc::{testnet2::Testnet2, Network};
+use snarkvm_parameters::testnet2::{CommitmentSchemeBytes, InnerCircuitIDCRHBytes};
+use snarkvm_utilities::FromBytes;
+
+use criterion::Criterion;
+
+fn commitment_scheme_setup(c: &mut Criterion) {
+    c.bench_function("commitment_scheme_setup", move |b| {
+        b.iter(|| <Testnet2 as Network>::CommitmentScheme::setup("AleoCommitmentScheme0"))
+    });
+}
+
+fn commitment_scheme_load_bytes(c: &mut Criterion) {
+    c.bench_function("commitment_scheme_load_bytes", move |b| {
+        b.iter(|| {
+            <Testnet2 as Network>::CommitmentScheme::read_le(&CommitmentSchemeBytes::load_bytes().unwrap()[..]).unwrap()
+        })
+    });
+}
+
+fn inner_circuit_id_crh_setup(c: &mut Criterion) {
+    c.bench_function("inner_circuit_id_crh_setup", move |b| {
+        b.iter(|| <Testnet2 as Network>::InnerCircuitIDCRH::setup("AleoInnerCircuitIDCRH0"))
+    });
+}
+
+fn inner_circuit_id_crh_load_bytes(c: &mut Criterion) {
+    c.bench_function("inner_circuit_id_crh_load_bytes", move |b| {
+        b.iter(|| {
+            <Testnet2 as Network>::InnerCircuitIDCRH::read_le(&InnerCircuitIDCRHBytes::load_bytes().unwrap()[..])
+                .unwrap()
+        })
+    });
+}
+
+criterion_group! {
+    name = parameters;
+    config = Criterion::default().sample_size(10);
+    targets = commitment_scheme_setup, commitment_scheme_load_bytes, inner_circuit_id_crh_setup, inner_circuit_id_crh_load_bytes
+}
+
+criterion_main!(parameters);
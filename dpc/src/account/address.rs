@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{account_format, AccountError, ComputeKey, Network, PrivateKey, ViewKey};
-use snarkvm_algorithms::{EncryptionScheme, SignatureScheme};
+use snarkvm_algorithms::{EncryptionScheme, SignatureScheme, VRF};
 use snarkvm_curves::AffineCurve;
 use snarkvm_utilities::{
     fmt,
@@ -65,6 +65,12 @@ impl<N: Network> Address<N> {
     pub fn verify_signature(&self, message: &[u8], signature: &N::AccountSignature) -> Result<bool, AccountError> {
         Ok(N::account_signature_scheme().verify(&self.0, message, signature)?)
     }
+
+    /// Verifies a VRF proof on an input produced by the account private key.
+    /// Returns `true` if the proof is valid. Otherwise, returns `false`.
+    pub fn verify_vrf(&self, input: &[u8], proof: &<N::AccountVRF as VRF>::Proof) -> Result<bool, AccountError> {
+        Ok(N::account_vrf().verify(&self.0, input, proof)?)
+    }
 }
 
 impl<N: Network> From<PrivateKey<N>> for Address<N> {
@@ -23,7 +23,7 @@ use crate::{
     ACCOUNT_SEED_R_SIG_DOMAIN,
     ACCOUNT_SEED_SK_SIG_DOMAIN,
 };
-use snarkvm_algorithms::traits::{SignatureScheme, PRF};
+use snarkvm_algorithms::traits::{SignatureScheme, PRF, VRF};
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{FromBytes, ToBytes, UniformRand};
 
@@ -62,6 +62,16 @@ impl<N: Network> PrivateKey<N> {
             .into())
     }
 
+    /// Returns a VRF proof on an input using the account private key.
+    /// The VRF output is given by `N::account_vrf().proof_to_hash(proof)`.
+    pub fn prove_vrf<R: Rng + CryptoRng>(
+        &self,
+        input: &[u8],
+        rng: &mut R,
+    ) -> Result<<N::AccountVRF as VRF>::Proof, AccountError> {
+        Ok(N::account_vrf().prove(&self.to_decryption_key(), input, rng)?)
+    }
+
     /// Returns the address from the private key.
     pub fn to_address(&self) -> Address<N> {
         Address::from_private_key(self)
@@ -169,6 +169,29 @@ mod testnet1 {
         }
     }
 
+    #[test]
+    fn test_account_vrf() {
+        let private_key = PrivateKey::<Testnet1>::from_str(ALEO_TESTNET1_PRIVATE_KEY).unwrap();
+        let address = Address::<Testnet1>::from_private_key(&private_key);
+        let input = "Hi, I'm an Aleo account VRF input!".as_bytes();
+
+        let proof = private_key.prove_vrf(input, &mut thread_rng()).unwrap();
+        assert!(address.verify_vrf(input, &proof).unwrap());
+        assert!(!address.verify_vrf(b"Bad input", &proof).unwrap());
+
+        // Ensure the proof does not verify for another address.
+        let other_address = Address::<Testnet1>::from_private_key(&PrivateKey::new(&mut thread_rng()));
+        assert!(!other_address.verify_vrf(input, &proof).unwrap());
+
+        // Ensure the output is unique for the address and input.
+        let other_proof = private_key.prove_vrf(input, &mut thread_rng()).unwrap();
+        assert!(address.verify_vrf(input, &other_proof).unwrap());
+        assert_eq!(
+            Testnet1::account_vrf().proof_to_hash(&proof).unwrap(),
+            Testnet1::account_vrf().proof_to_hash(&other_proof).unwrap()
+        );
+    }
+
     #[test]
     fn test_account_signature_compatibility() {
         for i in 0..25 {
@@ -290,10 +313,24 @@ mod testnet1 {
 
 #[cfg(test)]
 mod testnet2 {
-    use crate::{testnet2::Testnet2, Account, AccountScheme, Address, Network, PrivateKey, ViewKey};
+    use crate::{
+        testnet2::Testnet2,
+        Account,
+        AccountScheme,
+        Address,
+        AleoAmount,
+        ComputeKey,
+        LedgerProof,
+        Network,
+        Operation,
+        PrivateKey,
+        Record,
+        Request,
+        ViewKey,
+    };
     use snarkvm_algorithms::prelude::*;
     use snarkvm_curves::AffineCurve;
-    use snarkvm_utilities::{FromBytes, ToBytes};
+    use snarkvm_utilities::{to_bytes_le, FromBytes, ToBytes};
 
     use rand::{thread_rng, Rng, SeedableRng};
     use rand_chacha::ChaChaRng;
@@ -444,6 +481,29 @@ mod testnet2 {
         }
     }
 
+    #[test]
+    fn test_account_vrf() {
+        let private_key = PrivateKey::<Testnet2>::from_str(ALEO_TESTNET2_PRIVATE_KEY).unwrap();
+        let address = Address::<Testnet2>::from_private_key(&private_key);
+        let input = "Hi, I'm an Aleo account VRF input!".as_bytes();
+
+        let proof = private_key.prove_vrf(input, &mut thread_rng()).unwrap();
+        assert!(address.verify_vrf(input, &proof).unwrap());
+        assert!(!address.verify_vrf(b"Bad input", &proof).unwrap());
+
+        // Ensure the proof does not verify for another address.
+        let other_address = Address::<Testnet2>::from_private_key(&PrivateKey::new(&mut thread_rng()));
+        assert!(!other_address.verify_vrf(input, &proof).unwrap());
+
+        // Ensure the output is unique for the address and input.
+        let other_proof = private_key.prove_vrf(input, &mut thread_rng()).unwrap();
+        assert!(address.verify_vrf(input, &other_proof).unwrap());
+        assert_eq!(
+            Testnet2::account_vrf().proof_to_hash(&proof).unwrap(),
+            Testnet2::account_vrf().proof_to_hash(&other_proof).unwrap()
+        );
+    }
+
     #[test]
     fn test_account_signature_compatibility() {
         for i in 0..25 {
@@ -561,4 +621,77 @@ mod testnet2 {
             );
         }
     }
+
+    #[test]
+    fn test_multisig_account_request() {
+        let rng = &mut thread_rng();
+        let signature_scheme = Testnet2::account_signature_scheme();
+
+        // Run the distributed key generation for a 2-of-3 multisig account.
+        let (generators, packages): (Vec<_>, Vec<_>) = (1..=3)
+            .map(|index| signature_scheme.multisig_keygen_begin(index, 2, 3, rng).unwrap())
+            .unzip();
+        let key_shares: Vec<_> = generators
+            .iter()
+            .map(|recipient| {
+                let received_shares: Vec<_> = generators
+                    .iter()
+                    .map(|sender| (sender.index(), sender.share_for(recipient.index()).unwrap()))
+                    .collect();
+                signature_scheme
+                    .multisig_keygen_finalize(recipient.clone(), &packages, &received_shares)
+                    .unwrap()
+            })
+            .collect();
+
+        // Derive the multisig account address.
+        let multisig_public_key = signature_scheme.multisig_aggregate_public_key(2, &packages).unwrap();
+        let public_key = signature_scheme.multisig_public_key(&multisig_public_key);
+        let address = Address::<Testnet2>::read_le(&public_key.to_x_coordinate().to_bytes_le().unwrap()[..]).unwrap();
+
+        // Construct the records for a request from the multisig account, and the message that it signs.
+        let amount = AleoAmount(1234);
+        let records: Vec<_> = (0..Testnet2::NUM_INPUT_RECORDS)
+            .map(|_| Record::new_noop_input(address, rng).unwrap())
+            .collect();
+        let commitments: Vec<_> = records.iter().map(|record| record.commitment()).collect();
+        let message = to_bytes_le![commitments].unwrap();
+
+        // Sign with participants 2 and 3.
+        let (nonces_2, commitment_2) = signature_scheme.multisig_commit(&key_shares[1], rng);
+        let (nonces_3, commitment_3) = signature_scheme.multisig_commit(&key_shares[2], rng);
+        let commitments = [commitment_2, commitment_3];
+        let signature_shares = [
+            signature_scheme
+                .multisig_sign(&key_shares[1], nonces_2, &commitments, &message)
+                .unwrap(),
+            signature_scheme
+                .multisig_sign(&key_shares[2], nonces_3, &commitments, &message)
+                .unwrap(),
+        ];
+        let signature: <Testnet2 as Network>::AccountSignature = signature_scheme
+            .multisig_aggregate(&multisig_public_key, &commitments, &signature_shares, &message)
+            .unwrap()
+            .into();
+
+        // Ensure the signature is bound to the multisig account.
+        assert!(address.verify_signature(&message, &signature).unwrap());
+        assert_eq!(
+            address,
+            Address::from_compute_key(&ComputeKey::from_signature(&signature).unwrap())
+        );
+
+        // Ensure the multisig signature completes the request.
+        let request = Request::<Testnet2>::from(
+            records,
+            vec![LedgerProof::default(); Testnet2::NUM_INPUT_RECORDS],
+            Operation::Coinbase(address, amount),
+            (-amount).unwrap(),
+            signature,
+            false,
+        )
+        .unwrap();
+        assert!(request.is_valid());
+        assert_eq!(address, request.caller().unwrap());
+    }
 }
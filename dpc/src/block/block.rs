@@ -190,7 +190,13 @@ impl<N: Network> Block<N> {
         };
 
         // Ensure the coinbase reward is equal to or greater than the expected block reward.
-        let coinbase_reward = AleoAmount::ZERO.sub(coinbase_transaction.value_balance()); // Make it a positive number.
+        let coinbase_reward = match -coinbase_transaction.value_balance() {
+            Ok(coinbase_reward) => coinbase_reward, // Make it a positive number.
+            Err(error) => {
+                eprintln!("{}", error);
+                return false;
+            }
+        };
         let block_reward = Self::block_reward(self.height());
         if coinbase_reward < block_reward {
             eprintln!("Coinbase reward must be >= {}, found {}", block_reward, coinbase_reward);
@@ -198,7 +204,17 @@ impl<N: Network> Block<N> {
         }
 
         // Ensure the coinbase reward less transaction fees is less than or equal to the block reward.
-        let candidate_block_reward = AleoAmount::ZERO.sub(self.transactions.net_value_balance()); // Make it a positive number.
+        let candidate_block_reward = match self
+            .transactions
+            .net_value_balance()
+            .and_then(|balance| (-balance).map_err(Into::into))
+        {
+            Ok(candidate_block_reward) => candidate_block_reward, // Make it a positive number.
+            Err(error) => {
+                eprintln!("{}", error);
+                return false;
+            }
+        };
         if candidate_block_reward > block_reward {
             eprintln!("Block reward must be <= {}", block_reward);
             return false;
@@ -144,23 +144,25 @@ impl<N: Network> Transactions<N> {
     }
 
     /// Returns the net value balance, by summing the value balance from all transactions.
-    pub fn net_value_balance(&self) -> AleoAmount {
-        self.transactions
+    pub fn net_value_balance(&self) -> Result<AleoAmount> {
+        Ok(self
+            .transactions
             .iter()
             .map(Transaction::value_balance)
-            .fold(AleoAmount::ZERO, |a, b| a.add(b))
+            .sum::<Result<AleoAmount, _>>()?)
     }
 
     /// Returns the total transaction fees, by summing the value balance from all positive transactions.
     /// Note - this amount does *not* include the block reward.
-    pub fn transaction_fees(&self) -> AleoAmount {
-        self.transactions
+    pub fn transaction_fees(&self) -> Result<AleoAmount> {
+        Ok(self
+            .transactions
             .iter()
             .filter_map(|t| match t.value_balance().is_negative() {
                 true => None,
                 false => Some(t.value_balance()),
             })
-            .fold(AleoAmount::ZERO, |a, b| a.add(b))
+            .sum::<Result<AleoAmount, _>>()?)
     }
 
     /// Returns the transactions root, by computing the root for a Merkle tree of the transaction IDs.
@@ -47,10 +47,10 @@ fn dpc_execute_circuits_test<N: Network>(expected_inner_num_constraints: usize,
     // Compute the value balance.
     let mut value_balance = AleoAmount::ZERO;
     for record in request.records().iter().take(N::NUM_INPUT_RECORDS) {
-        value_balance = value_balance.add(AleoAmount::from_bytes(record.value() as i64));
+        value_balance = (value_balance + AleoAmount::from_bytes(record.value() as i64)).unwrap();
     }
     for record in response.records().iter().take(N::NUM_OUTPUT_RECORDS) {
-        value_balance = value_balance.sub(AleoAmount::from_bytes(record.value() as i64));
+        value_balance = (value_balance - AleoAmount::from_bytes(record.value() as i64)).unwrap();
     }
 
     // Compute the local transitions root.
@@ -0,0 +1,30 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#[derive(Debug, Error)]
+pub enum AmountError {
+    #[error("Amount overflowed when computing {}", _0)]
+    ArithmeticOverflow(String),
+
+    #[error("Invalid amount denomination {}", _0)]
+    InvalidDenomination(String),
+
+    #[error("Invalid amount {}", _0)]
+    InvalidFormat(String),
+
+    #[error("Amount {} has more decimal places than its denomination allows", _0)]
+    InvalidPrecision(String),
+}
@@ -17,6 +17,9 @@
 pub mod account;
 pub use account::*;
 
+pub mod amount;
+pub use amount::*;
+
 pub mod bech32m;
 pub use bech32m::*;
 
@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+
+use anyhow::Result;
+use chrono::Utc;
+use rand::{CryptoRng, Rng};
+use std::sync::atomic::AtomicBool;
+
+///
+/// The state of the canon chain that the next block is mined on.
+///
+/// A block template is a snapshot of the latest block and the memory pool, so the next block
+/// may be mined without holding a reference to the ledger for the duration of the proof of succinct work.
+///
+#[derive(Clone, Debug)]
+pub struct BlockTemplate<N: Network> {
+    /// The hash of the latest block.
+    previous_block_hash: N::BlockHash,
+    /// The height of the next block.
+    block_height: u32,
+    /// The timestamp of the latest block.
+    previous_timestamp: i64,
+    /// The difficulty target of the latest block.
+    previous_difficulty_target: u64,
+    /// The ledger root of the latest block.
+    previous_ledger_root: N::LedgerRoot,
+    /// The unconfirmed transactions to include in the next block.
+    transactions: Vec<Transaction<N>>,
+}
+
+impl<N: Network> BlockTemplate<N> {
+    /// Initializes a new block template.
+    pub fn new(
+        previous_block_hash: N::BlockHash,
+        block_height: u32,
+        previous_timestamp: i64,
+        previous_difficulty_target: u64,
+        previous_ledger_root: N::LedgerRoot,
+        transactions: Vec<Transaction<N>>,
+    ) -> Self {
+        Self {
+            previous_block_hash,
+            block_height,
+            previous_timestamp,
+            previous_difficulty_target,
+            previous_ledger_root,
+            transactions,
+        }
+    }
+
+    /// Returns the height of the next block.
+    pub fn block_height(&self) -> u32 {
+        self.block_height
+    }
+
+    /// Returns the unconfirmed transactions to include in the next block.
+    pub fn transactions(&self) -> &Vec<Transaction<N>> {
+        &self.transactions
+    }
+
+    /// Mines the next block, with a coinbase transaction for the given recipient.
+    pub fn mine<R: Rng + CryptoRng>(
+        &self,
+        recipient: Address<N>,
+        terminator: &AtomicBool,
+        rng: &mut R,
+    ) -> Result<Block<N>> {
+        // Compute the block difficulty target.
+        let block_timestamp = Utc::now().timestamp();
+        let difficulty_target = Blocks::<N>::compute_difficulty_target(
+            self.previous_timestamp,
+            self.previous_difficulty_target,
+            block_timestamp,
+        );
+
+        // Construct the new block transactions.
+        let amount = Block::<N>::block_reward(self.block_height);
+        let coinbase_transaction = Transaction::<N>::new_coinbase(recipient, amount, rng)?;
+        let transactions = Transactions::from(&[vec![coinbase_transaction], self.transactions.clone()].concat())?;
+
+        // Mine the next block.
+        Block::mine(
+            self.previous_block_hash,
+            self.block_height,
+            block_timestamp,
+            difficulty_target,
+            self.previous_ledger_root,
+            transactions,
+            terminator,
+            rng,
+        )
+    }
+}
@@ -43,7 +43,16 @@ pub struct Blocks<N: Network> {
 impl<N: Network> Blocks<N> {
     /// Initializes a new instance of `Blocks` with the genesis block.
     pub fn new() -> Result<Self> {
-        let genesis_block = N::genesis_block();
+        Self::from_genesis(N::genesis_block())
+    }
+
+    /// Initializes a new instance of `Blocks` with the given genesis block.
+    pub fn from_genesis(genesis_block: &Block<N>) -> Result<Self> {
+        // Ensure the given block is a valid genesis block.
+        if !genesis_block.is_genesis() || !genesis_block.is_valid() {
+            return Err(anyhow!("The given block is not a valid genesis block"));
+        }
+
         let height = genesis_block.height();
 
         let mut blocks = Self {
@@ -126,14 +135,11 @@ impl<N: Network> Blocks<N> {
 
     /// Returns the block given the block height.
     pub fn get_block(&self, height: u32) -> Result<Block<N>> {
-        match height == 0 {
-            true => Ok(N::genesis_block().clone()),
-            false => Ok(Block::from(
-                self.get_previous_block_hash(height)?.clone(),
-                self.get_block_header(height)?.clone(),
-                self.get_block_transactions(height)?.clone(),
-            )?),
-        }
+        Ok(Block::from(
+            self.get_previous_block_hash(height)?.clone(),
+            self.get_block_header(height)?.clone(),
+            self.get_block_transactions(height)?.clone(),
+        )?)
     }
 
     /// Returns the block hash given the block height.
@@ -151,6 +157,19 @@ impl<N: Network> Blocks<N> {
         }
     }
 
+    /// Returns the transaction given the transaction ID.
+    pub fn get_transaction(&self, transaction_id: &N::TransactionID) -> Result<Transaction<N>> {
+        match self
+            .transactions
+            .values()
+            .flat_map(|transactions| &**transactions)
+            .find(|transaction| transaction.transaction_id() == *transaction_id)
+        {
+            Some(transaction) => Ok(transaction.clone()),
+            None => Err(anyhow!("Missing transaction {}", transaction_id)),
+        }
+    }
+
     /// Returns `true` if the given block height exists.
     pub fn contains_height(&self, height: u32) -> bool {
         self.previous_hashes.contains_key(&height)
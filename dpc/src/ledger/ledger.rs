@@ -17,7 +17,6 @@
 use crate::prelude::*;
 
 use anyhow::{anyhow, Result};
-use chrono::Utc;
 use rand::{CryptoRng, Rng};
 use std::{collections::HashMap, sync::atomic::AtomicBool};
 
@@ -41,6 +40,15 @@ impl<N: Network> Ledger<N> {
         })
     }
 
+    /// Initializes a new instance of the ledger with the given genesis block.
+    pub fn from_genesis(genesis_block: &Block<N>) -> Result<Self> {
+        Ok(Self {
+            canon_blocks: Blocks::from_genesis(genesis_block)?,
+            orphan_blocks: Default::default(),
+            memory_pool: MemoryPool::new(),
+        })
+    }
+
     /// Returns the latest block height.
     pub fn latest_block_height(&self) -> u32 {
         self.canon_blocks.latest_block_height()
@@ -76,6 +84,16 @@ impl<N: Network> Ledger<N> {
         self.canon_blocks.latest_block()
     }
 
+    /// Returns the block given the block height.
+    pub fn get_block(&self, height: u32) -> Result<Block<N>> {
+        self.canon_blocks.get_block(height)
+    }
+
+    /// Returns the transaction given the transaction ID.
+    pub fn get_transaction(&self, transaction_id: &N::TransactionID) -> Result<Transaction<N>> {
+        self.canon_blocks.get_transaction(transaction_id)
+    }
+
     /// Returns `true` if the given ledger root exists on the canon chain.
     pub fn contains_ledger_root(&self, ledger_root: &N::LedgerRoot) -> bool {
         self.canon_blocks.contains_ledger_root(ledger_root)
@@ -97,6 +115,9 @@ impl<N: Network> Ledger<N> {
         // Attempt to insert the block into canon.
         self.canon_blocks.add_next(block)?;
 
+        // On success, clear the memory pool of the block transactions.
+        self.memory_pool.remove_transactions(block.transactions());
+
         Ok(())
     }
 
@@ -140,6 +161,18 @@ impl<N: Network> Ledger<N> {
         Ok(())
     }
 
+    /// Returns the template for mining the next block, which includes the transactions in the memory pool.
+    pub fn to_block_template(&self) -> Result<BlockTemplate<N>> {
+        Ok(BlockTemplate::new(
+            self.latest_block_hash(),
+            self.latest_block_height() + 1,
+            self.latest_block_timestamp()?,
+            self.latest_block_difficulty_target()?,
+            self.latest_ledger_root(),
+            self.memory_pool.transactions(),
+        ))
+    }
+
     /// Mines a new block and adds it to the canon blocks.
     pub fn mine_next_block<R: Rng + CryptoRng>(
         &mut self,
@@ -147,36 +180,8 @@ impl<N: Network> Ledger<N> {
         terminator: &AtomicBool,
         rng: &mut R,
     ) -> Result<()> {
-        // Prepare the new block.
-        let previous_block_hash = self.latest_block_hash();
-        let block_height = self.latest_block_height() + 1;
-
-        // Compute the block difficulty target.
-        let previous_timestamp = self.latest_block_timestamp()?;
-        let previous_difficulty_target = self.latest_block_difficulty_target()?;
-        let block_timestamp = Utc::now().timestamp();
-        let difficulty_target =
-            Blocks::<N>::compute_difficulty_target(previous_timestamp, previous_difficulty_target, block_timestamp);
-
-        // Construct the new block transactions.
-        let amount = Block::<N>::block_reward(block_height);
-        let coinbase_transaction = Transaction::<N>::new_coinbase(recipient, amount, rng)?;
-        let transactions = Transactions::from(&[vec![coinbase_transaction], self.memory_pool.transactions()].concat())?;
-
-        // Retrieve the current ledger root.
-        let previous_ledger_root = self.canon_blocks.latest_ledger_root();
-
         // Mine the next block.
-        let block = Block::mine(
-            previous_block_hash,
-            block_height,
-            block_timestamp,
-            difficulty_target,
-            previous_ledger_root,
-            transactions,
-            terminator,
-            rng,
-        )?;
+        let block = self.to_block_template()?.mine(recipient, terminator, rng)?;
 
         // Attempt to add the block to the canon chain.
         self.add_next_block(&block)?;
@@ -240,4 +245,30 @@ mod tests {
             assert_eq!(1, ledger.latest_block_height());
         }
     }
+
+    #[test]
+    fn test_from_genesis() {
+        let rng = &mut thread_rng();
+        let recipient = Account::<Testnet2>::new(rng);
+
+        let genesis_block = Block::<Testnet2>::new_genesis(recipient.address(), rng).unwrap();
+        let mut ledger = Ledger::<Testnet2>::from_genesis(&genesis_block).unwrap();
+        assert_eq!(0, ledger.latest_block_height());
+        assert_eq!(genesis_block, ledger.get_block(0).unwrap());
+
+        // Ensure the genesis coinbase transaction can be retrieved.
+        let coinbase_transaction = genesis_block.to_coinbase_transaction().unwrap();
+        let transaction_id = coinbase_transaction.transaction_id();
+        assert_eq!(coinbase_transaction, ledger.get_transaction(&transaction_id).unwrap());
+
+        // Ensure the ledger can be extended from the custom genesis block.
+        ledger
+            .mine_next_block(recipient.address(), &AtomicBool::new(false), rng)
+            .unwrap();
+        assert_eq!(1, ledger.latest_block_height());
+        assert_eq!(ledger.latest_block().unwrap(), ledger.get_block(1).unwrap());
+
+        // Ensure a non-genesis block is rejected.
+        assert!(Ledger::<Testnet2>::from_genesis(&ledger.latest_block().unwrap()).is_err());
+    }
 }
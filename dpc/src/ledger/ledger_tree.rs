@@ -16,7 +16,7 @@
 
 use crate::prelude::*;
 use snarkvm_algorithms::{
-    merkle_tree::{MerklePath, MerkleTree},
+    merkle_tree::{IncrementalMerkleTree, MerklePath},
     prelude::*,
 };
 use snarkvm_utilities::has_duplicates;
@@ -25,11 +25,14 @@ use anyhow::{anyhow, Result};
 use std::{collections::HashMap, sync::Arc};
 
 /// A ledger tree contains all block hashes on the ledger.
+///
+/// Only the frontier of the tree is stored, so appending a block hash costs O(depth).
+/// Inclusion proofs are built on demand from the block hashes.
 #[derive(Derivative)]
 #[derivative(Clone(bound = "N: Network"), Debug(bound = "N: Network"))]
 pub struct LedgerTree<N: Network> {
     #[derivative(Debug = "ignore")]
-    tree: Arc<MerkleTree<N::LedgerRootParameters>>,
+    tree: IncrementalMerkleTree<N::LedgerRootParameters>,
     block_hashes: HashMap<N::BlockHash, u32>,
     current_index: u32,
 }
@@ -38,10 +41,7 @@ impl<N: Network> LedgerTreeScheme<N> for LedgerTree<N> {
     /// Initializes an empty ledger tree.
     fn new() -> Result<Self> {
         Ok(Self {
-            tree: Arc::new(MerkleTree::<N::LedgerRootParameters>::new::<N::BlockHash>(
-                Arc::new(N::ledger_root_parameters().clone()),
-                &vec![],
-            )?),
+            tree: IncrementalMerkleTree::<N::LedgerRootParameters>::new(Arc::new(N::ledger_root_parameters().clone()))?,
             block_hashes: Default::default(),
             current_index: 0,
         })
@@ -55,7 +55,7 @@ impl<N: Network> LedgerTreeScheme<N> for LedgerTree<N> {
             return Err(MerkleError::Message(format!("{} already exists in the ledger tree", block_hash)).into());
         }
 
-        self.tree = Arc::new(self.tree.rebuild(self.current_index as usize, &[block_hash])?);
+        self.tree.append(block_hash)?;
         self.block_hashes.insert(*block_hash, self.current_index);
         self.current_index += 1;
 
@@ -84,14 +84,8 @@ impl<N: Network> LedgerTreeScheme<N> for LedgerTree<N> {
         let start_index = self.current_index;
         let num_block_hashes = block_hashes.len();
 
-        // Add the block hashes to the tree. Start the tree from scratch if the tree is currently empty.
-        self.tree = match self.current_index {
-            0 => Arc::new(MerkleTree::<N::LedgerRootParameters>::new::<N::BlockHash>(
-                Arc::new(N::ledger_root_parameters().clone()),
-                &block_hashes,
-            )?),
-            _ => Arc::new(self.tree.rebuild(self.current_index as usize, &block_hashes)?),
-        };
+        // Add the block hashes to the tree.
+        self.tree.append_all(block_hashes)?;
 
         self.block_hashes.extend(
             block_hashes
@@ -122,10 +116,25 @@ impl<N: Network> LedgerTreeScheme<N> for LedgerTree<N> {
 
     /// Returns the Merkle path for a given block hash.
     fn to_ledger_inclusion_proof(&self, block_hash: &N::BlockHash) -> Result<MerklePath<N::LedgerRootParameters>> {
-        match self.get_block_hash_index(block_hash) {
-            Some(index) => Ok(self.tree.generate_proof(*index as usize, block_hash)?),
-            _ => Err(MerkleError::MissingLeaf(format!("{}", block_hash)).into()),
+        let index = match self.get_block_hash_index(block_hash) {
+            Some(index) => *index as usize,
+            _ => return Err(MerkleError::MissingLeaf(format!("{}", block_hash)).into()),
+        };
+
+        // Order the block hashes by their index in the tree.
+        let mut leaves = vec![N::BlockHash::default(); self.block_hashes.len()];
+        for (block_hash, index) in &self.block_hashes {
+            leaves[*index as usize] = *block_hash;
+        }
+
+        // Witness the block hash, and bring the witness up to date with the subsequent block hashes.
+        let mut witness =
+            IncrementalMerkleTree::from_leaves(self.tree.parameters().clone(), &leaves[..=index])?.witness()?;
+        for leaf in &leaves[index + 1..] {
+            witness.append(leaf)?;
         }
+
+        Ok(witness.path()?)
     }
 }
 
@@ -134,3 +143,38 @@ impl<N: Network> Default for LedgerTree<N> {
         Self::new().unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+    use snarkvm_algorithms::merkle_tree::MerkleTree;
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    #[test]
+    fn test_ledger_tree() {
+        let rng = &mut thread_rng();
+        let block_hashes: Vec<<Testnet2 as Network>::BlockHash> = (0..10).map(|_| UniformRand::rand(rng)).collect();
+
+        let mut ledger_tree = LedgerTree::<Testnet2>::new().unwrap();
+        ledger_tree.add(&block_hashes[0]).unwrap();
+        ledger_tree.add_all(&block_hashes[1..]).unwrap();
+        assert!(ledger_tree.add(&block_hashes[0]).is_err());
+
+        // Ensure the root matches the materialized Merkle tree.
+        let expected_tree = MerkleTree::<<Testnet2 as Network>::LedgerRootParameters>::new(
+            Arc::new(Testnet2::ledger_root_parameters().clone()),
+            &block_hashes,
+        )
+        .unwrap();
+        assert_eq!(*expected_tree.root(), *ledger_tree.root());
+
+        // Ensure the inclusion proofs verify against the root.
+        for block_hash in &block_hashes {
+            let proof = ledger_tree.to_ledger_inclusion_proof(block_hash).unwrap();
+            assert!(proof.verify(&ledger_tree.root(), block_hash).unwrap());
+        }
+    }
+}
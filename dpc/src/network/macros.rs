@@ -22,6 +22,18 @@ macro_rules! dpc_setup {
             PARAMETER.get_or_init(|| Self::$type_name::setup($setup_msg))
         }
     };
+    // Loads the parameter from the checksummed bytes that were generated with `$setup_msg`.
+    ($network: ident, $fn_name: ident, $type_name: ident, $setup_msg: expr, $parameter: ident) => {
+        #[inline]
+        fn $fn_name() -> &'static Self::$type_name {
+            static PARAMETER: OnceCell<<$network as Network>::$type_name> = OnceCell::new();
+            PARAMETER.get_or_init(|| {
+                Self::$type_name::read_le(
+                    $parameter::load_bytes().unwrap_or_else(|_| panic!("Failed to load parameter bytes for {}", $setup_msg)).as_slice()
+                ).unwrap_or_else(|_| panic!("Failed to read {} from bytes", $setup_msg))
+            })
+        }
+    };
 }
 
 #[rustfmt::skip]
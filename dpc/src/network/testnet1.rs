@@ -37,6 +37,7 @@ use snarkvm_algorithms::{
     prf::PoseidonPRF,
     signature::AleoSignatureScheme,
     snark::groth16::Groth16,
+    vrf::AleoVRF,
 };
 use snarkvm_curves::{
     bls12_377::Bls12_377,
@@ -57,6 +58,7 @@ use snarkvm_gadgets::{
         prf::PoseidonPRFGadget,
         signature::AleoSignatureSchemeGadget,
         snark::Groth16VerifierGadget,
+        vrf::AleoVRFGadget,
     },
     curves::{bls12_377::PairingGadget, edwards_bls12::EdwardsBls12Gadget, edwards_bw6::EdwardsBW6Gadget},
 };
@@ -173,6 +175,9 @@ impl Network for Testnet1 {
     type AccountSignaturePublicKey = <Self::AccountSignatureScheme as SignatureScheme>::PublicKey;
     type AccountSignature = AleoObject<<Self::AccountSignatureScheme as SignatureScheme>::Signature, { Self::SIGNATURE_PREFIX }, { Self::SIGNATURE_SIZE_IN_BYTES }>;
 
+    type AccountVRF = AleoVRF<Self::ProgramCurveParameters>;
+    type AccountVRFGadget = AleoVRFGadget<Self::ProgramCurveParameters, Self::InnerScalarField>;
+
     type BlockHashCRH = BHPCRH<Self::ProgramProjectiveCurve, 16, 32>;
     type BlockHashCRHGadget = BHPCRHGadget<Self::ProgramProjectiveCurve, Self::InnerScalarField, Self::ProgramAffineCurveGadget, 16, 32>;
     type BlockHash = AleoLocator<<Self::BlockHashCRH as CRH>::Output, { Self::BLOCK_HASH_PREFIX }>;
@@ -241,17 +246,18 @@ impl Network for Testnet1 {
 
     dpc_setup!{Testnet1, account_encryption_scheme, RecordCiphertextScheme, ACCOUNT_ENCRYPTION_AND_SIGNATURE_INPUT}
     dpc_setup!{Testnet1, account_signature_scheme, AccountSignatureScheme, ACCOUNT_ENCRYPTION_AND_SIGNATURE_INPUT}
-    dpc_setup!{Testnet1, block_hash_crh, BlockHashCRH, "AleoBlockHashCRH0"}
-    dpc_setup!{Testnet1, block_header_root_parameters, BlockHeaderRootParameters, "AleoBlockHeaderRootCRH0"}
-    dpc_setup!{Testnet1, ciphertext_id_crh, CiphertextIDCRH, "AleoCiphertextIDCRH0"}
-    dpc_setup!{Testnet1, commitment_scheme, CommitmentScheme, "AleoCommitmentScheme0"}
+    dpc_setup!{Testnet1, account_vrf, AccountVRF, ACCOUNT_ENCRYPTION_AND_SIGNATURE_INPUT}
+    dpc_setup!{Testnet1, block_hash_crh, BlockHashCRH, "AleoBlockHashCRH0", BlockHashCRHBytes}
+    dpc_setup!{Testnet1, block_header_root_parameters, BlockHeaderRootParameters, "AleoBlockHeaderRootCRH0", BlockHeaderRootParametersBytes}
+    dpc_setup!{Testnet1, ciphertext_id_crh, CiphertextIDCRH, "AleoCiphertextIDCRH0", CiphertextIDCRHBytes}
+    dpc_setup!{Testnet1, commitment_scheme, CommitmentScheme, "AleoCommitmentScheme0", CommitmentSchemeBytes}
     dpc_setup!{Testnet1, function_id_crh, FunctionIDCRH, "AleoFunctionIDCRH0"}
-    dpc_setup!{Testnet1, inner_circuit_id_crh, InnerCircuitIDCRH, "AleoInnerCircuitIDCRH0"}
-    dpc_setup!{Testnet1, ledger_root_parameters, LedgerRootParameters, "AleoLedgerRootCRH0"}
-    dpc_setup!{Testnet1, program_id_parameters, ProgramIDParameters, "AleoProgramIDCRH0"}
-    dpc_setup!{Testnet1, transactions_root_parameters, TransactionsRootParameters, "AleoTransactionsRootCRH0"}
-    dpc_setup!{Testnet1, transaction_id_parameters, TransactionIDParameters, "AleoTransactionIDCRH0"}
-    dpc_setup!{Testnet1, transition_id_parameters, TransitionIDParameters, "AleoTransitionIDCRH0"}
+    dpc_setup!{Testnet1, inner_circuit_id_crh, InnerCircuitIDCRH, "AleoInnerCircuitIDCRH0", InnerCircuitIDCRHBytes}
+    dpc_setup!{Testnet1, ledger_root_parameters, LedgerRootParameters, "AleoLedgerRootCRH0", LedgerRootParametersBytes}
+    dpc_setup!{Testnet1, program_id_parameters, ProgramIDParameters, "AleoProgramIDCRH0", ProgramIDParametersBytes}
+    dpc_setup!{Testnet1, transactions_root_parameters, TransactionsRootParameters, "AleoTransactionsRootCRH0", TransactionsRootParametersBytes}
+    dpc_setup!{Testnet1, transaction_id_parameters, TransactionIDParameters, "AleoTransactionIDCRH0", TransactionIDParametersBytes}
+    dpc_setup!{Testnet1, transition_id_parameters, TransitionIDParameters, "AleoTransitionIDCRH0", TransitionIDParametersBytes}
 
     dpc_snark_setup!{Testnet1, inner_proving_key, InnerSNARK, ProvingKey, InnerProvingKeyBytes, "inner proving key"}
     dpc_snark_setup!{Testnet1, inner_verifying_key, InnerSNARK, VerifyingKey, InnerVerifyingKeyBytes, "inner verifying key"}
@@ -317,6 +323,33 @@ mod tests {
         assert_eq!(Testnet1::NETWORK_NAME, "testnet1");
     }
 
+    #[test]
+    fn test_setup_parameters_sanity_check() {
+        // Verify the parameters loaded from bytes match the parameters derived from their setup messages.
+        assert_eq!(Testnet1::block_hash_crh(), &<Testnet1 as Network>::BlockHashCRH::setup("AleoBlockHashCRH0"));
+        assert_eq!(
+            Testnet1::block_header_root_parameters(),
+            &<Testnet1 as Network>::BlockHeaderRootParameters::setup("AleoBlockHeaderRootCRH0")
+        );
+        assert_eq!(Testnet1::ciphertext_id_crh(), &<Testnet1 as Network>::CiphertextIDCRH::setup("AleoCiphertextIDCRH0"));
+        assert_eq!(Testnet1::commitment_scheme(), &<Testnet1 as Network>::CommitmentScheme::setup("AleoCommitmentScheme0"));
+        assert_eq!(Testnet1::inner_circuit_id_crh(), &<Testnet1 as Network>::InnerCircuitIDCRH::setup("AleoInnerCircuitIDCRH0"));
+        assert_eq!(Testnet1::ledger_root_parameters(), &<Testnet1 as Network>::LedgerRootParameters::setup("AleoLedgerRootCRH0"));
+        assert_eq!(Testnet1::program_id_parameters(), &<Testnet1 as Network>::ProgramIDParameters::setup("AleoProgramIDCRH0"));
+        assert_eq!(
+            Testnet1::transactions_root_parameters(),
+            &<Testnet1 as Network>::TransactionsRootParameters::setup("AleoTransactionsRootCRH0")
+        );
+        assert_eq!(
+            Testnet1::transaction_id_parameters(),
+            &<Testnet1 as Network>::TransactionIDParameters::setup("AleoTransactionIDCRH0")
+        );
+        assert_eq!(
+            Testnet1::transition_id_parameters(),
+            &<Testnet1 as Network>::TransitionIDParameters::setup("AleoTransitionIDCRH0")
+        );
+    }
+
     #[test]
     fn test_inner_circuit_sanity_check() {
         // Verify the inner circuit verifying key matches the one derived from the inner circuit proving key.
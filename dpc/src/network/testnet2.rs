@@ -38,6 +38,7 @@ use snarkvm_algorithms::{
     prf::PoseidonPRF,
     signature::AleoSignatureScheme,
     snark::groth16::Groth16,
+    vrf::AleoVRF,
 };
 use snarkvm_curves::{
     bls12_377::Bls12_377,
@@ -58,6 +59,7 @@ use snarkvm_gadgets::{
         prf::PoseidonPRFGadget,
         signature::AleoSignatureSchemeGadget,
         snark::Groth16VerifierGadget,
+        vrf::AleoVRFGadget,
     },
     curves::{bls12_377::PairingGadget, edwards_bls12::EdwardsBls12Gadget, edwards_bw6::EdwardsBW6Gadget},
 };
@@ -179,6 +181,9 @@ impl Network for Testnet2 {
     type AccountSignaturePublicKey = <Self::AccountSignatureScheme as SignatureScheme>::PublicKey;
     type AccountSignature = AleoObject<<Self::AccountSignatureScheme as SignatureScheme>::Signature, { Self::SIGNATURE_PREFIX }, { Self::SIGNATURE_SIZE_IN_BYTES }>;
 
+    type AccountVRF = AleoVRF<Self::ProgramCurveParameters>;
+    type AccountVRFGadget = AleoVRFGadget<Self::ProgramCurveParameters, Self::InnerScalarField>;
+
     type BlockHashCRH = BHPCRH<Self::ProgramProjectiveCurve, 16, 32>;
     type BlockHashCRHGadget = BHPCRHGadget<Self::ProgramProjectiveCurve, Self::InnerScalarField, Self::ProgramAffineCurveGadget, 16, 32>;
     type BlockHash = AleoLocator<<Self::BlockHashCRH as CRH>::Output, { Self::BLOCK_HASH_PREFIX }>;
@@ -247,17 +252,18 @@ impl Network for Testnet2 {
 
     dpc_setup!{Testnet2, account_encryption_scheme, RecordCiphertextScheme, ACCOUNT_ENCRYPTION_AND_SIGNATURE_INPUT}
     dpc_setup!{Testnet2, account_signature_scheme, AccountSignatureScheme, ACCOUNT_ENCRYPTION_AND_SIGNATURE_INPUT}
-    dpc_setup!{Testnet2, block_hash_crh, BlockHashCRH, "AleoBlockHashCRH0"}
-    dpc_setup!{Testnet2, block_header_root_parameters, BlockHeaderRootParameters, "AleoBlockHeaderRootCRH0"}
-    dpc_setup!{Testnet2, ciphertext_id_crh, CiphertextIDCRH, "AleoCiphertextIDCRH0"}
-    dpc_setup!{Testnet2, commitment_scheme, CommitmentScheme, "AleoCommitmentScheme0"}
+    dpc_setup!{Testnet2, account_vrf, AccountVRF, ACCOUNT_ENCRYPTION_AND_SIGNATURE_INPUT}
+    dpc_setup!{Testnet2, block_hash_crh, BlockHashCRH, "AleoBlockHashCRH0", BlockHashCRHBytes}
+    dpc_setup!{Testnet2, block_header_root_parameters, BlockHeaderRootParameters, "AleoBlockHeaderRootCRH0", BlockHeaderRootParametersBytes}
+    dpc_setup!{Testnet2, ciphertext_id_crh, CiphertextIDCRH, "AleoCiphertextIDCRH0", CiphertextIDCRHBytes}
+    dpc_setup!{Testnet2, commitment_scheme, CommitmentScheme, "AleoCommitmentScheme0", CommitmentSchemeBytes}
     dpc_setup!{Testnet2, function_id_crh, FunctionIDCRH, "AleoFunctionIDCRH0"}
-    dpc_setup!{Testnet2, inner_circuit_id_crh, InnerCircuitIDCRH, "AleoInnerCircuitIDCRH0"}
-    dpc_setup!{Testnet2, ledger_root_parameters, LedgerRootParameters, "AleoLedgerRootCRH0"}
-    dpc_setup!{Testnet2, program_id_parameters, ProgramIDParameters, "AleoProgramIDCRH0"}
-    dpc_setup!{Testnet2, transactions_root_parameters, TransactionsRootParameters, "AleoTransactionsRootCRH0"}
-    dpc_setup!{Testnet2, transaction_id_parameters, TransactionIDParameters, "AleoTransactionIDCRH0"}
-    dpc_setup!{Testnet2, transition_id_parameters, TransitionIDParameters, "AleoTransitionIDCRH0"}
+    dpc_setup!{Testnet2, inner_circuit_id_crh, InnerCircuitIDCRH, "AleoInnerCircuitIDCRH0", InnerCircuitIDCRHBytes}
+    dpc_setup!{Testnet2, ledger_root_parameters, LedgerRootParameters, "AleoLedgerRootCRH0", LedgerRootParametersBytes}
+    dpc_setup!{Testnet2, program_id_parameters, ProgramIDParameters, "AleoProgramIDCRH0", ProgramIDParametersBytes}
+    dpc_setup!{Testnet2, transactions_root_parameters, TransactionsRootParameters, "AleoTransactionsRootCRH0", TransactionsRootParametersBytes}
+    dpc_setup!{Testnet2, transaction_id_parameters, TransactionIDParameters, "AleoTransactionIDCRH0", TransactionIDParametersBytes}
+    dpc_setup!{Testnet2, transition_id_parameters, TransitionIDParameters, "AleoTransitionIDCRH0", TransitionIDParametersBytes}
 
     dpc_snark_setup!{Testnet2, inner_proving_key, InnerSNARK, ProvingKey, InnerProvingKeyBytes, "inner circuit proving key"}
     dpc_snark_setup!{Testnet2, inner_verifying_key, InnerSNARK, VerifyingKey, InnerVerifyingKeyBytes, "inner circuit verifying key"}
@@ -327,6 +333,33 @@ mod tests {
         assert_eq!(Testnet2::NETWORK_NAME, "testnet2");
     }
 
+    #[test]
+    fn test_setup_parameters_sanity_check() {
+        // Verify the parameters loaded from bytes match the parameters derived from their setup messages.
+        assert_eq!(Testnet2::block_hash_crh(), &<Testnet2 as Network>::BlockHashCRH::setup("AleoBlockHashCRH0"));
+        assert_eq!(
+            Testnet2::block_header_root_parameters(),
+            &<Testnet2 as Network>::BlockHeaderRootParameters::setup("AleoBlockHeaderRootCRH0")
+        );
+        assert_eq!(Testnet2::ciphertext_id_crh(), &<Testnet2 as Network>::CiphertextIDCRH::setup("AleoCiphertextIDCRH0"));
+        assert_eq!(Testnet2::commitment_scheme(), &<Testnet2 as Network>::CommitmentScheme::setup("AleoCommitmentScheme0"));
+        assert_eq!(Testnet2::inner_circuit_id_crh(), &<Testnet2 as Network>::InnerCircuitIDCRH::setup("AleoInnerCircuitIDCRH0"));
+        assert_eq!(Testnet2::ledger_root_parameters(), &<Testnet2 as Network>::LedgerRootParameters::setup("AleoLedgerRootCRH0"));
+        assert_eq!(Testnet2::program_id_parameters(), &<Testnet2 as Network>::ProgramIDParameters::setup("AleoProgramIDCRH0"));
+        assert_eq!(
+            Testnet2::transactions_root_parameters(),
+            &<Testnet2 as Network>::TransactionsRootParameters::setup("AleoTransactionsRootCRH0")
+        );
+        assert_eq!(
+            Testnet2::transaction_id_parameters(),
+            &<Testnet2 as Network>::TransactionIDParameters::setup("AleoTransactionIDCRH0")
+        );
+        assert_eq!(
+            Testnet2::transition_id_parameters(),
+            &<Testnet2 as Network>::TransitionIDParameters::setup("AleoTransitionIDCRH0")
+        );
+    }
+
     #[test]
     fn test_inner_circuit_sanity_check() {
         // Verify the inner circuit verifying key matches the one derived from the inner circuit proving key.
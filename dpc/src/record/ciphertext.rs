@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Address, Network, Payload, Record, RecordError, ViewKey};
+use crate::{Address, Network, Payload, Record, RecordError, RecordViewKey, ViewKey};
 use snarkvm_algorithms::traits::{EncryptionScheme, CRH};
 use snarkvm_utilities::{
     fmt,
@@ -80,7 +80,8 @@ impl<N: Network> RecordCiphertext<N> {
             return Err(anyhow!("Records must be <= 65535 bytes, found {} bytes", buffer.len()));
         }
 
-        // Encrypt the record bytes.
+        // Encrypt the record bytes. The unauthenticated format is used, as the inner circuit recomputes
+        // this ciphertext for the ciphertext ID, and the transition proof already binds the ciphertext.
         let randomizer = N::account_encryption_scheme().generate_randomness(rng);
         let ciphertext = N::account_encryption_scheme().encrypt(&*record.owner(), &randomizer, &buffer)?;
 
@@ -92,6 +93,27 @@ impl<N: Network> RecordCiphertext<N> {
         // Decrypt the record ciphertext.
         let plaintext = N::account_encryption_scheme().decrypt(&*recipient_view_key, &self.ciphertext)?;
 
+        // Derive the record owner.
+        let owner = Address::from_view_key(&recipient_view_key);
+
+        Self::to_record(owner, plaintext)
+    }
+
+    /// Decrypt the record ciphertext using the record view key.
+    pub fn decrypt_with_record_view_key(&self, record_view_key: &RecordViewKey<N>) -> Result<Record<N>> {
+        // Decrypt the record ciphertext.
+        let owner = record_view_key.owner();
+        let plaintext = N::account_encryption_scheme().decrypt_with_symmetric_key(
+            &*owner,
+            record_view_key.symmetric_key(),
+            &self.ciphertext,
+        )?;
+
+        Self::to_record(owner, plaintext)
+    }
+
+    /// Returns the record for the given owner from the decrypted plaintext bytes.
+    fn to_record(owner: Address<N>, plaintext: Vec<u8>) -> Result<Record<N>> {
         let mut cursor = Cursor::new(plaintext);
 
         // Deserialize the plaintext bytes.
@@ -101,9 +123,6 @@ impl<N: Network> RecordCiphertext<N> {
         let serial_number_nonce = N::SerialNumber::read_le(&mut cursor)?;
         let commitment_randomness = N::CommitmentRandomness::read_le(&mut cursor)?;
 
-        // Derive the record owner.
-        let owner = Address::from_view_key(&recipient_view_key);
-
         Ok(Record::from(
             owner,
             value,
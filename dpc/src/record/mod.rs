@@ -26,5 +26,8 @@ pub use record::*;
 pub mod record_proof;
 pub use record_proof::*;
 
+pub mod record_view_key;
+pub use record_view_key::*;
+
 #[cfg(test)]
 mod tests;
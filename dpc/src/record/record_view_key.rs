@@ -0,0 +1,211 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Address, CiphertextRandomizer, Network, RecordError};
+use snarkvm_algorithms::traits::EncryptionScheme;
+use snarkvm_utilities::{
+    fmt,
+    io::Result as IoResult,
+    str::FromStr,
+    FromBytes,
+    FromBytesDeserializer,
+    Read,
+    ToBytes,
+    ToBytesSerializer,
+    Write,
+};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+pub type RecordSymmetricKey<N> = <<N as Network>::RecordCiphertextScheme as EncryptionScheme>::SymmetricKey;
+
+/// A view key for a single record ciphertext, which allows anyone holding it
+/// to decrypt that record without the account view key of the owner.
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "N: Network"),
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network"),
+    Hash(bound = "N: Network")
+)]
+pub struct RecordViewKey<N: Network> {
+    /// The owner of the record.
+    owner: Address<N>,
+    /// The symmetric key shared between the owner and the ciphertext randomizer.
+    symmetric_key: RecordSymmetricKey<N>,
+}
+
+impl<N: Network> RecordViewKey<N> {
+    /// Derives the record view key for a record ciphertext, from its owner and ciphertext randomizer.
+    pub fn new(owner: Address<N>, ciphertext_randomizer: &CiphertextRandomizer<N>) -> Self {
+        Self {
+            owner,
+            symmetric_key: N::account_encryption_scheme().generate_symmetric_key(&*owner, ciphertext_randomizer),
+        }
+    }
+
+    /// Returns the owner of the record.
+    pub fn owner(&self) -> Address<N> {
+        self.owner
+    }
+
+    /// Returns a reference to the symmetric key.
+    pub fn symmetric_key(&self) -> &RecordSymmetricKey<N> {
+        &self.symmetric_key
+    }
+}
+
+impl<N: Network> FromBytes for RecordViewKey<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let owner = FromBytes::read_le(&mut reader)?;
+        let symmetric_key = FromBytes::read_le(&mut reader)?;
+
+        Ok(Self { owner, symmetric_key })
+    }
+}
+
+impl<N: Network> ToBytes for RecordViewKey<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.owner.write_le(&mut writer)?;
+        self.symmetric_key.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromStr for RecordViewKey<N> {
+    type Err = RecordError;
+
+    fn from_str(record_view_key_hex: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from_bytes_le(&hex::decode(record_view_key_hex)?)?)
+    }
+}
+
+impl<N: Network> fmt::Display for RecordViewKey<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let bytes = self.to_bytes_le().expect("Failed to convert record view key to bytes");
+        write!(f, "{}", hex::encode(bytes))
+    }
+}
+
+impl<N: Network> Serialize for RecordViewKey<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => serializer.collect_str(self),
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for RecordViewKey<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => FromStr::from_str(&String::deserialize(deserializer)?).map_err(de::Error::custom),
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "record view key"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testnet2::Testnet2, Account, AccountScheme, Record, RecordCiphertext};
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    #[test]
+    fn test_decrypt_with_record_view_key() {
+        let rng = &mut thread_rng();
+        let account = Account::<Testnet2>::new(rng);
+
+        let expected_record = Record::new_output(
+            account.address(),
+            1234,
+            Default::default(),
+            *Testnet2::noop_program_id(),
+            UniformRand::rand(rng),
+            rng,
+        )
+        .unwrap();
+        let (ciphertext, ciphertext_randomizer) = RecordCiphertext::encrypt(&expected_record, rng).unwrap();
+
+        // The record view key decrypts the same record as the account view key.
+        let record_view_key = RecordViewKey::new(expected_record.owner(), &ciphertext_randomizer);
+        assert_eq!(expected_record, ciphertext.decrypt_with_record_view_key(&record_view_key).unwrap());
+        assert_eq!(expected_record, ciphertext.decrypt(&account.view_key()).unwrap());
+
+        // A record view key for another randomizer or owner does not decrypt the record.
+        let wrong_randomizer = Testnet2::account_encryption_scheme().generate_randomness(rng);
+        let wrong_record_view_key = RecordViewKey::new(expected_record.owner(), &wrong_randomizer);
+        assert!(ciphertext.decrypt_with_record_view_key(&wrong_record_view_key).is_err());
+
+        let wrong_record_view_key = RecordViewKey::new(Account::<Testnet2>::new(rng).address(), &ciphertext_randomizer);
+        assert!(ciphertext.decrypt_with_record_view_key(&wrong_record_view_key).is_err());
+    }
+
+    #[test]
+    fn test_serde_json() {
+        let rng = &mut thread_rng();
+        let account = Account::<Testnet2>::new(rng);
+        let ciphertext_randomizer = Testnet2::account_encryption_scheme().generate_randomness(rng);
+
+        let expected_record_view_key = RecordViewKey::<Testnet2>::new(account.address(), &ciphertext_randomizer);
+
+        // Serialize
+        let expected_string = expected_record_view_key.to_string();
+        let candidate_string = serde_json::to_string(&expected_record_view_key).unwrap();
+        assert_eq!(
+            expected_string,
+            serde_json::Value::from_str(&candidate_string)
+                .unwrap()
+                .as_str()
+                .unwrap()
+        );
+
+        // Deserialize
+        assert_eq!(
+            expected_record_view_key,
+            RecordViewKey::from_str(&expected_string).unwrap()
+        );
+        assert_eq!(expected_record_view_key, serde_json::from_str(&candidate_string).unwrap());
+    }
+
+    #[test]
+    fn test_bincode() {
+        let rng = &mut thread_rng();
+        let account = Account::<Testnet2>::new(rng);
+        let ciphertext_randomizer = Testnet2::account_encryption_scheme().generate_randomness(rng);
+
+        let expected_record_view_key = RecordViewKey::<Testnet2>::new(account.address(), &ciphertext_randomizer);
+
+        // Serialize
+        let expected_bytes = expected_record_view_key.to_bytes_le().unwrap();
+        assert_eq!(64, expected_bytes.len(), "Update me if serialization has changed");
+
+        // Deserialize
+        assert_eq!(
+            expected_record_view_key,
+            RecordViewKey::read_le(&expected_bytes[..]).unwrap()
+        );
+        assert_eq!(
+            expected_record_view_key,
+            bincode::deserialize(&bincode::serialize(&expected_record_view_key).unwrap()).unwrap()
+        );
+    }
+}
@@ -27,7 +27,7 @@ use snarkvm_algorithms::{crypto_hash::PoseidonDefaultParametersField, merkle_tre
 use snarkvm_curves::{AffineCurve, PairingEngine, ProjectiveCurve, TwistedEdwardsParameters};
 use snarkvm_fields::{Field, PrimeField, ToConstraintField};
 use snarkvm_gadgets::{
-    traits::algorithms::{CRHGadget, CommitmentGadget, EncryptionGadget, PRFGadget, SignatureGadget},
+    traits::algorithms::{CRHGadget, CommitmentGadget, EncryptionGadget, PRFGadget, SignatureGadget, VRFGadget},
     GroupGadget,
     MaskedCRHGadget,
     SNARKVerifierGadget,
@@ -199,6 +199,10 @@ pub trait Network: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + S
     type AccountSignaturePublicKey: ToConstraintField<Self::InnerScalarField> + Clone + Default + Debug + Display + ToBytes + FromBytes + PartialEq + Eq + Hash + Sync + Send;
     type AccountSignature: Bech32Object<<Self::AccountSignatureScheme as SignatureScheme>::Signature>;
 
+    /// VRF for verifiable randomness under account keys. Invoked only over `Self::InnerScalarField`.
+    type AccountVRF: VRF<PrivateKey = Self::ProgramScalarField, PublicKey = Self::ProgramAffineCurve>;
+    type AccountVRFGadget: VRFGadget<Self::AccountVRF, Self::InnerScalarField>;
+
     /// CRH schemes for the block hash. Invoked only over `Self::InnerScalarField`.
     type BlockHashCRH: CRH<Output = Self::InnerScalarField>;
     type BlockHashCRHGadget: CRHGadget<Self::BlockHashCRH, Self::InnerScalarField>;
@@ -282,6 +286,7 @@ pub trait Network: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + S
 
     fn account_encryption_scheme() -> &'static Self::RecordCiphertextScheme;
     fn account_signature_scheme() -> &'static Self::AccountSignatureScheme;
+    fn account_vrf() -> &'static Self::AccountVRF;
     fn block_hash_crh() -> &'static Self::BlockHashCRH;
     fn block_header_root_parameters() -> &'static Self::BlockHeaderRootParameters;
     fn ciphertext_id_crh() -> &'static Self::CiphertextIDCRH;
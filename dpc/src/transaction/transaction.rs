@@ -175,6 +175,17 @@ impl<N: Network> Transaction<N> {
             return false;
         }
 
+        // Returns `false` if the value balance of the transaction overflows.
+        if let Err(error) = self
+            .transitions
+            .iter()
+            .map(Transition::value_balance)
+            .sum::<Result<AleoAmount, _>>()
+        {
+            eprintln!("{}", error);
+            return false;
+        }
+
         // Initialize a local transitions tree.
         let mut transitions = match Transitions::<N>::new() {
             Ok(transitions) => transitions,
@@ -277,12 +288,15 @@ impl<N: Network> Transaction<N> {
     }
 
     /// Returns the value balance.
+    ///
+    /// The sum saturates instead of overflowing; `Transaction::is_valid` rejects
+    /// transactions whose value balance overflows.
     #[inline]
     pub fn value_balance(&self) -> AleoAmount {
         self.transitions
             .iter()
             .map(Transition::value_balance)
-            .fold(AleoAmount::ZERO, |a, b| a.add(*b))
+            .fold(AleoAmount::ZERO, |a, b| a.saturating_add(*b))
     }
 
     /// Returns a reference to the state transitions.
@@ -314,6 +328,28 @@ impl<N: Network> Transaction<N> {
             .collect()
     }
 
+    /// Returns the public records from the transaction, decrypted with the record view keys in its events.
+    #[inline]
+    pub fn to_records_with_record_view_keys(&self) -> Vec<Record<N>> {
+        let ciphertexts = self
+            .transitions
+            .iter()
+            .flat_map(Transition::ciphertexts)
+            .collect::<Vec<_>>();
+
+        self.events
+            .iter()
+            .filter_map(|event| match event {
+                Event::RecordViewKey(index, record_view_key) => ciphertexts
+                    .get(*index as usize)?
+                    .decrypt_with_record_view_key(record_view_key)
+                    .ok(),
+                _ => None,
+            })
+            .filter(|record| !record.is_dummy())
+            .collect()
+    }
+
     /// Returns the local proof for a given commitment.
     #[inline]
     pub fn to_local_proof(&self, record_commitment: N::Commitment) -> Result<LocalProof<N>> {
@@ -485,6 +521,63 @@ mod tests {
         assert_eq!(expected_record.program_id(), candidate_record.program_id());
     }
 
+    #[test]
+    fn test_decrypt_records_with_record_view_keys() {
+        let rng = &mut thread_rng();
+        let account = Account::<Testnet2>::new(rng);
+        let ledger_root = LedgerTree::<Testnet2>::new().unwrap().root();
+
+        // Craft a transaction with 1 public coinbase record.
+        let request = Request::new_coinbase(account.address(), AleoAmount(1234), true, rng).unwrap();
+        let transaction = VirtualMachine::<Testnet2>::new(ledger_root)
+            .unwrap()
+            .execute(&request, rng)
+            .unwrap()
+            .finalize()
+            .unwrap();
+
+        // Anyone can decrypt the public record, and it matches the record decrypted by its owner.
+        let decrypted_records = transaction.to_records_with_record_view_keys();
+        assert_eq!(decrypted_records.len(), 1);
+        assert_eq!(decrypted_records, transaction.to_decrypted_records(&account.view_key()));
+
+        let candidate_record = decrypted_records.first().unwrap();
+        assert_eq!(account.address(), candidate_record.owner());
+        assert_eq!(1234, candidate_record.value());
+
+        // Craft a transaction with 2 transitions, each with 1 public coinbase record.
+        let other_account = Account::<Testnet2>::new(rng);
+        let request = Request::new_coinbase(account.address(), AleoAmount(1234), true, rng).unwrap();
+        let other_request = Request::new_coinbase(other_account.address(), AleoAmount(5678), true, rng).unwrap();
+        let transaction = VirtualMachine::<Testnet2>::new(ledger_root)
+            .unwrap()
+            .execute(&request, rng)
+            .unwrap()
+            .execute(&other_request, rng)
+            .unwrap()
+            .finalize()
+            .unwrap();
+
+        // Each record view key decrypts only the record of the transition that emitted it.
+        let decrypted_records = transaction.to_records_with_record_view_keys();
+        assert_eq!(decrypted_records.len(), 2);
+        assert_eq!(account.address(), decrypted_records[0].owner());
+        assert_eq!(1234, decrypted_records[0].value());
+        assert_eq!(other_account.address(), decrypted_records[1].owner());
+        assert_eq!(5678, decrypted_records[1].value());
+
+        // A private coinbase record emits no record view keys.
+        let request = Request::new_coinbase(account.address(), AleoAmount(1234), false, rng).unwrap();
+        let transaction = VirtualMachine::<Testnet2>::new(ledger_root)
+            .unwrap()
+            .execute(&request, rng)
+            .unwrap()
+            .finalize()
+            .unwrap();
+        assert!(transaction.to_records_with_record_view_keys().is_empty());
+        assert_eq!(transaction.to_decrypted_records(&account.view_key()).len(), 1);
+    }
+
     #[test]
     fn test_transaction_serde_json() {
         let rng = &mut thread_rng();
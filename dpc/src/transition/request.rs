@@ -53,7 +53,7 @@ impl<N: Network> Request<N> {
     ) -> Result<Self> {
         let burner = PrivateKey::new(rng);
         let operation = Operation::Coinbase(recipient, amount);
-        let fee = AleoAmount::ZERO.sub(amount);
+        let fee = (-amount)?;
         Self::new(
             &burner,
             vec![],
@@ -104,6 +104,9 @@ impl<N: Network> Request<N> {
     }
 
     /// Signs and returns a new instance of a request.
+    ///
+    /// The signature only covers the commitments of the input records, as this is the message
+    /// that the inner circuit verifies, so the request must be signed where the transaction is built.
     pub fn new<R: Rng + CryptoRng>(
         caller: &PrivateKey<N>,
         records: Vec<Record<N>>,
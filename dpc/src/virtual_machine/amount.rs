@@ -14,18 +14,24 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::AmountError;
 use snarkvm_utilities::{FromBytes, ToBytes};
 
 use serde::{Deserialize, Serialize};
 use std::{
+    convert::TryFrom,
     fmt,
     io::{Read, Result as IoResult, Write},
+    iter::Sum,
+    ops::{Add, Neg, Sub},
+    str::FromStr,
 };
 
 /// Represents the amount of ALEOs.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct AleoAmount(pub i64);
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Denomination {
     /// AB
     BYTE,
@@ -46,6 +52,19 @@ impl Denomination {
     }
 }
 
+impl FromStr for Denomination {
+    type Err = AmountError;
+
+    fn from_str(denomination: &str) -> Result<Self, Self::Err> {
+        match denomination.to_lowercase().as_str() {
+            "ab" | "byte" | "bytes" => Ok(Denomination::BYTE),
+            "ag" | "gate" | "gates" => Ok(Denomination::GATE),
+            "aleo" | "credit" | "credits" => Ok(Denomination::CREDIT),
+            _ => Err(AmountError::InvalidDenomination(denomination.to_string())),
+        }
+    }
+}
+
 impl fmt::Display for Denomination {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", match self {
@@ -85,16 +104,34 @@ impl AleoAmount {
         Self::from_bytes(bytes)
     }
 
-    /// Add the values of two `AleoAmount`s
-    #[allow(clippy::should_implement_trait)]
-    pub fn add(self, b: Self) -> Self {
-        Self::from_bytes(self.0 + b.0)
+    /// Returns the sum of two `AleoAmount`s, or `None` if the sum overflows.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Self)
     }
 
-    /// Subtract the value of two `AleoAmounts`
-    #[allow(clippy::should_implement_trait)]
-    pub fn sub(self, b: AleoAmount) -> Self {
-        Self::from_bytes(self.0 - b.0)
+    /// Returns the difference of two `AleoAmount`s, or `None` if the difference overflows.
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Self)
+    }
+
+    /// Returns the negation of the `AleoAmount`, or `None` if the negation overflows.
+    pub fn checked_neg(self) -> Option<Self> {
+        self.0.checked_neg().map(Self)
+    }
+
+    /// Returns the sum of two `AleoAmount`s, saturating at the numeric bounds.
+    pub const fn saturating_add(self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+
+    /// Returns the difference of two `AleoAmount`s, saturating at the numeric bounds.
+    pub const fn saturating_sub(self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0))
+    }
+
+    /// Returns the negation of the `AleoAmount`, saturating at the numeric bounds.
+    pub const fn saturating_neg(self) -> Self {
+        Self(self.0.saturating_neg())
     }
 
     /// Returns `true` the amount is positive and `false` if the amount is zero or
@@ -113,6 +150,105 @@ impl AleoAmount {
     pub const fn is_zero(self) -> bool {
         self.0 == 0
     }
+
+    /// Parses a decimal number of the given denomination, such as `1.5` credits.
+    fn from_decimal(decimal: &str, denomination: Denomination) -> Result<Self, AmountError> {
+        let invalid_format = || AmountError::InvalidFormat(decimal.to_string());
+
+        let (is_negative, magnitude) = match decimal.strip_prefix('-') {
+            Some(magnitude) => (true, magnitude),
+            None => (false, decimal),
+        };
+        let (integer, fraction) = match magnitude.split_once('.') {
+            Some((integer, fraction)) => (integer, fraction),
+            None => (magnitude, ""),
+        };
+
+        if integer.is_empty() || !integer.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit()) {
+            return Err(invalid_format());
+        }
+        if magnitude.ends_with('.') {
+            return Err(invalid_format());
+        }
+
+        let precision = denomination.precision();
+        let fraction = fraction.trim_end_matches('0');
+        if fraction.len() > precision as usize {
+            return Err(AmountError::InvalidPrecision(decimal.to_string()));
+        }
+
+        // Scale the integer and fractional parts into bytes, and check the result fits in an `i64`.
+        let scale = 10i128.pow(precision);
+        let integer = integer.parse::<i128>().map_err(|_| invalid_format())?;
+        let fraction = match fraction.is_empty() {
+            true => 0,
+            false => {
+                fraction.parse::<i128>().map_err(|_| invalid_format())? * 10i128.pow(precision - fraction.len() as u32)
+            }
+        };
+        let bytes = integer
+            .checked_mul(scale)
+            .and_then(|bytes| bytes.checked_add(fraction))
+            .map(|bytes| if is_negative { -bytes } else { bytes })
+            .ok_or_else(|| AmountError::ArithmeticOverflow(decimal.to_string()))?;
+
+        i64::try_from(bytes)
+            .map(Self)
+            .map_err(|_| AmountError::ArithmeticOverflow(decimal.to_string()))
+    }
+}
+
+impl Add for AleoAmount {
+    type Output = Result<Self, AmountError>;
+
+    fn add(self, other: Self) -> Self::Output {
+        self.checked_add(other)
+            .ok_or_else(|| AmountError::ArithmeticOverflow(format!("{} + {}", self, other)))
+    }
+}
+
+impl Sub for AleoAmount {
+    type Output = Result<Self, AmountError>;
+
+    fn sub(self, other: Self) -> Self::Output {
+        self.checked_sub(other)
+            .ok_or_else(|| AmountError::ArithmeticOverflow(format!("{} - {}", self, other)))
+    }
+}
+
+impl Neg for AleoAmount {
+    type Output = Result<Self, AmountError>;
+
+    fn neg(self) -> Self::Output {
+        self.checked_neg()
+            .ok_or_else(|| AmountError::ArithmeticOverflow(format!("-{}", self)))
+    }
+}
+
+impl Sum<AleoAmount> for Result<AleoAmount, AmountError> {
+    fn sum<I: Iterator<Item = AleoAmount>>(iter: I) -> Self {
+        iter.fold(Ok(AleoAmount::ZERO), |sum, amount| sum? + amount)
+    }
+}
+
+impl<'a> Sum<&'a AleoAmount> for Result<AleoAmount, AmountError> {
+    fn sum<I: Iterator<Item = &'a AleoAmount>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl FromStr for AleoAmount {
+    type Err = AmountError;
+
+    /// Parses an amount such as `1.5 credits` or `1500 gates`. An amount without a denomination is in bytes.
+    fn from_str(amount: &str) -> Result<Self, Self::Err> {
+        let mut parts = amount.split_whitespace();
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(decimal), None, None) => Self::from_decimal(decimal, Denomination::BYTE),
+            (Some(decimal), Some(denomination), None) => Self::from_decimal(decimal, denomination.parse()?),
+            _ => Err(AmountError::InvalidFormat(amount.to_string())),
+        }
+    }
 }
 
 impl ToBytes for AleoAmount {
@@ -160,7 +296,7 @@ mod tests {
         let b = AleoAmount::from_bytes(*b);
         let result = AleoAmount::from_bytes(*result);
 
-        assert_eq!(result, a.add(b));
+        assert_eq!(result, (a + b).unwrap());
     }
 
     fn test_subtraction(a: &i64, b: &i64, result: &i64) {
@@ -168,7 +304,7 @@ mod tests {
         let b = AleoAmount::from_bytes(*b);
         let result = AleoAmount::from_bytes(*result);
 
-        assert_eq!(result, a.sub(b));
+        assert_eq!(result, (a - b).unwrap());
     }
 
     pub(crate) struct AmountDenominationTestCase {
@@ -254,6 +390,108 @@ mod tests {
         }
     }
 
+    mod checked_arithmetic {
+        use super::*;
+
+        #[test]
+        fn test_overflow() {
+            let max = AleoAmount(i64::MAX);
+            let min = AleoAmount(i64::MIN);
+
+            assert!((max + AleoAmount::ONE_BYTE).is_err());
+            assert!((min - AleoAmount::ONE_BYTE).is_err());
+            assert!((-min).is_err());
+            assert_eq!(AleoAmount(-i64::MAX), (-max).unwrap());
+
+            assert_eq!(None, max.checked_add(AleoAmount::ONE_BYTE));
+            assert_eq!(None, min.checked_sub(AleoAmount::ONE_BYTE));
+            assert_eq!(None, min.checked_neg());
+        }
+
+        #[test]
+        fn test_saturating() {
+            let max = AleoAmount(i64::MAX);
+            let min = AleoAmount(i64::MIN);
+
+            assert_eq!(max, max.saturating_add(AleoAmount::ONE_CREDIT));
+            assert_eq!(min, min.saturating_sub(AleoAmount::ONE_CREDIT));
+            assert_eq!(max, min.saturating_neg());
+            assert_eq!(AleoAmount(1_001_000), AleoAmount::ONE_CREDIT.saturating_add(AleoAmount::ONE_GATE));
+        }
+
+        #[test]
+        fn test_sum() {
+            let amounts = vec![AleoAmount::ONE_CREDIT, AleoAmount::ONE_GATE, AleoAmount::ONE_BYTE];
+            assert_eq!(AleoAmount(1_001_001), amounts.iter().sum::<Result<AleoAmount, _>>().unwrap());
+            assert_eq!(AleoAmount::ZERO, Vec::<AleoAmount>::new().into_iter().sum::<Result<AleoAmount, _>>().unwrap());
+
+            // A sum overflows even if a later amount would bring it back in range.
+            let amounts = vec![AleoAmount(i64::MAX), AleoAmount::ONE_BYTE, AleoAmount(-1)];
+            assert!(amounts.into_iter().sum::<Result<AleoAmount, _>>().is_err());
+        }
+    }
+
+    mod parsing {
+        use super::*;
+
+        #[test]
+        fn test_valid_parsing() {
+            let test_cases = [
+                ("0", 0),
+                ("1234", 1234),
+                ("-1234", -1234),
+                ("1234 bytes", 1234),
+                ("1 byte", 1),
+                ("1500 gates", 1_500_000),
+                ("1.5 gates", 1500),
+                ("1.5 credits", 1_500_000),
+                ("1.500 credits", 1_500_000),
+                ("0.000001 credits", 1),
+                ("-2.25 credits", -2_250_000),
+                ("3 ALEO", 3_000_000),
+                ("3 AG", 3_000),
+                ("3 AB", 3),
+                ("  1 credit  ", 1_000_000),
+                ("-9223372036854775808", i64::MIN),
+            ];
+
+            for (amount, bytes) in test_cases.iter() {
+                assert_eq!(AleoAmount(*bytes), amount.parse::<AleoAmount>().unwrap(), "{}", amount);
+            }
+        }
+
+        #[test]
+        fn test_invalid_parsing() {
+            let test_cases = [
+                "",
+                "credits",
+                "1 2 credits",
+                "1.5.5 credits",
+                ".5 credits",
+                "1. credits",
+                "+1 credits",
+                "1e3 gates",
+                "1.5 coins",
+                "1.5 bytes",
+                "0.0000001 credits",
+                "9223372036854775808",
+                "9223372036854775807 credits",
+            ];
+
+            for amount in test_cases.iter() {
+                assert!(amount.parse::<AleoAmount>().is_err(), "{}", amount);
+            }
+        }
+
+        #[test]
+        fn test_display_round_trip() {
+            for bytes in [0, 1, -1, 1_500_000, i64::MAX, i64::MIN].iter() {
+                let amount = AleoAmount(*bytes);
+                assert_eq!(amount, amount.to_string().parse().unwrap());
+            }
+        }
+    }
+
     mod test_invalid {
         use super::*;
 
@@ -136,17 +136,7 @@ impl<N: Network> ResponseBuilder<N> {
             .iter()
             .enumerate()
             .take(N::NUM_OUTPUT_RECORDS)
-            .map(|(i, output)| {
-                let record = output.to_record(serial_numbers[i], rng)?;
-
-                // Add the record view key event if the output record is public.
-                if output.is_public() && events.len() < N::NUM_EVENTS as usize {
-                    // TODO (raychu86): Add the record view key instead of the placeholder byte.
-                    events.push(Event::RecordViewKey(i as u8, vec![0u8; 32]))
-                }
-
-                Ok(record)
-            })
+            .map(|(i, output)| output.to_record(serial_numbers[i], rng))
             .collect::<Result<Vec<_>>>()?;
 
         // Ensure the input records have the correct program ID.
@@ -174,13 +164,22 @@ impl<N: Network> ResponseBuilder<N> {
         // Compute the encrypted records.
         let (ciphertexts, ciphertext_randomizers) = Self::encrypt_records(&output_records, rng)?;
 
+        // Add the record view key events for the public output records.
+        // The virtual machine offsets each index by the output records of the preceding transitions.
+        for (i, output) in outputs.iter().enumerate().take(N::NUM_OUTPUT_RECORDS) {
+            if output.is_public() && events.len() < N::NUM_EVENTS as usize {
+                let record_view_key = RecordViewKey::new(output_records[i].owner(), &ciphertext_randomizers[i]);
+                events.push(Event::RecordViewKey(i as u16, record_view_key));
+            }
+        }
+
         // Compute the value balance.
         let mut value_balance = AleoAmount::ZERO;
         for record in input_records.iter().take(N::NUM_INPUT_RECORDS) {
-            value_balance = value_balance.add(AleoAmount::from_bytes(record.value() as i64));
+            value_balance = (value_balance + AleoAmount::from_bytes(record.value() as i64))?;
         }
         for record in output_records.iter().take(N::NUM_OUTPUT_RECORDS) {
-            value_balance = value_balance.sub(AleoAmount::from_bytes(record.value() as i64));
+            value_balance = (value_balance - AleoAmount::from_bytes(record.value() as i64))?;
         }
 
         // Ensure the value balance matches the fee from the request.
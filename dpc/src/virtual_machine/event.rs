@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Network, Operation};
+use crate::{Network, Operation, RecordViewKey};
 use snarkvm_utilities::{FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
 
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
@@ -28,8 +28,8 @@ use std::{
 pub enum Event<N: Network> {
     /// Emits publicly-visible arbitrary data.
     Custom(Vec<u8>),
-    /// Emits the view key for an output record at the specified index in a transition.
-    RecordViewKey(u8, Vec<u8>),
+    /// Emits the view key for an output record at the specified index in a transaction.
+    RecordViewKey(u16, RecordViewKey<N>),
     /// Emits the operation performed in a transition.
     Operation(Operation<N>),
 }
@@ -58,9 +58,8 @@ impl<N: Network> FromBytes for Event<N> {
                 Ok(Self::Custom(buffer))
             }
             1 => {
-                let index: u8 = FromBytes::read_le(&mut reader)?;
-                let mut record_view_key = vec![0u8; 32];
-                reader.read_exact(&mut record_view_key)?;
+                let index: u16 = FromBytes::read_le(&mut reader)?;
+                let record_view_key = FromBytes::read_le(&mut reader)?;
                 Ok(Self::RecordViewKey(index, record_view_key))
             }
             2 => Ok(Self::Operation(FromBytes::read_le(&mut reader)?)),
@@ -101,8 +100,8 @@ impl<N: Network> FromStr for Event<N> {
             }
             1 => {
                 let index = serde_json::from_value(event["index"].clone())?;
-                let record_view_key: String = serde_json::from_value(event["record_view_key"].clone())?;
-                Ok(Self::RecordViewKey(index, hex::decode(record_view_key)?))
+                let record_view_key = serde_json::from_value(event["record_view_key"].clone())?;
+                Ok(Self::RecordViewKey(index, record_view_key))
             }
             2 => {
                 let operation = serde_json::from_value(event["operation"].clone())?;
@@ -126,7 +125,7 @@ impl<N: Network> fmt::Display for Event<N> {
                 serde_json::json!({
                     "id": self.id(),
                     "index": index,
-                    "record_view_key": hex::encode(record_view_key), // TODO (raychu86): Have serializer for record_view_key
+                    "record_view_key": record_view_key,
                 })
             }
             Self::Operation(operation) => {
@@ -49,3 +49,6 @@ pub use program_public_variables::*;
 
 pub mod virtual_machine;
 pub use virtual_machine::*;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    testnet2::*,
+    Address,
+    Function,
+    FunctionType,
+    Network,
+    PrivateKey,
+    ProgramPrivateVariables,
+    ProgramPublicVariables,
+};
+use snarkvm_algorithms::VRF;
+use snarkvm_gadgets::prelude::*;
+use snarkvm_r1cs::{ConstraintSystem, SynthesisError, TestConstraintSystem};
+use snarkvm_utilities::{ToBytes, UniformRand};
+
+use anyhow::{anyhow, Result};
+use rand::SeedableRng;
+use rand_chacha::ChaChaRng;
+
+/// A function that verifies a VRF proof on the transition ID, under the account address.
+struct VRFFunction<N: Network> {
+    address: Address<N>,
+    proof: <N::AccountVRF as VRF>::Proof,
+}
+
+impl<N: Network> Function<N> for VRFFunction<N> {
+    fn function_id(&self) -> N::FunctionID {
+        Default::default()
+    }
+
+    fn function_type(&self) -> FunctionType {
+        FunctionType::Full
+    }
+
+    fn execute(
+        &self,
+        _public: ProgramPublicVariables<N>,
+        _private: &dyn ProgramPrivateVariables<N>,
+    ) -> Result<N::ProgramProof> {
+        Err(anyhow!("The VRF function is only synthesized in tests"))
+    }
+
+    fn verify(&self, _public: &ProgramPublicVariables<N>, _proof: &N::ProgramProof) -> bool {
+        false
+    }
+
+    fn synthesize<CS: ConstraintSystem<N::InnerScalarField>>(
+        &self,
+        cs: &mut CS,
+        public: &ProgramPublicVariables<N>,
+    ) -> Result<(), SynthesisError> {
+        let vrf =
+            N::AccountVRFGadget::alloc_constant(&mut cs.ns(|| "Declare the account VRF"), || Ok(N::account_vrf()))?;
+
+        let public_key = <N::AccountVRFGadget as VRFGadget<_, _>>::PublicKeyGadget::alloc(
+            &mut cs.ns(|| "Allocate the address"),
+            || Ok(*self.address),
+        )?;
+        let input = UInt8::alloc_vec(
+            &mut cs.ns(|| "Allocate the transition ID"),
+            &public.transition_id.to_bytes_le()?,
+        )?;
+        let proof = <N::AccountVRFGadget as VRFGadget<_, _>>::ProofGadget::alloc(
+            &mut cs.ns(|| "Allocate the VRF proof"),
+            || Ok(&self.proof),
+        )?;
+
+        let is_valid = vrf.verify(&mut cs.ns(|| "Verify the VRF proof"), &public_key, &input, &proof)?;
+        is_valid.enforce_equal(&mut cs.ns(|| "Check the VRF proof is valid"), &Boolean::Constant(true))
+    }
+}
+
+#[test]
+fn test_vrf_in_function_synthesize() {
+    let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+
+    let private_key = PrivateKey::<Testnet2>::new(rng);
+    let address = Address::from_private_key(&private_key);
+    let public = ProgramPublicVariables::<Testnet2> {
+        transition_id: UniformRand::rand(rng),
+    };
+    let proof = private_key
+        .prove_vrf(&public.transition_id.to_bytes_le().unwrap(), rng)
+        .unwrap();
+
+    // Ensure the function is satisfied by a valid proof.
+    let function = VRFFunction { address, proof };
+    let mut cs = TestConstraintSystem::<<Testnet2 as Network>::InnerScalarField>::new();
+    function.synthesize(&mut cs, &public).unwrap();
+    assert!(cs.is_satisfied());
+
+    // Ensure the function is not satisfied by the proof under another address.
+    let other_address = Address::from_private_key(&PrivateKey::new(rng));
+    let function = VRFFunction {
+        address: other_address,
+        proof,
+    };
+    let mut cs = TestConstraintSystem::<<Testnet2 as Network>::InnerScalarField>::new();
+    function.synthesize(&mut cs, &public).unwrap();
+    assert!(!cs.is_satisfied());
+
+    // Ensure the function is not satisfied by the proof on another transition ID.
+    let function = VRFFunction { address, proof };
+    let other_public = ProgramPublicVariables::<Testnet2> {
+        transition_id: UniformRand::rand(rng),
+    };
+    let mut cs = TestConstraintSystem::<<Testnet2 as Network>::InnerScalarField>::new();
+    function.synthesize(&mut cs, &other_public).unwrap();
+    assert!(!cs.is_satisfied());
+}
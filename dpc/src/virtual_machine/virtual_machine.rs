@@ -68,6 +68,23 @@ impl<N: Network> VirtualMachine<N> {
         Ok(self)
     }
 
+    /// Adds the events of the given response into the virtual machine, for its latest transition.
+    /// Record view key events are offset to index the output records of the transaction.
+    fn add_response_events(self, response: &Response<N>) -> Result<Self> {
+        let offset = self.transitions.len().saturating_sub(1) * N::NUM_OUTPUT_RECORDS;
+        let events = response
+            .events()
+            .iter()
+            .map(|event| match event {
+                Event::RecordViewKey(index, record_view_key) => {
+                    Event::RecordViewKey(offset as u16 + *index, record_view_key.clone())
+                }
+                event => event.clone(),
+            })
+            .collect();
+        self.add_events(&events)
+    }
+
     /// Returns the number of transitions in the virtual machine.
     pub fn num_transitions(&self) -> usize {
         self.transitions.len()
@@ -151,7 +168,7 @@ impl<N: Network> VirtualMachine<N> {
         // Update the state of the virtual machine.
         self.local_transitions.add(&transition)?;
         self.transitions.push(transition);
-        self = self.add_events(response.events())?;
+        self = self.add_response_events(&response)?;
 
         Ok(self)
     }
@@ -180,7 +197,13 @@ impl<N: Network> VirtualMachine<N> {
     ) -> Result<Response<N>> {
         ResponseBuilder::new()
             .add_request(request.clone())
-            .add_output(Output::new(recipient, amount, Default::default(), None, false)?)
+            .add_output(Output::new(
+                recipient,
+                amount,
+                Default::default(),
+                None,
+                request.is_public(),
+            )?)
             .build(rng)
     }
 
@@ -198,21 +221,33 @@ impl<N: Network> VirtualMachine<N> {
         }
 
         // Compute the starting balance of the caller.
-        let starting_balance = request.to_balance().sub(request.fee());
+        let starting_balance = (request.to_balance() - request.fee())?;
         if starting_balance.is_negative() {
             return Err(VMError::BalanceInsufficient.into());
         }
 
         // Compute the final balance of the caller.
-        let caller_balance = starting_balance.sub(amount);
+        let caller_balance = (starting_balance - amount)?;
         if caller_balance.is_negative() {
             return Err(VMError::BalanceInsufficient.into());
         }
 
         ResponseBuilder::new()
             .add_request(request.clone())
-            .add_output(Output::new(caller, caller_balance, Default::default(), None, false)?)
-            .add_output(Output::new(recipient, amount, Default::default(), None, false)?)
+            .add_output(Output::new(
+                caller,
+                caller_balance,
+                Default::default(),
+                None,
+                request.is_public(),
+            )?)
+            .add_output(Output::new(
+                recipient,
+                amount,
+                Default::default(),
+                None,
+                request.is_public(),
+            )?)
             .build(rng)
     }
 
@@ -242,13 +277,13 @@ impl<N: Network> VirtualMachine<N> {
         }
 
         // Compute the starting balance of the caller.
-        let starting_balance = request.to_balance().sub(request.fee());
+        let starting_balance = (request.to_balance() - request.fee())?;
         if starting_balance.is_negative() {
             return Err(VMError::BalanceInsufficient.into());
         }
 
         // Compute the final balance of the caller.
-        let caller_balance = starting_balance.sub(function_inputs.amount);
+        let caller_balance = (starting_balance - function_inputs.amount)?;
         if caller_balance.is_negative() {
             return Err(VMError::BalanceInsufficient.into());
         }
@@ -374,7 +409,7 @@ impl<N: Network> VirtualMachine<N> {
         self.transitions.push(transition);
 
         // Add events to the virtual machine.
-        self = self.add_events(response.events())?;
+        self = self.add_response_events(&response)?;
         for event in custom_events {
             let custom_event = Event::Custom(event);
             self = self.add_event(custom_event)?;
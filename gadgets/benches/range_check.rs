@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use rand::{Rng, SeedableRng};
+use rand_xorshift::XorShiftRng;
+
+use snarkvm_gadgets::prelude::*;
+use snarkvm_r1cs::{ConstraintCounter, ConstraintSystem, Fr, TestConstraintSystem};
+
+macro_rules! create_range_check_bench {
+    ($bench_name:ident, $bench_id:expr, $foo_name:ident, |$cs:ident, $a:ident, $b:ident| $check:expr) => {
+        fn $bench_name(c: &mut Criterion) {
+            fn $foo_name<CS: ConstraintSystem<Fr>>(cs: &mut CS, rng: &mut XorShiftRng) {
+                let a: u32 = rng.gen();
+                let b: u32 = rng.gen();
+
+                let bench_run_id: u64 = rng.gen();
+
+                let $a = &FpGadget::alloc(cs.ns(|| format!("{}: a", bench_run_id)), || {
+                    Ok(Fr::from(a as u128))
+                })
+                .unwrap();
+                let $b = &FpGadget::alloc(cs.ns(|| format!("{}: b", bench_run_id)), || {
+                    Ok(Fr::from(b as u128))
+                })
+                .unwrap();
+
+                let $cs = cs.ns(|| format!("{}: check", bench_run_id));
+                $check;
+            }
+
+            let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+            let mut counter = ConstraintCounter::default();
+            $foo_name(&mut counter, &mut rng);
+            println!(
+                "range_check::{}: {} constraints",
+                $bench_id, counter.num_constraints
+            );
+
+            let mut cs = TestConstraintSystem::<Fr>::new();
+
+            c.bench_function(&format!("range_check::{}", $bench_id), |b| {
+                b.iter(|| $foo_name(&mut cs, &mut rng))
+            });
+        }
+    };
+}
+
+create_range_check_bench!(bench_bits_32, "bits_32", bits_32, |cs, a, _b| {
+    a.enforce_bit_length(cs, 32).unwrap()
+});
+create_range_check_bench!(bench_bits_64, "bits_64", bits_64, |cs, a, _b| {
+    a.enforce_bit_length(cs, 64).unwrap()
+});
+create_range_check_bench!(bench_is_le_32, "is_le_32", is_le_32, |cs, a, b| {
+    a.is_less_than_or_equal_bounded(cs, b, 32).unwrap();
+});
+create_range_check_bench!(bench_enforce_le_32, "enforce_le_32", enforce_le_32, |cs, a, b| {
+    let (min, max) = match a.get_value() <= b.get_value() {
+        true => (a, b),
+        false => (b, a),
+    };
+    min.enforce_less_than_or_equal_bounded(cs, max, 32).unwrap()
+});
+
+criterion_group!(
+    name = benches_range_check;
+    config = Criterion::default();
+    targets = bench_bits_32,
+    bench_bits_64,
+);
+
+criterion_group!(
+    name = benches_comparison;
+    config = Criterion::default();
+    targets = bench_is_le_32,
+    bench_enforce_le_32,
+);
+
+criterion_main!(benches_range_check, benches_comparison);
@@ -17,6 +17,9 @@
 pub mod poseidon;
 pub use poseidon::*;
 
+pub mod rescue;
+pub use rescue::*;
+
 pub mod sponge;
 pub use sponge::*;
 
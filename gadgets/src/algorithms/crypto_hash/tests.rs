@@ -15,14 +15,21 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    algorithms::crypto_hash::{CryptographicSpongeVar, PoseidonSpongeGadget},
+    algorithms::crypto_hash::{CryptographicSpongeVar, PoseidonSpongeGadget, RescueSpongeGadget},
     AllocGadget,
     FieldGadget,
     FpGadget,
 };
-use snarkvm_algorithms::crypto_hash::{poseidon::PoseidonSponge, CryptographicSponge, PoseidonDefaultParametersField};
+use snarkvm_algorithms::crypto_hash::{
+    generate_rescue_parameters,
+    poseidon::PoseidonSponge,
+    CryptographicSponge,
+    PoseidonDefaultParametersField,
+    RescueSponge,
+};
 use snarkvm_curves::bls12_377::Fr;
-use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
+use snarkvm_fields::One;
+use snarkvm_r1cs::{ConstraintCounter, ConstraintSystem, TestConstraintSystem};
 use snarkvm_utilities::{test_rng, UniformRand};
 
 use std::sync::Arc;
@@ -56,3 +63,75 @@ fn absorb_test() {
     assert_eq!(constraint_squeeze[0].get_value().unwrap(), native_squeeze[0]);
     assert!(cs.is_satisfied());
 }
+
+#[test]
+fn rescue_absorb_test() {
+    let mut rng = test_rng();
+
+    let mut cs = TestConstraintSystem::<Fr>::new();
+
+    let absorb: Vec<_> = (0..256).map(|_| Fr::rand(&mut rng)).collect();
+    let absorb_var: Vec<_> = absorb
+        .iter()
+        .enumerate()
+        .map(|(i, v)| FpGadget::<Fr>::alloc_input(cs.ns(|| format!("alloc input {}", i)), || Ok((*v).clone())).unwrap())
+        .collect();
+
+    let sponge_params = Arc::new(generate_rescue_parameters::<Fr>(2, 17, 128).unwrap());
+
+    let mut native_sponge = RescueSponge::<Fr>::new(&sponge_params);
+    let mut constraint_sponge = RescueSpongeGadget::<Fr>::new(cs.ns(|| "new sponge"), &sponge_params);
+
+    native_sponge.absorb(&absorb);
+    constraint_sponge.absorb(cs.ns(|| "absorb"), absorb_var.iter()).unwrap();
+
+    let native_squeeze = native_sponge.squeeze_field_elements(3);
+    let constraint_squeeze = constraint_sponge
+        .squeeze_field_elements(cs.ns(|| "squeeze"), 3)
+        .unwrap();
+
+    for (native, constraint) in native_squeeze.iter().zip(constraint_squeeze.iter()) {
+        assert_eq!(constraint.get_value().unwrap(), *native);
+    }
+    assert!(cs.is_satisfied());
+}
+
+#[test]
+fn permutation_constraints_test() {
+    let poseidon_params = Arc::new(Fr::get_default_poseidon_parameters(2, false).unwrap());
+    let rescue_params = Arc::new(generate_rescue_parameters::<Fr>(2, 17, 128).unwrap());
+
+    // Count the constraints of a single permutation for each sponge.
+    let mut poseidon_cs = ConstraintCounter::default();
+    let poseidon_input: Vec<_> = (0..2)
+        .map(|i| FpGadget::<Fr>::alloc(poseidon_cs.ns(|| format!("alloc input {}", i)), || Ok(Fr::one())).unwrap())
+        .collect();
+    let mut poseidon_sponge = PoseidonSpongeGadget::<Fr>::new(poseidon_cs.ns(|| "new sponge"), &poseidon_params);
+    poseidon_sponge
+        .absorb(poseidon_cs.ns(|| "absorb"), poseidon_input.iter())
+        .unwrap();
+    poseidon_sponge
+        .squeeze_field_elements(poseidon_cs.ns(|| "squeeze"), 1)
+        .unwrap();
+
+    let mut rescue_cs = ConstraintCounter::default();
+    let rescue_input: Vec<_> = (0..2)
+        .map(|i| FpGadget::<Fr>::alloc(rescue_cs.ns(|| format!("alloc input {}", i)), || Ok(Fr::one())).unwrap())
+        .collect();
+    let mut rescue_sponge = RescueSpongeGadget::<Fr>::new(rescue_cs.ns(|| "new sponge"), &rescue_params);
+    rescue_sponge
+        .absorb(rescue_cs.ns(|| "absorb"), rescue_input.iter())
+        .unwrap();
+    rescue_sponge
+        .squeeze_field_elements(rescue_cs.ns(|| "squeeze"), 1)
+        .unwrap();
+
+    println!("Poseidon permutation constraints: {}", poseidon_cs.num_constraints);
+    println!("Rescue permutation constraints: {}", rescue_cs.num_constraints);
+
+    // Each S-box with `alpha = 17` costs 7 constraints, and each inverse S-box costs one more to check the result.
+    let poseidon_sboxes = (poseidon_params.rate + 1) * poseidon_params.full_rounds + poseidon_params.partial_rounds;
+    let rescue_sboxes = (rescue_params.rate + 1) * rescue_params.rounds;
+    assert_eq!(7 * poseidon_sboxes, poseidon_cs.num_constraints);
+    assert_eq!(7 * rescue_sboxes + 8 * rescue_sboxes, rescue_cs.num_constraints);
+}
@@ -29,7 +29,10 @@ use crate::{
     UInt8,
 };
 use itertools::Itertools;
-use snarkvm_algorithms::{crypto_hash::PoseidonDefaultParametersField, encryption::ECIESPoseidonEncryption};
+use snarkvm_algorithms::{
+    crypto_hash::PoseidonDefaultParametersField,
+    encryption::{ECIESPoseidonEncryption, ECIES_POSEIDON_AUTHENTICATED_CIPHERTEXT_VERSION, ECIES_POSEIDON_MAC_DOMAIN},
+};
 use snarkvm_curves::{
     templates::twisted_edwards_extended::{Affine as TEAffine, Projective as TEProjective},
     AffineCurve,
@@ -320,48 +323,28 @@ impl<TE: TwistedEdwardsParameters<BaseField = F>, F: PrimeField> EqGadget<F>
 }
 
 impl<TE: TwistedEdwardsParameters<BaseField = F>, F: PrimeField + PoseidonDefaultParametersField>
-    EncryptionGadget<ECIESPoseidonEncryption<TE>, F> for ECIESPoseidonEncryptionGadget<TE, F>
+    ECIESPoseidonEncryptionGadget<TE, F>
 {
-    type PrivateKeyGadget = ECIESPoseidonEncryptionPrivateKeyGadget<TE, F>;
-    type PublicKeyGadget = ECIESPoseidonEncryptionPublicKeyGadget<TE, F>;
-    type RandomnessGadget = ECIESPoseidonEncryptionRandomnessGadget<TE>;
-
-    fn check_public_key_gadget<CS: ConstraintSystem<TE::BaseField>>(
+    ///
+    /// Returns the randomizer, public key commitment, and ciphertext elements (and their bytes)
+    /// for the given message, along with the sponge used to derive the keystream.
+    ///
+    fn encrypt_field_elements<CS: ConstraintSystem<F>>(
         &self,
         mut cs: CS,
-        private_key: &Self::PrivateKeyGadget,
-    ) -> Result<Self::PublicKeyGadget, SynthesisError> {
-        let private_key_bits = private_key.0.iter().flat_map(|b| b.to_bits_le()).collect::<Vec<_>>();
-        let mut public_key = <TEAffineGadget<TE, F> as GroupGadget<TEAffine<TE>, F>>::zero(cs.ns(|| "zero"))?;
-
-        let num_powers = private_key_bits.len();
-
-        let generator_powers: Vec<TEAffine<TE>> = {
-            let mut generator_powers = Vec::new();
-            let mut generator = self.encryption.generator.into_projective();
-            for _ in 0..num_powers {
-                generator_powers.push(generator.clone());
-                generator.double_in_place();
-            }
-            TEProjective::<TE>::batch_normalization(&mut generator_powers);
-            generator_powers.into_iter().map(|v| v.into()).collect()
-        };
-
-        public_key.scalar_multiplication(
-            cs.ns(|| "check_public_key_gadget"),
-            private_key_bits.iter().zip_eq(&generator_powers),
-        )?;
-
-        Ok(ECIESPoseidonEncryptionPublicKeyGadget::<TE, F> { 0: public_key })
-    }
-
-    fn check_encryption_gadget<CS: ConstraintSystem<F>>(
-        &self,
-        mut cs: CS,
-        randomness: &Self::RandomnessGadget,
-        public_key: &Self::PublicKeyGadget,
+        randomness: &ECIESPoseidonEncryptionRandomnessGadget<TE>,
+        public_key: &ECIESPoseidonEncryptionPublicKeyGadget<TE, F>,
         message: &[UInt8],
-    ) -> Result<Vec<UInt8>, SynthesisError> {
+    ) -> Result<
+        (
+            FpGadget<F>,
+            FpGadget<F>,
+            Vec<FpGadget<F>>,
+            Vec<UInt8>,
+            PoseidonSpongeGadget<F>,
+        ),
+        SynthesisError,
+    > {
         let affine_zero: TEAffineGadget<TE, F> =
             <TEAffineGadget<TE, F> as GroupGadget<TEAffine<TE>, F>>::zero(cs.ns(|| "affine zero")).unwrap();
 
@@ -428,8 +411,7 @@ impl<TE: TwistedEdwardsParameters<BaseField = F>, F: PrimeField + PoseidonDefaul
 
         let res_bytes = res.to_bytes(cs.ns(|| "convert the masked results into bytes"))?;
 
-        // Put the bytes of the x coordinate of the randomness group element
-        // into the beginning of the ciphertext.
+        // Compute the x coordinate of the randomness group element.
         let generator_gadget = TEAffineGadget::<TE, F>::alloc_constant(cs.ns(|| "alloc generator"), || {
             Ok(self.encryption.generator.clone())
         })?;
@@ -439,12 +421,106 @@ impl<TE: TwistedEdwardsParameters<BaseField = F>, F: PrimeField + PoseidonDefaul
             &affine_zero,
             randomness_bits.iter().copied(),
         )?;
-        let random_elem_bytes = randomness_elem
-            .x
-            .to_bytes(cs.ns(|| "convert the randomness element to bytes"))?;
+
+        Ok((randomness_elem.x, public_key_commitment, res, res_bytes, sponge))
+    }
+}
+
+impl<TE: TwistedEdwardsParameters<BaseField = F>, F: PrimeField + PoseidonDefaultParametersField>
+    EncryptionGadget<ECIESPoseidonEncryption<TE>, F> for ECIESPoseidonEncryptionGadget<TE, F>
+{
+    type PrivateKeyGadget = ECIESPoseidonEncryptionPrivateKeyGadget<TE, F>;
+    type PublicKeyGadget = ECIESPoseidonEncryptionPublicKeyGadget<TE, F>;
+    type RandomnessGadget = ECIESPoseidonEncryptionRandomnessGadget<TE>;
+
+    fn check_public_key_gadget<CS: ConstraintSystem<TE::BaseField>>(
+        &self,
+        mut cs: CS,
+        private_key: &Self::PrivateKeyGadget,
+    ) -> Result<Self::PublicKeyGadget, SynthesisError> {
+        let private_key_bits = private_key.0.iter().flat_map(|b| b.to_bits_le()).collect::<Vec<_>>();
+        let mut public_key = <TEAffineGadget<TE, F> as GroupGadget<TEAffine<TE>, F>>::zero(cs.ns(|| "zero"))?;
+
+        let num_powers = private_key_bits.len();
+
+        let generator_powers: Vec<TEAffine<TE>> = {
+            let mut generator_powers = Vec::new();
+            let mut generator = self.encryption.generator.into_projective();
+            for _ in 0..num_powers {
+                generator_powers.push(generator.clone());
+                generator.double_in_place();
+            }
+            TEProjective::<TE>::batch_normalization(&mut generator_powers);
+            generator_powers.into_iter().map(|v| v.into()).collect()
+        };
+
+        public_key.scalar_multiplication(
+            cs.ns(|| "check_public_key_gadget"),
+            private_key_bits.iter().zip_eq(&generator_powers),
+        )?;
+
+        Ok(ECIESPoseidonEncryptionPublicKeyGadget::<TE, F> { 0: public_key })
+    }
+
+    fn check_encryption_gadget<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        randomness: &Self::RandomnessGadget,
+        public_key: &Self::PublicKeyGadget,
+        message: &[UInt8],
+    ) -> Result<Vec<UInt8>, SynthesisError> {
+        let (randomizer, public_key_commitment, _, res_bytes, _) =
+            self.encrypt_field_elements(cs.ns(|| "encrypt"), randomness, public_key, message)?;
+
+        let random_elem_bytes = randomizer.to_bytes(cs.ns(|| "convert the randomness element to bytes"))?;
         let public_key_commitment_bytes =
             public_key_commitment.to_bytes(cs.ns(|| "convert the public key commitment to bytes"))?;
 
         Ok([random_elem_bytes, public_key_commitment_bytes, res_bytes].concat())
     }
+
+    fn check_authenticated_encryption_gadget<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        randomness: &Self::RandomnessGadget,
+        public_key: &Self::PublicKeyGadget,
+        message: &[UInt8],
+    ) -> Result<Vec<UInt8>, SynthesisError> {
+        let (randomizer, public_key_commitment, ciphertext, res_bytes, mut sponge) =
+            self.encrypt_field_elements(cs.ns(|| "encrypt"), randomness, public_key, message)?;
+
+        // Squeeze one element for the MAC key, and authenticate the ciphertext.
+        let mac_key = sponge.squeeze_field_elements(cs.ns(|| "squeeze for the MAC key"), 1)?[0].clone();
+        let mac = {
+            let params =
+                <TE::BaseField as PoseidonDefaultParametersField>::get_default_poseidon_parameters(4, false).unwrap();
+            let mut sponge = PoseidonSpongeGadget::<TE::BaseField>::new(cs.ns(|| "sponge for MAC"), &params);
+            sponge.absorb(
+                cs.ns(|| "absorb the MAC header"),
+                [
+                    FpGadget::<F>::Constant(F::from_bytes_le_mod_order(ECIES_POSEIDON_MAC_DOMAIN.as_bytes())),
+                    mac_key,
+                    randomizer.clone(),
+                    public_key_commitment.clone(),
+                ]
+                .iter(),
+            )?;
+            sponge.absorb(cs.ns(|| "absorb the ciphertext for MAC"), ciphertext.iter())?;
+            sponge.squeeze_field_elements(cs.ns(|| "squeeze for MAC"), 1)?[0].clone()
+        };
+
+        let random_elem_bytes = randomizer.to_bytes(cs.ns(|| "convert the randomness element to bytes"))?;
+        let public_key_commitment_bytes =
+            public_key_commitment.to_bytes(cs.ns(|| "convert the public key commitment to bytes"))?;
+        let mac_bytes = mac.to_bytes(cs.ns(|| "convert the MAC to bytes"))?;
+
+        Ok([
+            vec![UInt8::constant(ECIES_POSEIDON_AUTHENTICATED_CIPHERTEXT_VERSION)],
+            random_elem_bytes,
+            public_key_commitment_bytes,
+            res_bytes,
+            mac_bytes,
+        ]
+        .concat())
+    }
 }
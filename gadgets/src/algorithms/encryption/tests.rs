@@ -140,4 +140,68 @@ mod ecies_poseidon {
         }
         assert!(cs.is_satisfied());
     }
+
+    #[test]
+    fn test_ecies_poseidon_authenticated_encryption_equivalence() {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+
+        let encryption_scheme = TestEncryptionScheme::setup("test_authenticated_encryption_gadget");
+
+        let private_key = encryption_scheme.generate_private_key(rng);
+        let public_key = encryption_scheme.generate_public_key(&private_key);
+
+        let randomness = encryption_scheme.generate_randomness(rng);
+        let message: Vec<u8> = (0..10).map(|_| rand::random::<u8>()).collect();
+        let ciphertext = encryption_scheme
+            .encrypt_authenticated(&public_key, &randomness, &message)
+            .unwrap();
+
+        // Alloc parameters, public key, plaintext, randomness, and blinding exponents
+        let encryption =
+            TestEncryptionSchemeGadget::alloc_constant(&mut cs.ns(|| "parameters_gadget"), || Ok(&encryption_scheme))
+                .unwrap();
+        let public_key_gadget =
+            <TestEncryptionSchemeGadget as EncryptionGadget<TestEncryptionScheme, _>>::PublicKeyGadget::alloc(
+                &mut cs.ns(|| "public_key_gadget"),
+                || Ok(&public_key),
+            )
+            .unwrap();
+        let message_gadget = UInt8::alloc_vec(&mut cs.ns(|| "plaintext_gadget"), &message).unwrap();
+
+        let randomness_gadget =
+            <TestEncryptionSchemeGadget as EncryptionGadget<TestEncryptionScheme, _>>::RandomnessGadget::alloc(
+                &mut cs.ns(|| "randomness_gadget"),
+                || Ok(&randomness),
+            )
+            .unwrap();
+
+        // Expected ciphertext gadget
+        let expected_ciphertext_gadget = UInt8::alloc_vec(&mut cs.ns(|| "ciphertext_gadget"), &ciphertext).unwrap();
+
+        println!("number of constraints for inputs: {}", cs.num_constraints());
+
+        let ciphertext_gadget = encryption
+            .check_authenticated_encryption_gadget(
+                &mut cs.ns(|| "ciphertext_gadget_evaluation"),
+                &randomness_gadget,
+                &public_key_gadget,
+                &message_gadget,
+            )
+            .unwrap();
+
+        expected_ciphertext_gadget
+            .enforce_equal(
+                cs.ns(|| "Check that declared and computed ciphertexts are equal"),
+                &ciphertext_gadget,
+            )
+            .unwrap();
+
+        println!("number of constraints total: {}", cs.num_constraints());
+
+        if !cs.is_satisfied() {
+            println!("which is unsatisfied: {:?}", cs.which_is_unsatisfied().unwrap());
+        }
+        assert!(cs.is_satisfied());
+    }
 }
@@ -20,5 +20,8 @@ pub use masked_tree::*;
 pub mod merkle_path;
 pub use merkle_path::*;
 
+pub mod sparse_merkle_path;
+pub use sparse_merkle_path::*;
+
 #[cfg(test)]
 pub mod tests;
@@ -0,0 +1,214 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::borrow::Borrow;
+
+use snarkvm_algorithms::{
+    merkle_tree::SparseMerklePath,
+    traits::MerkleParameters,
+};
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+use crate::{
+    algorithms::merkle_tree::merkle_path::hash_inner_node_gadget,
+    bits::{boolean::Boolean, ToBitsLEGadget, ToBytesGadget},
+    traits::{algorithms::CRHGadget, alloc::AllocGadget, eq::ConditionalEqGadget, select::CondSelectGadget},
+};
+
+pub struct SparseMerklePathGadget<P: MerkleParameters, HG: CRHGadget<P::H, F>, F: PrimeField> {
+    /// `path[i]` is the entry of sibling of ith node from bottom to top.
+    path: Vec<HG::OutputGadget>,
+    /// The hash of an unoccupied leaf.
+    empty_hash: HG::OutputGadget,
+}
+
+impl<P: MerkleParameters, HG: CRHGadget<P::H, F>, F: PrimeField> SparseMerklePathGadget<P, HG, F> {
+    /// Enforces that the given key is in the tree with the given root.
+    pub fn check_membership<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        crh: &HG,
+        root: &HG::OutputGadget,
+        key: impl ToBytesGadget<F>,
+    ) -> Result<(), SynthesisError> {
+        self.conditionally_check_membership(cs, crh, root, key, &Boolean::Constant(true))
+    }
+
+    pub fn conditionally_check_membership<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        crh: &HG,
+        root: &HG::OutputGadget,
+        key: impl ToBytesGadget<F>,
+        should_enforce: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        let (leaf_hash, traversal) = self.key_to_leaf(cs.ns(|| "key_to_leaf"), crh, key)?;
+        let expected_root = self.calculate_root(cs.ns(|| "calculate_root"), crh, leaf_hash, &traversal)?;
+
+        root.conditional_enforce_equal(&mut cs.ns(|| "root_is_eq"), &expected_root, should_enforce)
+    }
+
+    /// Enforces that the given key is not in the tree with the given root.
+    pub fn check_non_membership<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        crh: &HG,
+        root: &HG::OutputGadget,
+        key: impl ToBytesGadget<F>,
+    ) -> Result<(), SynthesisError> {
+        self.conditionally_check_non_membership(cs, crh, root, key, &Boolean::Constant(true))
+    }
+
+    pub fn conditionally_check_non_membership<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        crh: &HG,
+        root: &HG::OutputGadget,
+        key: impl ToBytesGadget<F>,
+        should_enforce: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        // The leaf of an absent key holds the empty hash, at the position given by the leaf hash of the key.
+        let (_, traversal) = self.key_to_leaf(cs.ns(|| "key_to_leaf"), crh, key)?;
+        let expected_root =
+            self.calculate_root(cs.ns(|| "calculate_root"), crh, self.empty_hash.clone(), &traversal)?;
+
+        root.conditional_enforce_equal(&mut cs.ns(|| "root_is_eq"), &expected_root, should_enforce)
+    }
+
+    /// Returns the leaf hash of the given key, and the position of its leaf,
+    /// which is given by the little-endian bits of the leaf hash.
+    fn key_to_leaf<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        crh: &HG,
+        key: impl ToBytesGadget<F>,
+    ) -> Result<(HG::OutputGadget, Vec<Boolean>), SynthesisError> {
+        let key_bytes = key.to_bytes(&mut cs.ns(|| "key_to_bytes"))?;
+        let leaf_hash = crh.check_evaluation_gadget(cs.ns(|| "leaf_hash"), key_bytes)?;
+
+        // The leaf hash is decomposed strictly, so that the position of the key is unique.
+        let leaf_hash_bytes = leaf_hash.to_bytes_strict(&mut cs.ns(|| "leaf_hash_to_bytes"))?;
+        let traversal = leaf_hash_bytes.to_bits_le(cs.ns(|| "leaf_hash_to_bits"))?;
+        Ok((leaf_hash, traversal))
+    }
+
+    fn calculate_root<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        crh: &HG,
+        leaf_hash: HG::OutputGadget,
+        traversal: &[Boolean],
+    ) -> Result<HG::OutputGadget, SynthesisError> {
+        // Ensure the path has a sibling for every bit of the leaf position.
+        if traversal.len() != self.path.len() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        let mut curr_hash = leaf_hash;
+
+        // At any given bit, the bit being 0 indicates our currently hashed value is the left,
+        // and the bit being 1 indicates our currently hashed value is on the right.
+        for (i, (bit, sibling)) in traversal.iter().zip(self.path.iter()).enumerate() {
+            let left_hash = HG::OutputGadget::conditionally_select(
+                cs.ns(|| format!("cond_select_left_{}", i)),
+                bit,
+                sibling,
+                &curr_hash,
+            )?;
+            let right_hash = HG::OutputGadget::conditionally_select(
+                cs.ns(|| format!("cond_select_right_{}", i)),
+                bit,
+                &curr_hash,
+                sibling,
+            )?;
+
+            curr_hash = hash_inner_node_gadget::<P::H, HG, F, _>(
+                &mut cs.ns(|| format!("hash_inner_node_{}", i)),
+                crh,
+                &left_hash,
+                &right_hash,
+            )?;
+        }
+
+        Ok(curr_hash)
+    }
+}
+
+impl<P, HGadget, F> SparseMerklePathGadget<P, HGadget, F>
+where
+    P: MerkleParameters,
+    HGadget: CRHGadget<P::H, F>,
+    F: PrimeField,
+{
+    fn alloc_empty_hash<CS: ConstraintSystem<F>>(
+        cs: CS,
+        sparse_merkle_path: &SparseMerklePath<P>,
+    ) -> Result<HGadget::OutputGadget, SynthesisError> {
+        let empty_hash = sparse_merkle_path
+            .parameters
+            .hash_empty()
+            .map_err(|error| SynthesisError::AnyhowError(error.into()))?;
+        HGadget::OutputGadget::alloc_constant(cs, || Ok(empty_hash))
+    }
+}
+
+impl<P, HGadget, F> AllocGadget<SparseMerklePath<P>, F> for SparseMerklePathGadget<P, HGadget, F>
+where
+    P: MerkleParameters,
+    HGadget: CRHGadget<P::H, F>,
+    F: PrimeField,
+{
+    fn alloc<Fn, T, CS: ConstraintSystem<F>>(mut cs: CS, value_gen: Fn) -> Result<Self, SynthesisError>
+    where
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<SparseMerklePath<P>>,
+    {
+        let sparse_merkle_path = value_gen()?.borrow().clone();
+
+        let mut path = Vec::with_capacity(sparse_merkle_path.path.len());
+        for (i, node) in sparse_merkle_path.path.iter().enumerate() {
+            path.push(HGadget::OutputGadget::alloc(
+                &mut cs.ns(|| format!("alloc_node_{}", i)),
+                || Ok(node.clone()),
+            )?);
+        }
+
+        let empty_hash = Self::alloc_empty_hash(cs.ns(|| "alloc_empty_hash"), &sparse_merkle_path)?;
+
+        Ok(SparseMerklePathGadget { path, empty_hash })
+    }
+
+    fn alloc_input<Fn, T, CS: ConstraintSystem<F>>(mut cs: CS, value_gen: Fn) -> Result<Self, SynthesisError>
+    where
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<SparseMerklePath<P>>,
+    {
+        let sparse_merkle_path = value_gen()?.borrow().clone();
+
+        let mut path = Vec::with_capacity(sparse_merkle_path.path.len());
+        for (i, node) in sparse_merkle_path.path.iter().enumerate() {
+            path.push(HGadget::OutputGadget::alloc_input(
+                &mut cs.ns(|| format!("alloc_input_node_{}", i)),
+                || Ok(node.clone()),
+            )?);
+        }
+
+        let empty_hash = Self::alloc_empty_hash(cs.ns(|| "alloc_empty_hash"), &sparse_merkle_path)?;
+
+        Ok(SparseMerklePathGadget { path, empty_hash })
+    }
+}
@@ -21,7 +21,7 @@ use rand::{thread_rng, Rng};
 
 use snarkvm_algorithms::{
     crh::{PedersenCRH, PedersenCompressedCRH, BHPCRH},
-    merkle_tree::{MaskedMerkleTreeParameters, MerkleTree},
+    merkle_tree::{MaskedMerkleTreeParameters, MerkleTree, MerkleTreeParameters, SparseMerkleTree},
     traits::{MaskedMerkleParameters, MerkleParameters, CRH},
 };
 use snarkvm_curves::{bls12_377::Fr, edwards_bls12::EdwardsProjective};
@@ -237,6 +237,95 @@ fn update_merkle_tree<P: MerkleParameters, F: PrimeField, HG: CRHGadget<P::H, F>
     assert!(satisfied);
 }
 
+fn check_sparse_merkle_tree<P: MerkleParameters, F: PrimeField, HG: CRHGadget<P::H, F>>(
+    keys: &[[u8; 30]],
+    absent_keys: &[[u8; 30]],
+    use_bad_root: bool,
+) {
+    let parameters = Arc::new(P::setup("merkle_tree_test"));
+    let mut tree = SparseMerkleTree::<P>::new(parameters.clone()).unwrap();
+    for key in keys.iter() {
+        assert!(tree.insert(key).unwrap());
+    }
+    let root = if use_bad_root {
+        <P::H as CRH>::Output::default()
+    } else {
+        *tree.root()
+    };
+
+    let mut satisfied = true;
+    let proofs = keys
+        .iter()
+        .map(|key| (key, true))
+        .chain(absent_keys.iter().map(|key| (key, false)));
+    for (i, (key, is_member)) in proofs.enumerate() {
+        let proof = tree.generate_proof(key).unwrap();
+
+        // Ensure the in-circuit verification agrees with the native verification.
+        let is_valid = match is_member {
+            true => proof.verify_membership(&root, key).unwrap(),
+            false => proof.verify_non_membership(&root, key).unwrap(),
+        };
+        assert_eq!(!use_bad_root, is_valid);
+
+        let mut cs = TestConstraintSystem::<F>::new();
+
+        let crh = HG::alloc_constant(&mut cs.ns(|| "crh"), || Ok(parameters.crh())).unwrap();
+        let root_gadget = <HG as CRHGadget<_, _>>::OutputGadget::alloc(&mut cs.ns(|| "root"), || Ok(root)).unwrap();
+        let path = SparseMerklePathGadget::<_, HG, _>::alloc(&mut cs.ns(|| "path"), || Ok(proof)).unwrap();
+        let key_gadget = UInt8::alloc_vec(cs.ns(|| "alloc_key"), key).unwrap();
+
+        match is_member {
+            true => path.check_membership(cs.ns(|| "check_membership"), &crh, &root_gadget, &key_gadget),
+            false => path.check_non_membership(cs.ns(|| "check_non_membership"), &crh, &root_gadget, &key_gadget),
+        }
+        .unwrap();
+
+        if !cs.is_satisfied() {
+            satisfied = false;
+            println!(
+                "Unsatisfied constraint in proof {}: {}",
+                i,
+                cs.which_is_unsatisfied().unwrap()
+            );
+        }
+        assert_eq!(is_valid, cs.is_satisfied());
+
+        // Ensure the opposite claim is not satisfied.
+        let mut cs = TestConstraintSystem::<F>::new();
+
+        let crh = HG::alloc_constant(&mut cs.ns(|| "crh"), || Ok(parameters.crh())).unwrap();
+        let root_gadget = <HG as CRHGadget<_, _>>::OutputGadget::alloc(&mut cs.ns(|| "root"), || Ok(root)).unwrap();
+        let path =
+            SparseMerklePathGadget::<_, HG, _>::alloc(&mut cs.ns(|| "path"), || Ok(tree.generate_proof(key).unwrap()))
+                .unwrap();
+        let key_gadget = UInt8::alloc_vec(cs.ns(|| "alloc_key"), key).unwrap();
+
+        match is_member {
+            true => path.check_non_membership(cs.ns(|| "check_non_membership"), &crh, &root_gadget, &key_gadget),
+            false => path.check_membership(cs.ns(|| "check_membership"), &crh, &root_gadget, &key_gadget),
+        }
+        .unwrap();
+        assert!(!cs.is_satisfied());
+    }
+
+    // Ensure a path of the wrong length is rejected.
+    let mut proof = tree.generate_proof(&keys[0]).unwrap();
+    proof.path.pop();
+
+    let mut cs = TestConstraintSystem::<F>::new();
+    let crh = HG::alloc_constant(&mut cs.ns(|| "crh"), || Ok(parameters.crh())).unwrap();
+    let root_gadget = <HG as CRHGadget<_, _>>::OutputGadget::alloc(&mut cs.ns(|| "root"), || Ok(root)).unwrap();
+    let path = SparseMerklePathGadget::<_, HG, _>::alloc(&mut cs.ns(|| "path"), || Ok(proof)).unwrap();
+    let key_gadget = UInt8::alloc_vec(cs.ns(|| "alloc_key"), &keys[0]).unwrap();
+    assert!(
+        path.check_membership(cs.ns(|| "check_membership"), &crh, &root_gadget, &key_gadget)
+            .is_err()
+    );
+
+    assert!(satisfied);
+}
+
 mod merkle_tree_pedersen_crh_on_projective {
     use super::*;
 
@@ -466,4 +555,21 @@ mod merkle_tree_poseidon {
         }
         update_merkle_tree::<EdwardsMerkleParameters, Fr, HG>(&leaves);
     }
+
+    #[test]
+    fn good_sparse_root_test() {
+        let mut rng = thread_rng();
+        let keys: Vec<[u8; 30]> = vec![rng.gen()];
+        let absent_keys: Vec<[u8; 30]> = vec![rng.gen()];
+        check_sparse_merkle_tree::<MerkleTreeParameters<H, 16>, Fr, HG>(&keys, &absent_keys, false);
+    }
+
+    #[should_panic]
+    #[test]
+    fn bad_sparse_root_test() {
+        let mut rng = thread_rng();
+        let keys: Vec<[u8; 30]> = vec![rng.gen()];
+        let absent_keys: Vec<[u8; 30]> = vec![rng.gen()];
+        check_sparse_merkle_tree::<MerkleTreeParameters<H, 16>, Fr, HG>(&keys, &absent_keys, true);
+    }
 }
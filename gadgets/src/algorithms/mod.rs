@@ -22,3 +22,4 @@ pub mod merkle_tree;
 pub mod prf;
 pub mod signature;
 pub mod snark;
+pub mod vrf;
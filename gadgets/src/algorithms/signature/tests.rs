@@ -151,4 +151,92 @@ mod aleo {
         }
         assert!(cs.is_satisfied());
     }
+
+    #[test]
+    fn test_multisig_signature_verification() {
+        let message = "Hi, I am an Aleo multisig signature!".as_bytes();
+        let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+
+        let signature_scheme = TestSignatureScheme::setup("aleo_multisig_signature_verification_test");
+
+        // Run the distributed key generation for a 2-of-3 multisig account.
+        let (generators, packages): (Vec<_>, Vec<_>) = (1..=3)
+            .map(|index| signature_scheme.multisig_keygen_begin(index, 2, 3, rng).unwrap())
+            .unzip();
+        let key_shares: Vec<_> = generators
+            .iter()
+            .map(|recipient| {
+                let received_shares: Vec<_> = generators
+                    .iter()
+                    .map(|sender| (sender.index(), sender.share_for(recipient.index()).unwrap()))
+                    .collect();
+                signature_scheme
+                    .multisig_keygen_finalize(recipient.clone(), &packages, &received_shares)
+                    .unwrap()
+            })
+            .collect();
+
+        // Sign with participants 1 and 3.
+        let (nonces_1, commitment_1) = signature_scheme.multisig_commit(&key_shares[0], rng);
+        let (nonces_3, commitment_3) = signature_scheme.multisig_commit(&key_shares[2], rng);
+        let commitments = [commitment_1, commitment_3];
+        let signature_shares = [
+            signature_scheme
+                .multisig_sign(&key_shares[0], nonces_1, &commitments, message)
+                .unwrap(),
+            signature_scheme
+                .multisig_sign(&key_shares[2], nonces_3, &commitments, message)
+                .unwrap(),
+        ];
+        let multisig_public_key = key_shares[0].public_key();
+        let signature = signature_scheme
+            .multisig_aggregate(multisig_public_key, &commitments, &signature_shares, message)
+            .unwrap();
+
+        let public_key = signature_scheme.multisig_public_key(multisig_public_key);
+        assert!(signature_scheme.verify(&public_key, &message, &signature).unwrap());
+
+        // Ensure the multisig signature verifies with the existing signature gadget.
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let signature_scheme_gadget =
+            TestSignatureSchemeGadget::alloc_constant(&mut cs.ns(|| "signature_scheme_gadget"), || {
+                Ok(signature_scheme)
+            })
+            .unwrap();
+
+        let public_key_gadget =
+            <TestSignatureSchemeGadget as SignatureGadget<TestSignatureScheme, Fr>>::PublicKeyGadget::alloc(
+                cs.ns(|| "alloc_public_key"),
+                || Ok(public_key),
+            )
+            .unwrap();
+
+        let message_gadget = UInt8::alloc_vec(cs.ns(|| "alloc_message"), message).unwrap();
+
+        let signature_gadget =
+            <TestSignatureSchemeGadget as SignatureGadget<TestSignatureScheme, Fr>>::SignatureGadget::alloc(
+                cs.ns(|| "alloc_signature"),
+                || Ok(signature),
+            )
+            .unwrap();
+
+        let verification = signature_scheme_gadget
+            .verify(
+                cs.ns(|| "verify"),
+                &public_key_gadget,
+                &message_gadget,
+                &signature_gadget,
+            )
+            .unwrap();
+
+        verification
+            .enforce_equal(cs.ns(|| "check_verification"), &Boolean::constant(true))
+            .unwrap();
+
+        if !cs.is_satisfied() {
+            println!("which is unsatisfied: {:?}", cs.which_is_unsatisfied().unwrap());
+        }
+        assert!(cs.is_satisfied());
+    }
 }
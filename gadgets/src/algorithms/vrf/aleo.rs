@@ -0,0 +1,321 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    algorithms::crypto_hash::PoseidonCryptoHashGadget,
+    bits::Boolean,
+    integers::uint::UInt8,
+    traits::{algorithms::VRFGadget, alloc::AllocGadget, curves::GroupGadget, eq::EqGadget, select::CondSelectGadget},
+    CryptoHashGadget,
+    FpGadget,
+    ToBitsLEGadget,
+    ToConstraintFieldGadget,
+};
+use snarkvm_algorithms::{
+    crypto_hash::PoseidonDefaultParametersField,
+    hash_to_curve::HashToField,
+    vrf::{AleoVRF, AleoVRFProof, ALEO_VRF_CHALLENGE_DOMAIN, ALEO_VRF_HASH_TO_CURVE_DOMAIN, ALEO_VRF_OUTPUT_DOMAIN},
+    VRF,
+};
+use snarkvm_curves::{templates::twisted_edwards_extended::Affine as TEAffine, Elligator2Parameters};
+use snarkvm_fields::{FieldParameters, PrimeField, SquareRootField};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use itertools::Itertools;
+use std::{borrow::Borrow, marker::PhantomData};
+
+type TEAffineGadget<TE, F> = crate::curves::templates::twisted_edwards::AffineGadget<TE, F, FpGadget<F>>;
+
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "TE: Elligator2Parameters<BaseField = F>, F: PrimeField"),
+    Debug(bound = "TE: Elligator2Parameters<BaseField = F>, F: PrimeField")
+)]
+pub struct AleoVRFProofGadget<TE: Elligator2Parameters<BaseField = F>, F: PrimeField> {
+    pub(crate) gamma: TEAffineGadget<TE, F>,
+    pub(crate) challenge: FpGadget<F>,
+    pub(crate) response: FpGadget<F>,
+}
+
+impl<TE: Elligator2Parameters<BaseField = F>, F: PrimeField + PoseidonDefaultParametersField>
+    AllocGadget<AleoVRFProof<TE>, F> for AleoVRFProofGadget<TE, F>
+{
+    fn alloc_constant<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<AleoVRFProof<TE>>,
+        CS: ConstraintSystem<F>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let proof = *value_gen()?.borrow();
+
+        // Cast <G as Group>::ScalarField as F.
+        let challenge: F = FromBytes::read_le(&proof.challenge.to_bytes_le()?[..])?;
+        let response: F = FromBytes::read_le(&proof.response.to_bytes_le()?[..])?;
+
+        let gamma = TEAffineGadget::<TE, F>::alloc_constant(cs.ns(|| "alloc_constant_gamma"), || Ok(proof.gamma()?))?;
+        let challenge = FpGadget::<F>::alloc_constant(cs.ns(|| "alloc_constant_challenge"), || Ok(&challenge))?;
+        let response = FpGadget::<F>::alloc_constant(cs.ns(|| "alloc_constant_response"), || Ok(&response))?;
+
+        Ok(Self {
+            gamma,
+            challenge,
+            response,
+        })
+    }
+
+    fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<AleoVRFProof<TE>>, CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let proof = *value_gen()?.borrow();
+
+        // Cast <G as Group>::ScalarField as F.
+        let challenge: F = FromBytes::read_le(&proof.challenge.to_bytes_le()?[..])?;
+        let response: F = FromBytes::read_le(&proof.response.to_bytes_le()?[..])?;
+
+        // Gamma is checked to be in the prime-order subgroup, which makes the output unique.
+        let gamma = TEAffineGadget::<TE, F>::alloc_checked(cs.ns(|| "alloc_gamma"), || Ok(proof.gamma()?))?;
+        let challenge = FpGadget::<F>::alloc(cs.ns(|| "alloc_challenge"), || Ok(&challenge))?;
+        let response = FpGadget::<F>::alloc(cs.ns(|| "alloc_response"), || Ok(&response))?;
+
+        Ok(Self {
+            gamma,
+            challenge,
+            response,
+        })
+    }
+
+    fn alloc_input<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<AleoVRFProof<TE>>, CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let proof = *value_gen()?.borrow();
+
+        // Cast <G as Group>::ScalarField as F.
+        let challenge: F = FromBytes::read_le(&proof.challenge.to_bytes_le()?[..])?;
+        let response: F = FromBytes::read_le(&proof.response.to_bytes_le()?[..])?;
+
+        let gamma = proof.gamma()?;
+        let gamma_x = FpGadget::<F>::alloc_input(cs.ns(|| "alloc_input_gamma_x"), || Ok(gamma.x))?;
+        let gamma = TEAffineGadget::<TE, F>::alloc_checked(cs.ns(|| "alloc_gamma"), || Ok(gamma))?;
+        gamma.x.enforce_equal(cs.ns(|| "check gamma x consistency"), &gamma_x)?;
+
+        let challenge = FpGadget::<F>::alloc_input(cs.ns(|| "alloc_input_challenge"), || Ok(&challenge))?;
+        let response = FpGadget::<F>::alloc_input(cs.ns(|| "alloc_input_response"), || Ok(&response))?;
+
+        Ok(Self {
+            gamma,
+            challenge,
+            response,
+        })
+    }
+}
+
+pub struct AleoVRFGadget<TE: Elligator2Parameters<BaseField = F>, F: PrimeField + PoseidonDefaultParametersField> {
+    pub(crate) vrf: AleoVRF<TE>,
+    pub(crate) _engine: PhantomData<F>,
+}
+
+impl<TE: Elligator2Parameters<BaseField = F>, F: PrimeField + PoseidonDefaultParametersField>
+    AllocGadget<AleoVRF<TE>, F> for AleoVRFGadget<TE, F>
+{
+    fn alloc_constant<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<AleoVRF<TE>>, CS: ConstraintSystem<F>>(
+        _cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Self {
+            vrf: (*value_gen()?.borrow()).clone(),
+            _engine: PhantomData,
+        })
+    }
+
+    /// The VRF parameters are public bases, which the verifier uses as constants.
+    fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<AleoVRF<TE>>, CS: ConstraintSystem<F>>(
+        cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Self::alloc_constant(cs, value_gen)
+    }
+
+    /// The VRF parameters are allocated as constants, as in `alloc`.
+    fn alloc_input<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<AleoVRF<TE>>, CS: ConstraintSystem<F>>(
+        cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Self::alloc_constant(cs, value_gen)
+    }
+}
+
+impl<
+    TE: Elligator2Parameters<BaseField = F>,
+    F: PrimeField + SquareRootField + PoseidonDefaultParametersField + HashToField,
+> VRFGadget<AleoVRF<TE>, F> for AleoVRFGadget<TE, F>
+{
+    type OutputGadget = FpGadget<F>;
+    type ProofGadget = AleoVRFProofGadget<TE, F>;
+    type PublicKeyGadget = TEAffineGadget<TE, F>;
+
+    fn verify<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        public_key: &Self::PublicKeyGadget,
+        input: &[UInt8],
+        proof: &Self::ProofGadget,
+    ) -> Result<Boolean, SynthesisError> {
+        // Prepare the zero element in affine form for use.
+        let zero_affine: TEAffineGadget<TE, F> =
+            <TEAffineGadget<TE, F> as GroupGadget<TEAffine<TE>, F>>::zero(cs.ns(|| "affine zero"))?;
+
+        // Compute H := HashToCurve(G^sk, input).
+        let h = self.hash_to_curve(cs.ns(|| "H := HashToCurve(G^sk, input)"), public_key, input)?;
+
+        // Prepare s and c, by converting the response and the challenge to bits.
+        let s = Self::scalar_to_bits(cs.ns(|| "response to bits"), &proof.response)?;
+        let c = Self::scalar_to_bits(cs.ns(|| "challenge to bits"), &proof.challenge)?;
+
+        // Compute G^s.
+        let g_s = {
+            let mut g_s = zero_affine.clone();
+            for (i, (base, bit)) in self.vrf.parameters().iter().zip_eq(&s).enumerate() {
+                let added = g_s.add_constant(cs.ns(|| format!("add_g_s_{}", i)), base)?;
+
+                g_s = TEAffineGadget::<TE, F>::conditionally_select(
+                    cs.ns(|| format!("cond_select_g_s_{}", i)),
+                    bit,
+                    &added,
+                    &g_s,
+                )?;
+            }
+            g_s
+        };
+
+        // Compute G^k := G^s G^sk^c.
+        let g_sk_c = <TEAffineGadget<TE, F> as GroupGadget<TEAffine<TE>, F>>::mul_bits(
+            public_key,
+            cs.ns(|| "G^sk^c"),
+            &zero_affine,
+            c.iter().cloned(),
+        )?;
+        let g_k =
+            <TEAffineGadget<TE, F> as GroupGadget<TEAffine<TE>, F>>::add(&g_s, cs.ns(|| "G^k := G^s G^sk^c"), &g_sk_c)?;
+
+        // Compute H^k := H^s gamma^c.
+        let h_s = <TEAffineGadget<TE, F> as GroupGadget<TEAffine<TE>, F>>::mul_bits(
+            &h,
+            cs.ns(|| "H^s"),
+            &zero_affine,
+            s.into_iter(),
+        )?;
+        let gamma_c = <TEAffineGadget<TE, F> as GroupGadget<TEAffine<TE>, F>>::mul_bits(
+            &proof.gamma,
+            cs.ns(|| "gamma^c"),
+            &zero_affine,
+            c.into_iter(),
+        )?;
+        let h_k = <TEAffineGadget<TE, F> as GroupGadget<TEAffine<TE>, F>>::add(
+            &h_s,
+            cs.ns(|| "H^k := H^s gamma^c"),
+            &gamma_c,
+        )?;
+
+        // Compute the candidate challenge as Hash(G^sk, H, gamma, G^k, H^k).
+        let candidate_challenge = {
+            let preimage = [
+                FpGadget::<F>::Constant(F::from_bytes_le_mod_order(ALEO_VRF_CHALLENGE_DOMAIN.as_bytes())),
+                public_key.x.clone(),
+                h.x.clone(),
+                proof.gamma.x.clone(),
+                g_k.x,
+                h_k.x,
+            ];
+
+            // Compute the hash of the preimage on the base field.
+            let hash = PoseidonCryptoHashGadget::<F, 4, false>::check_evaluation_gadget(
+                cs.ns(|| "Poseidon of preimage"),
+                &preimage,
+            )?;
+
+            // Truncate the output to fit the scalar field.
+            let mut hash_bits = hash.to_bits_le(cs.ns(|| "convert the hash into bits"))?;
+            hash_bits.resize(
+                <TE::ScalarField as PrimeField>::Parameters::CAPACITY as usize,
+                Boolean::Constant(false),
+            );
+
+            // Output the challenge.
+            Boolean::le_bits_to_fp_var(cs.ns(|| "obtain the truncated hash"), &hash_bits)?
+        };
+
+        proof.challenge.is_eq(cs.ns(|| "Check challenge"), &candidate_challenge)
+    }
+
+    fn proof_to_hash<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        proof: &Self::ProofGadget,
+    ) -> Result<Self::OutputGadget, SynthesisError> {
+        PoseidonCryptoHashGadget::<F, 4, false>::check_evaluation_gadget(cs.ns(|| "Poseidon of gamma"), &[
+            FpGadget::<F>::Constant(F::from_bytes_le_mod_order(ALEO_VRF_OUTPUT_DOMAIN.as_bytes())),
+            proof.gamma.x.clone(),
+        ])
+    }
+}
+
+impl<
+    TE: Elligator2Parameters<BaseField = F>,
+    F: PrimeField + SquareRootField + PoseidonDefaultParametersField + HashToField,
+> AleoVRFGadget<TE, F>
+{
+    /// Returns H := HashToCurve(G^sk, input), matching `AleoVRF::hash_to_curve`.
+    fn hash_to_curve<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        public_key: &TEAffineGadget<TE, F>,
+        input: &[UInt8],
+    ) -> Result<TEAffineGadget<TE, F>, SynthesisError> {
+        // Construct the hash input (G^sk, input).
+        let mut preimage = vec![public_key.x.clone()];
+        preimage.push(FpGadget::<F>::Constant(F::from(input.len() as u128)));
+        preimage.extend_from_slice(&input.to_constraint_field(cs.ns(|| "convert input into field elements"))?);
+
+        // Compute u_i := Hash(domain_i, G^sk, input), for i in {0, 1}.
+        let mut u = Vec::with_capacity(2);
+        for i in 0..2 {
+            let domain = format!("{}_{}", ALEO_VRF_HASH_TO_CURVE_DOMAIN, i);
+            let mut hash_input = vec![FpGadget::<F>::Constant(F::from_bytes_le_mod_order(domain.as_bytes()))];
+            hash_input.extend_from_slice(&preimage);
+            u.push(PoseidonCryptoHashGadget::<F, 4, false>::check_evaluation_gadget(
+                cs.ns(|| format!("Poseidon of preimage {}", i)),
+                &hash_input,
+            )?);
+        }
+
+        TEAffineGadget::<TE, F>::hash_to_curve(cs.ns(|| "hash_to_curve"), &u[0], &u[1])
+    }
+
+    /// Returns the bits of the given scalar, truncated to fit the scalar field.
+    fn scalar_to_bits<CS: ConstraintSystem<F>>(cs: CS, scalar: &FpGadget<F>) -> Result<Vec<Boolean>, SynthesisError> {
+        let mut bits = scalar.to_bits_le_strict(cs)?;
+        bits.resize(
+            <TE::ScalarField as PrimeField>::Parameters::MODULUS_BITS as usize,
+            Boolean::Constant(false),
+        );
+        Ok(bits)
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod aleo {
+    use crate::{
+        algorithms::vrf::AleoVRFGadget,
+        integers::uint::UInt8,
+        traits::{algorithms::VRFGadget, alloc::AllocGadget, eq::EqGadget},
+        Boolean,
+        FpGadget,
+    };
+    use snarkvm_algorithms::{traits::VRF, vrf::AleoVRF};
+    use snarkvm_curves::{
+        bls12_377::Fr,
+        edwards_bls12::{EdwardsParameters, Fr as EdwardsFr},
+    };
+    use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
+    use snarkvm_utilities::UniformRand;
+
+    use rand::SeedableRng;
+    use rand_chacha::ChaChaRng;
+
+    type TestVRF = AleoVRF<EdwardsParameters>;
+    type TestVRFGadget = AleoVRFGadget<EdwardsParameters, Fr>;
+
+    #[test]
+    fn test_vrf_verification() {
+        let input = "Hi, I am an Aleo VRF input!".as_bytes();
+        let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+
+        let vrf = TestVRF::setup("aleo_vrf_verification_test");
+        let private_key = EdwardsFr::rand(rng);
+        let public_key = vrf.generate_public_key(&private_key);
+        let proof = vrf.prove(&private_key, input, rng).unwrap();
+        let output = vrf.proof_to_hash(&proof).unwrap();
+        assert!(vrf.verify(&public_key, input, &proof).unwrap());
+
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let vrf_gadget = TestVRFGadget::alloc_constant(&mut cs.ns(|| "vrf_gadget"), || Ok(vrf)).unwrap();
+        assert_eq!(cs.num_constraints(), 0);
+
+        let public_key_gadget =
+            <TestVRFGadget as VRFGadget<TestVRF, Fr>>::PublicKeyGadget::alloc(cs.ns(|| "alloc_public_key"), || {
+                Ok(public_key)
+            })
+            .unwrap();
+        let input_gadget = UInt8::alloc_vec(cs.ns(|| "alloc_input"), input).unwrap();
+        let proof_gadget =
+            <TestVRFGadget as VRFGadget<TestVRF, Fr>>::ProofGadget::alloc(cs.ns(|| "alloc_proof"), || Ok(proof))
+                .unwrap();
+
+        let verification = vrf_gadget
+            .verify(cs.ns(|| "verify"), &public_key_gadget, &input_gadget, &proof_gadget)
+            .unwrap();
+        verification
+            .enforce_equal(cs.ns(|| "check_verification"), &Boolean::constant(true))
+            .unwrap();
+
+        // Ensure the output matches the native output.
+        let output_gadget = vrf_gadget
+            .proof_to_hash(cs.ns(|| "proof_to_hash"), &proof_gadget)
+            .unwrap();
+        let expected_output_gadget = FpGadget::<Fr>::alloc_input(cs.ns(|| "alloc_output"), || Ok(output)).unwrap();
+        output_gadget
+            .enforce_equal(cs.ns(|| "check_output"), &expected_output_gadget)
+            .unwrap();
+
+        if !cs.is_satisfied() {
+            println!("which is unsatisfied: {:?}", cs.which_is_unsatisfied().unwrap());
+        }
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn failed_test_vrf_verification() {
+        let input = "Hi, I am an Aleo VRF input!".as_bytes();
+        let bad_input = "Bad input".as_bytes();
+        let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+
+        let vrf = TestVRF::setup("failed_aleo_vrf_verification_test");
+        let private_key = EdwardsFr::rand(rng);
+        let public_key = vrf.generate_public_key(&private_key);
+        let proof = vrf.prove(&private_key, input, rng).unwrap();
+        assert!(!vrf.verify(&public_key, bad_input, &proof).unwrap());
+
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let vrf_gadget = TestVRFGadget::alloc_constant(&mut cs.ns(|| "vrf_gadget"), || Ok(vrf)).unwrap();
+        let public_key_gadget =
+            <TestVRFGadget as VRFGadget<TestVRF, Fr>>::PublicKeyGadget::alloc(cs.ns(|| "alloc_public_key"), || {
+                Ok(public_key)
+            })
+            .unwrap();
+        let bad_input_gadget = UInt8::alloc_vec(cs.ns(|| "alloc_input"), bad_input).unwrap();
+        let proof_gadget =
+            <TestVRFGadget as VRFGadget<TestVRF, Fr>>::ProofGadget::alloc(cs.ns(|| "alloc_proof"), || Ok(proof))
+                .unwrap();
+
+        let verification = vrf_gadget
+            .verify(cs.ns(|| "verify"), &public_key_gadget, &bad_input_gadget, &proof_gadget)
+            .unwrap();
+        verification
+            .enforce_equal(cs.ns(|| "check_verification"), &Boolean::constant(false))
+            .unwrap();
+
+        if !cs.is_satisfied() {
+            println!("which is unsatisfied: {:?}", cs.which_is_unsatisfied().unwrap());
+        }
+        assert!(cs.is_satisfied());
+    }
+}
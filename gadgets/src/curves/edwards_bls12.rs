@@ -25,7 +25,7 @@ mod test {
     use super::EdwardsBls12Gadget;
     use crate::{
         curves::{
-            templates::twisted_edwards::test::{edwards_constraint_costs, edwards_test},
+            templates::twisted_edwards::test::{edwards_constraint_costs, edwards_test, hash_to_curve_test},
             tests_group::group_test,
         },
         traits::{alloc::AllocGadget, eq::EqGadget},
@@ -48,6 +48,13 @@ mod test {
         assert!(cs.is_satisfied());
     }
 
+    #[test]
+    fn edwards_bls12_hash_to_curve_test() {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        hash_to_curve_test::<_, EdwardsParameters, _>(&mut cs);
+        assert!(cs.is_satisfied());
+    }
+
     #[test]
     fn edwards_bls12_group_gadgets_test() {
         let mut cs = TestConstraintSystem::<Fq>::new();
@@ -26,7 +26,7 @@ mod test {
     use super::EdwardsBW6Gadget;
     use crate::{
         curves::{
-            templates::twisted_edwards::test::{edwards_constraint_costs, edwards_test},
+            templates::twisted_edwards::test::{edwards_constraint_costs, edwards_test, hash_to_curve_test},
             tests_group::group_test,
         },
         traits::{alloc::AllocGadget, eq::EqGadget},
@@ -49,6 +49,13 @@ mod test {
         assert!(cs.is_satisfied());
     }
 
+    #[test]
+    fn edwards_bw6_hash_to_curve_test() {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        hash_to_curve_test::<_, EdwardsParameters, _>(&mut cs);
+        assert!(cs.is_satisfied());
+    }
+
     #[test]
     fn edwards_bw6_group_gadgets_test() {
         let mut cs = TestConstraintSystem::<Fq>::new();
@@ -0,0 +1,146 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_curves::{
+    templates::twisted_edwards_extended::Affine as TEAffine,
+    traits::{Elligator2Parameters, MontgomeryParameters},
+};
+use snarkvm_fields::{PrimeField, SquareRootField};
+use snarkvm_r1cs::{errors::SynthesisError, Assignment, ConstraintSystem};
+use snarkvm_utilities::{bititerator::BitIteratorBE, BigInteger};
+
+use crate::{
+    bits::{Boolean, ToBitsLEGadget},
+    curves::templates::twisted_edwards::AffineGadget,
+    traits::{alloc::AllocGadget, curves::GroupGadget, eq::EqGadget, fields::FieldGadget, select::CondSelectGadget},
+    FpGadget,
+};
+
+impl<P: Elligator2Parameters<BaseField = F>, F: PrimeField + SquareRootField> AffineGadget<P, F, FpGadget<F>> {
+    /// Returns the point for the field element `u` under the Elligator 2 map,
+    /// matching `HashToCurve::map_to_curve` in `snarkvm_algorithms::hash_to_curve`.
+    ///
+    /// The square root and the choice between the two candidate x-coordinates are witnessed.
+    /// As `Z` is a non-square, exactly one candidate has a square right-hand side, and the
+    /// constraint `y^2 = g(x)` forces the choice, while the sign of `y` is fixed by its parity.
+    pub fn map_to_curve<CS: ConstraintSystem<F>>(mut cs: CS, u: &FpGadget<F>) -> Result<Self, SynthesisError> {
+        let j = <P::MontgomeryParameters as MontgomeryParameters>::COEFF_A;
+        let k = <P::MontgomeryParameters as MontgomeryParameters>::COEFF_B;
+        let k_inv = k.inverse().get()?;
+        let (c1, c2) = (j * k_inv, k_inv.square());
+
+        let zero = FpGadget::<F>::zero(cs.ns(|| "zero"))?;
+        let one = FpGadget::<F>::one(cs.ns(|| "one"))?;
+
+        // Compute tv1 = Z * u^2, which is set to zero if it equals -1.
+        let tv1 = u
+            .square(cs.ns(|| "u^2"))?
+            .mul_by_constant(cs.ns(|| "Z * u^2"), &P::ELLIGATOR2_Z)?;
+        let minus_one = FpGadget::<F>::Constant(-F::one());
+        let is_minus_one = tv1.is_eq(cs.ns(|| "tv1 == -1"), &minus_one)?;
+        let tv1 = FpGadget::conditionally_select(cs.ns(|| "select tv1"), &is_minus_one, &zero, &tv1)?;
+
+        // Compute x1 = -c1 / (1 + tv1) and gx1 = x1^3 + c1 * x1^2 + c2 * x1.
+        let x1 = tv1
+            .add_constant(cs.ns(|| "1 + tv1"), &F::one())?
+            .inverse(cs.ns(|| "(1 + tv1)^-1"))?
+            .mul_by_constant(cs.ns(|| "x1"), &-c1)?;
+        let gx1 = x1
+            .add_constant(cs.ns(|| "x1 + c1"), &c1)?
+            .mul(cs.ns(|| "(x1 + c1) * x1"), &x1)?
+            .add_constant(cs.ns(|| "(x1 + c1) * x1 + c2"), &c2)?
+            .mul(cs.ns(|| "gx1"), &x1)?;
+
+        // Compute x2 = -x1 - c1 and gx2 = tv1 * gx1.
+        let x2 = x1.negate(cs.ns(|| "-x1"))?.sub_constant(cs.ns(|| "x2"), &c1)?;
+        let gx2 = tv1.mul(cs.ns(|| "gx2"), &gx1)?;
+
+        // Witness whether gx1 is square, and the square root with the sign prescribed by the map.
+        let witness = match gx1.get_value().zip(gx2.get_value()) {
+            Some((gx1, gx2)) => {
+                let is_square = gx1.legendre().is_qr() || gx1.is_zero();
+                let y = if is_square { gx1 } else { gx2 }.sqrt().get()?;
+                let y = if y.to_repr().is_odd() == is_square { y } else { -y };
+                Some((is_square, y))
+            }
+            None => None,
+        };
+        let is_square = Boolean::alloc(cs.ns(|| "is_square"), || witness.map(|(is_square, _)| is_square).get())?;
+        let y = FpGadget::alloc(cs.ns(|| "y"), || witness.map(|(_, y)| y).get())?;
+
+        let x = FpGadget::conditionally_select(cs.ns(|| "select x"), &is_square, &x1, &x2)?;
+        let gx = FpGadget::conditionally_select(cs.ns(|| "select gx"), &is_square, &gx1, &gx2)?;
+        y.square_equals(cs.ns(|| "y^2 == gx"), &gx)?;
+
+        // Enforce that sgn0(y) is set if and only if gx1 is square.
+        let y_bits = y.to_bits_le_strict(cs.ns(|| "y to bits"))?;
+        y_bits[0].enforce_equal(cs.ns(|| "sgn0(y) == is_square"), &is_square)?;
+
+        // Compute the point (s, t) on the Montgomery curve, and map it to the twisted Edwards curve.
+        let s = x.mul_by_constant(cs.ns(|| "s"), &k)?;
+        let t = y.mul_by_constant(cs.ns(|| "t"), &k)?;
+
+        let s_plus_one = s.add_constant(cs.ns(|| "s + 1"), &F::one())?;
+        let s_minus_one = s.sub_constant(cs.ns(|| "s - 1"), &F::one())?;
+        let denominator = s_plus_one.mul(cs.ns(|| "(s + 1) * t"), &t)?;
+        let is_exceptional = denominator.is_eq(cs.ns(|| "is_exceptional"), &zero)?;
+        let denominator =
+            FpGadget::conditionally_select(cs.ns(|| "select denominator"), &is_exceptional, &one, &denominator)?;
+        let denominator_inv = denominator.inverse(cs.ns(|| "denominator inverse"))?;
+
+        let v = denominator_inv
+            .mul(cs.ns(|| "v * t"), &s_plus_one)?
+            .mul(cs.ns(|| "v"), &s)?;
+        let w = denominator_inv
+            .mul(cs.ns(|| "w * (s + 1)"), &t)?
+            .mul(cs.ns(|| "w"), &s_minus_one)?;
+
+        // The exceptional points are sent to the identity (0, 1).
+        let v = FpGadget::conditionally_select(cs.ns(|| "select v"), &is_exceptional, &zero, &v)?;
+        let w = FpGadget::conditionally_select(cs.ns(|| "select w"), &is_exceptional, &one, &w)?;
+
+        Ok(Self::new(v, w))
+    }
+
+    /// Returns the point in the prime-order subgroup for the field elements `u0` and `u1`,
+    /// matching `HashToCurve::hash_to_curve` for the field elements computed by `hash_to_field`.
+    pub fn hash_to_curve<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        u0: &FpGadget<F>,
+        u1: &FpGadget<F>,
+    ) -> Result<Self, SynthesisError> {
+        let q0 = Self::map_to_curve(cs.ns(|| "map u0"), u0)?;
+        let q1 = Self::map_to_curve(cs.ns(|| "map u1"), u1)?;
+        let q = <Self as GroupGadget<TEAffine<P>, F>>::add(&q0, cs.ns(|| "q0 + q1"), &q1)?;
+        q.mul_by_cofactor(cs.ns(|| "clear cofactor"))
+    }
+
+    /// Returns the multiplication of this point by the cofactor.
+    pub fn mul_by_cofactor<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Self, SynthesisError> {
+        // The leading bit of the cofactor is set.
+        let mut bits = BitIteratorBE::new(P::COFACTOR).skip_while(|bit| !bit);
+        bits.next();
+
+        let mut result = self.clone();
+        for (i, bit) in bits.enumerate() {
+            <Self as GroupGadget<TEAffine<P>, F>>::double_in_place(&mut result, cs.ns(|| format!("double_{}", i)))?;
+            if bit {
+                result = <Self as GroupGadget<TEAffine<P>, F>>::add(&result, cs.ns(|| format!("add_{}", i)), self)?;
+            }
+        }
+        Ok(result)
+    }
+}
@@ -38,6 +38,8 @@ use crate::{
     ToConstraintFieldGadget,
 };
 
+mod elligator2;
+
 #[cfg(test)]
 pub mod test;
 
@@ -16,12 +16,14 @@
 
 use crate::{
     bits::boolean::{AllocatedBit, Boolean},
-    curves::tests_group::group_test,
+    curves::{templates::twisted_edwards::AffineGadget, tests_group::group_test},
+    fields::FpGadget,
     traits::{alloc::AllocGadget, curves::GroupGadget, select::CondSelectGadget},
 };
+use snarkvm_algorithms::hash_to_curve::{hash_to_field, HashToCurve, HashToField};
 use snarkvm_curves::{
     templates::twisted_edwards_extended::Affine as TEAffine,
-    traits::{Group, TwistedEdwardsParameters},
+    traits::{Elligator2Parameters, Group, TwistedEdwardsParameters},
 };
 use snarkvm_fields::{Field, PrimeField};
 use snarkvm_r1cs::ConstraintSystem;
@@ -83,3 +85,34 @@ where
     assert_eq!(cond_select_cost, <GG as CondSelectGadget<_>>::cost());
     assert_eq!(add_cost, GG::cost_of_add());
 }
+
+pub(crate) fn hash_to_curve_test<F, P, CS>(cs: &mut CS)
+where
+    F: PrimeField + HashToField,
+    P: Elligator2Parameters<BaseField = F>,
+    CS: ConstraintSystem<F>,
+{
+    let dst = b"snarkVM-TEST";
+    for i in 0..5 {
+        let message = format!("message {}", i);
+        let native_result = TEAffine::<P>::hash_to_curve(message.as_bytes(), dst).unwrap();
+
+        let u = hash_to_field::<F>(message.as_bytes(), dst, 2).unwrap();
+        let u0 = FpGadget::alloc(cs.ns(|| format!("u0_{}", i)), || Ok(u[0])).unwrap();
+        let u1 = FpGadget::alloc(cs.ns(|| format!("u1_{}", i)), || Ok(u[1])).unwrap();
+
+        let q0 = AffineGadget::<P, F, FpGadget<F>>::map_to_curve(cs.ns(|| format!("map_to_curve_{}", i)), &u0).unwrap();
+        assert_eq!(
+            GroupGadget::<TEAffine<P>, F>::get_value(&q0).unwrap(),
+            TEAffine::<P>::map_to_curve(u[0])
+        );
+
+        let result =
+            AffineGadget::<P, F, FpGadget<F>>::hash_to_curve(cs.ns(|| format!("hash_to_curve_{}", i)), &u0, &u1)
+                .unwrap();
+        assert_eq!(
+            GroupGadget::<TEAffine<P>, F>::get_value(&result).unwrap(),
+            native_result
+        );
+    }
+}
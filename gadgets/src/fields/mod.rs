@@ -25,3 +25,5 @@ pub use fp6_3over2::*;
 
 pub mod fp12;
 pub use fp12::*;
+
+pub mod range;
@@ -0,0 +1,332 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use core::ops::Neg;
+
+use snarkvm_fields::{FieldParameters, PrimeField};
+use snarkvm_r1cs::{errors::SynthesisError, Assignment, ConstraintSystem, ConstraintVariable, LinearCombination};
+use snarkvm_utilities::bititerator::BitIteratorLE;
+
+use crate::{
+    bits::Boolean,
+    fields::{AllocatedFp, FpGadget},
+    traits::{alloc::AllocGadget, fields::FieldGadget},
+};
+
+/// Range checks and comparisons of field elements that hold small integers.
+///
+/// None of these methods check that their inputs fit in `num_bits` bits unless stated otherwise;
+/// callers are expected to have enforced the bound once, e.g. with [`FpGadget::to_bits_le_bounded`],
+/// and may then compare the bounded values any number of times.
+///
+/// The inner circuit does not use these gadgets yet, as doing so changes its constraint system
+/// and requires new circuit parameters; its value checks still use its own bit decompositions.
+impl<F: PrimeField> FpGadget<F> {
+    /// Returns the field element whose little-endian bits are `bits`.
+    ///
+    /// The result is a linear combination of the bits, so this costs no constraints.
+    pub fn from_bits_le<CS: ConstraintSystem<F>>(_cs: CS, bits: &[Boolean]) -> Result<Self, SynthesisError> {
+        assert!(bits.len() <= F::Parameters::CAPACITY as usize);
+
+        let mut value = Some(F::zero());
+        let mut lc = LinearCombination::zero();
+        let mut coeff = F::one();
+
+        for bit in bits {
+            value = match (value, bit.get_value()) {
+                (Some(value), Some(bit)) => Some(if bit { value + coeff } else { value }),
+                _ => None,
+            };
+            lc = lc + bit.lc(CS::one(), coeff);
+            coeff.double_in_place();
+        }
+
+        if bits.iter().all(|bit| matches!(bit, Boolean::Constant(_))) {
+            return Ok(Self::Constant(value.get()?));
+        }
+
+        Ok(Self::Variable(AllocatedFp {
+            value,
+            variable: ConstraintVariable::from(lc),
+        }))
+    }
+
+    /// Returns the `num_bits` little-endian bits of `self`, enforcing `0 <= self < 2^num_bits`.
+    ///
+    /// This costs `num_bits + 1` constraints.
+    pub fn to_bits_le_bounded<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        num_bits: usize,
+    ) -> Result<Vec<Boolean>, SynthesisError> {
+        assert!(num_bits <= F::Parameters::CAPACITY as usize);
+
+        let bit_values = match self.get_value() {
+            Some(value) => BitIteratorLE::new(value.to_repr()).map(Some).collect::<Vec<_>>(),
+            None => vec![None; num_bits],
+        };
+
+        if let Self::Constant(_) = self {
+            return match bit_values.iter().skip(num_bits).any(|bit| *bit == Some(true)) {
+                true => Err(SynthesisError::Unsatisfiable),
+                false => Ok(bit_values[..num_bits]
+                    .iter()
+                    .map(|bit| Boolean::constant(bit.unwrap()))
+                    .collect()),
+            };
+        }
+
+        let bits = bit_values
+            .into_iter()
+            .take(num_bits)
+            .enumerate()
+            .map(|(i, bit)| Boolean::alloc(cs.ns(|| format!("bit {}", i)), || bit.get()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let packed = Self::from_bits_le(cs.ns(|| "pack bits"), &bits)?;
+        cs.enforce(
+            || "packing constraint",
+            |lc| lc,
+            |lc| lc,
+            |_| Self::to_lc::<CS>(&packed) + Self::to_lc::<CS>(self).neg(),
+        );
+
+        Ok(bits)
+    }
+
+    /// Enforces `0 <= self < 2^num_bits`.
+    ///
+    /// This costs `num_bits + 1` constraints.
+    pub fn enforce_bit_length<CS: ConstraintSystem<F>>(&self, cs: CS, num_bits: usize) -> Result<(), SynthesisError> {
+        self.to_bits_le_bounded(cs, num_bits).map(|_| ())
+    }
+
+    /// Returns `self <= other`, assuming `0 <= self, other < 2^num_bits`.
+    ///
+    /// This costs `num_bits + 2` constraints.
+    pub fn is_less_than_or_equal_bounded<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        num_bits: usize,
+    ) -> Result<Boolean, SynthesisError> {
+        assert!(num_bits < F::Parameters::CAPACITY as usize);
+
+        // `other - self + 2^num_bits` lies in `[1, 2^(num_bits + 1))`,
+        // and its top bit is set if and only if `self <= other`.
+        let difference = other
+            .sub(cs.ns(|| "difference"), self)?
+            .add_constant(cs.ns(|| "shift"), &F::from(2u128).pow([num_bits as u64]))?;
+        let bits = difference.to_bits_le_bounded(cs.ns(|| "decompose difference"), num_bits + 1)?;
+
+        Ok(bits[num_bits])
+    }
+
+    /// Returns `self < other`, assuming `0 <= self, other < 2^num_bits`.
+    ///
+    /// This costs `num_bits + 2` constraints.
+    pub fn is_less_than_bounded<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        other: &Self,
+        num_bits: usize,
+    ) -> Result<Boolean, SynthesisError> {
+        Ok(other.is_less_than_or_equal_bounded(cs, self, num_bits)?.not())
+    }
+
+    /// Enforces `self <= other`, assuming `0 <= self, other < 2^num_bits`.
+    ///
+    /// This costs `num_bits + 1` constraints.
+    pub fn enforce_less_than_or_equal_bounded<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        num_bits: usize,
+    ) -> Result<(), SynthesisError> {
+        // `other - self` lies in `[0, 2^num_bits)` if and only if `self <= other`.
+        let difference = other.sub(cs.ns(|| "difference"), self)?;
+        difference.enforce_bit_length(cs.ns(|| "bound difference"), num_bits)
+    }
+
+    /// Enforces `self < other`, assuming `0 <= self, other < 2^num_bits`.
+    ///
+    /// This costs `num_bits + 1` constraints.
+    pub fn enforce_less_than_bounded<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        num_bits: usize,
+    ) -> Result<(), SynthesisError> {
+        let successor = self.add_constant(cs.ns(|| "successor"), &F::one())?;
+        successor.enforce_less_than_or_equal_bounded(cs.ns(|| "enforce successor"), other, num_bits)
+    }
+
+    /// Returns the linear combination that `self` represents.
+    fn to_lc<CS: ConstraintSystem<F>>(&self) -> LinearCombination<F> {
+        match self {
+            Self::Constant(value) => LinearCombination::zero() + (*value, CS::one()),
+            Self::Variable(variable) => &variable.variable + LinearCombination::zero(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_r1cs::{ConstraintCounter, TestConstraintSystem};
+
+    use rand::{thread_rng, Rng};
+
+    const ITERATIONS: usize = 100;
+
+    fn alloc(cs: &mut TestConstraintSystem<Fr>, name: &str, value: u64) -> FpGadget<Fr> {
+        FpGadget::alloc(cs.ns(|| name), || Ok(Fr::from(value as u128))).unwrap()
+    }
+
+    #[test]
+    fn test_to_bits_le_bounded() {
+        let rng = &mut thread_rng();
+
+        for _ in 0..ITERATIONS {
+            let value: u64 = rng.gen();
+            let num_bits = 64 - value.leading_zeros() as usize;
+
+            // A value fits in its own bit length.
+            let mut cs = TestConstraintSystem::<Fr>::new();
+            let value_gadget = alloc(&mut cs, "value", value);
+            let bits = value_gadget.to_bits_le_bounded(cs.ns(|| "bits"), num_bits).unwrap();
+            assert!(cs.is_satisfied());
+            assert_eq!(bits.len(), num_bits);
+            for (i, bit) in bits.iter().enumerate() {
+                assert_eq!(bit.get_value().unwrap(), (value >> i) & 1 == 1);
+            }
+
+            // A value does not fit in one bit less than its bit length.
+            if num_bits > 0 {
+                let mut cs = TestConstraintSystem::<Fr>::new();
+                let value_gadget = alloc(&mut cs, "value", value);
+                value_gadget.enforce_bit_length(cs.ns(|| "bits"), num_bits - 1).unwrap();
+                assert!(!cs.is_satisfied());
+            }
+        }
+
+        // Negative values wrap around the modulus, and are out of range.
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let value_gadget = FpGadget::alloc(cs.ns(|| "value"), || Ok(-Fr::from(1u128))).unwrap();
+        value_gadget.enforce_bit_length(cs.ns(|| "bits"), 64).unwrap();
+        assert!(!cs.is_satisfied());
+
+        // Constants are checked natively.
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let constant = FpGadget::Constant(Fr::from(255u128));
+        assert!(constant.enforce_bit_length(cs.ns(|| "fits"), 8).is_ok());
+        assert!(constant.enforce_bit_length(cs.ns(|| "does not fit"), 7).is_err());
+        assert_eq!(cs.num_constraints(), 0);
+    }
+
+    #[test]
+    fn test_from_bits_le() {
+        let rng = &mut thread_rng();
+
+        for _ in 0..ITERATIONS {
+            let value: u64 = rng.gen();
+
+            let mut cs = TestConstraintSystem::<Fr>::new();
+            let bits = (0..64)
+                .map(|i| Boolean::alloc(cs.ns(|| format!("bit {}", i)), || Ok((value >> i) & 1 == 1)).unwrap())
+                .collect::<Vec<_>>();
+            let num_constraints = cs.num_constraints();
+
+            let packed = FpGadget::from_bits_le(cs.ns(|| "pack"), &bits).unwrap();
+            assert_eq!(packed.get_value().unwrap(), Fr::from(value as u128));
+            assert_eq!(cs.num_constraints(), num_constraints);
+
+            // Packing the bits back inverts the decomposition.
+            let unpacked = packed.to_bits_le_bounded(cs.ns(|| "unpack"), 64).unwrap();
+            assert_eq!(
+                unpacked.iter().map(|bit| bit.get_value()).collect::<Vec<_>>(),
+                bits.iter().map(|bit| bit.get_value()).collect::<Vec<_>>()
+            );
+            assert!(cs.is_satisfied());
+        }
+    }
+
+    #[test]
+    fn test_less_than_or_equal_bounded() {
+        let rng = &mut thread_rng();
+
+        for _ in 0..ITERATIONS {
+            let a: u64 = rng.gen();
+            let b: u64 = match rng.gen_range(0..3) {
+                0 => a,
+                1 => a.saturating_add(rng.gen_range(0..16)),
+                _ => rng.gen(),
+            };
+
+            let mut cs = TestConstraintSystem::<Fr>::new();
+            let a_gadget = alloc(&mut cs, "a", a);
+            let b_gadget = alloc(&mut cs, "b", b);
+
+            let a_le_b = a_gadget
+                .is_less_than_or_equal_bounded(cs.ns(|| "a <= b"), &b_gadget, 64)
+                .unwrap();
+            let b_le_a = b_gadget
+                .is_less_than_or_equal_bounded(cs.ns(|| "b <= a"), &a_gadget, 64)
+                .unwrap();
+            let a_lt_b = a_gadget.is_less_than_bounded(cs.ns(|| "a < b"), &b_gadget, 64).unwrap();
+            assert_eq!(a_le_b.get_value().unwrap(), a <= b);
+            assert_eq!(b_le_a.get_value().unwrap(), b <= a);
+            assert_eq!(a_lt_b.get_value().unwrap(), a < b);
+            assert!(cs.is_satisfied());
+
+            // The enforcing variants are satisfied exactly when the relation holds.
+            let mut cs = TestConstraintSystem::<Fr>::new();
+            let a_gadget = alloc(&mut cs, "a", a);
+            let b_gadget = alloc(&mut cs, "b", b);
+            a_gadget
+                .enforce_less_than_or_equal_bounded(cs.ns(|| "a <= b"), &b_gadget, 64)
+                .unwrap();
+            assert_eq!(cs.is_satisfied(), a <= b);
+
+            let mut cs = TestConstraintSystem::<Fr>::new();
+            let a_gadget = alloc(&mut cs, "a", a);
+            let b_gadget = alloc(&mut cs, "b", b);
+            a_gadget
+                .enforce_less_than_bounded(cs.ns(|| "a < b"), &b_gadget, 64)
+                .unwrap();
+            assert_eq!(cs.is_satisfied(), a < b);
+        }
+    }
+
+    #[test]
+    fn test_range_check_constraints() {
+        let a = FpGadget::Variable(AllocatedFp::from(ConstraintCounter::default(), &Fr::from(5u128)));
+        let b = FpGadget::Variable(AllocatedFp::from(ConstraintCounter::default(), &Fr::from(7u128)));
+
+        let mut cs = ConstraintCounter::default();
+        a.enforce_bit_length(&mut cs, 64).unwrap();
+        assert_eq!(cs.num_constraints, 65);
+
+        let mut cs = ConstraintCounter::default();
+        a.is_less_than_or_equal_bounded(&mut cs, &b, 64).unwrap();
+        assert_eq!(cs.num_constraints, 66);
+
+        let mut cs = ConstraintCounter::default();
+        a.enforce_less_than_or_equal_bounded(&mut cs, &b, 64).unwrap();
+        assert_eq!(cs.num_constraints, 65);
+    }
+}
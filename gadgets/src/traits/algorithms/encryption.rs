@@ -53,4 +53,13 @@ pub trait EncryptionGadget<E: EncryptionScheme, F: PrimeField>: AllocGadget<E, F
         public_key: &Self::PublicKeyGadget,
         input: &[UInt8],
     ) -> Result<Vec<UInt8>, SynthesisError>;
+
+    /// Returns the authenticated ciphertext, matching `EncryptionScheme::encrypt_authenticated`.
+    fn check_authenticated_encryption_gadget<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        randomness: &Self::RandomnessGadget,
+        public_key: &Self::PublicKeyGadget,
+        input: &[UInt8],
+    ) -> Result<Vec<UInt8>, SynthesisError>;
 }
@@ -0,0 +1,45 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_algorithms::traits::VRF;
+use snarkvm_fields::Field;
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+use crate::{
+    integers::uint::UInt8,
+    traits::{alloc::AllocGadget, eq::EqGadget},
+    Boolean,
+};
+
+pub trait VRFGadget<V: VRF, F: Field>: AllocGadget<V, F> {
+    type PublicKeyGadget: EqGadget<F> + AllocGadget<V::PublicKey, F> + Clone;
+    type ProofGadget: AllocGadget<V::Proof, F> + Clone;
+    type OutputGadget: EqGadget<F> + AllocGadget<V::Output, F> + Clone;
+
+    fn verify<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        public_key: &Self::PublicKeyGadget,
+        input: &[UInt8],
+        proof: &Self::ProofGadget,
+    ) -> Result<Boolean, SynthesisError>;
+
+    fn proof_to_hash<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        proof: &Self::ProofGadget,
+    ) -> Result<Self::OutputGadget, SynthesisError>;
+}
@@ -94,6 +94,7 @@ impl<F: PrimeField> AHPForR1CS<F> {
     /// The number of the variables must include the "one" variable. That is, it
     /// must be with respect to the number of formatted public inputs.
     pub fn max_degree(num_constraints: usize, num_variables: usize, num_non_zero: usize) -> Result<usize, AHPError> {
+        // The radix-2 size of domain K bounds its mixed-radix size, so this holds in every mode.
         let padded_matrix_dim = matrices::padded_matrix_dim(num_variables, num_constraints);
         let zk_bound = 1;
         let domain_h_size = EvaluationDomain::<F>::compute_size_of_domain(padded_matrix_dim)
@@ -114,13 +115,24 @@ impl<F: PrimeField> AHPForR1CS<F> {
         .unwrap())
     }
 
+    /// Returns the domain K, over which the non-zero entries of the constraint matrices are indexed.
+    /// Unlike domain H, domain K does not need to contain the input domain as a subgroup,
+    /// so it may be a mixed-radix domain.
+    pub fn non_zero_domain(num_non_zero: usize, mixed_radix: bool) -> Result<EvaluationDomain<F>, AHPError> {
+        let domain_k = match mixed_radix {
+            true => EvaluationDomain::new_mixed_radix(num_non_zero),
+            false => EvaluationDomain::new(num_non_zero),
+        };
+        Ok(domain_k.ok_or(SynthesisError::PolynomialDegreeTooLarge)?)
+    }
+
     /// Get all the strict degree bounds enforced in the AHP.
-    pub fn get_degree_bounds(info: &CircuitInfo<F>) -> [usize; 2] {
+    pub fn get_degree_bounds(info: &CircuitInfo<F>, mixed_radix: bool) -> [usize; 2] {
         let mut degree_bounds = [0usize; 2];
         let num_constraints = info.num_constraints;
         let num_non_zero = info.num_non_zero;
         let h_size = EvaluationDomain::<F>::compute_size_of_domain(num_constraints).unwrap();
-        let k_size = EvaluationDomain::<F>::compute_size_of_domain(num_non_zero).unwrap();
+        let k_size = Self::non_zero_domain(num_non_zero, mixed_radix).unwrap().size();
 
         degree_bounds[0] = h_size - 2;
         degree_bounds[1] = k_size - 2;
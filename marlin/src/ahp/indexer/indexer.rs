@@ -33,8 +33,8 @@ use core::marker::PhantomData;
 use snarkvm_utilities::println;
 
 impl<F: PrimeField> AHPForR1CS<F> {
-    /// Generate the index for this constraint system.
-    pub fn index<C: ConstraintSynthesizer<F>>(c: &C) -> Result<Circuit<F>, AHPError> {
+    /// Generate the index for this constraint system, with a mixed-radix domain K if `mixed_radix` is set.
+    pub fn index<C: ConstraintSynthesizer<F>>(c: &C, mixed_radix: bool) -> Result<Circuit<F>, AHPError> {
         let index_time = start_timer!(|| "AHP::Index");
 
         let constraint_time = start_timer!(|| "Generating constraints");
@@ -88,7 +88,7 @@ impl<F: PrimeField> AHPForR1CS<F> {
         };
 
         let domain_h = EvaluationDomain::new(num_constraints).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-        let domain_k = EvaluationDomain::new(num_non_zero).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let domain_k = Self::non_zero_domain(num_non_zero, mixed_radix)?;
         let x_domain =
             EvaluationDomain::new(num_padded_public_variables).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
 
@@ -104,6 +104,7 @@ impl<F: PrimeField> AHPForR1CS<F> {
     pub fn prover_init<'a, C: ConstraintSynthesizer<F>>(
         index: &'a Circuit<F>,
         circuit: &C,
+        mixed_radix: bool,
     ) -> Result<ProverState<'a, F>, AHPError> {
         let init_time = start_timer!(|| "AHP::Prover::Init");
 
@@ -185,7 +186,7 @@ impl<F: PrimeField> AHPForR1CS<F> {
 
         let domain_h = EvaluationDomain::new(num_constraints).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
 
-        let domain_k = EvaluationDomain::new(num_non_zero).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let domain_k = Self::non_zero_domain(num_non_zero, mixed_radix)?;
 
         let domain_x = EvaluationDomain::new(num_public_variables).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
 
@@ -613,9 +614,12 @@ impl<F: PrimeField> AHPForR1CS<F> {
     }
 
     /// Output the degree bounds of oracles in the third round.
-    pub fn prover_third_round_degree_bounds(info: &CircuitInfo<F>) -> impl Iterator<Item = Option<usize>> {
+    pub fn prover_third_round_degree_bounds(
+        info: &CircuitInfo<F>,
+        mixed_radix: bool,
+    ) -> impl Iterator<Item = Option<usize>> {
         let num_non_zero = info.num_non_zero;
-        let k_size = EvaluationDomain::<F>::compute_size_of_domain(num_non_zero).unwrap();
+        let k_size = Self::non_zero_domain(num_non_zero, mixed_radix).unwrap().size();
 
         vec![Some(k_size - 2), None].into_iter()
     }
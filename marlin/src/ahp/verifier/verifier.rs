@@ -36,6 +36,7 @@ impl<TargetField: PrimeField> AHPForR1CS<TargetField> {
     /// Output the first message and next round state.
     pub fn verifier_first_round<BaseField: PrimeField, R: FiatShamirRng<TargetField, BaseField>>(
         index_info: CircuitInfo<TargetField>,
+        mixed_radix: bool,
         fs_rng: &mut R,
     ) -> Result<(VerifierFirstMessage<TargetField>, VerifierState<TargetField>), AHPError> {
         // Check that the R1CS is a square matrix.
@@ -46,8 +47,7 @@ impl<TargetField: PrimeField> AHPForR1CS<TargetField> {
         let domain_h =
             EvaluationDomain::new(index_info.num_constraints).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
 
-        let domain_k =
-            EvaluationDomain::new(index_info.num_non_zero).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let domain_k = Self::non_zero_domain(index_info.num_non_zero, mixed_radix)?;
 
         let elems = fs_rng.squeeze_nonnative_field_elements(4, OptimizationType::Weight)?;
         let alpha = elems[0];
@@ -1056,7 +1056,7 @@ mod test {
         }
         // Execute the verifier first round.
         let (first_round_message, first_round_state) =
-            AHPForR1CSNative::verifier_first_round(circuit_pk.circuit.index_info.clone(), fs_rng).unwrap();
+            AHPForR1CSNative::verifier_first_round(circuit_pk.circuit.index_info.clone(), false, fs_rng).unwrap();
 
         // Execute the verifier first round gadget.
         let (first_round_message_gadget, first_round_state_gadget) =
@@ -1186,7 +1186,7 @@ mod test {
         }
         // Execute the verifier first round.
         let (_first_round_message, first_round_state) =
-            AHPForR1CSNative::verifier_first_round(circuit_pk.circuit.index_info.clone(), fs_rng).unwrap();
+            AHPForR1CSNative::verifier_first_round(circuit_pk.circuit.index_info.clone(), false, fs_rng).unwrap();
 
         // Execute the verifier first round gadget.
         let (_first_round_message_gadget, first_round_state_gadget) =
@@ -1353,7 +1353,7 @@ mod test {
         }
         // Execute the verifier first round.
         let (_first_round_message, first_round_state) =
-            AHPForR1CSNative::verifier_first_round(circuit_pk.circuit.index_info.clone(), fs_rng).unwrap();
+            AHPForR1CSNative::verifier_first_round(circuit_pk.circuit.index_info.clone(), false, fs_rng).unwrap();
 
         // Execute the verifier first round gadget.
         let (_first_round_message_gadget, first_round_state_gadget) =
@@ -1574,7 +1574,7 @@ mod test {
         }
         // Execute the verifier first round.
         let (_first_round_message, first_round_state) =
-            AHPForR1CSNative::verifier_first_round(circuit_pk.circuit.index_info.clone(), fs_rng).unwrap();
+            AHPForR1CSNative::verifier_first_round(circuit_pk.circuit.index_info.clone(), false, fs_rng).unwrap();
 
         let (domain_h_size, domain_k_size) = {
             let domain_h = EvaluationDomain::<Fr>::new(circuit_vk.circuit_info.num_constraints)
@@ -1882,7 +1882,7 @@ mod test {
         }
         // Execute the verifier first round.
         let (_first_round_message, first_round_state) =
-            AHPForR1CSNative::verifier_first_round(circuit_pk.circuit.index_info.clone(), fs_rng).unwrap();
+            AHPForR1CSNative::verifier_first_round(circuit_pk.circuit.index_info.clone(), false, fs_rng).unwrap();
 
         let (domain_h_size, domain_k_size) = {
             let domain_h = EvaluationDomain::<Fr>::new(circuit_vk.circuit_info.num_constraints)
@@ -2058,7 +2058,7 @@ mod test {
             .into_iter()
             .chain(AHPForR1CSNative::prover_first_round_degree_bounds(&index_info))
             .chain(AHPForR1CSNative::prover_second_round_degree_bounds(&index_info))
-            .chain(AHPForR1CSNative::prover_third_round_degree_bounds(&index_info));
+            .chain(AHPForR1CSNative::prover_third_round_degree_bounds(&index_info, false));
 
         let polynomial_labels: Vec<String> = if is_recursion {
             AHPForR1CSNative::<Fr>::polynomial_labels_with_vanishing().collect()
@@ -98,10 +98,10 @@ impl<
         let is_recursion = MM::RECURSION;
 
         // TODO: Add check that c is in the correct mode.
-        let circuit = AHPForR1CS::index(c)?;
+        let circuit = AHPForR1CS::index(c, MM::MIXED_RADIX)?;
         let srs = PC::setup(circuit.max_degree(), rng)?;
 
-        let coeff_support = AHPForR1CS::get_degree_bounds(&circuit.index_info);
+        let coeff_support = AHPForR1CS::get_degree_bounds(&circuit.index_info, MM::MIXED_RADIX);
 
         // Marlin only needs degree 2 random polynomials
         let supported_hiding_bound = 1;
@@ -112,8 +112,7 @@ impl<
         if is_recursion {
             let domain_h = EvaluationDomain::new(circuit.index_info.num_constraints)
                 .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-            let domain_k = EvaluationDomain::new(circuit.index_info.num_non_zero)
-                .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+            let domain_k = AHPForR1CS::non_zero_domain(circuit.index_info.num_non_zero, MM::MIXED_RADIX)?;
 
             vanishing_polys = vec![
                 LabeledPolynomial::new(
@@ -176,7 +175,7 @@ impl<
         let is_recursion = MM::RECURSION;
 
         // TODO: Add check that c is in the correct mode.
-        let index = AHPForR1CS::index(circuit)?;
+        let index = AHPForR1CS::index(circuit, MM::MIXED_RADIX)?;
         if universal_srs.max_degree() < index.max_degree() {
             return Err(MarlinError::IndexTooLarge(
                 universal_srs.max_degree(),
@@ -184,7 +183,7 @@ impl<
             ));
         }
 
-        let coefficient_support = AHPForR1CS::get_degree_bounds(&index.index_info);
+        let coefficient_support = AHPForR1CS::get_degree_bounds(&index.index_info, MM::MIXED_RADIX);
 
         // Marlin only needs degree 2 random polynomials.
         let supported_hiding_bound = 1;
@@ -199,8 +198,7 @@ impl<
         if is_recursion {
             let domain_h = EvaluationDomain::new(index.index_info.num_constraints)
                 .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-            let domain_k =
-                EvaluationDomain::new(index.index_info.num_non_zero).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+            let domain_k = AHPForR1CS::non_zero_domain(index.index_info.num_non_zero, MM::MIXED_RADIX)?;
 
             vanishing_polynomials = vec![
                 LabeledPolynomial::new(
@@ -270,7 +268,7 @@ impl<
             return Err(MarlinError::Terminated);
         }
 
-        let prover_init_state = AHPForR1CS::prover_init(&circuit_proving_key.circuit, circuit)?;
+        let prover_init_state = AHPForR1CS::prover_init(&circuit_proving_key.circuit, circuit, MM::MIXED_RADIX)?;
         let public_input = prover_init_state.public_input();
         let padded_public_input = prover_init_state.padded_public_input();
 
@@ -328,8 +326,11 @@ impl<
             return Err(MarlinError::Terminated);
         }
 
-        let (verifier_first_message, verifier_state) =
-            AHPForR1CS::verifier_first_round(circuit_proving_key.circuit_verifying_key.circuit_info, &mut fs_rng)?;
+        let (verifier_first_message, verifier_state) = AHPForR1CS::verifier_first_round(
+            circuit_proving_key.circuit_verifying_key.circuit_info,
+            MM::MIXED_RADIX,
+            &mut fs_rng,
+        )?;
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
@@ -405,8 +406,8 @@ impl<
         let vanishing_polys = if is_recursion {
             let domain_h = EvaluationDomain::new(circuit_proving_key.circuit.index_info.num_constraints)
                 .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-            let domain_k = EvaluationDomain::new(circuit_proving_key.circuit.index_info.num_non_zero)
-                .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+            let domain_k =
+                AHPForR1CS::non_zero_domain(circuit_proving_key.circuit.index_info.num_non_zero, MM::MIXED_RADIX)?;
 
             vec![
                 LabeledPolynomial::new(
@@ -625,7 +626,8 @@ impl<
             fs_rng.absorb_bytes(&to_bytes_le![first_commitments, proof.prover_messages[0]].unwrap());
         }
 
-        let (_, verifier_state) = AHPForR1CS::verifier_first_round(circuit_verifying_key.circuit_info, &mut fs_rng)?;
+        let (_, verifier_state) =
+            AHPForR1CS::verifier_first_round(circuit_verifying_key.circuit_info, MM::MIXED_RADIX, &mut fs_rng)?;
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
@@ -674,7 +676,7 @@ impl<
             .into_iter()
             .chain(AHPForR1CS::prover_first_round_degree_bounds(&index_info))
             .chain(AHPForR1CS::prover_second_round_degree_bounds(&index_info))
-            .chain(AHPForR1CS::prover_third_round_degree_bounds(&index_info));
+            .chain(AHPForR1CS::prover_third_round_degree_bounds(&index_info, MM::MIXED_RADIX));
 
         let polynomial_labels: Vec<String> = if is_recursion {
             AHPForR1CS::<TargetField>::polynomial_labels_with_vanishing().collect()
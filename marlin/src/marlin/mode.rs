@@ -20,6 +20,9 @@ use core::fmt::Debug;
 pub trait MarlinMode: Clone + Debug {
     /// Specifies whether this is for a recursive proof of at least depth-1.
     const RECURSION: bool;
+    /// Specifies whether the domain K, over which the non-zero matrix entries are indexed,
+    /// may be a mixed-radix domain. The Marlin verifier gadget only supports radix-2 domains.
+    const MIXED_RADIX: bool;
 }
 
 /// TODO (howardwu): Combine all of the testnet configurations into an environment struct higher up.
@@ -29,6 +32,7 @@ pub struct MarlinTestnet1Mode;
 
 impl MarlinMode for MarlinTestnet1Mode {
     const RECURSION: bool = false;
+    const MIXED_RADIX: bool = false;
 }
 
 /// The Marlin testnet2 mode does not assume recursive proofs of any depth.
@@ -37,6 +41,7 @@ pub struct MarlinTestnet2Mode;
 
 impl MarlinMode for MarlinTestnet2Mode {
     const RECURSION: bool = true;
+    const MIXED_RADIX: bool = false;
 }
 
 /// The Marlin default mode assumes a recursive proof of at least depth-1.
@@ -45,4 +50,17 @@ pub struct MarlinRecursiveMode;
 
 impl MarlinMode for MarlinRecursiveMode {
     const RECURSION: bool = true;
+    const MIXED_RADIX: bool = false;
+}
+
+/// The Marlin mixed-radix mode does not assume recursive proofs of any depth,
+/// and indexes the non-zero matrix entries over the smallest supported domain.
+/// Its universal SRS must support the degree bounds of mixed-radix domains,
+/// as with `KZG10DegreeBoundsConfig::MARLIN_MIXED_RADIX`.
+#[derive(Clone, Debug)]
+pub struct MarlinMixedRadixMode;
+
+impl MarlinMode for MarlinMixedRadixMode {
+    const RECURSION: bool = false;
+    const MIXED_RADIX: bool = true;
 }
@@ -73,10 +73,15 @@ mod marlin {
     use super::*;
     use crate::{
         fiat_shamir::FiatShamirChaChaRng,
-        marlin::{MarlinSNARK, MarlinTestnet1Mode},
+        marlin::{MarlinMixedRadixMode, MarlinSNARK, MarlinTestnet1Mode},
     };
+    use snarkvm_algorithms::fft::EvaluationDomain;
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
-    use snarkvm_polycommit::{marlin_pc::MarlinKZG10, sonic_pc::SonicKZG10};
+    use snarkvm_polycommit::{
+        kzg10::{KZG10DegreeBoundsConfig, PowersOfTau, KZG10},
+        marlin_pc::MarlinKZG10,
+        sonic_pc::SonicKZG10,
+    };
     use snarkvm_utilities::rand::{test_rng, UniformRand};
 
     use blake2::Blake2s;
@@ -87,6 +92,8 @@ mod marlin {
 
     type MultiPCSonic = SonicKZG10<Bls12_377>;
     type MarlinSonicInst = MarlinSNARK<Fr, Fq, MultiPCSonic, FiatShamirChaChaRng<Fr, Fq, Blake2s>, MarlinTestnet1Mode>;
+    type MarlinMixedRadixInst =
+        MarlinSNARK<Fr, Fq, MultiPCSonic, FiatShamirChaChaRng<Fr, Fq, Blake2s>, MarlinMixedRadixMode>;
 
     macro_rules! impl_marlin_test {
         ($test_struct: ident, $marlin_inst: tt) => {
@@ -176,6 +183,70 @@ mod marlin {
         MarlinPCTest::test_circuit(num_constraints, num_variables);
         SonicPCTest::test_circuit(num_constraints, num_variables);
     }
+
+    #[test]
+    fn prove_and_verify_with_powers_of_tau_srs() {
+        let rng = &mut test_rng();
+
+        let max_degree = crate::ahp::AHPForR1CS::<Fr>::max_degree(100, 25, 300).unwrap();
+        let mut powers_of_tau =
+            PowersOfTau::<Bls12_377>::new(max_degree, &KZG10DegreeBoundsConfig::MARLIN, false).unwrap();
+        powers_of_tau.contribute(rng).unwrap();
+        powers_of_tau.contribute(rng).unwrap();
+        let universal_srs = KZG10::setup_from_powers_of_tau(powers_of_tau, rng).unwrap();
+
+        let a = Fr::rand(rng);
+        let b = Fr::rand(rng);
+        let mut c = a;
+        c.mul_assign(&b);
+        let mut d = c;
+        d.mul_assign(&b);
+        let circ = Circuit {
+            a: Some(a),
+            b: Some(b),
+            num_constraints: 100,
+            num_variables: 25,
+        };
+
+        let (index_pk, index_vk) = MarlinInst::circuit_setup(&universal_srs, &circ).unwrap();
+        let proof = MarlinInst::prove(&index_pk, &circ, rng).unwrap();
+        assert!(MarlinInst::verify(&index_vk, &[c, d], &proof).unwrap());
+        assert!(!MarlinInst::verify(&index_vk, &[a, a], &proof).unwrap());
+    }
+
+    #[test]
+    fn prove_and_verify_with_mixed_radix_domain() {
+        let rng = &mut test_rng();
+
+        let max_degree = crate::ahp::AHPForR1CS::<Fr>::max_degree(100, 25, 300).unwrap();
+        let universal_srs =
+            KZG10::<Bls12_377>::setup(max_degree, &KZG10DegreeBoundsConfig::MARLIN_MIXED_RADIX, true, rng).unwrap();
+
+        let a = Fr::rand(rng);
+        let b = Fr::rand(rng);
+        let mut c = a;
+        c.mul_assign(&b);
+        let mut d = c;
+        d.mul_assign(&b);
+        let circ = Circuit {
+            a: Some(a),
+            b: Some(b),
+            num_constraints: 25,
+            num_variables: 25,
+        };
+
+        let (index_pk, index_vk) = MarlinMixedRadixInst::circuit_setup(&universal_srs, &circ).unwrap();
+
+        // Ensure the domain K is a mixed-radix domain, smaller than the radix-2 domain.
+        let num_non_zero = index_vk.circuit_info.num_non_zero;
+        let domain_k = crate::ahp::AHPForR1CS::<Fr>::non_zero_domain(num_non_zero, true).unwrap();
+        assert!(!domain_k.is_radix_2());
+        assert!(domain_k.size() < EvaluationDomain::<Fr>::new(num_non_zero).unwrap().size());
+
+        let proof = MarlinMixedRadixInst::prove(&index_pk, &circ, rng).unwrap();
+        assert!(MarlinMixedRadixInst::verify(&index_vk, &[c, d], &proof).unwrap());
+        assert!(!MarlinMixedRadixInst::verify(&index_vk, &[a, a], &proof).unwrap());
+    }
 }
 
 mod marlin_recursion {
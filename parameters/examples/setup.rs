@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use snarkvm_algorithms::{crh::sha256::sha256, CRH, SNARK, SRS};
+use snarkvm_algorithms::{crh::sha256::sha256, CommitmentScheme, MerkleParameters, CRH, SNARK, SRS};
 use snarkvm_dpc::{
     Execution,
     Function,
@@ -72,6 +72,62 @@ fn write_metadata(filename: &str, metadata: &Value) -> Result<()> {
     Ok(())
 }
 
+/// Writes the given parameter and its metadata to the files for the given parameter name.
+fn write_parameter<T: ToBytes>(name: &str, parameter: &T) -> Result<()> {
+    let parameter = parameter.to_bytes_le()?;
+
+    let metadata = json!({
+        "parameters_checksum": checksum(&parameter),
+        "parameters_size": parameter.len(),
+    });
+
+    println!("{}\n{}", name, serde_json::to_string_pretty(&metadata)?);
+    write_metadata(&format!("{}.metadata", name), &metadata)?;
+    write_local(&format!("{}.parameters", name), &parameter)?;
+
+    Ok(())
+}
+
+/// Runs the setup of the parameters that are derived from setup messages.
+pub fn parameters_setup<N: Network>() -> Result<()> {
+    write_parameter("block_hash_crh", &N::BlockHashCRH::setup("AleoBlockHashCRH0"))?;
+    write_parameter(
+        "block_header_root_parameters",
+        &N::BlockHeaderRootParameters::setup("AleoBlockHeaderRootCRH0"),
+    )?;
+    write_parameter("ciphertext_id_crh", &N::CiphertextIDCRH::setup("AleoCiphertextIDCRH0"))?;
+    write_parameter(
+        "commitment_scheme",
+        &N::CommitmentScheme::setup("AleoCommitmentScheme0"),
+    )?;
+    write_parameter(
+        "inner_circuit_id_crh",
+        &N::InnerCircuitIDCRH::setup("AleoInnerCircuitIDCRH0"),
+    )?;
+    write_parameter(
+        "ledger_root_parameters",
+        &N::LedgerRootParameters::setup("AleoLedgerRootCRH0"),
+    )?;
+    write_parameter(
+        "program_id_parameters",
+        &N::ProgramIDParameters::setup("AleoProgramIDCRH0"),
+    )?;
+    write_parameter(
+        "transactions_root_parameters",
+        &N::TransactionsRootParameters::setup("AleoTransactionsRootCRH0"),
+    )?;
+    write_parameter(
+        "transaction_id_parameters",
+        &N::TransactionIDParameters::setup("AleoTransactionIDCRH0"),
+    )?;
+    write_parameter(
+        "transition_id_parameters",
+        &N::TransitionIDParameters::setup("AleoTransitionIDCRH0"),
+    )?;
+
+    Ok(())
+}
+
 /// Runs a universal SRS setup.
 pub fn universal_setup<N: Network>() -> Result<()> {
     const UNIVERSAL_METADATA: &str = "universal.metadata";
@@ -289,6 +345,11 @@ pub fn main() -> Result<()> {
             "testnet2" => outer_setup::<snarkvm_dpc::testnet2::Testnet2>()?,
             _ => panic!("Invalid network"),
         },
+        "parameters" => match args[2].as_str() {
+            "testnet1" => parameters_setup::<snarkvm_dpc::testnet1::Testnet1>()?,
+            "testnet2" => parameters_setup::<snarkvm_dpc::testnet2::Testnet2>()?,
+            _ => panic!("Invalid network"),
+        },
         "posw" => match args[2].as_str() {
             "testnet1" => posw_setup::<snarkvm_dpc::testnet1::Testnet1>()?,
             "testnet2" => posw_setup::<snarkvm_dpc::testnet2::Testnet2>()?,
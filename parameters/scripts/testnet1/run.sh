@@ -1,4 +1,5 @@
 # These are dependent on each other.
+./parameters.sh || exit
 ./noop.sh || exit
 ./inner.sh || exit
 ./outer.sh || exit
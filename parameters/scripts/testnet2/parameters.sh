@@ -0,0 +1,12 @@
+# This script will run the setup of the parameters derived from setup messages, and move the resulting
+# `.parameters` and `.metadata` files to the `resources` folder under the `src/setup` directory,
+# which is shared by all networks.
+
+RUST_BACKTRACE=1 cargo run --release --example setup parameters testnet2 || exit
+
+for parameter in block_hash_crh block_header_root_parameters ciphertext_id_crh commitment_scheme inner_circuit_id_crh \
+    ledger_root_parameters program_id_parameters transactions_root_parameters transaction_id_parameters transition_id_parameters
+do
+    mv $parameter.metadata ../../src/setup/resources
+    mv $parameter.parameters ../../src/setup/resources
+done
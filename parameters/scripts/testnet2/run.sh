@@ -1,4 +1,5 @@
 # These are dependent on each other.
+./parameters.sh || exit
 ./universal.sh || exit
 ./noop.sh || exit
 ./inner.sh || exit
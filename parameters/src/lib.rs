@@ -29,6 +29,8 @@ pub mod macros;
 pub mod errors;
 pub use errors::*;
 
+pub mod setup;
+
 pub mod testnet1;
 
 pub mod testnet2;
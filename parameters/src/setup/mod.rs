@@ -0,0 +1,29 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The parameters derived from setup messages. The setup messages are the same for every network,
+//! so the networks share these parameters.
+
+impl_local!(BlockHashCRHBytes, "./resources/", "block_hash_crh", "parameters");
+impl_local!(BlockHeaderRootParametersBytes, "./resources/", "block_header_root_parameters", "parameters");
+impl_local!(CiphertextIDCRHBytes, "./resources/", "ciphertext_id_crh", "parameters");
+impl_local!(CommitmentSchemeBytes, "./resources/", "commitment_scheme", "parameters");
+impl_local!(InnerCircuitIDCRHBytes, "./resources/", "inner_circuit_id_crh", "parameters");
+impl_local!(LedgerRootParametersBytes, "./resources/", "ledger_root_parameters", "parameters");
+impl_local!(ProgramIDParametersBytes, "./resources/", "program_id_parameters", "parameters");
+impl_local!(TransactionsRootParametersBytes, "./resources/", "transactions_root_parameters", "parameters");
+impl_local!(TransactionIDParametersBytes, "./resources/", "transaction_id_parameters", "parameters");
+impl_local!(TransitionIDParametersBytes, "./resources/", "transition_id_parameters", "parameters");
@@ -19,6 +19,9 @@ pub use genesis::*;
 
 const REMOTE_URL: &str = "https://s3-us-west-1.amazonaws.com/aleo.parameters";
 
+// Setup Parameters
+pub use crate::setup::*;
+
 // Noop Circuit
 impl_local!(NoopProvingKeyBytes, "./resources/", "noop", "proving");
 impl_local!(NoopVerifyingKeyBytes, "./resources/", "noop", "verifying");
@@ -19,6 +19,9 @@ pub use genesis::*;
 
 const REMOTE_URL: &str = "https://s3-us-west-1.amazonaws.com/aleo.parameters";
 
+// Setup Parameters
+pub use crate::setup::*;
+
 // Universal SRS
 impl_remote!(UniversalSRSBytes, REMOTE_URL, "./resources/", "universal", "srs");
 
@@ -99,6 +99,9 @@ pub enum Error {
     /// The commitment was generated incorrectly, tampered with, or doesn't support the polynomial.
     MalformedCommitment(String),
 
+    /// A contribution to a powers-of-tau ceremony is malformed or inconsistent with the transcript.
+    InvalidContribution(String),
+
     Terminated,
 }
 
@@ -158,6 +161,7 @@ impl core::fmt::Display for Error {
             ),
             Error::IncorrectInputLength(err) => write!(f, "{}", err),
             Error::MalformedCommitment(err) => write!(f, "{}", err),
+            Error::InvalidContribution(err) => write!(f, "{}", err),
             Error::Terminated => write!(f, "terminated"),
         }
     }
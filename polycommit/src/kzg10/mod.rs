@@ -24,10 +24,10 @@
 use crate::{BTreeMap, Error, LabeledPolynomial, PCRandomness, Polynomial, ToString, Vec};
 use snarkvm_algorithms::{
     cfg_iter,
-    msm::{FixedBaseMSM, VariableBaseMSM},
+    msm::{FixedBaseMSM, PrecomputedBases, VariableBaseMSM},
 };
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
-use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_fields::{FftParameters, Field, One, PrimeField, Zero};
 use snarkvm_utilities::rand::UniformRand;
 
 use core::{
@@ -43,12 +43,19 @@ use rayon::prelude::*;
 mod data_structures;
 pub use data_structures::*;
 
+mod powers_of_tau;
+pub use powers_of_tau::*;
+
+mod vector_commitment;
+pub use vector_commitment::*;
+
 #[derive(Debug, PartialEq, Eq)]
-#[allow(deprecated)]
+#[allow(deprecated, non_camel_case_types)]
 pub enum KZG10DegreeBoundsConfig {
     #[deprecated]
     ALL,
     MARLIN,
+    MARLIN_MIXED_RADIX,
     LIST(Vec<usize>),
     NONE,
 }
@@ -63,23 +70,43 @@ impl KZG10DegreeBoundsConfig {
                 // Consider that we are using radix-2 FFT,
                 // there are only a few possible domain sizes and therefore degree bounds.
                 //
-                // We do not consider mixed-radix FFT for simplicity, as the curves that we
-                // are using have very high two-arity.
-
-                let mut radix_2_possible_domain_sizes = vec![];
-
-                let mut cur = 2usize;
-                while cur - 2 <= max_degree {
-                    radix_2_possible_domain_sizes.push(cur - 2);
-                    cur *= 2;
-                }
-
-                radix_2_possible_domain_sizes
+                // Mixed-radix domains are only supported by `MARLIN_MIXED_RADIX`, as every degree bound
+                // adds a power of h to the verifier key.
+                Self::marlin_degree_bounds(max_degree, 1, 0)
+            }
+            KZG10DegreeBoundsConfig::MARLIN_MIXED_RADIX => {
+                // The domain sizes are powers of two, or of the form `2^i * SMALL_SUBGROUP_BASE^j`.
+                let base = F::FftParameters::SMALL_SUBGROUP_BASE.unwrap_or(1) as usize;
+                let base_adicity = F::FftParameters::SMALL_SUBGROUP_BASE_ADICITY.unwrap_or(0);
+                Self::marlin_degree_bounds(max_degree, base, base_adicity)
             }
             KZG10DegreeBoundsConfig::LIST(v) => v.clone(),
             KZG10DegreeBoundsConfig::NONE => vec![],
         }
     }
+
+    /// Returns the degree bounds `domain_size - 2` for the domain sizes `2^i * base^j`, where `j <= base_adicity`.
+    fn marlin_degree_bounds(max_degree: usize, base: usize, base_adicity: u32) -> Vec<usize> {
+        let mut possible_degree_bounds = vec![];
+
+        let mut base_part = 1usize;
+        for _ in 0..=base_adicity {
+            let mut cur = core::cmp::max(base_part, 2);
+            while cur - 2 <= max_degree {
+                possible_degree_bounds.push(cur - 2);
+                cur *= 2;
+            }
+
+            base_part = match base_part.checked_mul(base) {
+                Some(base_part) => base_part,
+                None => break,
+            };
+        }
+
+        possible_degree_bounds.sort_unstable();
+        possible_degree_bounds.dedup();
+        possible_degree_bounds
+    }
 }
 
 /// `KZG10` is an implementation of the polynomial commitment scheme of
@@ -228,6 +255,39 @@ impl<E: PairingEngine> KZG10<E> {
         hiding_bound: Option<usize>,
         terminator: &AtomicBool,
         rng: Option<&mut dyn RngCore>,
+    ) -> Result<(Commitment<E>, Randomness<E>), Error> {
+        Self::commit_internal(powers, None, polynomial, hiding_bound, terminator, rng)
+    }
+
+    /// Outputs a commitment to `polynomial`, using the precomputed multiples of `powers.powers_of_g`.
+    ///
+    /// The precomputed bases may be reused across commitments, for example with
+    /// `PrecomputedBases::new(&powers.powers_of_g)`.
+    pub fn commit_with_precomputed_bases(
+        powers: &Powers<E>,
+        precomputed_powers_of_g: &PrecomputedBases<E::G1Affine>,
+        polynomial: &Polynomial<E::Fr>,
+        hiding_bound: Option<usize>,
+        terminator: &AtomicBool,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(Commitment<E>, Randomness<E>), Error> {
+        Self::commit_internal(
+            powers,
+            Some(precomputed_powers_of_g),
+            polynomial,
+            hiding_bound,
+            terminator,
+            rng,
+        )
+    }
+
+    fn commit_internal(
+        powers: &Powers<E>,
+        precomputed_powers_of_g: Option<&PrecomputedBases<E::G1Affine>>,
+        polynomial: &Polynomial<E::Fr>,
+        hiding_bound: Option<usize>,
+        terminator: &AtomicBool,
+        rng: Option<&mut dyn RngCore>,
     ) -> Result<(Commitment<E>, Randomness<E>), Error> {
         Self::check_degree_is_too_large(polynomial.degree(), powers.size())?;
 
@@ -237,10 +297,17 @@ impl<E: PairingEngine> KZG10<E> {
             hiding_bound,
         ));
 
-        let (num_leading_zeros, plain_coeffs) = skip_leading_zeros_and_convert_to_bigints(&polynomial);
-
         let msm_time = start_timer!(|| "MSM to compute commitment to plaintext poly");
-        let mut commitment = VariableBaseMSM::multi_scalar_mul(&powers.powers_of_g[num_leading_zeros..], &plain_coeffs);
+        let mut commitment = match precomputed_powers_of_g {
+            Some(precomputed_powers_of_g) => {
+                Self::check_degree_is_too_large(polynomial.degree(), precomputed_powers_of_g.len())?;
+                precomputed_powers_of_g.multi_scalar_mul(&convert_to_bigints(&polynomial.coeffs))
+            }
+            None => {
+                let (num_leading_zeros, plain_coeffs) = skip_leading_zeros_and_convert_to_bigints(&polynomial);
+                VariableBaseMSM::multi_scalar_mul(&powers.powers_of_g[num_leading_zeros..], &plain_coeffs)
+            }
+        };
         end_timer!(msm_time);
 
         if terminator.load(Ordering::Relaxed) {
@@ -502,7 +569,7 @@ impl<E: PairingEngine> KZG10<E> {
 
 fn skip_leading_zeros_and_convert_to_bigints<F: PrimeField>(p: &Polynomial<F>) -> (usize, Vec<F::BigInteger>) {
     let mut num_leading_zeros = 0;
-    while p.coeffs[num_leading_zeros].is_zero() && num_leading_zeros < p.coeffs.len() {
+    while num_leading_zeros < p.coeffs.len() && p.coeffs[num_leading_zeros].is_zero() {
         num_leading_zeros += 1;
     }
     let coeffs = convert_to_bigints(&p.coeffs[num_leading_zeros..]);
@@ -690,6 +757,31 @@ mod tests {
         batch_check_test_template::<Bls12_377>().expect("test failed for bls12-377");
     }
 
+    #[test]
+    fn commit_with_precomputed_bases_test() {
+        let rng = &mut test_rng();
+
+        let degree = 16;
+        let pp = KZG_Bls12_377::setup(degree, &KZG10DegreeBoundsConfig::NONE, false, rng).unwrap();
+        let (powers, _) = KZG_Bls12_377::trim(&pp, degree);
+        let precomputed_powers_of_g = PrecomputedBases::new(&powers.powers_of_g);
+
+        for degree in [1, 7, 16] {
+            let p = Polynomial::<Fr>::rand(degree, rng);
+            let (comm, _) = KZG10::commit(&powers, &p, None, &AtomicBool::new(false), None).unwrap();
+            let (precomputed_comm, _) = KZG10::commit_with_precomputed_bases(
+                &powers,
+                &precomputed_powers_of_g,
+                &p,
+                None,
+                &AtomicBool::new(false),
+                None,
+            )
+            .unwrap();
+            assert_eq!(comm, precomputed_comm);
+        }
+    }
+
     #[test]
     fn test_degree_is_too_large() {
         let rng = &mut test_rng();
@@ -0,0 +1,639 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! An updatable powers-of-tau ceremony for the universal parameters of [`KZG10`].
+//!
+//! Each participant multiplies the secret `beta` of the parameters by a secret factor `tau`,
+//! and publishes a proof of knowledge of `tau`. The resulting `beta` is unknown unless every
+//! participant colludes. The transcript of contributions may be verified by anyone, and the
+//! resulting powers converted into [`UniversalParams`].
+
+use super::{KZG10DegreeBoundsConfig, UniversalParams, KZG10};
+use crate::{BTreeMap, Error, ToString, Vec};
+use snarkvm_algorithms::{cfg_iter, msm::VariableBaseMSM};
+use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_utilities::{
+    error,
+    io::{Read, Result as IoResult, Write},
+    rand::UniformRand,
+    FromBytes,
+    ToBytes,
+};
+
+use rand_chacha::ChaChaRng;
+use rand_core::{RngCore, SeedableRng};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The domain separator from which the base of the `gamma` powers is derived.
+const GAMMA_G_DOMAIN: &[u8] = b"snarkVM KZG10 powers of tau gamma_g";
+
+/// The BLAKE2s digest of a ceremony transcript or contribution.
+pub type ContributionHash = [u8; 32];
+
+/// The public key of a contribution to the ceremony, which proves knowledge of the
+/// factor `tau` that the contribution multiplied `beta` by.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""), PartialEq(bound = ""), Eq(bound = ""))]
+pub struct PublicKey<E: PairingEngine> {
+    /// `beta G` after the contribution.
+    pub beta_g_after: E::G1Affine,
+    /// A random element `s` of G1.
+    pub s: E::G1Affine,
+    /// `s * tau`.
+    pub s_tau: E::G1Affine,
+    /// `r * tau`, where `r` in G2 is derived from `s`, `s_tau` and `transcript`.
+    pub r_tau: E::G2Affine,
+    /// The digest of the ceremony transcript before the contribution.
+    pub transcript: ContributionHash,
+}
+
+impl<E: PairingEngine> PublicKey<E> {
+    /// Returns the hash of this contribution, which participants publish to attest to their contribution.
+    pub fn hash(&self) -> Result<ContributionHash, Error> {
+        Ok(to_contribution_hash(blake2s_simd::blake2s(&to_bytes(self)?)))
+    }
+}
+
+impl<E: PairingEngine> ToBytes for PublicKey<E> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.beta_g_after.write_le(&mut writer)?;
+        self.s.write_le(&mut writer)?;
+        self.s_tau.write_le(&mut writer)?;
+        self.r_tau.write_le(&mut writer)?;
+        self.transcript.write_le(&mut writer)
+    }
+}
+
+impl<E: PairingEngine> FromBytes for PublicKey<E> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self {
+            beta_g_after: FromBytes::read_le(&mut reader)?,
+            s: FromBytes::read_le(&mut reader)?,
+            s_tau: FromBytes::read_le(&mut reader)?,
+            r_tau: FromBytes::read_le(&mut reader)?,
+            transcript: FromBytes::read_le(&mut reader)?,
+        })
+    }
+}
+
+/// The state of a powers-of-tau ceremony for the universal parameters of [`KZG10`].
+///
+/// Before the first contribution, `beta` is one, and every power is the corresponding base:
+/// the generator `G` of G1, a point `gamma G` of G1 hashed from a fixed domain separator,
+/// and the generator `H` of G2.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""), PartialEq(bound = ""), Eq(bound = ""))]
+pub struct PowersOfTau<E: PairingEngine> {
+    /// Group elements of the form `{ \beta^i G }`, where `i` ranges from 0 to `max_degree`.
+    pub powers_of_g: Vec<E::G1Affine>,
+    /// Group elements of the form `{ \beta^i \gamma G }`, where `i` ranges from 0 to `max_degree + 1`.
+    pub powers_of_gamma_g: Vec<E::G1Affine>,
+    /// \beta times the generator of G2.
+    pub beta_h: E::G2Affine,
+    /// Supported degree bounds.
+    pub supported_degree_bounds: Vec<usize>,
+    /// Group elements of the form `{ \beta^{-(max_degree - i)} H }`, where `i` is the supported degree bound.
+    /// This is empty if the ceremony does not produce G2 powers.
+    pub inverse_neg_powers_of_h: BTreeMap<usize, E::G2Affine>,
+    /// The digest of the parameters before the first contribution.
+    pub initial_hash: ContributionHash,
+    /// The public keys of the contributions, in order.
+    pub contributions: Vec<PublicKey<E>>,
+}
+
+impl<E: PairingEngine> PowersOfTau<E> {
+    /// Initializes a ceremony for polynomials up to degree `max_degree`, with the same
+    /// degree bounds as [`KZG10::setup`] for the given configuration.
+    pub fn new(
+        max_degree: usize,
+        supported_degree_bounds_config: &KZG10DegreeBoundsConfig,
+        produce_g2_powers: bool,
+    ) -> Result<Self, Error> {
+        let mut supported_degree_bounds = supported_degree_bounds_config.get_list::<E::Fr>(max_degree);
+        supported_degree_bounds.sort_unstable();
+        supported_degree_bounds.dedup();
+        if let Some(degree_bound) = supported_degree_bounds.last().filter(|bound| **bound > max_degree) {
+            return Err(Error::UnsupportedDegreeBound(*degree_bound));
+        }
+        Self::initial(max_degree, supported_degree_bounds, produce_g2_powers)
+    }
+
+    /// Returns the maximum degree of the polynomials supported by the parameters.
+    pub fn max_degree(&self) -> usize {
+        self.powers_of_g.len().saturating_sub(1)
+    }
+
+    /// Contributes a new secret factor to `beta`, and returns the hash of the contribution.
+    /// The secret factor is dropped once the parameters are updated.
+    pub fn contribute<R: RngCore>(&mut self, rng: &mut R) -> Result<ContributionHash, Error> {
+        let contribute_time = start_timer!(|| format!("PowersOfTau::Contribute with degree {}", self.max_degree()));
+        let max_degree = self.max_degree();
+
+        // Sample the secret factor, and prove knowledge of it.
+        let tau = loop {
+            let tau = E::Fr::rand(rng);
+            if !tau.is_zero() {
+                break tau;
+            }
+        };
+        let tau_inverse = tau.inverse().unwrap();
+
+        let s = E::G1Projective::rand(rng).into_affine();
+        let s_tau = (s.into_projective() * tau).into_affine();
+        let transcript = self.transcript_digest()?;
+        let r_tau = (hash_to_g2::<E>(&s, &s_tau, &transcript)? * tau).into_affine();
+
+        // Compute `tau^i`, where `i` ranges from 0 to `max_degree + 1`.
+        let powers_of_tau = {
+            let mut powers_of_tau = vec![E::Fr::one()];
+            let mut cur = tau;
+            for _ in 0..=max_degree {
+                powers_of_tau.push(cur);
+                cur *= &tau;
+            }
+            powers_of_tau
+        };
+
+        // Update the parameters.
+        let batch_mul = |bases: &[E::G1Affine]| {
+            E::G1Projective::batch_normalization_into_affine(
+                cfg_iter!(bases)
+                    .zip(&powers_of_tau)
+                    .map(|(base, power)| base.into_projective() * *power)
+                    .collect(),
+            )
+        };
+        self.powers_of_g = batch_mul(&self.powers_of_g);
+        self.powers_of_gamma_g = batch_mul(&self.powers_of_gamma_g);
+        self.beta_h = (self.beta_h.into_projective() * tau).into_affine();
+        for (i, neg_power_of_h) in self.inverse_neg_powers_of_h.iter_mut() {
+            *neg_power_of_h =
+                (neg_power_of_h.into_projective() * tau_inverse.pow(&[(max_degree - *i) as u64])).into_affine();
+        }
+
+        let public_key = PublicKey {
+            beta_g_after: self.powers_of_g[1],
+            s,
+            s_tau,
+            r_tau,
+            transcript,
+        };
+        let contribution_hash = public_key.hash()?;
+        self.contributions.push(public_key);
+
+        end_timer!(contribute_time);
+        Ok(contribution_hash)
+    }
+
+    /// Verifies the transcript of contributions and the consistency of the resulting powers,
+    /// and returns the hashes of the contributions, in order.
+    pub fn verify<R: RngCore>(&self, rng: &mut R) -> Result<Vec<ContributionHash>, Error> {
+        let verify_time = start_timer!(|| format!("PowersOfTau::Verify with {} powers", self.powers_of_g.len()));
+
+        // The parameters must have the shape of the initial parameters.
+        if self.powers_of_g.len() < 2 || self.powers_of_gamma_g.len() != self.powers_of_g.len() + 1 {
+            return Err(Error::InvalidContribution(
+                "The parameters have an invalid number of powers".to_string(),
+            ));
+        }
+        let max_degree = self.max_degree();
+        if self.supported_degree_bounds.iter().any(|bound| *bound > max_degree) {
+            return Err(Error::InvalidContribution(
+                "The parameters have an invalid degree bound".to_string(),
+            ));
+        }
+        let produce_g2_powers = !self.inverse_neg_powers_of_h.is_empty();
+        let initial = Self::initial(max_degree, self.supported_degree_bounds.clone(), produce_g2_powers)?;
+        if self.initial_hash != initial.initial_hash
+            || self
+                .inverse_neg_powers_of_h
+                .keys()
+                .ne(initial.inverse_neg_powers_of_h.keys())
+        {
+            return Err(Error::InvalidContribution(
+                "The parameters do not extend the initial parameters".to_string(),
+            ));
+        }
+
+        // Each contribution must prove knowledge of the factor it multiplied `beta` by.
+        let mut beta_g_before = initial.powers_of_g[1];
+        let mut contribution_hashes = Vec::with_capacity(self.contributions.len());
+        for (i, public_key) in self.contributions.iter().enumerate() {
+            if public_key.transcript != transcript_digest(&self.initial_hash, &self.contributions[..i])? {
+                return Err(Error::InvalidContribution(format!(
+                    "Contribution {} has an invalid transcript",
+                    i
+                )));
+            }
+            if public_key.beta_g_after.is_zero() || public_key.s.is_zero() || public_key.s_tau.is_zero() {
+                return Err(Error::InvalidContribution(format!(
+                    "Contribution {} has a zero element",
+                    i
+                )));
+            }
+
+            let r = hash_to_g2::<E>(&public_key.s, &public_key.s_tau, &public_key.transcript)?.into_affine();
+            if !same_ratio::<E>((public_key.s, public_key.s_tau), (r, public_key.r_tau))
+                || !same_ratio::<E>((beta_g_before, public_key.beta_g_after), (r, public_key.r_tau))
+            {
+                return Err(Error::InvalidContribution(format!(
+                    "Contribution {} has an invalid proof of knowledge",
+                    i
+                )));
+            }
+
+            beta_g_before = public_key.beta_g_after;
+            contribution_hashes.push(public_key.hash()?);
+        }
+
+        // The final powers must be consistent with the final `beta`.
+        let g = initial.powers_of_g[0];
+        let h = E::G2Affine::prime_subgroup_generator();
+        if self.powers_of_g[0] != g
+            || self.powers_of_gamma_g[0] != initial.powers_of_gamma_g[0]
+            || self.powers_of_g[1] != beta_g_before
+            || !same_ratio::<E>((g, self.powers_of_g[1]), (h, self.beta_h))
+        {
+            return Err(Error::InvalidContribution(
+                "The final beta is inconsistent with the contributions".to_string(),
+            ));
+        }
+        if !same_ratio::<E>(
+            merge_pairs::<E, _>(&self.powers_of_g[..max_degree], &self.powers_of_g[1..], rng),
+            (h, self.beta_h),
+        ) || !same_ratio::<E>(
+            merge_pairs::<E, _>(
+                &self.powers_of_gamma_g[..=max_degree],
+                &self.powers_of_gamma_g[1..],
+                rng,
+            ),
+            (h, self.beta_h),
+        ) {
+            return Err(Error::InvalidContribution(
+                "The final powers are inconsistent with the final beta".to_string(),
+            ));
+        }
+        for (i, neg_power_of_h) in &self.inverse_neg_powers_of_h {
+            if !same_ratio::<E>((g, self.powers_of_g[max_degree - *i]), (*neg_power_of_h, h)) {
+                return Err(Error::InvalidContribution(format!(
+                    "The negative power of h for degree bound {} is inconsistent with the final beta",
+                    i
+                )));
+            }
+        }
+
+        end_timer!(verify_time);
+        Ok(contribution_hashes)
+    }
+
+    /// Converts the powers into universal parameters for [`KZG10`], laid out as in [`KZG10::setup`].
+    ///
+    /// The transcript should be verified with [`PowersOfTau::verify`] before the parameters are used.
+    pub fn into_universal_params(self) -> UniversalParams<E> {
+        let max_degree = self.max_degree();
+        let h = E::G2Affine::prime_subgroup_generator();
+
+        let inverse_powers_of_g = self
+            .supported_degree_bounds
+            .iter()
+            .map(|i| (*i, self.powers_of_g[max_degree - *i]))
+            .collect();
+        let prepared_h = h.prepare();
+        let prepared_beta_h = self.beta_h.prepare();
+
+        UniversalParams {
+            powers_of_g: self.powers_of_g,
+            powers_of_gamma_g: self.powers_of_gamma_g.into_iter().enumerate().collect(),
+            h,
+            beta_h: self.beta_h,
+            supported_degree_bounds: self.supported_degree_bounds,
+            inverse_powers_of_g,
+            inverse_neg_powers_of_h: self.inverse_neg_powers_of_h,
+            prepared_h,
+            prepared_beta_h,
+        }
+    }
+
+    /// Returns the parameters before the first contribution.
+    fn initial(max_degree: usize, supported_degree_bounds: Vec<usize>, produce_g2_powers: bool) -> Result<Self, Error> {
+        if max_degree < 1 {
+            return Err(Error::DegreeIsZero);
+        }
+
+        let g = E::G1Affine::prime_subgroup_generator();
+        let gamma_g = E::G1Projective::rand(&mut ChaChaRng::from_seed(to_contribution_hash(blake2s_simd::blake2s(
+            GAMMA_G_DOMAIN,
+        ))))
+        .into_affine();
+        let h = E::G2Affine::prime_subgroup_generator();
+
+        let inverse_neg_powers_of_h = if produce_g2_powers {
+            supported_degree_bounds.iter().map(|i| (*i, h)).collect()
+        } else {
+            BTreeMap::new()
+        };
+
+        let mut params = Self {
+            powers_of_g: vec![g; max_degree + 1],
+            powers_of_gamma_g: vec![gamma_g; max_degree + 2],
+            beta_h: h,
+            supported_degree_bounds,
+            inverse_neg_powers_of_h,
+            initial_hash: ContributionHash::default(),
+            contributions: vec![],
+        };
+        params.initial_hash = to_contribution_hash(blake2s_simd::blake2s(&to_bytes(&params)?));
+        Ok(params)
+    }
+
+    /// Returns the digest of the ceremony transcript so far.
+    fn transcript_digest(&self) -> Result<ContributionHash, Error> {
+        transcript_digest(&self.initial_hash, &self.contributions)
+    }
+}
+
+impl<E: PairingEngine> KZG10<E> {
+    /// Converts the powers of a verified powers-of-tau ceremony into public parameters
+    /// for the polynomial commitment scheme.
+    pub fn setup_from_powers_of_tau<R: RngCore>(
+        powers_of_tau: PowersOfTau<E>,
+        rng: &mut R,
+    ) -> Result<UniversalParams<E>, Error> {
+        powers_of_tau.verify(rng)?;
+        Ok(powers_of_tau.into_universal_params())
+    }
+}
+
+impl<E: PairingEngine> ToBytes for PowersOfTau<E> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        (self.powers_of_g.len() as u32).write_le(&mut writer)?;
+        self.powers_of_g.write_le(&mut writer)?;
+        self.powers_of_gamma_g.write_le(&mut writer)?;
+        self.beta_h.write_le(&mut writer)?;
+        (self.supported_degree_bounds.len() as u32).write_le(&mut writer)?;
+        for degree_bound in &self.supported_degree_bounds {
+            (*degree_bound as u32).write_le(&mut writer)?;
+        }
+        (!self.inverse_neg_powers_of_h.is_empty()).write_le(&mut writer)?;
+        for neg_power_of_h in self.inverse_neg_powers_of_h.values() {
+            neg_power_of_h.write_le(&mut writer)?;
+        }
+        self.initial_hash.write_le(&mut writer)?;
+        (self.contributions.len() as u32).write_le(&mut writer)?;
+        for public_key in &self.contributions {
+            public_key.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: PairingEngine> FromBytes for PowersOfTau<E> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // The counts are untrusted, so the vectors are not preallocated.
+        let num_powers: u32 = FromBytes::read_le(&mut reader)?;
+        if num_powers < 2 {
+            return Err(error("The powers of tau must support a degree of at least 1"));
+        }
+        let mut powers_of_g = Vec::new();
+        for _ in 0..num_powers {
+            powers_of_g.push(FromBytes::read_le(&mut reader)?);
+        }
+        let mut powers_of_gamma_g = Vec::new();
+        for _ in 0..=num_powers {
+            powers_of_gamma_g.push(FromBytes::read_le(&mut reader)?);
+        }
+        let beta_h = FromBytes::read_le(&mut reader)?;
+
+        // The degree bounds must be sorted, unique, and at most the maximum degree.
+        let num_degree_bounds: u32 = FromBytes::read_le(&mut reader)?;
+        if num_degree_bounds > num_powers {
+            return Err(error("Too many degree bounds for the powers of tau"));
+        }
+        let mut supported_degree_bounds = Vec::new();
+        for _ in 0..num_degree_bounds {
+            let degree_bound: u32 = FromBytes::read_le(&mut reader)?;
+            if degree_bound >= num_powers
+                || supported_degree_bounds
+                    .last()
+                    .map_or(false, |previous| *previous >= degree_bound as usize)
+            {
+                return Err(error("Invalid degree bound for the powers of tau"));
+            }
+            supported_degree_bounds.push(degree_bound as usize);
+        }
+        let produce_g2_powers: bool = FromBytes::read_le(&mut reader)?;
+        let mut inverse_neg_powers_of_h = BTreeMap::new();
+        if produce_g2_powers {
+            for degree_bound in &supported_degree_bounds {
+                inverse_neg_powers_of_h.insert(*degree_bound, FromBytes::read_le(&mut reader)?);
+            }
+        }
+        let initial_hash = FromBytes::read_le(&mut reader)?;
+
+        let num_contributions: u32 = FromBytes::read_le(&mut reader)?;
+        let mut contributions = Vec::new();
+        for _ in 0..num_contributions {
+            contributions.push(FromBytes::read_le(&mut reader)?);
+        }
+
+        Ok(Self {
+            powers_of_g,
+            powers_of_gamma_g,
+            beta_h,
+            supported_degree_bounds,
+            inverse_neg_powers_of_h,
+            initial_hash,
+            contributions,
+        })
+    }
+}
+
+/// Returns the serialization of the given value.
+fn to_bytes<T: ToBytes>(value: &T) -> Result<Vec<u8>, Error> {
+    let mut bytes = vec![];
+    value
+        .write_le(&mut bytes)
+        .map_err(|error| Error::InvalidContribution(error.to_string()))?;
+    Ok(bytes)
+}
+
+/// Returns the given BLAKE2s digest as an array.
+fn to_contribution_hash(hash: blake2s_simd::Hash) -> ContributionHash {
+    let mut contribution_hash = ContributionHash::default();
+    contribution_hash.copy_from_slice(hash.as_bytes());
+    contribution_hash
+}
+
+/// Returns the digest of the transcript made of `initial_hash` and the given contributions.
+fn transcript_digest<E: PairingEngine>(
+    initial_hash: &ContributionHash,
+    contributions: &[PublicKey<E>],
+) -> Result<ContributionHash, Error> {
+    let mut state = blake2s_simd::State::new();
+    state.update(initial_hash);
+    for public_key in contributions {
+        state.update(&to_bytes(public_key)?);
+    }
+    Ok(to_contribution_hash(state.finalize()))
+}
+
+/// Derives the element `r` of G2 for a proof of knowledge, with an unknown discrete logarithm.
+fn hash_to_g2<E: PairingEngine>(
+    s: &E::G1Affine,
+    s_tau: &E::G1Affine,
+    transcript: &ContributionHash,
+) -> Result<E::G2Projective, Error> {
+    let mut state = blake2s_simd::State::new();
+    state.update(&to_bytes(s)?);
+    state.update(&to_bytes(s_tau)?);
+    state.update(transcript);
+    Ok(E::G2Projective::rand(&mut ChaChaRng::from_seed(to_contribution_hash(
+        state.finalize(),
+    ))))
+}
+
+/// Returns `true` if `g1.1 = g1.0 * x` and `g2.1 = g2.0 * x` for the same `x`, by checking
+/// `e(g1.0, g2.1) == e(g1.1, g2.0)`.
+fn same_ratio<E: PairingEngine>(g1: (E::G1Affine, E::G1Affine), g2: (E::G2Affine, E::G2Affine)) -> bool {
+    E::pairing(g1.0, g2.1) == E::pairing(g1.1, g2.0)
+}
+
+/// Returns a random linear combination of `left` and the same combination of `right`, which have
+/// the same ratio with overwhelming probability only if each pair of elements has that ratio.
+fn merge_pairs<E: PairingEngine, R: RngCore>(
+    left: &[E::G1Affine],
+    right: &[E::G1Affine],
+    rng: &mut R,
+) -> (E::G1Affine, E::G1Affine) {
+    assert_eq!(left.len(), right.len());
+    let scalars = (0..left.len()).map(|_| E::Fr::rand(rng).to_repr()).collect::<Vec<_>>();
+    (
+        VariableBaseMSM::multi_scalar_mul(left, &scalars).into_affine(),
+        VariableBaseMSM::multi_scalar_mul(right, &scalars).into_affine(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Polynomial;
+    use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+    use snarkvm_utilities::rand::test_rng;
+
+    use core::sync::atomic::AtomicBool;
+
+    type PowersOfTauBls12_377 = PowersOfTau<Bls12_377>;
+
+    #[test]
+    fn test_contribute_and_verify() {
+        let rng = &mut test_rng();
+        let max_degree = 16;
+
+        let mut powers_of_tau = PowersOfTauBls12_377::new(max_degree, &KZG10DegreeBoundsConfig::MARLIN, true).unwrap();
+        assert!(powers_of_tau.verify(rng).unwrap().is_empty());
+
+        let mut hashes = vec![];
+        for _ in 0..3 {
+            hashes.push(powers_of_tau.contribute(rng).unwrap());
+        }
+        assert_eq!(powers_of_tau.verify(rng).unwrap(), hashes);
+
+        // The converted parameters must support commitments and openings.
+        let pp = KZG10::setup_from_powers_of_tau(powers_of_tau, rng).unwrap();
+        assert_eq!(pp.powers_of_g.len(), max_degree + 1);
+        assert_eq!(pp.powers_of_gamma_g.len(), max_degree + 2);
+        let (ck, vk) = KZG10::trim(&pp, max_degree);
+
+        let p = Polynomial::rand(max_degree, rng);
+        let (comm, rand) = KZG10::commit(&ck, &p, Some(1), &AtomicBool::new(false), Some(rng)).unwrap();
+        let point = Fr::rand(rng);
+        let proof = KZG10::open(&ck, &p, point, &rand).unwrap();
+        assert!(KZG10::check(&vk, &comm, point, p.evaluate(point), &proof).unwrap());
+
+        for (i, neg_power_of_h) in &pp.inverse_neg_powers_of_h {
+            assert!(same_ratio::<Bls12_377>(
+                (pp.powers_of_g[0], pp.inverse_powers_of_g[i]),
+                (*neg_power_of_h, pp.h)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_tampered_transcript() {
+        let rng = &mut test_rng();
+
+        let mut powers_of_tau = PowersOfTauBls12_377::new(8, &KZG10DegreeBoundsConfig::NONE, false).unwrap();
+        powers_of_tau.contribute(rng).unwrap();
+        powers_of_tau.contribute(rng).unwrap();
+
+        // A tampered power must be rejected.
+        let mut tampered = powers_of_tau.clone();
+        tampered.powers_of_g[3] = tampered.powers_of_g[4];
+        assert!(tampered.verify(rng).is_err());
+
+        // A tampered proof of knowledge must be rejected.
+        let mut tampered = powers_of_tau.clone();
+        tampered.contributions[0].s_tau = tampered.contributions[0].s;
+        assert!(tampered.verify(rng).is_err());
+
+        // A removed contribution must be rejected.
+        let mut tampered = powers_of_tau;
+        tampered.contributions.remove(0);
+        assert!(tampered.verify(rng).is_err());
+    }
+
+    #[test]
+    fn test_serialization() {
+        let rng = &mut test_rng();
+
+        let mut powers_of_tau = PowersOfTauBls12_377::new(8, &KZG10DegreeBoundsConfig::MARLIN, true).unwrap();
+        powers_of_tau.contribute(rng).unwrap();
+
+        let bytes = powers_of_tau.to_bytes_le().unwrap();
+        let recovered = PowersOfTauBls12_377::read_le(&bytes[..]).unwrap();
+        assert_eq!(powers_of_tau, recovered);
+        assert!(recovered.verify(rng).is_ok());
+
+        // Ensure malformed counts are rejected without allocating them.
+        let mut malformed = bytes.clone();
+        malformed[..4].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(PowersOfTauBls12_377::read_le(&malformed[..]).is_err());
+
+        let mut malformed = bytes.clone();
+        malformed[..4].copy_from_slice(&0u32.to_le_bytes());
+        assert!(PowersOfTauBls12_377::read_le(&malformed[..]).is_err());
+
+        let num_degree_bounds_offset = 4
+            + powers_of_tau.powers_of_g.to_bytes_le().unwrap().len()
+            + powers_of_tau.powers_of_gamma_g.to_bytes_le().unwrap().len()
+            + powers_of_tau.beta_h.to_bytes_le().unwrap().len();
+        let mut malformed = bytes.clone();
+        malformed[num_degree_bounds_offset..num_degree_bounds_offset + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(PowersOfTauBls12_377::read_le(&malformed[..]).is_err());
+
+        // Ensure degree bounds above the maximum degree are rejected.
+        let mut malformed = bytes;
+        malformed[num_degree_bounds_offset + 4..num_degree_bounds_offset + 8].copy_from_slice(&9u32.to_le_bytes());
+        assert!(PowersOfTauBls12_377::read_le(&malformed[..]).is_err());
+    }
+}
@@ -0,0 +1,785 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A vector commitment built on [`KZG10`].
+//!
+//! A vector of `VECTOR_SIZE` field elements is committed to as the polynomial whose evaluation
+//! at the `i`-th element of a multiplicative subgroup is the `i`-th entry of the vector.
+//! Each position is opened with a [`KZG10`] evaluation proof. A batch of positions is opened with
+//! a single proof, which commits to the quotient of the polynomial by the vanishing polynomial of
+//! the positions, and shows the quotient is consistent with the commitment at a random challenge.
+
+use super::{
+    convert_to_bigints,
+    skip_leading_zeros_and_convert_to_bigints,
+    Commitment,
+    Powers,
+    PowersOfTau,
+    Proof,
+    Randomness,
+    UniversalParams,
+    VerifierKey,
+    KZG10,
+};
+use crate::{Cow, Error, PCRandomness, PCUniversalParams, Polynomial, ToString, Vec};
+use snarkvm_algorithms::{fft::EvaluationDomain, msm::VariableBaseMSM, CommitmentError, CommitmentScheme};
+use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{Field, One, PrimeField, ToConstraintField, Zero};
+use snarkvm_utilities::{
+    error,
+    io::{Read, Result as IoResult, Write},
+    FromBytes,
+    ToBytes,
+};
+
+use core::{ops::Mul, sync::atomic::AtomicBool};
+use rand_core::RngCore;
+
+/// The domain separator of the challenge of a batch opening.
+const KZG10_VECTOR_BATCH_OPENING_DOMAIN: &str = "AleoKZG10VectorBatchOpening0";
+
+/// The parameters of a [`KZG10VectorCommitment`], trimmed from the universal parameters of [`KZG10`].
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""), PartialEq(bound = ""), Eq(bound = ""))]
+pub struct KZG10VectorCommitmentParameters<E: PairingEngine> {
+    /// Group elements of the form `{ \beta^i G }`, where `i` ranges from 0 to `domain_size - 1`.
+    pub powers_of_g: Vec<E::G1Affine>,
+    /// Group elements of the form `{ \beta^i \gamma G }`, where `i` ranges from 0 to `domain_size`.
+    pub powers_of_gamma_g: Vec<E::G1Affine>,
+    /// The generator of G2.
+    pub h: E::G2Affine,
+    /// \beta times the above generator of G2.
+    pub beta_h: E::G2Affine,
+}
+
+impl<E: PairingEngine> ToBytes for KZG10VectorCommitmentParameters<E> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        (self.powers_of_g.len() as u32).write_le(&mut writer)?;
+        for power in &self.powers_of_g {
+            power.write_le(&mut writer)?;
+        }
+
+        (self.powers_of_gamma_g.len() as u32).write_le(&mut writer)?;
+        for power in &self.powers_of_gamma_g {
+            power.write_le(&mut writer)?;
+        }
+
+        self.h.write_le(&mut writer)?;
+        self.beta_h.write_le(&mut writer)
+    }
+}
+
+impl<E: PairingEngine> FromBytes for KZG10VectorCommitmentParameters<E> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // The number of powers is untrusted, so the powers are not preallocated.
+        let powers_of_g_len: u32 = FromBytes::read_le(&mut reader)?;
+        let mut powers_of_g = Vec::new();
+        for _ in 0..powers_of_g_len {
+            powers_of_g.push(FromBytes::read_le(&mut reader)?);
+        }
+
+        let powers_of_gamma_g_len: u32 = FromBytes::read_le(&mut reader)?;
+        let mut powers_of_gamma_g = Vec::new();
+        for _ in 0..powers_of_gamma_g_len {
+            powers_of_gamma_g.push(FromBytes::read_le(&mut reader)?);
+        }
+
+        Ok(Self {
+            powers_of_g,
+            powers_of_gamma_g,
+            h: FromBytes::read_le(&mut reader)?,
+            beta_h: FromBytes::read_le(&mut reader)?,
+        })
+    }
+}
+
+/// A proof that a [`KZG10VectorCommitment`] opens to the given values at a batch of positions.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""), PartialEq(bound = ""), Eq(bound = ""))]
+pub struct KZG10VectorBatchProof<E: PairingEngine> {
+    /// A commitment to the quotient of the committed polynomial by the vanishing polynomial of the positions.
+    pub w: E::G1Affine,
+    /// A commitment to the witness that the committed polynomial, minus the quotient scaled by the
+    /// vanishing polynomial at the challenge, evaluates to the interpolated values at the challenge.
+    pub challenge_w: E::G1Affine,
+    /// The evaluations of the blinding polynomial at the positions, if the commitment is hiding.
+    pub random_values: Option<Vec<E::Fr>>,
+}
+
+impl<E: PairingEngine> ToBytes for KZG10VectorBatchProof<E> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.w.write_le(&mut writer)?;
+        self.challenge_w.write_le(&mut writer)?;
+
+        match &self.random_values {
+            Some(random_values) => {
+                true.write_le(&mut writer)?;
+                (random_values.len() as u32).write_le(&mut writer)?;
+                for random_value in random_values {
+                    random_value.write_le(&mut writer)?;
+                }
+                Ok(())
+            }
+            None => false.write_le(&mut writer),
+        }
+    }
+}
+
+impl<E: PairingEngine> FromBytes for KZG10VectorBatchProof<E> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let w = FromBytes::read_le(&mut reader)?;
+        let challenge_w = FromBytes::read_le(&mut reader)?;
+
+        let random_values = match bool::read_le(&mut reader)? {
+            true => {
+                // The number of random values is untrusted, so the values are not preallocated.
+                let num_random_values: u32 = FromBytes::read_le(&mut reader)?;
+                let mut random_values = Vec::new();
+                for _ in 0..num_random_values {
+                    random_values.push(FromBytes::read_le(&mut reader)?);
+                }
+                Some(random_values)
+            }
+            false => None,
+        };
+
+        Ok(Self {
+            w,
+            challenge_w,
+            random_values,
+        })
+    }
+}
+
+/// A commitment to a vector of `VECTOR_SIZE` scalar field elements, with openings of individual positions.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""), PartialEq(bound = ""), Eq(bound = ""))]
+pub struct KZG10VectorCommitment<E: PairingEngine, const VECTOR_SIZE: usize> {
+    pub parameters: KZG10VectorCommitmentParameters<E>,
+}
+
+impl<E: PairingEngine, const VECTOR_SIZE: usize> KZG10VectorCommitment<E, VECTOR_SIZE> {
+    /// Specializes the powers of a verified [`PowersOfTau`] ceremony to vectors of `VECTOR_SIZE` elements.
+    ///
+    /// The ceremony must have at least one contribution, as the trapdoor of the initial parameters is known.
+    pub fn from_powers_of_tau<R: RngCore>(powers_of_tau: PowersOfTau<E>, rng: &mut R) -> Result<Self, Error> {
+        if powers_of_tau.contributions.is_empty() {
+            return Err(Error::InvalidContribution(
+                "The ceremony has no contributions".to_string(),
+            ));
+        }
+        Self::trim(&KZG10::setup_from_powers_of_tau(powers_of_tau, rng)?)
+    }
+
+    /// Specializes the universal parameters of [`KZG10`] to vectors of `VECTOR_SIZE` elements.
+    fn trim(pp: &UniversalParams<E>) -> Result<Self, Error> {
+        let domain_size = Self::domain()?.size();
+        if domain_size > pp.max_degree() + 1 {
+            return Err(Error::TrimmingDegreeTooLarge);
+        }
+
+        let powers_of_g = pp.powers_of_g[..domain_size].to_vec();
+        let powers_of_gamma_g = (0..=domain_size)
+            .map(|i| pp.powers_of_gamma_g.get(&i).copied())
+            .collect::<Option<Vec<_>>>()
+            .ok_or(Error::TrimmingDegreeTooLarge)?;
+
+        Ok(Self {
+            parameters: KZG10VectorCommitmentParameters {
+                powers_of_g,
+                powers_of_gamma_g,
+                h: pp.h,
+                beta_h: pp.beta_h,
+            },
+        })
+    }
+
+    /// Returns the evaluation domain, whose `i`-th element is the position of the `i`-th entry of a vector.
+    pub fn domain() -> Result<EvaluationDomain<E::Fr>, Error> {
+        EvaluationDomain::new(VECTOR_SIZE).ok_or_else(|| {
+            Error::IncorrectInputLength(format!(
+                "vectors of size {} are not supported by the field",
+                VECTOR_SIZE
+            ))
+        })
+    }
+
+    /// Outputs a commitment to `values`, which are padded with zeros to `VECTOR_SIZE` elements.
+    ///
+    /// If `hiding_bound` is set, the commitment hides `values` for up to `hiding_bound` opened positions.
+    pub fn commit_vector(
+        &self,
+        values: &[E::Fr],
+        hiding_bound: Option<usize>,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(Commitment<E>, Randomness<E>), Error> {
+        let polynomial = Self::interpolate(values)?;
+        KZG10::commit(&self.powers(), &polynomial, hiding_bound, &AtomicBool::new(false), rng)
+    }
+
+    /// Outputs a proof that the entry at `index` of the vector committed to with `randomness` is `values[index]`.
+    pub fn open(&self, values: &[E::Fr], index: usize, randomness: &Randomness<E>) -> Result<Proof<E>, Error> {
+        let polynomial = Self::interpolate(values)?;
+        KZG10::open(&self.powers(), &polynomial, Self::point(index)?, randomness)
+    }
+
+    /// Outputs a single proof that the entries at the given distinct `indices` of the vector
+    /// committed to with `randomness` are the corresponding entries of `values`.
+    ///
+    /// For the polynomial `p`, the positions `z_i`, and their vanishing polynomial `Z`, the proof
+    /// commits to `q := p / Z`. The remainder of this division interpolates the opened values.
+    /// The proof then opens `p - Z(c) q` at a challenge `c`, which is derived from the commitments,
+    /// the positions, and the opened values.
+    pub fn open_batch(
+        &self,
+        values: &[E::Fr],
+        indices: &[usize],
+        randomness: &Randomness<E>,
+    ) -> Result<KZG10VectorBatchProof<E>, Error> {
+        let polynomial = Self::interpolate(values)?;
+        let hiding_polynomial = match randomness.is_hiding() {
+            true => Some(&randomness.blinding_polynomial),
+            false => None,
+        };
+        let commitment = Commitment(self.commit_polynomials(&polynomial, hiding_polynomial)?);
+
+        // Compute the vanishing polynomial of the positions.
+        let points = Self::batch_points(indices)?;
+        let vanishing_polynomial = points
+            .iter()
+            .fold(Polynomial::from_coefficients_vec(vec![E::Fr::one()]), |product, point| {
+                &product * &Polynomial::from_coefficients_vec(vec![-*point, E::Fr::one()])
+            });
+
+        // Commit to the quotients by the vanishing polynomial.
+        let quotient = &polynomial / &vanishing_polynomial;
+        let hiding_quotient = hiding_polynomial.map(|hiding_polynomial| hiding_polynomial / &vanishing_polynomial);
+        let w = self.commit_polynomials(&quotient, hiding_quotient.as_ref())?;
+
+        // Derive the challenge from the commitments, positions, and opened values.
+        let opened_values = points.iter().map(|point| polynomial.evaluate(*point)).collect::<Vec<_>>();
+        let random_values = hiding_polynomial
+            .map(|hiding_polynomial| points.iter().map(|point| hiding_polynomial.evaluate(*point)).collect());
+        let challenge = Self::batch_challenge(&commitment, &w, &points, &opened_values, random_values.as_deref())?;
+
+        // Open p - Z(c) q at the challenge. The witness does not depend on the constant term,
+        // so the interpolated values at the challenge are omitted.
+        let vanishing_at_challenge = vanishing_polynomial.evaluate(challenge);
+        let divisor = Polynomial::from_coefficients_vec(vec![-challenge, E::Fr::one()]);
+
+        let mut linearized_polynomial = polynomial;
+        linearized_polynomial += (-vanishing_at_challenge, &quotient);
+        let linearized_hiding_polynomial = hiding_polynomial.zip(hiding_quotient.as_ref()).map(
+            |(hiding_polynomial, hiding_quotient)| {
+                let mut linearized_hiding_polynomial = hiding_polynomial.clone();
+                linearized_hiding_polynomial += (-vanishing_at_challenge, hiding_quotient);
+                &linearized_hiding_polynomial / &divisor
+            },
+        );
+        let challenge_w = self.commit_polynomials(
+            &(&linearized_polynomial / &divisor),
+            linearized_hiding_polynomial.as_ref(),
+        )?;
+
+        Ok(KZG10VectorBatchProof {
+            w,
+            challenge_w,
+            random_values,
+        })
+    }
+
+    /// Verifies that `value` is the entry at `index` of the vector committed inside `commitment`.
+    pub fn verify(
+        &self,
+        commitment: &Commitment<E>,
+        index: usize,
+        value: E::Fr,
+        proof: &Proof<E>,
+    ) -> Result<bool, Error> {
+        KZG10::check(&self.verifier_key()?, commitment, Self::point(index)?, value, proof)
+    }
+
+    /// Verifies that each of `values` is the entry at the corresponding position in `indices`
+    /// of the vector committed inside `commitment`.
+    pub fn verify_batch(
+        &self,
+        commitment: &Commitment<E>,
+        indices: &[usize],
+        values: &[E::Fr],
+        proof: &KZG10VectorBatchProof<E>,
+    ) -> Result<bool, Error> {
+        let num_random_values = proof.random_values.as_ref().map_or(indices.len(), Vec::len);
+        if indices.len() != values.len() || indices.len() != num_random_values {
+            return Err(Error::IncorrectInputLength(format!(
+                "found {} indices, {} values and {} random values",
+                indices.len(),
+                values.len(),
+                num_random_values
+            )));
+        }
+
+        let points = Self::batch_points(indices)?;
+        let random_values = proof.random_values.as_deref();
+        let challenge = Self::batch_challenge(commitment, &proof.w, &points, values, random_values)?;
+
+        // Evaluate the vanishing polynomial, and the interpolations of the values, at the challenge.
+        let vanishing_at_challenge = points.iter().map(|point| challenge - point).product::<E::Fr>();
+        let lagrange_coefficients = Self::lagrange_coefficients(&points, challenge)?;
+        let interpolate = |values: &[E::Fr]| {
+            values
+                .iter()
+                .zip(&lagrange_coefficients)
+                .map(|(value, coefficient)| *value * coefficient)
+                .sum::<E::Fr>()
+        };
+
+        // The commitment to p - Z(c) q, less its evaluation at the challenge, must open to zero at the challenge.
+        let vk = self.verifier_key()?;
+        let mut linearized_commitment = commitment.0.into_projective()
+            - proof.w.mul(vanishing_at_challenge).into_projective()
+            - vk.g.mul(interpolate(values)).into_projective();
+        if let Some(random_values) = random_values {
+            linearized_commitment -= vk.gamma_g.mul(interpolate(random_values)).into_projective();
+        }
+
+        KZG10::check(
+            &vk,
+            &Commitment(linearized_commitment.into_affine()),
+            challenge,
+            E::Fr::zero(),
+            &Proof {
+                w: proof.challenge_w,
+                random_v: None,
+            },
+        )
+    }
+
+    /// Returns the randomness of a commitment output by [`CommitmentScheme::commit`] with the
+    /// given `randomness`, for use in openings.
+    ///
+    /// Such a commitment no longer hides the vector once any position is opened.
+    pub fn randomness_from_scalar(randomness: E::Fr) -> Randomness<E> {
+        let mut kzg_randomness = Randomness::empty();
+        kzg_randomness.blinding_polynomial = Polynomial::from_coefficients_vec(vec![randomness]);
+        kzg_randomness
+    }
+
+    /// Returns the polynomial whose evaluations over the domain are `values`, padded with zeros.
+    fn interpolate(values: &[E::Fr]) -> Result<Polynomial<E::Fr>, Error> {
+        if values.len() > VECTOR_SIZE {
+            return Err(Error::IncorrectInputLength(format!(
+                "expected at most {} values, found {}",
+                VECTOR_SIZE,
+                values.len()
+            )));
+        }
+
+        let domain = Self::domain()?;
+        let mut evaluations = values.to_vec();
+        evaluations.resize(domain.size(), E::Fr::zero());
+        Ok(Polynomial::from_coefficients_vec(domain.ifft(&evaluations)))
+    }
+
+    /// Returns the element of the domain at which the entry at `index` is opened.
+    fn point(index: usize) -> Result<E::Fr, Error> {
+        if index >= VECTOR_SIZE {
+            return Err(Error::IncorrectInputLength(format!(
+                "index {} is out of bounds for vectors of size {}",
+                index, VECTOR_SIZE
+            )));
+        }
+        Ok(Self::domain()?.group_gen.pow(&[index as u64]))
+    }
+
+    /// Returns the elements of the domain for a batch of indices, which must be non-empty and distinct.
+    fn batch_points(indices: &[usize]) -> Result<Vec<E::Fr>, Error> {
+        if indices.is_empty() {
+            return Err(Error::IncorrectInputLength("expected at least one index".to_string()));
+        }
+        if indices.iter().enumerate().any(|(i, index)| indices[..i].contains(index)) {
+            return Err(Error::IncorrectInputLength("expected distinct indices".to_string()));
+        }
+        indices.iter().map(|index| Self::point(*index)).collect()
+    }
+
+    /// Returns the Lagrange basis polynomials of the given distinct points, evaluated at the challenge.
+    fn lagrange_coefficients(points: &[E::Fr], challenge: E::Fr) -> Result<Vec<E::Fr>, Error> {
+        points
+            .iter()
+            .enumerate()
+            .map(|(i, point)| {
+                let (numerator, denominator) = points
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, _)| *j != i)
+                    .fold((E::Fr::one(), E::Fr::one()), |(numerator, denominator), (_, other)| {
+                        (numerator * (challenge - other), denominator * (*point - other))
+                    });
+                denominator
+                    .inverse()
+                    .map(|inverse| numerator * inverse)
+                    .ok_or_else(|| Error::IncorrectInputLength("expected distinct points".to_string()))
+            })
+            .collect()
+    }
+
+    /// Returns the challenge of a batch opening, as a hash of the commitment, the commitment to
+    /// the quotient, the positions, and the opened values.
+    fn batch_challenge(
+        commitment: &Commitment<E>,
+        w: &E::G1Affine,
+        points: &[E::Fr],
+        values: &[E::Fr],
+        random_values: Option<&[E::Fr]>,
+    ) -> Result<E::Fr, Error> {
+        let mut preimage = KZG10_VECTOR_BATCH_OPENING_DOMAIN.as_bytes().to_vec();
+        let mut write_preimage = || -> IoResult<()> {
+            commitment.write_le(&mut preimage)?;
+            w.write_le(&mut preimage)?;
+            (points.len() as u32).write_le(&mut preimage)?;
+            for element in points.iter().chain(values).chain(random_values.unwrap_or(&[])) {
+                element.write_le(&mut preimage)?;
+            }
+            Ok(())
+        };
+        write_preimage().map_err(|error| Error::MalformedCommitment(error.to_string()))?;
+
+        Ok(E::Fr::from_bytes_le_mod_order(
+            blake2s_simd::blake2s(&preimage).as_bytes(),
+        ))
+    }
+
+    /// Returns the commitment to `polynomial`, plus the commitment to `hiding_polynomial` under the powers of `gamma G`.
+    fn commit_polynomials(
+        &self,
+        polynomial: &Polynomial<E::Fr>,
+        hiding_polynomial: Option<&Polynomial<E::Fr>>,
+    ) -> Result<E::G1Affine, Error> {
+        KZG10::<E>::check_degree_is_too_large(polynomial.degree(), self.parameters.powers_of_g.len())?;
+        let (num_leading_zeros, coeffs) = skip_leading_zeros_and_convert_to_bigints(polynomial);
+        let mut commitment =
+            VariableBaseMSM::multi_scalar_mul(&self.parameters.powers_of_g[num_leading_zeros..], &coeffs);
+
+        if let Some(hiding_polynomial) = hiding_polynomial {
+            KZG10::<E>::check_degree_is_too_large(
+                hiding_polynomial.degree(),
+                self.parameters.powers_of_gamma_g.len(),
+            )?;
+            commitment += &VariableBaseMSM::multi_scalar_mul(
+                &self.parameters.powers_of_gamma_g,
+                &convert_to_bigints(&hiding_polynomial.coeffs),
+            );
+        }
+
+        Ok(commitment.into_affine())
+    }
+
+    fn powers(&self) -> Powers<E> {
+        Powers {
+            powers_of_g: Cow::Borrowed(&self.parameters.powers_of_g),
+            powers_of_gamma_g: Cow::Borrowed(&self.parameters.powers_of_gamma_g),
+        }
+    }
+
+    fn verifier_key(&self) -> Result<VerifierKey<E>, Error> {
+        match (
+            self.parameters.powers_of_g.first(),
+            self.parameters.powers_of_gamma_g.first(),
+        ) {
+            (Some(g), Some(gamma_g)) => Ok(VerifierKey {
+                g: *g,
+                gamma_g: *gamma_g,
+                h: self.parameters.h,
+                beta_h: self.parameters.beta_h,
+                prepared_h: self.parameters.h.prepare(),
+                prepared_beta_h: self.parameters.beta_h.prepare(),
+            }),
+            _ => Err(Error::TrimmingDegreeTooLarge),
+        }
+    }
+
+    /// Returns `true` if the parameters have the number of powers that [`Self::from_powers_of_tau`] outputs.
+    fn is_valid(parameters: &KZG10VectorCommitmentParameters<E>) -> bool {
+        match Self::domain() {
+            Ok(domain) => {
+                parameters.powers_of_g.len() == domain.size()
+                    && parameters.powers_of_gamma_g.len() == domain.size() + 1
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+impl<E: PairingEngine, const VECTOR_SIZE: usize> CommitmentScheme for KZG10VectorCommitment<E, VECTOR_SIZE> {
+    type Output = Commitment<E>;
+    type Parameters = KZG10VectorCommitmentParameters<E>;
+    type Randomness = E::Fr;
+
+    /// Panics, as parameters sampled from a public message have a known trapdoor, so commitments
+    /// under them would not be binding. Use [`Self::from_powers_of_tau`] instead.
+    fn setup(_message: &str) -> Self {
+        panic!("KZG10 vector commitment parameters must be built from a verified powers-of-tau ceremony")
+    }
+
+    /// Commits to `input`, packed into field elements, and blinded by `randomness`.
+    ///
+    /// Positions of the committed vector are opened with [`Self::randomness_from_scalar`].
+    fn commit(&self, input: &[u8], randomness: &Self::Randomness) -> Result<Self::Output, CommitmentError> {
+        let values: Vec<E::Fr> = input
+            .to_field_elements()
+            .map_err(|error| CommitmentError::Message(error.to_string()))?;
+        let polynomial = Self::interpolate(&values).map_err(|error| CommitmentError::Message(error.to_string()))?;
+        let commitment = self
+            .commit_polynomials(&polynomial, Some(&Polynomial::from_coefficients_vec(vec![*randomness])))
+            .map_err(|error| CommitmentError::Message(error.to_string()))?;
+        Ok(Commitment(commitment))
+    }
+
+    fn parameters(&self) -> Self::Parameters {
+        self.parameters.clone()
+    }
+}
+
+impl<E: PairingEngine, const VECTOR_SIZE: usize> From<KZG10VectorCommitmentParameters<E>>
+    for KZG10VectorCommitment<E, VECTOR_SIZE>
+{
+    fn from(parameters: KZG10VectorCommitmentParameters<E>) -> Self {
+        Self { parameters }
+    }
+}
+
+impl<E: PairingEngine, const VECTOR_SIZE: usize> ToBytes for KZG10VectorCommitment<E, VECTOR_SIZE> {
+    #[inline]
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.parameters.write_le(writer)
+    }
+}
+
+impl<E: PairingEngine, const VECTOR_SIZE: usize> FromBytes for KZG10VectorCommitment<E, VECTOR_SIZE> {
+    #[inline]
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        let parameters = FromBytes::read_le(reader)?;
+        if !Self::is_valid(&parameters) {
+            return Err(error("The vector commitment parameters do not match the vector size"));
+        }
+        Ok(Self { parameters })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kzg10::KZG10DegreeBoundsConfig;
+    use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+    use snarkvm_utilities::rand::{test_rng, UniformRand};
+
+    const VECTOR_SIZE: usize = 13;
+
+    type VectorCommitment = KZG10VectorCommitment<Bls12_377, VECTOR_SIZE>;
+
+    fn setup<R: RngCore>(rng: &mut R) -> VectorCommitment {
+        let mut powers_of_tau = PowersOfTau::<Bls12_377>::new(32, &KZG10DegreeBoundsConfig::NONE, false).unwrap();
+        powers_of_tau.contribute(rng).unwrap();
+        VectorCommitment::from_powers_of_tau(powers_of_tau, rng).unwrap()
+    }
+
+    #[test]
+    fn test_open_and_verify() {
+        let rng = &mut test_rng();
+        let vector_commitment = setup(rng);
+
+        let values: Vec<_> = (0..VECTOR_SIZE).map(|_| Fr::rand(rng)).collect();
+        for hiding_bound in [None, Some(1)] {
+            let (commitment, randomness) = vector_commitment
+                .commit_vector(&values, hiding_bound, Some(rng))
+                .unwrap();
+            assert_eq!(hiding_bound.is_some(), randomness.is_hiding());
+
+            for (index, value) in values.iter().enumerate() {
+                let proof = vector_commitment.open(&values, index, &randomness).unwrap();
+                assert!(vector_commitment.verify(&commitment, index, *value, &proof).unwrap());
+
+                // The proof must not verify for another value or another position.
+                assert!(
+                    !vector_commitment
+                        .verify(&commitment, index, Fr::rand(rng), &proof)
+                        .unwrap()
+                );
+                let other_index = (index + 1) % VECTOR_SIZE;
+                assert!(
+                    !vector_commitment
+                        .verify(&commitment, other_index, *value, &proof)
+                        .unwrap()
+                );
+            }
+        }
+
+        // The empty vector commits to the zero polynomial.
+        let (commitment, randomness) = vector_commitment.commit_vector(&[], None, None).unwrap();
+        let proof = vector_commitment.open(&[], 0, &randomness).unwrap();
+        assert!(vector_commitment.verify(&commitment, 0, Fr::zero(), &proof).unwrap());
+    }
+
+    #[test]
+    fn test_open_and_verify_batch() {
+        let rng = &mut test_rng();
+        let vector_commitment = setup(rng);
+
+        // A short vector is padded with zeros.
+        let values: Vec<_> = (0..VECTOR_SIZE - 3).map(|_| Fr::rand(rng)).collect();
+        let indices = [0, 3, 7, VECTOR_SIZE - 1];
+        let opened_values: Vec<_> = indices.iter().map(|i| *values.get(*i).unwrap_or(&Fr::zero())).collect();
+
+        for hiding_bound in [None, Some(4)] {
+            let (commitment, randomness) = vector_commitment
+                .commit_vector(&values, hiding_bound, Some(rng))
+                .unwrap();
+
+            let proof = vector_commitment.open_batch(&values, &indices, &randomness).unwrap();
+            assert_eq!(hiding_bound.is_some(), proof.random_values.is_some());
+            assert!(
+                vector_commitment
+                    .verify_batch(&commitment, &indices, &opened_values, &proof)
+                    .unwrap()
+            );
+
+            // The proof is serialized with its random values.
+            let recovered = KZG10VectorBatchProof::read_le(&proof.to_bytes_le().unwrap()[..]).unwrap();
+            assert_eq!(proof, recovered);
+
+            // The proof must not verify for another value, another position, or a subset of the positions.
+            let mut other_values = opened_values.clone();
+            other_values[2] = Fr::rand(rng);
+            assert!(
+                !vector_commitment
+                    .verify_batch(&commitment, &indices, &other_values, &proof)
+                    .unwrap()
+            );
+            let other_indices = [0, 3, 8, VECTOR_SIZE - 1];
+            assert!(
+                !vector_commitment
+                    .verify_batch(&commitment, &other_indices, &opened_values, &proof)
+                    .unwrap()
+            );
+            let subset_proof = vector_commitment.open_batch(&values, &indices[..2], &randomness).unwrap();
+            assert!(
+                vector_commitment
+                    .verify_batch(&commitment, &indices[..2], &opened_values[..2], &subset_proof)
+                    .unwrap()
+            );
+            assert!(!matches!(
+                vector_commitment.verify_batch(&commitment, &indices[..2], &opened_values[..2], &proof),
+                Ok(true)
+            ));
+
+            // The proof must not verify against another commitment.
+            let (other_commitment, _) = vector_commitment.commit_vector(&values[1..], None, None).unwrap();
+            assert!(
+                !vector_commitment
+                    .verify_batch(&other_commitment, &indices, &opened_values, &proof)
+                    .unwrap()
+            );
+        }
+
+        // The indices must be non-empty, distinct, and match the values.
+        let (commitment, randomness) = vector_commitment.commit_vector(&values, None, None).unwrap();
+        assert!(vector_commitment.open_batch(&values, &[], &randomness).is_err());
+        assert!(vector_commitment.open_batch(&values, &[1, 1], &randomness).is_err());
+        let proof = vector_commitment.open_batch(&values, &indices, &randomness).unwrap();
+        assert!(
+            vector_commitment
+                .verify_batch(&commitment, &indices[..2], &opened_values, &proof)
+                .is_err()
+        );
+        assert!(
+            vector_commitment
+                .verify_batch(&commitment, &[0, 0, 7, 12], &opened_values, &proof)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let rng = &mut test_rng();
+        let vector_commitment = setup(rng);
+
+        let values: Vec<_> = (0..VECTOR_SIZE + 1).map(|_| Fr::rand(rng)).collect();
+        assert!(vector_commitment.commit_vector(&values, None, None).is_err());
+
+        let values = &values[..VECTOR_SIZE];
+        let (_, randomness) = vector_commitment.commit_vector(values, None, None).unwrap();
+        assert!(vector_commitment.open(values, VECTOR_SIZE, &randomness).is_err());
+
+        // The universal parameters must support the size of the domain.
+        let pp = KZG10::<Bls12_377>::setup(8, &KZG10DegreeBoundsConfig::NONE, false, rng).unwrap();
+        assert!(VectorCommitment::trim(&pp).is_err());
+
+        // The ceremony must have a contribution.
+        let powers_of_tau = PowersOfTau::<Bls12_377>::new(32, &KZG10DegreeBoundsConfig::NONE, false).unwrap();
+        assert!(VectorCommitment::from_powers_of_tau(powers_of_tau, rng).is_err());
+
+        // Parameters without powers are rejected, instead of panicking.
+        let mut parameters = vector_commitment.parameters();
+        parameters.powers_of_g.clear();
+        parameters.powers_of_gamma_g.clear();
+        let proof = vector_commitment.open(values, 0, &randomness).unwrap();
+        let (commitment, _) = vector_commitment.commit_vector(values, None, None).unwrap();
+        assert!(VectorCommitment::from(parameters).verify(&commitment, 0, values[0], &proof).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_setup_from_message_panics() {
+        VectorCommitment::setup("KZG10VectorCommitmentTest");
+    }
+
+    #[test]
+    fn test_commitment_scheme() {
+        let rng = &mut test_rng();
+        let vector_commitment = setup(rng);
+
+        let input: Vec<u8> = (0..100).map(|_| rng.next_u32() as u8).collect();
+        let randomness = Fr::rand(rng);
+        let commitment = vector_commitment.commit(&input, &randomness).unwrap();
+
+        // The commitment opens to the packed input.
+        let values: Vec<Fr> = input.to_field_elements().unwrap();
+        let kzg_randomness = VectorCommitment::randomness_from_scalar(randomness);
+        for (index, value) in values.iter().enumerate() {
+            let proof = vector_commitment.open(&values, index, &kzg_randomness).unwrap();
+            assert!(vector_commitment.verify(&commitment, index, *value, &proof).unwrap());
+        }
+        let indices: Vec<_> = (0..values.len()).collect();
+        let proof = vector_commitment.open_batch(&values, &indices, &kzg_randomness).unwrap();
+        assert!(vector_commitment.verify_batch(&commitment, &indices, &values, &proof).unwrap());
+        assert_ne!(commitment, vector_commitment.commit(&input, &Fr::rand(rng)).unwrap());
+
+        let bytes = vector_commitment.to_bytes_le().unwrap();
+        let recovered = VectorCommitment::read_le(&bytes[..]).unwrap();
+        assert_eq!(vector_commitment, recovered);
+        assert_eq!(vector_commitment, VectorCommitment::from(recovered.parameters()));
+
+        // Parameters of another size are rejected.
+        assert!(KZG10VectorCommitment::<Bls12_377, 32>::read_le(&bytes[..]).is_err());
+        let mut parameters = vector_commitment.parameters();
+        parameters.powers_of_gamma_g.pop();
+        assert!(VectorCommitment::read_le(&parameters.to_bytes_le().unwrap()[..]).is_err());
+    }
+}
@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::cli::errors::CLIError;
+
+use std::str::FromStr;
 use structopt::StructOpt;
 
 #[derive(StructOpt, Debug)]
@@ -33,6 +36,65 @@ pub struct CLI {
 
 #[derive(StructOpt, Debug)]
 pub enum Command {
+    /// Manage Aleo accounts
+    Account(AccountCommand),
+
+    /// Decrypt records
+    Record(RecordCommand),
+
+    /// Inspect and verify transactions
+    Transaction(TransactionCommand),
+
+    /// Inspect and verify blocks
+    Block(BlockCommand),
+
+    /// Sign a message with an account private key
+    Sign {
+        /// The account private key
+        #[structopt(long)]
+        private_key: String,
+
+        /// The message to sign
+        #[structopt(long)]
+        message: String,
+
+        #[structopt(flatten)]
+        options: Options,
+    },
+
+    /// Verify a message signature for an account address
+    Verify {
+        /// The account address
+        #[structopt(long)]
+        address: String,
+
+        /// The signed message
+        #[structopt(long)]
+        message: String,
+
+        /// The account signature
+        #[structopt(long)]
+        signature: String,
+
+        #[structopt(flatten)]
+        options: Options,
+    },
+
+    /// Start a local development network that mines a private testnet2 chain (downloads the proving keys on first use)
+    Devnet {
+        /// The address of the genesis and coinbase recipient (defaults to a new account)
+        #[structopt(long)]
+        recipient: Option<String>,
+
+        /// The number of seconds between mined blocks
+        #[structopt(long, default_value = "10")]
+        interval: u64,
+
+        /// The port of the JSON-RPC endpoint on 127.0.0.1
+        #[structopt(long, default_value = "3030")]
+        rpc_port: u16,
+    },
+
     /// Update snarkVM to the latest version
     Update {
         /// Lists all available versions of snarkVM
@@ -44,3 +106,120 @@ pub enum Command {
         quiet: bool,
     },
 }
+
+#[derive(StructOpt, Debug)]
+pub enum AccountCommand {
+    /// Generate a new account
+    New {
+        #[structopt(flatten)]
+        options: Options,
+    },
+
+    /// Derive an account from a hex-encoded account seed
+    FromSeed {
+        /// The hex-encoded account seed
+        #[structopt(long)]
+        seed: String,
+
+        #[structopt(flatten)]
+        options: Options,
+    },
+
+    /// Inspect the keys and address of an account private key
+    Inspect {
+        /// The account private key
+        #[structopt(long)]
+        private_key: String,
+
+        #[structopt(flatten)]
+        options: Options,
+    },
+}
+
+#[derive(StructOpt, Debug)]
+pub enum RecordCommand {
+    /// Decrypt a record ciphertext with an account view key
+    Decrypt {
+        /// The hex-encoded record ciphertext
+        #[structopt(long)]
+        ciphertext: String,
+
+        /// The account view key
+        #[structopt(long)]
+        view_key: String,
+
+        #[structopt(flatten)]
+        options: Options,
+    },
+}
+
+#[derive(StructOpt, Debug)]
+pub enum TransactionCommand {
+    /// Decode a hex or JSON encoded transaction
+    Inspect {
+        /// The hex or JSON encoded transaction
+        transaction: String,
+
+        #[structopt(flatten)]
+        options: Options,
+    },
+
+    /// Decode a hex or JSON encoded transaction and verify its transitions
+    Verify {
+        /// The hex or JSON encoded transaction
+        transaction: String,
+
+        #[structopt(flatten)]
+        options: Options,
+    },
+}
+
+#[derive(StructOpt, Debug)]
+pub enum BlockCommand {
+    /// Decode a hex or JSON encoded block
+    Inspect {
+        /// The hex or JSON encoded block
+        block: String,
+
+        #[structopt(flatten)]
+        options: Options,
+    },
+
+    /// Decode a hex or JSON encoded block and verify it
+    Verify {
+        /// The hex or JSON encoded block
+        block: String,
+
+        #[structopt(flatten)]
+        options: Options,
+    },
+}
+
+#[derive(StructOpt, Debug)]
+pub struct Options {
+    /// The network to use (testnet1, testnet2)
+    #[structopt(long, default_value = "testnet2")]
+    pub network: NetworkType,
+
+    /// Print the output as JSON
+    #[structopt(long)]
+    pub json: bool,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NetworkType {
+    Testnet1,
+    Testnet2,
+}
+
+impl FromStr for NetworkType {
+    type Err = CLIError;
+
+    fn from_str(network: &str) -> Result<Self, Self::Err> {
+        match network {
+            "testnet1" => Ok(Self::Testnet1),
+            "testnet2" => Ok(Self::Testnet2),
+            _ => Err(CLIError::UnknownNetwork(network.to_string())),
+        }
+    }
+}
@@ -14,10 +14,93 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::cli::{Command, Updater};
+use crate::cli::{
+    AccountCommand,
+    BlockCommand,
+    CLIError,
+    Command,
+    Devnet,
+    NetworkType,
+    RecordCommand,
+    TransactionCommand,
+    Updater,
+};
+use snarkvm_dpc::{
+    testnet1::Testnet1,
+    testnet2::Testnet2,
+    Address,
+    Block,
+    Network,
+    PrivateKey,
+    RecordCiphertext,
+    Transaction,
+    ViewKey,
+};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use rand::thread_rng;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use std::{io, net::SocketAddr, str::FromStr, thread, time::Duration};
+
+/// Dispatches the given function call to the network selected in the command options.
+macro_rules! with_network {
+    ($options:expr, $function:ident($($argument:expr),*)) => {
+        match $options.network {
+            NetworkType::Testnet1 => $function::<Testnet1>($($argument),*),
+            NetworkType::Testnet2 => $function::<Testnet2>($($argument),*),
+        }
+    };
+}
 
 pub fn parse(command: Command) -> anyhow::Result<String> {
     match command {
+        Command::Account(command) => match command {
+            AccountCommand::New { options } => {
+                with_network!(options, account_new(options.json))
+            }
+            AccountCommand::FromSeed { seed, options } => {
+                with_network!(options, account_from_seed(&seed, options.json))
+            }
+            AccountCommand::Inspect { private_key, options } => {
+                with_network!(options, account_inspect(&private_key, options.json))
+            }
+        },
+        Command::Record(command) => match command {
+            RecordCommand::Decrypt {
+                ciphertext,
+                view_key,
+                options,
+            } => with_network!(options, record_decrypt(&ciphertext, &view_key, options.json)),
+        },
+        Command::Transaction(command) => match command {
+            TransactionCommand::Inspect { transaction, options } => {
+                with_network!(options, transaction_inspect(&transaction, options.json))
+            }
+            TransactionCommand::Verify { transaction, options } => {
+                with_network!(options, transaction_verify(&transaction, options.json))
+            }
+        },
+        Command::Block(command) => match command {
+            BlockCommand::Inspect { block, options } => with_network!(options, block_inspect(&block, options.json)),
+            BlockCommand::Verify { block, options } => with_network!(options, block_verify(&block, options.json)),
+        },
+        Command::Sign {
+            private_key,
+            message,
+            options,
+        } => with_network!(options, sign(&private_key, &message, options.json)),
+        Command::Verify {
+            address,
+            message,
+            signature,
+            options,
+        } => with_network!(options, verify(&address, &message, &signature, options.json)),
+        Command::Devnet {
+            recipient,
+            interval,
+            rpc_port,
+        } => devnet(recipient, interval, rpc_port),
         Command::Update { list, quiet } => match list {
             true => match Updater::show_available_releases() {
                 Ok(output) => Ok(output),
@@ -45,3 +128,336 @@ pub fn parse(command: Command) -> anyhow::Result<String> {
         }, // _ => Err(anyhow!("\nUnknown command\n")),
     }
 }
+
+/// Generates a new account.
+fn account_new<N: Network>(json: bool) -> anyhow::Result<String> {
+    account_output(PrivateKey::<N>::new(&mut thread_rng()), json)
+}
+
+/// Derives an account from the given hex-encoded account seed.
+fn account_from_seed<N: Network>(seed: &str, json: bool) -> anyhow::Result<String> {
+    let seed = hex::decode(seed).map_err(|error| CLIError::InvalidInput("seed", error.to_string()))?;
+    let seed =
+        N::AccountSeed::from_bytes_le(&seed).map_err(|error| CLIError::InvalidInput("seed", error.to_string()))?;
+    account_output(PrivateKey::<N>::from(&seed), json)
+}
+
+/// Returns the keys and address of the given account private key.
+fn account_inspect<N: Network>(private_key: &str, json: bool) -> anyhow::Result<String> {
+    account_output(parse_input::<PrivateKey<N>>("private key", private_key)?, json)
+}
+
+fn account_output<N: Network>(private_key: PrivateKey<N>, json: bool) -> anyhow::Result<String> {
+    let view_key = ViewKey::from_private_key(&private_key);
+    let compute_key = private_key.to_compute_key();
+    let address = private_key.to_address();
+
+    format_output(
+        json!({
+            "private_key": private_key.to_string(),
+            "view_key": view_key.to_string(),
+            "compute_key": hex::encode(compute_key.to_bytes_le()?),
+            "address": address.to_string(),
+        }),
+        json,
+    )
+}
+
+/// Decrypts the given record ciphertext with the given account view key.
+fn record_decrypt<N: Network>(ciphertext: &str, view_key: &str, json: bool) -> anyhow::Result<String> {
+    let ciphertext = parse_input::<RecordCiphertext<N>>("ciphertext", ciphertext)?;
+    let view_key = parse_input::<ViewKey<N>>("view key", view_key)?;
+    let record = ciphertext.decrypt(&view_key)?;
+    format_output(serde_json::to_value(&record)?, json)
+}
+
+/// Returns the contents of the given hex or JSON encoded transaction.
+fn transaction_inspect<N: Network>(transaction: &str, json: bool) -> anyhow::Result<String> {
+    let transaction = decode_input::<Transaction<N>>("transaction", transaction)?;
+    format_output(
+        json!({
+            "transaction_id": transaction.transaction_id(),
+            "inner_circuit_id": transaction.inner_circuit_id(),
+            "ledger_root": transaction.ledger_root(),
+            "value_balance": transaction.value_balance(),
+            "transition_ids": transaction.transition_ids().collect::<Vec<_>>(),
+            "serial_numbers": transaction.serial_numbers().collect::<Vec<_>>(),
+            "commitments": transaction.commitments().collect::<Vec<_>>(),
+            "ciphertexts": transaction.ciphertexts().collect::<Vec<_>>(),
+            "events": transaction.events(),
+        }),
+        json,
+    )
+}
+
+/// Returns `true` if the given hex or JSON encoded transaction and its transitions are valid.
+fn transaction_verify<N: Network>(transaction: &str, json: bool) -> anyhow::Result<String> {
+    let transaction = decode_input::<Transaction<N>>("transaction", transaction)?;
+    format_output(
+        json!({
+            "transaction_id": transaction.transaction_id(),
+            "is_valid": transaction.is_valid(),
+        }),
+        json,
+    )
+}
+
+/// Returns the contents of the given hex or JSON encoded block.
+fn block_inspect<N: Network>(block: &str, json: bool) -> anyhow::Result<String> {
+    let block = decode_input::<Block<N>>("block", block)?;
+    format_output(
+        json!({
+            "block_hash": block.hash(),
+            "previous_block_hash": block.previous_block_hash(),
+            "height": block.height(),
+            "timestamp": block.timestamp(),
+            "difficulty_target": block.difficulty_target(),
+            "nonce": block.nonce().to_string(),
+            "previous_ledger_root": block.previous_ledger_root(),
+            "transactions_root": block.transactions_root(),
+            "transaction_ids": block
+                .transactions()
+                .iter()
+                .map(|transaction| transaction.transaction_id())
+                .collect::<Vec<_>>(),
+        }),
+        json,
+    )
+}
+
+/// Returns `true` if the given hex or JSON encoded block is valid.
+fn block_verify<N: Network>(block: &str, json: bool) -> anyhow::Result<String> {
+    let block = decode_input::<Block<N>>("block", block)?;
+    format_output(
+        json!({
+            "block_hash": block.hash(),
+            "height": block.height(),
+            "is_valid": block.is_valid(),
+        }),
+        json,
+    )
+}
+
+/// Signs the given message with the given account private key.
+fn sign<N: Network>(private_key: &str, message: &str, json: bool) -> anyhow::Result<String> {
+    let private_key = parse_input::<PrivateKey<N>>("private key", private_key)?;
+    let signature = private_key.sign(message.as_bytes(), &mut thread_rng())?;
+    format_output(
+        json!({
+            "address": private_key.to_address().to_string(),
+            "message": message,
+            "signature": signature.to_string(),
+        }),
+        json,
+    )
+}
+
+/// Returns `true` if the given signature is valid for the given message and account address.
+fn verify<N: Network>(address: &str, message: &str, signature: &str, json: bool) -> anyhow::Result<String> {
+    let address = parse_input::<Address<N>>("address", address)?;
+    // Account signatures are parsed through their human-readable serde format, as they do not implement `FromStr`.
+    let signature = serde_json::from_value::<N::AccountSignature>(json!(signature.trim()))
+        .map_err(|error| CLIError::InvalidInput("signature", error.to_string()))?;
+    format_output(
+        json!({
+            "address": address.to_string(),
+            "message": message,
+            "is_valid": address.verify_signature(message.as_bytes(), &signature)?,
+        }),
+        json,
+    )
+}
+
+/// Starts a devnet that mines a block every `interval` seconds, and serves JSON-RPC requests on `rpc_port`.
+fn devnet(recipient: Option<String>, interval: u64, rpc_port: u16) -> anyhow::Result<String> {
+    let recipient = match recipient {
+        Some(recipient) => parse_input::<Address<Testnet2>>("recipient", &recipient)?,
+        None => {
+            let private_key = PrivateKey::<Testnet2>::new(&mut thread_rng());
+            println!("Generated a devnet account with private key {}", private_key);
+            private_key.to_address()
+        }
+    };
+    println!("Starting a devnet for {}", recipient);
+
+    let devnet = Devnet::new(recipient)?;
+    let miner = devnet.start_miner(Duration::from_secs(interval));
+
+    // Stop the devnet once a line is entered on the standard input.
+    {
+        let devnet = devnet.clone();
+        thread::spawn(move || {
+            println!("Press Enter to stop the devnet");
+            // An empty standard input (e.g. when running in the background) does not stop the devnet.
+            if matches!(io::stdin().read_line(&mut String::new()), Ok(num_bytes) if num_bytes > 0) {
+                devnet.stop();
+            }
+        });
+    }
+
+    let result = devnet.serve(SocketAddr::from(([127, 0, 0, 1], rpc_port)));
+
+    devnet.stop();
+    miner.join().map_err(|_| anyhow::anyhow!("The devnet miner panicked"))?;
+    result?;
+
+    Ok(format!("Stopped the devnet at block {}", devnet.latest_block_height()))
+}
+
+/// Parses the given input string, tagging any error with the name of the input.
+fn parse_input<T: FromStr>(name: &'static str, input: &str) -> Result<T, CLIError>
+where
+    T::Err: ToString,
+{
+    T::from_str(input.trim()).map_err(|error| CLIError::InvalidInput(name, error.to_string()))
+}
+
+/// Decodes the given input as hex-encoded bytes, or as JSON if it is not valid hex.
+fn decode_input<T: FromBytes + DeserializeOwned>(name: &'static str, input: &str) -> Result<T, CLIError> {
+    let input = input.trim();
+    match hex::decode(input) {
+        Ok(bytes) => T::from_bytes_le(&bytes).map_err(|error| CLIError::InvalidInput(name, error.to_string())),
+        Err(_) => serde_json::from_str(input).map_err(|error| CLIError::InvalidInput(name, error.to_string())),
+    }
+}
+
+/// Returns the given output as pretty-printed JSON, or as one `key: value` line per field.
+fn format_output(output: Value, json: bool) -> anyhow::Result<String> {
+    if json {
+        return Ok(serde_json::to_string_pretty(&output)?);
+    }
+
+    let fields = match output {
+        Value::Object(fields) => fields,
+        output => return Ok(format_value(&output)),
+    };
+
+    let mut lines = Vec::with_capacity(fields.len());
+    for (key, value) in fields {
+        match value {
+            Value::Array(values) => {
+                lines.push(format!("{}:", key));
+                lines.extend(values.iter().map(|value| format!("  * {}", format_value(value))));
+            }
+            value => lines.push(format!("{}: {}", key, format_value(&value))),
+        }
+    }
+    Ok(lines.join("\n"))
+}
+
+fn format_value(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_dpc::Record;
+
+    #[test]
+    fn test_account_inspect() {
+        let account: Value = serde_json::from_str(&account_new::<Testnet2>(true).unwrap()).unwrap();
+        let private_key = account["private_key"].as_str().unwrap();
+
+        let candidate: Value = serde_json::from_str(&account_inspect::<Testnet2>(private_key, true).unwrap()).unwrap();
+        assert_eq!(account, candidate);
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let account: Value = serde_json::from_str(&account_new::<Testnet2>(true).unwrap()).unwrap();
+        let private_key = account["private_key"].as_str().unwrap();
+        let address = account["address"].as_str().unwrap();
+
+        let output: Value = serde_json::from_str(&sign::<Testnet2>(private_key, "message", true).unwrap()).unwrap();
+        let signature = output["signature"].as_str().unwrap();
+
+        let output: Value =
+            serde_json::from_str(&verify::<Testnet2>(address, "message", signature, true).unwrap()).unwrap();
+        assert_eq!(Value::Bool(true), output["is_valid"]);
+
+        let output: Value =
+            serde_json::from_str(&verify::<Testnet2>(address, "Message", signature, true).unwrap()).unwrap();
+        assert_eq!(Value::Bool(false), output["is_valid"]);
+    }
+
+    #[test]
+    fn test_record_decrypt() {
+        let rng = &mut thread_rng();
+        let private_key = PrivateKey::<Testnet2>::new(rng);
+        let view_key = ViewKey::from_private_key(&private_key);
+
+        let record = Record::new_noop_input(private_key.to_address(), rng).unwrap();
+        let (ciphertext, _) = RecordCiphertext::encrypt(&record, rng).unwrap();
+
+        let output: Value = serde_json::from_str(
+            &record_decrypt::<Testnet2>(&ciphertext.to_string(), &view_key.to_string(), true).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(serde_json::to_value(&record).unwrap(), output);
+
+        // Ensure the record is not decrypted with the view key of another account.
+        let other_view_key = ViewKey::from_private_key(&PrivateKey::<Testnet2>::new(rng));
+        assert!(record_decrypt::<Testnet2>(&ciphertext.to_string(), &other_view_key.to_string(), true).is_err());
+    }
+
+    #[test]
+    fn test_transaction_inspect_and_verify() {
+        let transaction = Testnet2::genesis_block().to_coinbase_transaction().unwrap();
+        let transaction_hex = hex::encode(transaction.to_bytes_le().unwrap());
+        let transaction_json = serde_json::to_string(&transaction).unwrap();
+
+        // Ensure the hex and JSON encodings are inspected identically.
+        let output: Value = serde_json::from_str(&transaction_inspect::<Testnet2>(&transaction_hex, true).unwrap()).unwrap();
+        assert_eq!(json!(transaction.transaction_id()), output["transaction_id"]);
+        assert_eq!(json!(transaction.events()), output["events"]);
+        assert_eq!(output.to_string(), {
+            let output: Value =
+                serde_json::from_str(&transaction_inspect::<Testnet2>(&transaction_json, true).unwrap()).unwrap();
+            output.to_string()
+        });
+
+        let output: Value = serde_json::from_str(&transaction_verify::<Testnet2>(&transaction_hex, true).unwrap()).unwrap();
+        assert_eq!(json!(transaction.transaction_id()), output["transaction_id"]);
+        assert_eq!(Value::Bool(true), output["is_valid"]);
+
+        // Ensure a malformed transaction is rejected.
+        assert!(transaction_inspect::<Testnet2>(&transaction_hex[2..], true).is_err());
+        assert!(transaction_verify::<Testnet2>("{}", true).is_err());
+    }
+
+    #[test]
+    fn test_block_inspect_and_verify() {
+        let block = Testnet2::genesis_block();
+        let block_hex = hex::encode(block.to_bytes_le().unwrap());
+
+        let output: Value = serde_json::from_str(&block_inspect::<Testnet2>(&block_hex, true).unwrap()).unwrap();
+        assert_eq!(json!(block.hash()), output["block_hash"]);
+        assert_eq!(json!(0), output["height"]);
+        assert_eq!(
+            json!(block.transactions().iter().map(|transaction| transaction.transaction_id()).collect::<Vec<_>>()),
+            output["transaction_ids"]
+        );
+
+        let output: Value = serde_json::from_str(&block_verify::<Testnet2>(&block_hex, true).unwrap()).unwrap();
+        assert_eq!(json!(block.hash()), output["block_hash"]);
+        assert_eq!(Value::Bool(true), output["is_valid"]);
+
+        // Ensure the plain output lists one transaction ID per line.
+        let output = block_inspect::<Testnet2>(&block_hex, false).unwrap();
+        assert!(output.contains(&format!("  * {}", block.transactions()[0].transaction_id())));
+
+        // Ensure a malformed block is rejected.
+        assert!(block_verify::<Testnet2>(&block_hex[..block_hex.len() - 2], true).is_err());
+    }
+
+    #[test]
+    fn test_unknown_network() {
+        assert_eq!(NetworkType::Testnet1, "testnet1".parse().unwrap());
+        assert_eq!(NetworkType::Testnet2, "testnet2".parse().unwrap());
+        assert!("mainnet".parse::<NetworkType>().is_err());
+    }
+}
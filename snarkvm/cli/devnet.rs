@@ -0,0 +1,399 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_dpc::{testnet2::Testnet2, Address, Block, Ledger, Network, Transaction};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use anyhow::{anyhow, Result};
+use rand::thread_rng;
+use serde_json::{json, Value};
+use std::{
+    io::{BufRead, BufReader, ErrorKind, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, TrySendError},
+        Arc,
+        Mutex,
+        RwLock,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// The maximum size of a JSON-RPC request body, in bytes.
+const MAX_REQUEST_SIZE: usize = 4 * 1024 * 1024;
+/// The number of threads that handle JSON-RPC connections.
+const NUM_WORKERS: usize = 4;
+/// The maximum number of accepted JSON-RPC connections that are waiting for a worker.
+const MAX_PENDING_CONNECTIONS: usize = 64;
+/// The read and write timeout of a JSON-RPC connection.
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A local development network, which mines a private `Testnet2` chain and serves it over JSON-RPC.
+///
+/// Note: The devnet proves coinbase transactions and mines blocks, which requires the `Testnet2`
+/// inner circuit and PoSW proving keys. If these are not stored locally, they are downloaded on first use.
+#[derive(Clone)]
+pub struct Devnet {
+    /// The ledger of the private chain.
+    ledger: Arc<RwLock<Ledger<Testnet2>>>,
+    /// The recipient of the genesis and coinbase rewards.
+    recipient: Address<Testnet2>,
+    /// The flag to stop the miner.
+    terminator: Arc<AtomicBool>,
+}
+
+impl Devnet {
+    /// Initializes a new devnet, with a freshly-mined genesis block for the given recipient.
+    pub fn new(recipient: Address<Testnet2>) -> Result<Self> {
+        let genesis_block = Block::<Testnet2>::new_genesis(recipient, &mut thread_rng())?;
+
+        Ok(Self {
+            ledger: Arc::new(RwLock::new(Ledger::from_genesis(&genesis_block)?)),
+            recipient,
+            terminator: Arc::new(AtomicBool::new(false)),
+        })
+    }
+
+    /// Returns the latest block height of the devnet.
+    pub fn latest_block_height(&self) -> u32 {
+        self.read_ledger().latest_block_height()
+    }
+
+    /// Starts a thread that mines the next block at the given interval, until the devnet is stopped.
+    pub fn start_miner(&self, interval: Duration) -> thread::JoinHandle<()> {
+        let devnet = self.clone();
+        thread::spawn(move || {
+            while !devnet.terminator.load(Ordering::SeqCst) {
+                let start = Instant::now();
+
+                match devnet.mine_next_block() {
+                    Ok(block) => println!("Mined block {} ({})", block.height(), block.hash()),
+                    Err(error) => eprintln!("Failed to mine the next block: {}", error),
+                }
+
+                // Wait for the remainder of the interval, unless the devnet is stopped.
+                while start.elapsed() < interval && !devnet.terminator.load(Ordering::SeqCst) {
+                    thread::sleep(Duration::from_millis(100).min(interval));
+                }
+            }
+        })
+    }
+
+    /// Stops the miner and the JSON-RPC server, aborting any block that is currently being mined.
+    pub fn stop(&self) {
+        self.terminator.store(true, Ordering::SeqCst);
+    }
+
+    /// Mines the next block and adds it to the ledger.
+    ///
+    /// The block is mined from a template of the ledger, so that queries and incoming
+    /// transactions are not blocked for the duration of the proof of succinct work.
+    pub fn mine_next_block(&self) -> Result<Block<Testnet2>> {
+        let template = self.read_ledger().to_block_template()?;
+        let block = template.mine(self.recipient, &self.terminator, &mut thread_rng())?;
+
+        self.write_ledger().add_next_block(&block)?;
+        Ok(block)
+    }
+
+    /// Serves JSON-RPC requests on the given local address, until the devnet is stopped.
+    pub fn serve(&self, address: SocketAddr) -> Result<()> {
+        if !address.ip().is_loopback() {
+            return Err(anyhow!(
+                "The devnet may only listen on a loopback address, found {}",
+                address
+            ));
+        }
+
+        let listener = TcpListener::bind(address)?;
+        println!("Listening for JSON-RPC requests on http://{}", listener.local_addr()?);
+
+        // Start the workers, which handle the accepted connections in order.
+        let (sender, receiver) = mpsc::sync_channel::<TcpStream>(MAX_PENDING_CONNECTIONS);
+        let receiver = Arc::new(Mutex::new(receiver));
+        let workers: Vec<_> = (0..NUM_WORKERS)
+            .map(|_| {
+                let devnet = self.clone();
+                let receiver = receiver.clone();
+                thread::spawn(move || loop {
+                    // The receiver is disconnected once the server stops accepting connections.
+                    let stream = match receiver.lock().map(|receiver| receiver.recv()) {
+                        Ok(Ok(stream)) => stream,
+                        _ => break,
+                    };
+                    if let Err(error) = devnet.handle_connection(stream) {
+                        eprintln!("Failed to handle JSON-RPC connection: {}", error);
+                    }
+                })
+            })
+            .collect();
+
+        // The listener does not block, so that the stop flag is checked between connections.
+        listener.set_nonblocking(true)?;
+
+        while !self.terminator.load(Ordering::SeqCst) {
+            match listener.accept() {
+                Ok((stream, _)) => {
+                    // If every worker is busy and the queue is full, the connection is dropped.
+                    if let Err(TrySendError::Full(_)) = sender.try_send(stream) {
+                        eprintln!("Dropped JSON-RPC connection, as the server is at capacity");
+                    }
+                }
+                Err(error) if error.kind() == ErrorKind::WouldBlock => thread::sleep(Duration::from_millis(100)),
+                Err(error) => eprintln!("Failed to accept JSON-RPC connection: {}", error),
+            }
+        }
+
+        // Wait for the workers to handle the pending connections.
+        drop(sender);
+        for worker in workers {
+            let _ = worker.join();
+        }
+
+        Ok(())
+    }
+
+    /// Handles a JSON-RPC request, and returns the JSON-RPC response.
+    pub fn handle_request(&self, request: &str) -> Value {
+        let request: Value = match serde_json::from_str(request) {
+            Ok(request) => request,
+            Err(error) => return Self::error_response(Value::Null, -32700, format!("Parse error: {}", error)),
+        };
+
+        let id = request["id"].clone();
+        let method = match request["method"].as_str() {
+            Some(method) => method,
+            None => return Self::error_response(id, -32600, "Invalid request: missing method".to_string()),
+        };
+        let params = match &request["params"] {
+            Value::Array(params) => params.clone(),
+            Value::Null => vec![],
+            _ => return Self::error_response(id, -32602, "Invalid params: expected an array".to_string()),
+        };
+
+        let result = match method {
+            "latestblockheight" => Ok(json!(self.latest_block_height())),
+            "latestblock" => self.read_ledger().latest_block().map(|block| json!(block)),
+            "getblock" => self.get_block(&params),
+            "gettransaction" => self.get_transaction(&params),
+            "getledgerproof" => self.get_ledger_proof(&params),
+            "sendtransaction" => self.send_transaction(&params),
+            _ => return Self::error_response(id, -32601, format!("Method not found: {}", method)),
+        };
+
+        match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(error) => Self::error_response(id, -32000, error.to_string()),
+        }
+    }
+
+    /// Returns the block for the given block height.
+    fn get_block(&self, params: &[Value]) -> Result<Value> {
+        let height = Self::param(params, 0)?
+            .as_u64()
+            .ok_or_else(|| anyhow!("Expected a block height"))?;
+        Ok(json!(self.read_ledger().get_block(height as u32)?))
+    }
+
+    /// Returns the transaction for the given transaction ID.
+    fn get_transaction(&self, params: &[Value]) -> Result<Value> {
+        let transaction_id: <Testnet2 as Network>::TransactionID =
+            serde_json::from_value(Self::param(params, 0)?.clone())?;
+        Ok(json!(self.read_ledger().get_transaction(&transaction_id)?))
+    }
+
+    /// Returns the hex-encoded ledger proof for the given commitment.
+    fn get_ledger_proof(&self, params: &[Value]) -> Result<Value> {
+        let commitment: <Testnet2 as Network>::Commitment = serde_json::from_value(Self::param(params, 0)?.clone())?;
+        let ledger_proof = self.read_ledger().to_ledger_proof(commitment)?;
+        Ok(json!(hex::encode(ledger_proof.to_bytes_le()?)))
+    }
+
+    /// Adds the given hex or JSON encoded transaction to the memory pool, and returns its transaction ID.
+    fn send_transaction(&self, params: &[Value]) -> Result<Value> {
+        let transaction: Transaction<Testnet2> = match Self::param(params, 0)? {
+            Value::String(transaction) => match hex::decode(transaction) {
+                Ok(bytes) => Transaction::from_bytes_le(&bytes)?,
+                Err(_) => serde_json::from_str(transaction)?,
+            },
+            transaction => serde_json::from_value(transaction.clone())?,
+        };
+
+        if !transaction.is_valid() {
+            return Err(anyhow!("The given transaction is invalid"));
+        }
+
+        self.write_ledger().add_unconfirmed_transaction(&transaction)?;
+        Ok(json!(transaction.transaction_id()))
+    }
+
+    /// Reads a single HTTP request from the given stream, and writes back the JSON-RPC response.
+    fn handle_connection(&self, mut stream: TcpStream) -> Result<()> {
+        // Accepted streams may inherit the non-blocking mode of the listener.
+        stream.set_nonblocking(false)?;
+        stream.set_read_timeout(Some(CONNECTION_TIMEOUT))?;
+        stream.set_write_timeout(Some(CONNECTION_TIMEOUT))?;
+        let mut reader = BufReader::new(stream.try_clone()?);
+
+        // Read the request line and headers, retaining the content length.
+        let mut content_length = 0usize;
+        let mut line = String::new();
+        loop {
+            line.clear();
+            if reader.read_line(&mut line)? == 0 {
+                return Ok(());
+            }
+            let header = line.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                if name.trim().eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse()?;
+                }
+            }
+        }
+
+        // Ensure the request body is bounded before allocating it.
+        if content_length > MAX_REQUEST_SIZE {
+            return Err(anyhow!(
+                "The request body of {} bytes exceeds the maximum of {} bytes",
+                content_length,
+                MAX_REQUEST_SIZE
+            ));
+        }
+
+        // Read the request body.
+        let mut body = vec![0u8; content_length];
+        reader.read_exact(&mut body)?;
+
+        let response = self.handle_request(&String::from_utf8_lossy(&body)).to_string();
+        write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            response.len(),
+            response
+        )?;
+        stream.flush()?;
+        Ok(())
+    }
+
+    fn read_ledger(&self) -> std::sync::RwLockReadGuard<Ledger<Testnet2>> {
+        self.ledger.read().expect("The devnet ledger lock is poisoned")
+    }
+
+    fn write_ledger(&self) -> std::sync::RwLockWriteGuard<Ledger<Testnet2>> {
+        self.ledger.write().expect("The devnet ledger lock is poisoned")
+    }
+
+    fn param(params: &[Value], index: usize) -> Result<&Value> {
+        params
+            .get(index)
+            .ok_or_else(|| anyhow!("Missing parameter at index {}", index))
+    }
+
+    fn error_response(id: Value, code: i64, message: String) -> Value {
+        json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_dpc::PrivateKey;
+
+    fn devnet() -> Devnet {
+        Devnet::new(PrivateKey::<Testnet2>::new(&mut thread_rng()).to_address()).unwrap()
+    }
+
+    #[test]
+    fn test_chain_queries() {
+        let devnet = devnet();
+        let block = devnet.mine_next_block().unwrap();
+
+        let response = devnet.handle_request(r#"{"jsonrpc":"2.0","id":1,"method":"latestblockheight"}"#);
+        assert_eq!(json!(1), response["result"]);
+
+        let response = devnet.handle_request(r#"{"jsonrpc":"2.0","id":2,"method":"getblock","params":[1]}"#);
+        assert_eq!(json!(block), response["result"]);
+
+        let transaction = block.to_coinbase_transaction().unwrap();
+        let request =
+            json!({ "jsonrpc": "2.0", "id": 3, "method": "gettransaction", "params": [transaction.transaction_id()] });
+        let response = devnet.handle_request(&request.to_string());
+        assert_eq!(json!(transaction), response["result"]);
+
+        let commitment = transaction.commitments().next().unwrap();
+        let request = json!({ "jsonrpc": "2.0", "id": 4, "method": "getledgerproof", "params": [commitment] });
+        let response = devnet.handle_request(&request.to_string());
+        assert!(response["result"].is_string());
+    }
+
+    #[test]
+    fn test_invalid_requests() {
+        let devnet = devnet();
+
+        let response = devnet.handle_request("{");
+        assert_eq!(json!(-32700), response["error"]["code"]);
+
+        let response = devnet.handle_request(r#"{"jsonrpc":"2.0","id":1,"method":"unknown"}"#);
+        assert_eq!(json!(-32601), response["error"]["code"]);
+
+        let response = devnet.handle_request(r#"{"jsonrpc":"2.0","id":2,"method":"getblock","params":[5]}"#);
+        assert_eq!(json!(-32000), response["error"]["code"]);
+    }
+
+    #[test]
+    fn test_oversized_request() {
+        let devnet = devnet();
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        write!(client, "POST / HTTP/1.1\r\nContent-Length: {}\r\n\r\n", usize::MAX).unwrap();
+
+        let (stream, _) = listener.accept().unwrap();
+        assert!(devnet.handle_connection(stream).is_err());
+    }
+
+    #[test]
+    fn test_idle_connection_times_out() {
+        let devnet = devnet();
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let _client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+
+        // A client that never sends its request does not hold the worker indefinitely.
+        let (stream, _) = listener.accept().unwrap();
+        let start = Instant::now();
+        assert!(devnet.handle_connection(stream).is_err());
+        assert!(start.elapsed() < CONNECTION_TIMEOUT * 2);
+    }
+
+    #[test]
+    fn test_serve_stops() {
+        let devnet = devnet();
+
+        let server = {
+            let devnet = devnet.clone();
+            thread::spawn(move || devnet.serve(SocketAddr::from(([127, 0, 0, 1], 0))))
+        };
+        devnet.stop();
+
+        assert!(server.join().unwrap().is_ok());
+    }
+}
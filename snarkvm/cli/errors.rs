@@ -14,6 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+#[derive(Debug, Error)]
+pub enum CLIError {
+    #[error("Invalid input for {}: {}", _0, _1)]
+    InvalidInput(&'static str, String),
+
+    #[error("Unknown network {}, expected testnet1 or testnet2", _0)]
+    UnknownNetwork(String),
+}
+
 #[derive(Debug, Error)]
 pub enum UpdaterError {
     #[error("{}: {}", _0, _1)]
@@ -20,6 +20,9 @@ pub use cli::*;
 pub mod commands;
 pub use commands::*;
 
+pub mod devnet;
+pub use devnet::*;
+
 pub mod errors;
 pub use errors::*;
 
@@ -0,0 +1,201 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::bindings::to_js_error;
+use snarkvm_dpc::{testnet2::Testnet2, Network};
+
+use rand::thread_rng;
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+/// An account private key for the Aleo testnet2 network.
+#[wasm_bindgen]
+pub struct PrivateKey {
+    pub(crate) private_key: snarkvm_dpc::PrivateKey<Testnet2>,
+}
+
+#[wasm_bindgen]
+impl PrivateKey {
+    /// Generates a new account private key.
+    #[wasm_bindgen(constructor)]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            private_key: snarkvm_dpc::PrivateKey::new(&mut thread_rng()),
+        }
+    }
+
+    /// Parses an account private key string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(private_key: &str) -> Result<PrivateKey, JsValue> {
+        Ok(Self {
+            private_key: snarkvm_dpc::PrivateKey::from_str(private_key).map_err(to_js_error)?,
+        })
+    }
+
+    /// Returns the account private key string.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.private_key.to_string()
+    }
+
+    /// Returns the account view key.
+    #[wasm_bindgen(js_name = toViewKey)]
+    pub fn to_view_key(&self) -> ViewKey {
+        ViewKey::from_private_key(self)
+    }
+
+    /// Returns the account address.
+    #[wasm_bindgen(js_name = toAddress)]
+    pub fn to_address(&self) -> Address {
+        Address::from_private_key(self)
+    }
+
+    /// Signs the given message, and returns the signature string.
+    pub fn sign(&self, message: &[u8]) -> Result<String, JsValue> {
+        Ok(self
+            .private_key
+            .sign(message, &mut thread_rng())
+            .map_err(to_js_error)?
+            .to_string())
+    }
+}
+
+/// An account view key for the Aleo testnet2 network.
+#[wasm_bindgen]
+pub struct ViewKey {
+    pub(crate) view_key: snarkvm_dpc::ViewKey<Testnet2>,
+}
+
+#[wasm_bindgen]
+impl ViewKey {
+    /// Derives the account view key from an account private key.
+    #[wasm_bindgen(js_name = fromPrivateKey)]
+    pub fn from_private_key(private_key: &PrivateKey) -> Self {
+        Self {
+            view_key: snarkvm_dpc::ViewKey::from_private_key(&private_key.private_key),
+        }
+    }
+
+    /// Parses an account view key string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(view_key: &str) -> Result<ViewKey, JsValue> {
+        Ok(Self {
+            view_key: snarkvm_dpc::ViewKey::from_str(view_key).map_err(to_js_error)?,
+        })
+    }
+
+    /// Returns the account view key string.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.view_key.to_string()
+    }
+
+    /// Returns the account address.
+    #[wasm_bindgen(js_name = toAddress)]
+    pub fn to_address(&self) -> Address {
+        Address::from_view_key(self)
+    }
+}
+
+/// An account address for the Aleo testnet2 network.
+#[wasm_bindgen]
+pub struct Address {
+    pub(crate) address: snarkvm_dpc::Address<Testnet2>,
+}
+
+#[wasm_bindgen]
+impl Address {
+    /// Derives the account address from an account private key.
+    #[wasm_bindgen(js_name = fromPrivateKey)]
+    pub fn from_private_key(private_key: &PrivateKey) -> Self {
+        Self {
+            address: private_key.private_key.to_address(),
+        }
+    }
+
+    /// Derives the account address from an account view key.
+    #[wasm_bindgen(js_name = fromViewKey)]
+    pub fn from_view_key(view_key: &ViewKey) -> Self {
+        Self {
+            address: snarkvm_dpc::Address::from_view_key(&view_key.view_key),
+        }
+    }
+
+    /// Parses an account address string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(address: &str) -> Result<Address, JsValue> {
+        Ok(Self {
+            address: snarkvm_dpc::Address::from_str(address).map_err(to_js_error)?,
+        })
+    }
+
+    /// Returns the account address string.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.address.to_string()
+    }
+
+    /// Returns `true` if the given signature string is valid for the given message.
+    pub fn verify(&self, message: &[u8], signature: &str) -> Result<bool, JsValue> {
+        let signature = <Testnet2 as Network>::AccountSignature::from_str(signature)
+            .map_err(|_| to_js_error(format!("Invalid signature {}", signature)))?;
+        self.address.verify_signature(message, &signature).map_err(to_js_error)
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_account() {
+        let private_key = PrivateKey::new();
+        let view_key = private_key.to_view_key();
+        let address = private_key.to_address();
+
+        assert_eq!(address.to_string(), view_key.to_address().to_string());
+        assert_eq!(
+            private_key.to_string(),
+            PrivateKey::from_string(&private_key.to_string()).unwrap().to_string()
+        );
+        assert_eq!(
+            view_key.to_string(),
+            ViewKey::from_string(&view_key.to_string()).unwrap().to_string()
+        );
+        assert_eq!(
+            address.to_string(),
+            Address::from_string(&address.to_string()).unwrap().to_string()
+        );
+        assert!(Address::from_string("aleo1invalid").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_sign_and_verify() {
+        let private_key = PrivateKey::new();
+        let address = private_key.to_address();
+
+        let signature = private_key.sign(b"message").unwrap();
+        assert!(address.verify(b"message", &signature).unwrap());
+        assert!(!address.verify(b"Message", &signature).unwrap());
+        assert!(!PrivateKey::new().to_address().verify(b"message", &signature).unwrap());
+    }
+}
@@ -0,0 +1,152 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::bindings::{to_js_error, Address, ViewKey};
+use snarkvm_dpc::testnet2::Testnet2;
+
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+/// An encrypted record for the Aleo testnet2 network.
+#[wasm_bindgen]
+pub struct RecordCiphertext {
+    pub(crate) ciphertext: snarkvm_dpc::RecordCiphertext<Testnet2>,
+}
+
+#[wasm_bindgen]
+impl RecordCiphertext {
+    /// Parses a hex-encoded record ciphertext.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(ciphertext: &str) -> Result<RecordCiphertext, JsValue> {
+        Ok(Self {
+            ciphertext: snarkvm_dpc::RecordCiphertext::from_str(ciphertext).map_err(to_js_error)?,
+        })
+    }
+
+    /// Returns the hex-encoded record ciphertext.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.ciphertext.to_string()
+    }
+
+    /// Decrypts the record ciphertext with the view key of the record owner.
+    pub fn decrypt(&self, view_key: &ViewKey) -> Result<Record, JsValue> {
+        Ok(Record {
+            record: self.ciphertext.decrypt(&view_key.view_key).map_err(to_js_error)?,
+        })
+    }
+}
+
+/// A decrypted record for the Aleo testnet2 network.
+#[wasm_bindgen]
+pub struct Record {
+    pub(crate) record: snarkvm_dpc::Record<Testnet2>,
+}
+
+#[wasm_bindgen]
+impl Record {
+    /// Parses a JSON-encoded record.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(record: &str) -> Result<Record, JsValue> {
+        Ok(Self {
+            record: snarkvm_dpc::Record::from_str(record).map_err(to_js_error)?,
+        })
+    }
+
+    /// Returns the JSON-encoded record.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.record.to_string()
+    }
+
+    /// Returns the record owner.
+    pub fn owner(&self) -> Address {
+        Address {
+            address: self.record.owner(),
+        }
+    }
+
+    /// Returns the record value.
+    pub fn value(&self) -> u64 {
+        self.record.value()
+    }
+
+    /// Returns the hex-encoded record payload.
+    pub fn payload(&self) -> String {
+        self.record.payload().to_string()
+    }
+
+    /// Returns the program ID of the record.
+    #[wasm_bindgen(js_name = programId)]
+    pub fn program_id(&self) -> String {
+        self.record.program_id().to_string()
+    }
+
+    /// Returns the record commitment.
+    pub fn commitment(&self) -> String {
+        self.record.commitment().to_string()
+    }
+
+    /// Returns `true` if the record is a noop record.
+    #[wasm_bindgen(js_name = isDummy)]
+    pub fn is_dummy(&self) -> bool {
+        self.record.is_dummy()
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use super::*;
+    use crate::bindings::PrivateKey;
+    use snarkvm_dpc::{Network, Payload};
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_decrypt() {
+        let rng = &mut thread_rng();
+        let private_key = PrivateKey::new();
+        let view_key = private_key.to_view_key();
+
+        let expected_record = snarkvm_dpc::Record::new_output(
+            private_key.to_address().address,
+            1234,
+            Payload::default(),
+            *Testnet2::noop_program_id(),
+            UniformRand::rand(rng),
+            rng,
+        )
+        .unwrap();
+        let (ciphertext, _) = snarkvm_dpc::RecordCiphertext::encrypt(&expected_record, rng).unwrap();
+
+        let ciphertext = RecordCiphertext::from_string(&ciphertext.to_string()).unwrap();
+        let record = ciphertext.decrypt(&view_key).unwrap();
+        assert_eq!(expected_record, record.record);
+        assert_eq!(1234, record.value());
+        assert_eq!(private_key.to_address().to_string(), record.owner().to_string());
+        assert_eq!(
+            record.to_string(),
+            Record::from_string(&record.to_string()).unwrap().to_string()
+        );
+
+        // Ensure a different view key does not recover the record.
+        assert!(ciphertext.decrypt(&PrivateKey::new().to_view_key()).is_err());
+    }
+}
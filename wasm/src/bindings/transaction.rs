@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::bindings::{to_js_error, Record, ViewKey};
+use snarkvm_dpc::testnet2::Testnet2;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+/// A transaction for the Aleo testnet2 network.
+#[wasm_bindgen]
+pub struct Transaction {
+    pub(crate) transaction: snarkvm_dpc::Transaction<Testnet2>,
+}
+
+#[wasm_bindgen]
+impl Transaction {
+    /// Parses a JSON-encoded transaction.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(transaction: &str) -> Result<Transaction, JsValue> {
+        Ok(Self {
+            transaction: snarkvm_dpc::Transaction::from_str(transaction).map_err(to_js_error)?,
+        })
+    }
+
+    /// Parses a hex-encoded transaction.
+    #[wasm_bindgen(js_name = fromHex)]
+    pub fn from_hex(transaction: &str) -> Result<Transaction, JsValue> {
+        let bytes = hex::decode(transaction).map_err(to_js_error)?;
+        Ok(Self {
+            transaction: snarkvm_dpc::Transaction::from_bytes_le(&bytes).map_err(to_js_error)?,
+        })
+    }
+
+    /// Returns the JSON-encoded transaction.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.transaction.to_string()
+    }
+
+    /// Returns the hex-encoded transaction.
+    #[wasm_bindgen(js_name = toHex)]
+    pub fn to_hex(&self) -> Result<String, JsValue> {
+        Ok(hex::encode(self.transaction.to_bytes_le().map_err(to_js_error)?))
+    }
+
+    /// Returns the transaction ID.
+    #[wasm_bindgen(js_name = transactionId)]
+    pub fn transaction_id(&self) -> String {
+        self.transaction.transaction_id().to_string()
+    }
+
+    /// Returns the ledger root used to construct the transaction.
+    #[wasm_bindgen(js_name = ledgerRoot)]
+    pub fn ledger_root(&self) -> String {
+        self.transaction.ledger_root().to_string()
+    }
+
+    /// Returns the value balance of the transaction, in gates.
+    #[wasm_bindgen(js_name = valueBalance)]
+    pub fn value_balance(&self) -> i64 {
+        self.transaction.value_balance().0
+    }
+
+    /// Returns the serial numbers of the transaction, as an array of strings.
+    #[wasm_bindgen(js_name = serialNumbers)]
+    pub fn serial_numbers(&self) -> Result<JsValue, JsValue> {
+        JsValue::from_serde(&self.transaction.serial_numbers().collect::<Vec<_>>()).map_err(to_js_error)
+    }
+
+    /// Returns the commitments of the transaction, as an array of strings.
+    pub fn commitments(&self) -> Result<JsValue, JsValue> {
+        JsValue::from_serde(&self.transaction.commitments().collect::<Vec<_>>()).map_err(to_js_error)
+    }
+
+    /// Returns the hex-encoded record ciphertexts of the transaction, as an array of strings.
+    pub fn ciphertexts(&self) -> Result<JsValue, JsValue> {
+        JsValue::from_serde(&self.transaction.ciphertexts().collect::<Vec<_>>()).map_err(to_js_error)
+    }
+
+    /// Returns the records in the transaction that belong to the given account view key.
+    #[wasm_bindgen(js_name = toDecryptedRecords)]
+    pub fn to_decrypted_records(&self, view_key: &ViewKey) -> Box<[JsValue]> {
+        self.transaction
+            .to_decrypted_records(&view_key.view_key)
+            .into_iter()
+            .map(|record| JsValue::from(Record { record }))
+            .collect()
+    }
+
+    /// Returns `true` if the transaction and its transitions are valid.
+    #[wasm_bindgen(js_name = isValid)]
+    pub fn is_valid(&self) -> bool {
+        self.transaction.is_valid()
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use super::*;
+    use crate::bindings::PrivateKey;
+    use snarkvm_dpc::Network;
+
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_parse() {
+        let expected_transaction = Testnet2::genesis_block().to_coinbase_transaction().unwrap();
+
+        let transaction = Transaction::from_string(&expected_transaction.to_string()).unwrap();
+        assert_eq!(expected_transaction, transaction.transaction);
+        assert_eq!(
+            expected_transaction.transaction_id().to_string(),
+            transaction.transaction_id()
+        );
+        assert_eq!(expected_transaction.value_balance().0, transaction.value_balance());
+
+        let candidate = Transaction::from_hex(&transaction.to_hex().unwrap()).unwrap();
+        assert_eq!(expected_transaction, candidate.transaction);
+
+        assert!(Transaction::from_string("{}").is_err());
+        assert!(Transaction::from_hex("00").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_to_decrypted_records() {
+        let transaction = Transaction {
+            transaction: Testnet2::genesis_block().to_coinbase_transaction().unwrap(),
+        };
+
+        // Ensure a new account does not own any records in the genesis transaction.
+        assert!(
+            transaction
+                .to_decrypted_records(&PrivateKey::new().to_view_key())
+                .is_empty()
+        );
+    }
+}